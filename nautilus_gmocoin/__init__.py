@@ -3,6 +3,13 @@ try:
 except ImportError:
     import _nautilus_gmocoin as gmocoin
 
+import atexit
+
+configure_logging = gmocoin.configure_logging
+
+# Stop the WebSocket loops and pending requests before the interpreter exits
+atexit.register(gmocoin.shutdown)
+
 from .config import GmocoinDataClientConfig, GmocoinExecClientConfig
 from .constants import (
     GMOCOIN_VENUE,
@@ -27,6 +34,7 @@ from .types import (
 __all__ = [
     # Rust types
     "gmocoin",
+    "configure_logging",
     # Config
     "GmocoinDataClientConfig",
     "GmocoinExecClientConfig",
@@ -0,0 +1,409 @@
+# Generated by scripts/generate_stubs.py from the `_nautilus_gmocoin` extension. Do not edit.
+
+import builtins
+import collections.abc
+import types
+import typing
+
+@typing.final
+class ArrowRecordBatch:
+    @property
+    def column_names(self) -> builtins.list[builtins.str]: ...
+    @property
+    def num_rows(self) -> builtins.int: ...
+    def __arrow_c_array__(self, requested_schema: typing.Any | None = None) -> builtins.tuple[types.CapsuleType, types.CapsuleType]: ...
+    def __arrow_c_schema__(self) -> types.CapsuleType: ...
+    def __arrow_c_stream__(self, requested_schema: typing.Any | None = None) -> types.CapsuleType: ...
+    def __len__(self) -> builtins.int: ...
+    def __repr__(self) -> builtins.str: ...
+
+@typing.final
+class BestBidOffer:
+    @property
+    def ask_price(self) -> builtins.str | None: ...
+    @property
+    def ask_size(self) -> builtins.str | None: ...
+    @property
+    def bid_price(self) -> builtins.str | None: ...
+    @property
+    def bid_size(self) -> builtins.str | None: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+
+@typing.final
+class BookDelta:
+    @property
+    def asks(self) -> builtins.list[DepthEntry]: ...
+    @property
+    def bids(self) -> builtins.list[DepthEntry]: ...
+    @property
+    def snapshot(self) -> builtins.bool: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+
+class CircuitOpenError(builtins.ConnectionError): ...
+
+@typing.final
+class Depth:
+    def __new__(cls, asks: collections.abc.Sequence[DepthEntry], bids: collections.abc.Sequence[DepthEntry], symbol: builtins.str, timestamp: builtins.str) -> Depth: ...
+    @property
+    def asks(self) -> builtins.list[DepthEntry]: ...
+    @property
+    def bids(self) -> builtins.list[DepthEntry]: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+
+@typing.final
+class DepthEntry:
+    def __new__(cls, price: builtins.str, size: builtins.str) -> DepthEntry: ...
+    @property
+    def price(self) -> builtins.str: ...
+    @property
+    def size(self) -> builtins.str: ...
+
+@typing.final
+class FeeModel:
+    def __new__(cls, symbols: typing.Any, trading_volume: typing.Any | None = None) -> FeeModel: ...
+    @property
+    def tier_level(self) -> builtins.int | None: ...
+    def fill(self, execution: typing.Any) -> typing.Any: ...
+    def quote(self, symbol: builtins.str, liquidity_side: builtins.str, price: typing.Any, size: typing.Any) -> typing.Any: ...
+    def rates(self, symbol: builtins.str) -> typing.Any | None: ...
+
+@typing.final
+class GmocoinDataClient:
+    def __new__(cls, ws_rate_limit_per_sec: builtins.float | None, tls_ca_file: builtins.str | None = None, tls_native_roots: builtins.bool | None = None, tls_min_version: builtins.str | None = None, venue_profile: str | collections.abc.Mapping[str, str] | None = None, user_agent: builtins.str | None = None, extra_headers: builtins.dict[builtins.str, builtins.str] | None = None, callback_payload: str | None = None, maintenance_windows: collections.abc.Sequence[builtins.str] | None = None, maintenance_status_check: builtins.bool | None = None, reconnect_initial_ms: builtins.int | None = None, reconnect_max_ms: builtins.int | None = None, reconnect_multiplier: builtins.float | None = None, reconnect_jitter: builtins.float | None = None, stale_timeout_ms: builtins.int | None = None, instrument_refresh_ms: builtins.int | None = None, event_gap_alarm_ms: builtins.int | None = None, callback_symbols: collections.abc.Sequence[builtins.str] | None = None, callback_channels: collections.abc.Sequence[builtins.str] | None = None, ticker_max_rate: builtins.float | None = None, book_interval_ms: builtins.int | None = None) -> GmocoinDataClient: ...
+    @property
+    def calendar(self) -> TradingCalendar: ...
+    def __aenter__(self) -> collections.abc.Awaitable[typing.Self]: ...
+    def __aexit__(self, _exc_type: typing.Any, _exc_value: typing.Any, _traceback: typing.Any) -> collections.abc.Awaitable[bool]: ...
+    def close(self, timeout_ms: builtins.int | None = None) -> collections.abc.Awaitable[bool]: ...
+    def connect(self) -> collections.abc.Awaitable[str]: ...
+    def disconnect(self) -> collections.abc.Awaitable[str]: ...
+    def get_stats(self) -> typing.Any: ...
+    def health(self) -> typing.Any: ...
+    def set_callback_error_handler(self, handler: typing.Any | None = None) -> None: ...
+    def set_callback_filter(self, symbols: collections.abc.Sequence[builtins.str] | None = None, channels: collections.abc.Sequence[builtins.str] | None = None) -> None: ...
+    def set_data_callback(self, callback: typing.Any) -> None: ...
+    def set_subscriptions(self, target: collections.abc.Sequence[typing.Any]) -> collections.abc.Awaitable[dict[str, list[list[str | None]]]]: ...
+    def subscribe(self, channel: builtins.str, symbol: builtins.str, option: builtins.str | None = None, backfill: builtins.int | None = None, book_mode: str | None = None) -> collections.abc.Awaitable[str]: ...
+    def subscribe_all(self, channel: builtins.str, option: builtins.str | None = None) -> collections.abc.Awaitable[list[str]]: ...
+    def unsubscribe(self, channel: builtins.str, symbol: builtins.str) -> collections.abc.Awaitable[str]: ...
+
+@typing.final
+class GmocoinExecutionClient:
+    def __new__(cls, api_key: builtins.str, api_secret: builtins.str, timeout_ms: builtins.int, proxy_url: builtins.str | None, rate_limit: str | float | None, circuit_breaker_threshold: builtins.int | None = None, circuit_breaker_cooldown_ms: builtins.int | None = None, group_rate_limits: builtins.dict[builtins.str, builtins.float] | None = None, share_rate_limiter: builtins.bool | None = None, tls_ca_file: builtins.str | None = None, tls_native_roots: builtins.bool | None = None, tls_min_version: builtins.str | None = None, pool_max_idle_per_host: builtins.int | None = None, pool_idle_timeout_ms: builtins.int | None = None, prefer_http2: builtins.bool | None = None, tcp_keepalive_ms: builtins.int | None = None, venue_profile: str | collections.abc.Mapping[str, str] | None = None, user_agent: builtins.str | None = None, extra_headers: builtins.dict[builtins.str, builtins.str] | None = None, callback_payload: str | None = None, maintenance_windows: collections.abc.Sequence[builtins.str] | None = None, maintenance_status_check: builtins.bool | None = None, reconnect_initial_ms: builtins.int | None = None, reconnect_max_ms: builtins.int | None = None, reconnect_multiplier: builtins.float | None = None, reconnect_jitter: builtins.float | None = None, stale_timeout_ms: builtins.int | None = None, rollover_lead_ms: builtins.int | None = None, leverage_fee_rate: builtins.float | None = None, requote_reserve: builtins.float | None = None, deadman_timeout_ms: builtins.int | None = None, deadman_flatten: builtins.bool | None = None, position_refresh_ms: builtins.int | None = None, fallback_after_ms: builtins.int | None = None, fallback_interval_ms: builtins.int | None = None, order_ack_alarm_ms: builtins.int | None = None, event_gap_alarm_ms: builtins.int | None = None, max_in_flight: builtins.int | None = None, symbols_ttl_ms: builtins.int | None = None, strict_sizes: builtins.bool | None = None, account_label: builtins.str | None = None, credentials: builtins.str | None = None, passphrase: builtins.str | None = None) -> GmocoinExecutionClient: ...
+    def __aenter__(self) -> collections.abc.Awaitable[typing.Self]: ...
+    def __aexit__(self, _exc_type: typing.Any, _exc_value: typing.Any, _traceback: typing.Any) -> collections.abc.Awaitable[bool]: ...
+    def account_label(self) -> builtins.str: ...
+    def account_names(self) -> builtins.list[builtins.str]: ...
+    def add_account(self, name: builtins.str, api_key: builtins.str, api_secret: builtins.str, rate_limit: str | float | None = None, group_rate_limits: builtins.dict[builtins.str, builtins.float] | None = None, credentials: builtins.str | None = None, passphrase: builtins.str | None = None) -> None: ...
+    def amend_order(self, order_id: builtins.str, price: builtins.str | None = None, size: builtins.str | None = None, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def amend_price(self, order_id: builtins.str, price: builtins.str, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def attach_market_data(self, data_client: GmocoinDataClient | None = None) -> None: ...
+    def cancel_algo(self, algo_id: builtins.int) -> builtins.bool: ...
+    def cancel_order(self, _symbol: builtins.str, order_id: builtins.str, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def cancel_orders(self, order_ids: collections.abc.Sequence[builtins.str], deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def change_losscut_price(self, position_id: builtins.int, losscut_price: builtins.str, account: builtins.str | None = None, symbol: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def change_order(self, order_id: builtins.str, price: builtins.str, losscut_price: builtins.str | None = None, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def clear_execution_quality(self, account: builtins.str | None = None) -> None: ...
+    def close(self, timeout_ms: builtins.int | None = None) -> collections.abc.Awaitable[bool]: ...
+    def close_bulk_order(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, client_order_id: builtins.str | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def close_order(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, settle_position: collections.abc.Sequence[builtins.tuple[builtins.int, builtins.str]], price: builtins.str | None = None, time_in_force: builtins.str | None = None, client_order_id: builtins.str | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def connect(self) -> collections.abc.Awaitable[str]: ...
+    def disconnect(self) -> collections.abc.Awaitable[str]: ...
+    def export_executions(self, path: builtins.str, symbols: collections.abc.Sequence[builtins.str], start: builtins.int | None = None, end: builtins.int | None = None, format: builtins.str = csv, account: builtins.str | None = None) -> collections.abc.Awaitable[int]: ...
+    def export_state(self, account: builtins.str | None = None) -> typing.Any: ...
+    def forget_submission(self, client_order_id: builtins.str, account: builtins.str | None = None) -> None: ...
+    def get_active_orders(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_algo(self, algo_id: builtins.int) -> typing.Any | None: ...
+    def get_assets_py(self, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_execution_history_arrow(self, symbols: collections.abc.Sequence[builtins.str], start: builtins.int | None = None, end: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_execution_quality(self, order_id: builtins.int | None = None, account: builtins.str | None = None) -> typing.Any | None: ...
+    def get_executions(self, order_id: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_latest_executions(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_latest_executions_arrow(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_margin_py(self, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_open_order_exposure(self, symbol: builtins.str | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_open_positions(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_order(self, order_id: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_order_history(self, symbol: builtins.str, start: builtins.int | None = None, end: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_position(self, symbol: builtins.str, side: builtins.str | None = None, account: builtins.str | None = None) -> PositionSnapshot | None: ...
+    def get_position_summary(self, symbol: builtins.str | None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_positions(self, account: builtins.str | None = None) -> builtins.list[PositionSnapshot]: ...
+    def get_stats(self) -> typing.Any: ...
+    def health(self) -> typing.Any: ...
+    def heartbeat(self) -> None: ...
+    def import_state(self, state: typing.Any, account: builtins.str | None = None) -> typing.Any: ...
+    def pause_replay(self) -> None: ...
+    def record_session(self, path: builtins.str | None = None, account: builtins.str | None = None) -> None: ...
+    def refresh_positions(self, account: builtins.str | None = None) -> collections.abc.Awaitable[None]: ...
+    def replay_position(self) -> typing.Any: ...
+    def replay_session(self, path: builtins.str, speed: builtins.float | None = None) -> collections.abc.Awaitable[int]: ...
+    def requote(self, order_id: builtins.str, price: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def resume_replay(self) -> None: ...
+    def seek_replay(self, ts_ms: builtins.int) -> None: ...
+    def set_callback_error_handler(self, handler: typing.Any | None = None) -> None: ...
+    def set_order_callback(self, callback: typing.Any) -> None: ...
+    def set_rate_limit(self, group: builtins.str, per_sec: builtins.float, account: builtins.str | None = None) -> None: ...
+    def set_replay_speed(self, speed: builtins.float | None = None) -> None: ...
+    def start_twap(self, symbol: builtins.str, side: builtins.str, quantity: builtins.str, duration_ms: builtins.int, slices: builtins.int, price: builtins.str | None = None, size_step: builtins.str | None = None, participation_cap: builtins.str | None = None, settle_type: builtins.str | None = None, account: builtins.str | None = None) -> builtins.int: ...
+    def submit_order(self, symbol: builtins.str, amount: builtins.str, side: builtins.str, execution_type: builtins.str, client_order_id: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, cancel_before: builtins.bool | None = None, losscut_price: builtins.str | None = None, settle_type: builtins.str | None = None, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, arrival_bid: builtins.str | None = None, arrival_ask: builtins.str | None = None, split: builtins.bool = False, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def venue_order_id(self, client_order_id: builtins.str, account: builtins.str | None = None) -> builtins.int | None: ...
+
+class GmocoinRestClient:
+    def __new__(cls, api_key: builtins.str, api_secret: builtins.str, timeout_ms: builtins.int, proxy_url: builtins.str | None, rate_limit: str | float | None, circuit_breaker_threshold: builtins.int | None = None, circuit_breaker_cooldown_ms: builtins.int | None = None, group_rate_limits: builtins.dict[builtins.str, builtins.float] | None = None, share_rate_limiter: builtins.bool | None = None, tls_ca_file: builtins.str | None = None, tls_native_roots: builtins.bool | None = None, tls_min_version: builtins.str | None = None, pool_max_idle_per_host: builtins.int | None = None, pool_idle_timeout_ms: builtins.int | None = None, prefer_http2: builtins.bool | None = None, tcp_keepalive_ms: builtins.int | None = None, venue_profile: str | collections.abc.Mapping[str, str] | None = None, user_agent: builtins.str | None = None, extra_headers: builtins.dict[builtins.str, builtins.str] | None = None, max_in_flight: builtins.int | None = None, symbols_ttl_ms: builtins.int | None = None, strict_sizes: builtins.bool | None = None, credentials: builtins.str | None = None, passphrase: builtins.str | None = None) -> GmocoinRestClient: ...
+    def close(self) -> None: ...
+    def delete_ws_auth_py(self, token: builtins.str) -> collections.abc.Awaitable[str]: ...
+    def get_active_orders_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_assets_py(self) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_executions_py(self, order_id: builtins.str) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_klines_arrow(self, symbol: builtins.str, interval: builtins.str, date: builtins.str, max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_klines_py(self, symbol: builtins.str, interval: builtins.str, date: builtins.str, max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_klines_range(self, symbol: builtins.str, interval: builtins.str, start: builtins.int, end: builtins.int, max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_klines_range_arrow(self, symbol: builtins.str, interval: builtins.str, start: builtins.int, end: builtins.int, max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_latest_executions_arrow(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_latest_executions_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_margin_py(self) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_open_positions_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_order_py(self, order_id: builtins.str) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_orderbooks_py(self, symbol: builtins.str) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_position_summary_py(self, symbol: builtins.str | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_stats(self) -> typing.Any: ...
+    def get_status_py(self) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_symbols_py(self, force_refresh: builtins.bool = False) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_ticker_py(self, symbol: builtins.str | None = None, max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_tickers(self, symbols: collections.abc.Sequence[builtins.str], max_wait_ms: builtins.int | None = None) -> collections.abc.Awaitable[dict[str, Ticker]]: ...
+    def get_trades_arrow(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None) -> collections.abc.Awaitable[ArrowRecordBatch]: ...
+    def get_trades_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_trading_volume_py(self) -> collections.abc.Awaitable[typing.Any]: ...
+    def health(self) -> typing.Any: ...
+    def post_cancel_bulk_order_py(self, symbols: collections.abc.Sequence[builtins.str], side: builtins.str | None) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_cancel_order_py(self, order_id: builtins.str) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_cancel_orders_py(self, order_ids: collections.abc.Sequence[builtins.int]) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_change_order_py(self, order_id: builtins.str, price: builtins.str, losscut_price: builtins.str | None = None, symbol: builtins.str | None = None, side: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_close_bulk_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_close_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, settle_position: collections.abc.Sequence[builtins.tuple[builtins.int, builtins.str]], price: builtins.str | None = None, time_in_force: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, cancel_before: builtins.bool | None = None, losscut_price: builtins.str | None = None, settle_type: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def post_ws_auth_py(self) -> collections.abc.Awaitable[typing.Any]: ...
+    def put_losscut_price_py(self, position_id: builtins.int, losscut_price: builtins.str, symbol: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def put_ws_auth_py(self, token: builtins.str) -> collections.abc.Awaitable[str]: ...
+    def set_rate_limit(self, group: builtins.str, per_sec: builtins.float) -> None: ...
+    def with_deadline(self, deadline_ms: builtins.int | None = None) -> GmocoinRestClient: ...
+    def with_timeout(self, timeout_ms: builtins.int | None = None) -> GmocoinRestClient: ...
+
+@typing.final
+class GmocoinSimExecutionClient:
+    def __new__(cls, data_client: GmocoinDataClient, latency_ms: builtins.int | None = None, latency_jitter_ms: builtins.int | None = None, maker_fee: builtins.float | None = None, taker_fee: builtins.float | None = None, initial_balances: builtins.dict[builtins.str, builtins.str] | None = None, callback_payload: str | None = None) -> GmocoinSimExecutionClient: ...
+    def __aenter__(self) -> collections.abc.Awaitable[typing.Self]: ...
+    def __aexit__(self, _exc_type: typing.Any, _exc_value: typing.Any, _traceback: typing.Any) -> collections.abc.Awaitable[bool]: ...
+    def cancel_order(self, _symbol: builtins.str, order_id: builtins.str, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def cancel_orders(self, order_ids: collections.abc.Sequence[builtins.str], deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def change_losscut_price(self, position_id: builtins.int, losscut_price: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def change_order(self, order_id: builtins.str, price: builtins.str, losscut_price: builtins.str | None = None, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def close(self, timeout_ms: builtins.int | None = None) -> collections.abc.Awaitable[bool]: ...
+    def close_bulk_order(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, client_order_id: builtins.str | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def close_order(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, settle_position: collections.abc.Sequence[builtins.tuple[builtins.int, builtins.str]], price: builtins.str | None = None, time_in_force: builtins.str | None = None, client_order_id: builtins.str | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def connect(self) -> collections.abc.Awaitable[str]: ...
+    def disconnect(self) -> collections.abc.Awaitable[str]: ...
+    def get_active_orders(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_assets_py(self, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_executions(self, order_id: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_latest_executions(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_margin_py(self, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_open_positions(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_order(self, order_id: builtins.str, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_position_summary(self, symbol: builtins.str | None, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+    def get_stats(self) -> typing.Any: ...
+    def set_callback_error_handler(self, handler: typing.Any | None = None) -> None: ...
+    def set_order_callback(self, callback: typing.Any) -> None: ...
+    def set_rate_limit(self, group: builtins.str, _per_sec: builtins.float) -> None: ...
+    def submit_order(self, symbol: builtins.str, amount: builtins.str, side: builtins.str, execution_type: builtins.str, client_order_id: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, cancel_before: builtins.bool | None = None, losscut_price: builtins.str | None = None, settle_type: builtins.str | None = None, deadline_ms: builtins.int | None = None, timeout_ms: builtins.int | None = None, arrival_bid: builtins.str | None = None, arrival_ask: builtins.str | None = None, split: builtins.bool = False, account: builtins.str | None = None) -> collections.abc.Awaitable[typing.Any]: ...
+
+@typing.final
+class OrderBook:
+    def __new__(cls, symbol: builtins.str) -> OrderBook: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+    def apply_snapshot(self, depth: Depth) -> None: ...
+    def get_asks(self) -> builtins.list[builtins.tuple[builtins.str, builtins.str]]: ...
+    def get_bids(self) -> builtins.list[builtins.tuple[builtins.str, builtins.str]]: ...
+    def get_top_n(self, n: builtins.int) -> builtins.tuple[builtins.list[builtins.tuple[builtins.str, builtins.str]], builtins.list[builtins.tuple[builtins.str, builtins.str]]]: ...
+
+class OrderTooLargeError(builtins.ValueError): ...
+
+@typing.final
+class PositionSnapshot:
+    @property
+    def avg_price(self) -> builtins.str: ...
+    @property
+    def loss_gain(self) -> builtins.str: ...
+    @property
+    def order_size(self) -> builtins.str | None: ...
+    @property
+    def side(self) -> builtins.str: ...
+    @property
+    def size(self) -> builtins.str: ...
+    @property
+    def source(self) -> builtins.str: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def updated_at(self) -> builtins.int: ...
+    def __repr__(self) -> builtins.str: ...
+
+@typing.final
+class SymbolInfo:
+    def __new__(cls, symbol: builtins.str) -> SymbolInfo: ...
+    @property
+    def maker_fee(self) -> builtins.str | None: ...
+    @property
+    def max_order_size(self) -> builtins.str | None: ...
+    @property
+    def min_close_order_size(self) -> builtins.str | None: ...
+    @property
+    def min_order_size(self) -> builtins.str | None: ...
+    @property
+    def size_step(self) -> builtins.str | None: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def taker_fee(self) -> builtins.str | None: ...
+    @property
+    def tick_size(self) -> builtins.str | None: ...
+
+@typing.final
+class SyncGmocoinRestClient(GmocoinRestClient):
+    def __new__(cls, *args, **kwargs) -> SyncGmocoinRestClient: ...
+    def delete_ws_auth_py(self, token: builtins.str) -> str: ...
+    def get_active_orders_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> typing.Any: ...
+    def get_assets_py(self) -> typing.Any: ...
+    def get_executions_py(self, order_id: builtins.str) -> typing.Any: ...
+    def get_klines_arrow(self, symbol: builtins.str, interval: builtins.str, date: builtins.str, max_wait_ms: builtins.int | None = None) -> ArrowRecordBatch: ...
+    def get_klines_py(self, symbol: builtins.str, interval: builtins.str, date: builtins.str, max_wait_ms: builtins.int | None = None) -> typing.Any: ...
+    def get_klines_range(self, symbol: builtins.str, interval: builtins.str, start: builtins.int, end: builtins.int, max_wait_ms: builtins.int | None = None) -> typing.Any: ...
+    def get_klines_range_arrow(self, symbol: builtins.str, interval: builtins.str, start: builtins.int, end: builtins.int, max_wait_ms: builtins.int | None = None) -> ArrowRecordBatch: ...
+    def get_latest_executions_arrow(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None) -> ArrowRecordBatch: ...
+    def get_latest_executions_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> typing.Any: ...
+    def get_margin_py(self) -> typing.Any: ...
+    def get_open_positions_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> typing.Any: ...
+    def get_order_py(self, order_id: builtins.str) -> typing.Any: ...
+    def get_orderbooks_py(self, symbol: builtins.str) -> typing.Any: ...
+    def get_position_summary_py(self, symbol: builtins.str | None) -> typing.Any: ...
+    def get_status_py(self) -> typing.Any: ...
+    def get_symbols_py(self, force_refresh: builtins.bool = False) -> typing.Any: ...
+    def get_ticker_py(self, symbol: builtins.str | None = None, max_wait_ms: builtins.int | None = None) -> typing.Any: ...
+    def get_tickers(self, symbols: collections.abc.Sequence[builtins.str], max_wait_ms: builtins.int | None = None) -> dict[str, Ticker]: ...
+    def get_trades_arrow(self, symbol: builtins.str, page: builtins.int | None = None, count: builtins.int | None = None) -> ArrowRecordBatch: ...
+    def get_trades_py(self, symbol: builtins.str, page: builtins.int | None, count: builtins.int | None) -> typing.Any: ...
+    def get_trading_volume_py(self) -> typing.Any: ...
+    def post_cancel_bulk_order_py(self, symbols: collections.abc.Sequence[builtins.str], side: builtins.str | None) -> typing.Any: ...
+    def post_cancel_order_py(self, order_id: builtins.str) -> typing.Any: ...
+    def post_cancel_orders_py(self, order_ids: collections.abc.Sequence[builtins.int]) -> typing.Any: ...
+    def post_change_order_py(self, order_id: builtins.str, price: builtins.str, losscut_price: builtins.str | None = None, symbol: builtins.str | None = None, side: builtins.str | None = None) -> typing.Any: ...
+    def post_close_bulk_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None) -> typing.Any: ...
+    def post_close_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, settle_position: collections.abc.Sequence[builtins.tuple[builtins.int, builtins.str]], price: builtins.str | None = None, time_in_force: builtins.str | None = None) -> typing.Any: ...
+    def post_order_py(self, symbol: builtins.str, side: builtins.str, execution_type: builtins.str, size: builtins.str, price: builtins.str | None = None, time_in_force: builtins.str | None = None, cancel_before: builtins.bool | None = None, losscut_price: builtins.str | None = None, settle_type: builtins.str | None = None) -> typing.Any: ...
+    def post_ws_auth_py(self) -> typing.Any: ...
+    def put_losscut_price_py(self, position_id: builtins.int, losscut_price: builtins.str, symbol: builtins.str | None = None) -> typing.Any: ...
+    def put_ws_auth_py(self, token: builtins.str) -> str: ...
+
+@typing.final
+class Ticker:
+    def __new__(cls, ask: builtins.str, bid: builtins.str, high: builtins.str, low: builtins.str, last: builtins.str, symbol: builtins.str, timestamp: builtins.str, volume: builtins.str) -> Ticker: ...
+    @property
+    def ask(self) -> builtins.str: ...
+    @property
+    def bid(self) -> builtins.str: ...
+    @property
+    def high(self) -> builtins.str: ...
+    @property
+    def last(self) -> builtins.str: ...
+    @property
+    def low(self) -> builtins.str: ...
+    @property
+    def symbol(self) -> builtins.str: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+    @property
+    def volume(self) -> builtins.str: ...
+
+@typing.final
+class Trade:
+    def __new__(cls, price: builtins.str, side: builtins.str, size: builtins.str, timestamp: builtins.str, symbol: builtins.str | None) -> Trade: ...
+    @property
+    def historical(self) -> builtins.bool: ...
+    @property
+    def price(self) -> builtins.str: ...
+    @property
+    def side(self) -> builtins.str: ...
+    @property
+    def size(self) -> builtins.str: ...
+    @property
+    def symbol(self) -> builtins.str | None: ...
+    @property
+    def timestamp(self) -> builtins.str: ...
+    @property
+    def ts_event(self) -> builtins.int | None: ...
+
+@typing.final
+class TradingCalendar:
+    def __new__(cls, maintenance_windows: collections.abc.Sequence[builtins.str] | None = None, rollover: builtins.str | None = None) -> TradingCalendar: ...
+    def __eq__(self, other: TradingCalendar) -> builtins.bool: ...
+    def __ne__(self, other: TradingCalendar) -> builtins.bool: ...
+    def is_open(self, ts: builtins.int | None = None) -> builtins.bool: ...
+    def next_close(self, ts: builtins.int | None = None) -> builtins.int | None: ...
+    def next_open(self, ts: builtins.int | None = None) -> builtins.int: ...
+    def next_rollover(self, ts: builtins.int | None = None) -> builtins.int: ...
+    def session(self, ts: builtins.int | None = None) -> typing.Any: ...
+    def trading_day(self, ts: builtins.int | None = None) -> builtins.str: ...
+
+def account_balance(asset: typing.Any) -> typing.Any: ...
+
+def configure_logging(level: builtins.str | None = None, format: builtins.str | None = None, target: builtins.str | None = None, rotation: builtins.str | None = None) -> None: ...
+
+def encrypt_credentials(api_key: builtins.str, api_secret: builtins.str, passphrase: builtins.str) -> builtins.str: ...
+
+def fill_report(execution: typing.Any, ts_init: builtins.int | None = None) -> typing.Any: ...
+
+def format_timestamp(ns: builtins.int) -> builtins.str: ...
+
+def kline_date(interval: builtins.str, ts: builtins.int | None = None) -> builtins.str: ...
+
+def margin_balance(margin: typing.Any) -> typing.Any: ...
+
+def order_status(status: builtins.str) -> builtins.str: ...
+
+def order_status_report(order: typing.Any, ts_init: builtins.int | None = None) -> typing.Any: ...
+
+def position_status_report(position: typing.Any, ts_init: builtins.int | None = None) -> typing.Any: ...
+
+def position_summary_report(summary: typing.Any, ts_init: builtins.int | None = None) -> typing.Any: ...
+
+def project_rollover_cost(summaries: typing.Any, fee_rate: builtins.float | None = None) -> typing.Any: ...
+
+def shutdown(timeout_ms: builtins.int | None = None) -> builtins.bool: ...
+
+def tax_report(executions: typing.Any, leverage_costs: typing.Any | None = None, opening: typing.Any | None = None) -> typing.Any: ...
+
+def timestamp_ns(timestamp: builtins.str) -> builtins.int: ...
+
+def verify_session(path: builtins.str) -> typing.Any: ...
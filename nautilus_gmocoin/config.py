@@ -1,4 +1,51 @@
-from typing import Optional
+import os
+from typing import Optional, Union
+
+RATE_LIMIT_TIERS = ("tier1", "tier2")
+TLS_VERSIONS = ("1.2", "1.3")
+CALLBACK_PAYLOADS = ("object", "msgpack")
+
+
+def _validate_rate_limit_tier(config) -> None:
+    tier = config.rate_limit_tier
+    if tier is not None and tier.lower() not in RATE_LIMIT_TIERS:
+        raise ValueError(f"rate_limit_tier must be one of {RATE_LIMIT_TIERS}, got {tier!r}")
+
+
+def _validate_tls_min_version(config) -> None:
+    version = config.tls_min_version
+    if version is not None and version not in TLS_VERSIONS:
+        raise ValueError(f"tls_min_version must be one of {TLS_VERSIONS}, got {version!r}")
+
+
+def _validate_callback_payload(config) -> None:
+    payload = config.callback_payload
+    if payload is not None and payload.lower() not in CALLBACK_PAYLOADS:
+        raise ValueError(f"callback_payload must be one of {CALLBACK_PAYLOADS}, got {payload!r}")
+
+
+def _validate_credentials(config) -> None:
+    name = type(config).__name__
+    if config.encrypted_credentials:
+        if config.api_key or config.api_secret:
+            raise ValueError(f"{name} takes either api_key / api_secret or encrypted_credentials, not both")
+    elif not config.api_key or not config.api_secret:
+        raise ValueError(f"{name} requires both api_key and api_secret, or encrypted_credentials")
+
+
+def rate_limit_setting(config):
+    """REST rate limit argument for the Rust clients: custom rate if set, else the tier."""
+    return config.rate_limit_per_sec or config.rate_limit_tier
+
+
+def credentials_passphrase(config, encrypted: Optional[str]) -> Optional[str]:
+    """Passphrase of *encrypted* credentials, from the environment (None without them)."""
+    if not encrypted:
+        return None
+    passphrase = os.environ.get(config.credentials_passphrase_env)
+    if not passphrase:
+        raise ValueError(f"encrypted_credentials need the passphrase in ${config.credentials_passphrase_env}")
+    return passphrase
 from nautilus_trader.config import LiveDataClientConfig, LiveExecClientConfig
 
 
@@ -6,24 +53,113 @@ class GmocoinDataClientConfig(LiveDataClientConfig):
     api_key: Optional[str] = None
     api_secret: Optional[str] = None
     timeout_ms: int = 10000
+    encrypted_credentials: Optional[str] = None  # Blob from gmocoin.encrypt_credentials, decrypted in memory instead of api_key / api_secret
+    credentials_passphrase_env: str = "GMOCOIN_CREDENTIALS_PASSPHRASE"  # Environment variable holding the passphrase of encrypted_credentials
     proxy_url: Optional[str] = None
     order_book_depth: int = 20
-    rate_limit_per_sec: Optional[float] = None  # REST API rate limit (default: 20 for Tier 1)
+    rate_limit_tier: Optional[str] = None  # "tier1" (20/s) or "tier2" (30/s) for all endpoint groups (default: tier1)
+    rate_limit_per_sec: Optional[float] = None  # Custom REST rate limit; overrides rate_limit_tier
+    group_rate_limits: Optional[dict[str, float]] = None  # Per-group overrides: "public" / "account" / "order"
+    share_rate_limiter: bool = False  # Share one limiter with all clients using the same API key in this process
+    circuit_breaker_threshold: Optional[int] = None  # Consecutive failures before failing fast (default: 5, 0 disables)
+    circuit_breaker_cooldown_ms: Optional[int] = None  # Fail-fast period once open (default: 30000)
     ws_rate_limit_per_sec: Optional[float] = None  # WS subscription rate (default: 0.5)
+    tls_ca_file: Optional[str] = None  # PEM bundle of extra trusted root certificates (REST and WS)
+    tls_native_roots: Optional[bool] = None  # Trust the OS root store too (default: True)
+    tls_min_version: Optional[str] = None  # Minimum TLS version: "1.2" or "1.3" (default: "1.2")
+    pool_max_idle_per_host: Optional[int] = None  # Idle REST connections kept per host (default: unlimited)
+    pool_idle_timeout_ms: Optional[int] = None  # Keep idle REST connections this long (default: 90000)
+    max_in_flight: Optional[int] = None  # Most REST requests outstanding at once (default: unlimited)
+    symbols_ttl_ms: Optional[int] = None  # Serve /v1/symbols from cache this long, 0 always fetches (default: 60000)
+    prefer_http2: bool = False  # Offer HTTP/2 for REST, falling back to HTTP/1.1
+    tcp_keepalive_ms: Optional[int] = None  # TCP keepalive interval for REST connections (default: 15000)
+    venue_profile: Optional[Union[str, dict[str, str]]] = None  # "production" (default) or {"rest_public", "rest_private", "ws_public", "ws_private"} URLs
+    user_agent: Optional[str] = None  # User-Agent for REST requests and WS handshakes
+    extra_headers: Optional[dict[str, str]] = None  # Static headers added to every REST request and WS handshake
+    callback_payload: Optional[str] = None  # "object" (default) or "msgpack": WS events reach the adapter as MessagePack bytes
+    maintenance_windows: Optional[list[str]] = None  # Daily "HH:MM-HH:MM" or weekly "Sat HH:MM-HH:MM" windows (JST) during which WS reconnects wait
+    maintenance_status_check: bool = True  # Before reconnecting WS, wait while /v1/status reports MAINTENANCE
+    reconnect_initial_ms: Optional[int] = None  # First WS reconnect delay (default: 1000)
+    reconnect_max_ms: Optional[int] = None  # Upper bound of the WS reconnect delay (default: 64000)
+    reconnect_multiplier: Optional[float] = None  # Delay growth per failed attempt (default: 2.0)
+    reconnect_jitter: Optional[float] = None  # Random 0-1 fraction by which each delay may be shortened (default: 0.0)
+    stale_timeout_ms: Optional[int] = None  # Reconnect WS when nothing is received for this long (default: never)
+    instrument_refresh_ms: Optional[int] = None  # Poll /v1/symbols this often and publish listing / parameter changes (default: never)
+    event_gap_alarm_ms: Optional[int] = None  # Warn when the connected WS receives nothing for this long (default: never)
+    ticker_max_rate: Optional[float] = None  # At most this many ticker updates per second and symbol, keeping the latest (default: all)
+    book_interval_ms: Optional[int] = None  # Emit order books at most this often, in their latest state (default: every update)
+    book_mode: Optional[str] = None  # "snapshot" (default), "delta" (changed levels only) or "bbo" (best bid / ask only, as quotes)
     trades_taker_only: bool = False  # Subscribe to trades with TAKER_ONLY filter
 
     def __post_init__(self):
-        if not self.api_key or not self.api_secret:
-            raise ValueError("GmocoinDataClientConfig requires both api_key and api_secret")
+        _validate_credentials(self)
+        _validate_rate_limit_tier(self)
+        _validate_tls_min_version(self)
+        _validate_callback_payload(self)
 
 
 class GmocoinExecClientConfig(LiveExecClientConfig):
     api_key: Optional[str] = None
     api_secret: Optional[str] = None
     timeout_ms: int = 10000
+    encrypted_credentials: Optional[str] = None  # Blob from gmocoin.encrypt_credentials, decrypted in memory instead of api_key / api_secret
+    credentials_passphrase_env: str = "GMOCOIN_CREDENTIALS_PASSPHRASE"  # Environment variable holding the passphrase of encrypted_credentials
     proxy_url: Optional[str] = None
-    rate_limit_per_sec: Optional[float] = None  # REST API rate limit (default: 20 for Tier 1)
+    rate_limit_tier: Optional[str] = None  # "tier1" (20/s) or "tier2" (30/s) for all endpoint groups (default: tier1)
+    rate_limit_per_sec: Optional[float] = None  # Custom REST rate limit; overrides rate_limit_tier
+    group_rate_limits: Optional[dict[str, float]] = None  # Per-group overrides: "public" / "account" / "order"
+    share_rate_limiter: bool = False  # Share one limiter with all clients using the same API key in this process
+    circuit_breaker_threshold: Optional[int] = None  # Consecutive failures before failing fast (default: 5, 0 disables)
+    circuit_breaker_cooldown_ms: Optional[int] = None  # Fail-fast period once open (default: 30000)
+    order_deadline_ms: Optional[int] = None  # Abandon submit/modify not completed within this time (default: no deadline)
+    order_timeout_ms: Optional[int] = None  # HTTP timeout for submit/modify/cancel (default: timeout_ms)
+    tls_ca_file: Optional[str] = None  # PEM bundle of extra trusted root certificates (REST and WS)
+    tls_native_roots: Optional[bool] = None  # Trust the OS root store too (default: True)
+    tls_min_version: Optional[str] = None  # Minimum TLS version: "1.2" or "1.3" (default: "1.2")
+    pool_max_idle_per_host: Optional[int] = None  # Idle REST connections kept per host (default: unlimited)
+    pool_idle_timeout_ms: Optional[int] = None  # Keep idle REST connections this long (default: 90000)
+    max_in_flight: Optional[int] = None  # Most REST requests outstanding at once (default: unlimited)
+    symbols_ttl_ms: Optional[int] = None  # Serve /v1/symbols from cache this long, 0 always fetches (default: 60000)
+    strict_sizes: bool = False  # Reject order sizes off the symbol's sizeStep instead of rounding them down
+    prefer_http2: bool = False  # Offer HTTP/2 for REST, falling back to HTTP/1.1
+    tcp_keepalive_ms: Optional[int] = None  # TCP keepalive interval for REST connections (default: 15000)
+    venue_profile: Optional[Union[str, dict[str, str]]] = None  # "production" (default) or {"rest_public", "rest_private", "ws_public", "ws_private"} URLs
+    user_agent: Optional[str] = None  # User-Agent for REST requests and WS handshakes
+    extra_headers: Optional[dict[str, str]] = None  # Static headers added to every REST request and WS handshake
+    callback_payload: Optional[str] = None  # "object" (default) or "msgpack": WS events reach the adapter as MessagePack bytes
+    maintenance_windows: Optional[list[str]] = None  # Daily "HH:MM-HH:MM" or weekly "Sat HH:MM-HH:MM" windows (JST) during which WS reconnects wait
+    maintenance_status_check: bool = True  # Before reconnecting WS, wait while /v1/status reports MAINTENANCE
+    reconnect_initial_ms: Optional[int] = None  # First WS reconnect delay (default: 5000)
+    reconnect_max_ms: Optional[int] = None  # Upper bound of the WS reconnect delay (default: 60000)
+    reconnect_multiplier: Optional[float] = None  # Delay growth per failed attempt (default: 2.0)
+    reconnect_jitter: Optional[float] = None  # Random 0-1 fraction by which each delay may be shortened (default: 0.0)
+    stale_timeout_ms: Optional[int] = None  # Reconnect WS when nothing is received for this long (default: never)
+    rollover_lead_ms: Optional[int] = None  # Project leverage fees this long before each 06:00 JST rollover and reconcile after (default: off)
+    leverage_fee_rate: Optional[float] = None  # Leverage fee per rollover as a fraction of position value (default: 0.0004)
+    deadman_timeout_ms: Optional[int] = None  # Cancel all open orders when no heartbeat arrives for this long (default: off)
+    deadman_flatten: bool = False  # Also close all positions at market when the dead-man's switch fires
+    deadman_auto_heartbeat: bool = True  # Heartbeat from the event loop; False leaves heartbeat() to the strategy
+    requote_reserve: Optional[float] = None  # Order rate limit tokens kept free before price-only modifies are queued and coalesced (default: 2)
+    position_refresh_ms: Optional[int] = None  # Reload the position summary cache from REST this often (default: 60000, 0: WS events only)
+    fallback_after_ms: Optional[int] = None  # Poll REST for missed order/execution events once the Private WS has been down this long (None/0: off)
+    fallback_interval_ms: Optional[int] = None  # Interval of those polls (default: 5000)
+    order_ack_alarm_ms: Optional[int] = None  # Warn when GMO answers an order later than this (default: never)
+    event_gap_alarm_ms: Optional[int] = None  # Warn when the connected Private WS receives nothing for this long (default: never)
+    accounts: Optional[dict[str, dict]] = None  # Further accounts by name: {"api_key", "api_secret" (or encrypted "credentials"), optional "rate_limit" / "group_rate_limits"}
+    strategy_accounts: Optional[dict[str, str]] = None  # Strategy id -> account name its orders go through (default: the main account)
+    account_label: Optional[str] = None  # "account" of the main account's order callback events, e.g. a node name (default: "main")
 
     def __post_init__(self):
-        if not self.api_key or not self.api_secret:
-            raise ValueError("GmocoinExecClientConfig requires both api_key and api_secret")
+        _validate_credentials(self)
+        _validate_rate_limit_tier(self)
+        _validate_tls_min_version(self)
+        _validate_callback_payload(self)
+        for name, account in (self.accounts or {}).items():
+            if account.get("credentials"):
+                if account.get("api_key") or account.get("api_secret"):
+                    raise ValueError(f"Account {name!r} takes either api_key / api_secret or credentials, not both")
+            elif not account.get("api_key") or not account.get("api_secret"):
+                raise ValueError(f"Account {name!r} requires both api_key and api_secret, or credentials")
+        for strategy_id, name in (self.strategy_accounts or {}).items():
+            if name not in (self.accounts or {}):
+                raise ValueError(f"strategy_accounts maps {strategy_id!r} to unknown account {name!r}")
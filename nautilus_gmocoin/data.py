@@ -1,15 +1,14 @@
 import asyncio
-import json
 import logging
-from datetime import datetime, timezone
 from decimal import Decimal
 from typing import Dict, List, Optional, Set
 
 from nautilus_trader.live.data_client import LiveMarketDataClient
 from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.model.identifiers import ClientId, Venue
-from .config import GmocoinDataClientConfig
+from .config import GmocoinDataClientConfig, credentials_passphrase, rate_limit_setting
 from .constants import BAR_SPEC_TO_GMO_INTERVAL, BAR_POLL_INTERVALS
+from .providers import GmocoinInstrumentProvider
 from .symbol_utils import extract_gmo_symbol, extract_quote_currency
 
 try:
@@ -18,6 +17,11 @@ except ImportError:
     import _nautilus_gmocoin as gmocoin
 
 
+def _field(data, name: str):
+    """Field of a Rust model object, or of its dict when delivered as MessagePack."""
+    return data.get(name) if isinstance(data, dict) else getattr(data, name)
+
+
 class GmocoinDataClient(LiveMarketDataClient):
     """
     GMO Coin live market data client.
@@ -48,17 +52,76 @@ class GmocoinDataClient(LiveMarketDataClient):
         # Rust clients
         self._rust_client = gmocoin.GmocoinDataClient(
             getattr(self.config, 'ws_rate_limit_per_sec', None),
+            tls_ca_file=getattr(self.config, 'tls_ca_file', None),
+            tls_native_roots=getattr(self.config, 'tls_native_roots', None),
+            tls_min_version=getattr(self.config, 'tls_min_version', None),
+            venue_profile=getattr(self.config, 'venue_profile', None),
+            user_agent=getattr(self.config, 'user_agent', None),
+            extra_headers=getattr(self.config, 'extra_headers', None),
+            callback_payload=getattr(self.config, 'callback_payload', None),
+            maintenance_windows=getattr(self.config, 'maintenance_windows', None),
+            maintenance_status_check=getattr(self.config, 'maintenance_status_check', None),
+            reconnect_initial_ms=getattr(self.config, 'reconnect_initial_ms', None),
+            reconnect_max_ms=getattr(self.config, 'reconnect_max_ms', None),
+            reconnect_multiplier=getattr(self.config, 'reconnect_multiplier', None),
+            reconnect_jitter=getattr(self.config, 'reconnect_jitter', None),
+            stale_timeout_ms=getattr(self.config, 'stale_timeout_ms', None),
+            instrument_refresh_ms=getattr(self.config, 'instrument_refresh_ms', None),
+            event_gap_alarm_ms=getattr(self.config, 'event_gap_alarm_ms', None),
+            ticker_max_rate=getattr(self.config, 'ticker_max_rate', None),
+            book_interval_ms=getattr(self.config, 'book_interval_ms', None),
         )
+        self._calendar = self._rust_client.calendar
         self._rust_client.set_data_callback(self._handle_rust_data)
 
+        # Encrypted credentials are decrypted by the Rust client, in memory
+        passphrase = credentials_passphrase(self.config, getattr(self.config, 'encrypted_credentials', None))
         self._rest_client = gmocoin.GmocoinRestClient(
             self.config.api_key or "",
             self.config.api_secret or "",
             self.config.timeout_ms,
             self.config.proxy_url,
-            getattr(self.config, 'rate_limit_per_sec', None),
+            rate_limit_setting(self.config),
+            credentials=getattr(self.config, 'encrypted_credentials', None),
+            passphrase=passphrase,
+            circuit_breaker_threshold=getattr(self.config, 'circuit_breaker_threshold', None),
+            circuit_breaker_cooldown_ms=getattr(self.config, 'circuit_breaker_cooldown_ms', None),
+            group_rate_limits=getattr(self.config, 'group_rate_limits', None),
+            share_rate_limiter=getattr(self.config, 'share_rate_limiter', False),
+            tls_ca_file=getattr(self.config, 'tls_ca_file', None),
+            tls_native_roots=getattr(self.config, 'tls_native_roots', None),
+            tls_min_version=getattr(self.config, 'tls_min_version', None),
+            pool_max_idle_per_host=getattr(self.config, 'pool_max_idle_per_host', None),
+            pool_idle_timeout_ms=getattr(self.config, 'pool_idle_timeout_ms', None),
+            max_in_flight=getattr(self.config, 'max_in_flight', None),
+            symbols_ttl_ms=getattr(self.config, 'symbols_ttl_ms', None),
+            prefer_http2=getattr(self.config, 'prefer_http2', False),
+            tcp_keepalive_ms=getattr(self.config, 'tcp_keepalive_ms', None),
+            venue_profile=getattr(self.config, 'venue_profile', None),
+            user_agent=getattr(self.config, 'user_agent', None),
+            extra_headers=getattr(self.config, 'extra_headers', None),
         )
 
+    @property
+    def calendar(self):
+        """GMO Coin's trading calendar (maintenance windows and the 06:00 JST rollover)."""
+        return self._calendar
+
+    def set_rate_limit(self, group: str, per_sec: float) -> None:
+        """Change the REST rate limit of an endpoint group ("public", "account", "order") on the fly."""
+        self._rest_client.set_rate_limit(group, per_sec)
+
+    def get_stats(self) -> dict:
+        """Runtime counters of the Public WebSocket and REST clients."""
+        return {
+            "websocket": self._rust_client.get_stats(),
+            "rest": self._rest_client.get_stats(),
+        }
+
+    def health(self) -> dict:
+        """Health snapshot (Public WS, public REST reachability and rate limit) for monitoring probes."""
+        return self._rust_client.health()
+
     async def _connect(self):
         self._logger.info("GmocoinDataClient connecting")
 
@@ -82,13 +145,18 @@ class GmocoinDataClient(LiveMarketDataClient):
             symbol = instrument.id.symbol
             # "BTC/JPY" -> "BTC", "BTCJPY" -> "BTC"
             gmo_symbol = extract_gmo_symbol(symbol.value)
-            self._subscribed_instruments[gmo_symbol] = instrument
 
             # Subscribe to all channels for this symbol
-            await self._rust_client.subscribe("ticker", gmo_symbol)
+            try:
+                await self._rust_client.subscribe("ticker", gmo_symbol)
+            except ValueError as e:
+                # Not listed by /v1/symbols
+                self._logger.error(f"Cannot subscribe to {instrument.id}: {e}")
+                continue
+            self._subscribed_instruments[gmo_symbol] = instrument
             trades_option = "TAKER_ONLY" if self.config.trades_taker_only else None
             await self._rust_client.subscribe("trades", gmo_symbol, trades_option)
-            await self._rust_client.subscribe("orderbooks", gmo_symbol)
+            await self._rust_client.subscribe("orderbooks", gmo_symbol, book_mode=getattr(self.config, 'book_mode', None))
 
         self._logger.info(f"Subscribed to {len(instruments)} instruments")
 
@@ -97,21 +165,59 @@ class GmocoinDataClient(LiveMarketDataClient):
 
     def _handle_rust_data(self, channel: str, data):
         """
-        Callback from Rust. channel is "ticker", "orderbooks", or "trades".
-        data is a PyObject (Ticker, OrderBook, or Trade).
+        Callback from Rust. channel is "ticker", "orderbooks", "book_deltas", "bbo", "trades",
+        "maintenance", "reconnect", "parse_error", "instrument" or "latency_alarm". data is a PyObject
+        (Ticker, OrderBook, BookDelta, BestBidOffer or Trade; a dict for the others), or the same
+        fields as MessagePack bytes with callback_payload="msgpack".
         """
         try:
+            if isinstance(data, bytes):
+                import msgspec
+                data = msgspec.msgpack.decode(data)
             if channel == "ticker":
                 self._handle_ticker(data)
             elif channel == "orderbooks":
                 self._handle_orderbook(data)
+            elif channel == "book_deltas":
+                self._handle_book_delta(data)
+            elif channel == "bbo":
+                self._handle_bbo(data)
             elif channel == "trades":
                 self._handle_trade(data)
+            elif channel == "maintenance":
+                self._logger.warning(f"Public WS maintenance {data.get('state')} ({data.get('source')})")
+            elif channel == "reconnect":
+                self._logger.warning(
+                    f"Public WS reconnected after {data.get('downtime_ms')}ms (reason={data.get('reason')})"
+                )
+            elif channel == "parse_error":
+                self._logger.error(
+                    f"Undecodable Public WS {data.get('channel')} message: {data.get('error')}: {data.get('raw')}"
+                )
+            elif channel == "instrument":
+                self._handle_instrument_event(data)
+            elif channel == "latency_alarm":
+                self._logger.warning(f"Public WS silent for {data.get('latency_ms')}ms (budget {data.get('threshold_ms')}ms)")
         except Exception as e:
             self._logger.error(f"Error handling data from Rust: {e}")
 
+    def _handle_instrument_event(self, data):
+        action, symbol = data["action"], data["symbol"]
+        if action == "removed":
+            self._logger.warning(f"GMO Coin delisted {symbol}")
+            self._subscribed_instruments.pop(symbol.upper(), None)
+            return
+        instrument = GmocoinInstrumentProvider.parse_instrument(data["info"])
+        if instrument is None:
+            return
+        self._logger.info(f"GMO Coin {action} {instrument.id}: {data.get('changes') or data['info']}")
+        self._instrument_provider.add(instrument)
+        if symbol.upper() in self._subscribed_instruments:
+            self._subscribed_instruments[symbol.upper()] = instrument
+        self._handle_data(instrument)
+
     def _handle_ticker(self, data):
-        symbol = data.symbol
+        symbol = _field(data, "symbol")
         instrument = self._subscribed_instruments.get(symbol)
         if not instrument:
             return
@@ -119,10 +225,11 @@ class GmocoinDataClient(LiveMarketDataClient):
         from nautilus_trader.model.data import QuoteTick
         from nautilus_trader.model.objects import Price, Quantity
 
-        bid = data.bid
-        ask = data.ask
+        bid = _field(data, "bid")
+        ask = _field(data, "ask")
 
         if bid and ask:
+            ts_init = self._clock.timestamp_ns()
             precision = instrument.price_precision
             quote = QuoteTick(
                 instrument_id=instrument.id,
@@ -130,13 +237,13 @@ class GmocoinDataClient(LiveMarketDataClient):
                 ask_price=Price(float(ask), precision),
                 bid_size=Quantity.from_str("0"),
                 ask_size=Quantity.from_str("0"),
-                ts_event=self._clock.timestamp_ns(),
-                ts_init=self._clock.timestamp_ns(),
+                ts_event=_field(data, "ts_event") or ts_init,
+                ts_init=ts_init,
             )
             self._handle_data(quote)
 
     def _handle_trade(self, data):
-        symbol = data.symbol if data.symbol else None
+        symbol = _field(data, "symbol")
         if not symbol:
             return
         instrument = self._subscribed_instruments.get(symbol)
@@ -148,23 +255,24 @@ class GmocoinDataClient(LiveMarketDataClient):
         from nautilus_trader.model.enums import AggressorSide
         from nautilus_trader.model.identifiers import TradeId
 
-        side_str = data.side
+        side_str = _field(data, "side")
         aggressor_side = AggressorSide.BUYER if side_str == "BUY" else AggressorSide.SELLER
+        ts_init = self._clock.timestamp_ns()
 
         tick = TradeTick(
             instrument_id=instrument.id,
-            price=Price.from_str(str(data.price)),
-            size=Quantity.from_str(str(data.size)),
+            price=Price.from_str(str(_field(data, "price"))),
+            size=Quantity.from_str(str(_field(data, "size"))),
             aggressor_side=aggressor_side,
-            trade_id=TradeId(str(data.timestamp)),
-            ts_event=self._clock.timestamp_ns(),
-            ts_init=self._clock.timestamp_ns(),
+            trade_id=TradeId(str(_field(data, "timestamp"))),
+            ts_event=_field(data, "ts_event") or ts_init,
+            ts_init=ts_init,
         )
         self._handle_data(tick)
 
     def _handle_orderbook(self, data):
-        # data is an OrderBook pyclass from Rust
-        symbol = data.symbol
+        # data is an OrderBook pyclass from Rust, or its dict (MessagePack)
+        symbol = _field(data, "symbol")
         instrument = self._subscribed_instruments.get(symbol)
         if not instrument:
             return
@@ -173,23 +281,88 @@ class GmocoinDataClient(LiveMarketDataClient):
         from nautilus_trader.model.enums import BookAction, OrderSide
         from nautilus_trader.model.objects import Price, Quantity
 
-        top_asks, top_bids = data.get_top_n(self.config.order_book_depth)
+        depth = self.config.order_book_depth
+        if isinstance(data, dict):
+            top_asks = [(level["price"], level["size"]) for level in data["asks"][:depth]]
+            top_bids = [(level["price"], level["size"]) for level in data["bids"][:depth]]
+        else:
+            top_asks, top_bids = data.get_top_n(depth)
         ts_init = self._clock.timestamp_ns()
+        ts_event = _field(data, "ts_event") or ts_init
 
         deltas = []
-        deltas.append(OrderBookDelta.clear(instrument.id, 0, ts_init, ts_init))
+        deltas.append(OrderBookDelta.clear(instrument.id, 0, ts_event, ts_init))
 
         for p, q in top_asks:
             order = BookOrder(OrderSide.SELL, Price.from_str(str(p)), Quantity.from_str(str(q)), 0)
-            deltas.append(OrderBookDelta(instrument.id, BookAction.ADD, order, 0, 0, ts_init, ts_init))
+            deltas.append(OrderBookDelta(instrument.id, BookAction.ADD, order, 0, 0, ts_event, ts_init))
 
         for p, q in top_bids:
             order = BookOrder(OrderSide.BUY, Price.from_str(str(p)), Quantity.from_str(str(q)), 0)
-            deltas.append(OrderBookDelta(instrument.id, BookAction.ADD, order, 0, 0, ts_init, ts_init))
+            deltas.append(OrderBookDelta(instrument.id, BookAction.ADD, order, 0, 0, ts_event, ts_init))
 
         snapshot = OrderBookDeltas(instrument.id, deltas)
         self._handle_data(snapshot)
 
+    def _handle_book_delta(self, data):
+        # Changed levels of the full book (order_book_depth does not apply);
+        # size "0" removes a level, and a snapshot replaces the book
+        symbol = _field(data, "symbol")
+        instrument = self._subscribed_instruments.get(symbol)
+        if not instrument:
+            return
+
+        from nautilus_trader.model.data import OrderBookDelta, OrderBookDeltas, BookOrder
+        from nautilus_trader.model.enums import BookAction, OrderSide
+        from nautilus_trader.model.objects import Price, Quantity
+
+        ts_init = self._clock.timestamp_ns()
+        ts_event = _field(data, "ts_event") or ts_init
+        snapshot = _field(data, "snapshot")
+
+        deltas = []
+        if snapshot:
+            deltas.append(OrderBookDelta.clear(instrument.id, 0, ts_event, ts_init))
+
+        for side, levels in ((OrderSide.SELL, _field(data, "asks")), (OrderSide.BUY, _field(data, "bids"))):
+            for level in levels:
+                size = str(_field(level, "size"))
+                if snapshot:
+                    action = BookAction.ADD
+                elif float(size) == 0:
+                    action = BookAction.DELETE
+                else:
+                    action = BookAction.UPDATE
+                order = BookOrder(side, Price.from_str(str(_field(level, "price"))), Quantity.from_str(size), 0)
+                deltas.append(OrderBookDelta(instrument.id, action, order, 0, 0, ts_event, ts_init))
+
+        if deltas:
+            self._handle_data(OrderBookDeltas(instrument.id, deltas))
+
+    def _handle_bbo(self, data):
+        symbol = _field(data, "symbol")
+        instrument = self._subscribed_instruments.get(symbol)
+        if not instrument:
+            return
+
+        from nautilus_trader.model.data import QuoteTick
+        from nautilus_trader.model.objects import Price, Quantity
+
+        bid, ask = _field(data, "bid_price"), _field(data, "ask_price")
+        if bid and ask:
+            ts_init = self._clock.timestamp_ns()
+            precision = instrument.price_precision
+            quote = QuoteTick(
+                instrument_id=instrument.id,
+                bid_price=Price(float(bid), precision),
+                ask_price=Price(float(ask), precision),
+                bid_size=Quantity.from_str(str(_field(data, "bid_size"))),
+                ask_size=Quantity.from_str(str(_field(data, "ask_size"))),
+                ts_event=_field(data, "ts_event") or ts_init,
+                ts_init=ts_init,
+            )
+            self._handle_data(quote)
+
     async def fetch_instruments(self) -> List[Instrument]:
         from nautilus_trader.model.instruments import CurrencyPair
         from nautilus_trader.model.identifiers import InstrumentId, Symbol
@@ -205,8 +378,7 @@ class GmocoinDataClient(LiveMarketDataClient):
             return Currency(code, 8, 0, code, CurrencyType.CRYPTO)
 
         try:
-            res_json = await self._rest_client.get_symbols_py()
-            data = json.loads(res_json)
+            data = await self._rest_client.get_symbols_py()
 
             if isinstance(data, dict):
                 symbols = data.get("data", data)
@@ -351,9 +523,14 @@ class GmocoinDataClient(LiveMarketDataClient):
         try:
             while True:
                 try:
-                    date_str = datetime.now(timezone.utc).strftime("%Y%m%d")
-                    resp_json = await self._rest_client.get_klines_py(gmo_symbol, gmo_interval, date_str)
-                    klines = json.loads(resp_json)
+                    # Trading day from 06:00 JST (YYYYMMDD), or the JST year
+                    # (YYYY) for 4 hours and longer
+                    date_str = gmocoin.kline_date(gmo_interval)
+                    # Shed this poll rather than queue behind other traffic;
+                    # the next poll picks up any bars it would have returned.
+                    klines = await self._rest_client.get_klines_py(
+                        gmo_symbol, gmo_interval, date_str, max_wait_ms=poll_interval * 1000,
+                    )
 
                     if isinstance(klines, dict):
                         klines = klines.get("data", klines)
@@ -373,6 +550,12 @@ class GmocoinDataClient(LiveMarketDataClient):
                         ts_event = int(open_time) * 1_000_000  # ms -> ns
                         ts_init = self._clock.timestamp_ns()
 
+                        # Empty bars GMO fills maintenance with are not trading
+                        if not self._calendar.is_open(ts_event) and Decimal(str(kline["volume"])) == 0:
+                            self._logger.debug(f"Skipped {bar_type_str} bar at {open_time} (maintenance)")
+                            self._bar_last_timestamps[bar_type_str] = open_time
+                            continue
+
                         bar = Bar(
                             bar_type=bar_type,
                             open=Price.from_str(str(kline["open"])),
@@ -388,6 +571,8 @@ class GmocoinDataClient(LiveMarketDataClient):
 
                 except asyncio.CancelledError:
                     raise
+                except TimeoutError as e:
+                    self._logger.debug(f"Skipped bar poll for {bar_type_str}: {e}")
                 except Exception as e:
                     self._logger.error(f"Error polling bars for {bar_type_str}: {e}")
 
@@ -453,8 +638,7 @@ class GmocoinDataClient(LiveMarketDataClient):
 
         # Fetch from GMO Coin API
         try:
-            res_json = await self._rest_client.get_symbols_py()
-            data = json.loads(res_json)
+            data = await self._rest_client.get_symbols_py()
             if isinstance(data, dict):
                 symbols = data.get("data", [])
             elif isinstance(data, list):
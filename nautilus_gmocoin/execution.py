@@ -1,5 +1,4 @@
 import asyncio
-import json
 import logging
 from typing import Dict, List, Optional
 from decimal import Decimal
@@ -17,6 +16,8 @@ from nautilus_trader.model.identifiers import Venue, ClientId, AccountId, Client
 from nautilus_trader.model.enums import (
     OrderSide, OrderType, OmsType, AccountType, OrderStatus,
     TimeInForce, LiquiditySide,
+    liquidity_side_from_str, order_side_from_str, order_status_from_str, order_type_from_str,
+    position_side_from_str, time_in_force_from_str,
 )
 from nautilus_trader.execution.messages import (
     SubmitOrder, CancelOrder, ModifyOrder,
@@ -24,11 +25,10 @@ from nautilus_trader.execution.messages import (
     GenerateFillReports, GeneratePositionStatusReports,
 )
 from nautilus_trader.execution.reports import OrderStatusReport, FillReport, PositionStatusReport
-from nautilus_trader.model.identifiers import TradeId, PositionId
+from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.objects import Price, Quantity
-from nautilus_trader.model.enums import PositionSide
 
-from .config import GmocoinExecClientConfig
+from .config import GmocoinExecClientConfig, credentials_passphrase, rate_limit_setting
 from .constants import NAUTILUS_TO_GMO_ORDER_TYPE, ORDER_STATUS_MAP, ORDER_TYPE_MAP, TIME_IN_FORCE_MAP
 
 try:
@@ -69,21 +69,90 @@ class GmocoinExecutionClient(LiveExecutionClient):
         self._set_account_id(self._account_id)
         self._order_states = {}
 
+        # Encrypted credentials are decrypted by the Rust clients, in memory
+        passphrase = credentials_passphrase(self.config, getattr(self.config, 'encrypted_credentials', None))
         self._rust_client = gmocoin.GmocoinExecutionClient(
             self.config.api_key or "",
             self.config.api_secret or "",
             self.config.timeout_ms,
             self.config.proxy_url,
-            getattr(self.config, 'rate_limit_per_sec', None),
+            rate_limit_setting(self.config),
+            credentials=getattr(self.config, 'encrypted_credentials', None),
+            passphrase=passphrase,
+            circuit_breaker_threshold=getattr(self.config, 'circuit_breaker_threshold', None),
+            circuit_breaker_cooldown_ms=getattr(self.config, 'circuit_breaker_cooldown_ms', None),
+            group_rate_limits=getattr(self.config, 'group_rate_limits', None),
+            share_rate_limiter=getattr(self.config, 'share_rate_limiter', False),
+            tls_ca_file=getattr(self.config, 'tls_ca_file', None),
+            tls_native_roots=getattr(self.config, 'tls_native_roots', None),
+            tls_min_version=getattr(self.config, 'tls_min_version', None),
+            pool_max_idle_per_host=getattr(self.config, 'pool_max_idle_per_host', None),
+            pool_idle_timeout_ms=getattr(self.config, 'pool_idle_timeout_ms', None),
+            max_in_flight=getattr(self.config, 'max_in_flight', None),
+            symbols_ttl_ms=getattr(self.config, 'symbols_ttl_ms', None),
+            strict_sizes=getattr(self.config, 'strict_sizes', False),
+            prefer_http2=getattr(self.config, 'prefer_http2', False),
+            tcp_keepalive_ms=getattr(self.config, 'tcp_keepalive_ms', None),
+            venue_profile=getattr(self.config, 'venue_profile', None),
+            user_agent=getattr(self.config, 'user_agent', None),
+            extra_headers=getattr(self.config, 'extra_headers', None),
+            callback_payload=getattr(self.config, 'callback_payload', None),
+            maintenance_windows=getattr(self.config, 'maintenance_windows', None),
+            maintenance_status_check=getattr(self.config, 'maintenance_status_check', None),
+            reconnect_initial_ms=getattr(self.config, 'reconnect_initial_ms', None),
+            reconnect_max_ms=getattr(self.config, 'reconnect_max_ms', None),
+            reconnect_multiplier=getattr(self.config, 'reconnect_multiplier', None),
+            reconnect_jitter=getattr(self.config, 'reconnect_jitter', None),
+            stale_timeout_ms=getattr(self.config, 'stale_timeout_ms', None),
+            rollover_lead_ms=getattr(self.config, 'rollover_lead_ms', None),
+            leverage_fee_rate=getattr(self.config, 'leverage_fee_rate', None),
+            requote_reserve=getattr(self.config, 'requote_reserve', None),
+            deadman_timeout_ms=getattr(self.config, 'deadman_timeout_ms', None),
+            deadman_flatten=getattr(self.config, 'deadman_flatten', None),
+            position_refresh_ms=getattr(self.config, 'position_refresh_ms', None),
+            fallback_after_ms=getattr(self.config, 'fallback_after_ms', None),
+            fallback_interval_ms=getattr(self.config, 'fallback_interval_ms', None),
+            order_ack_alarm_ms=getattr(self.config, 'order_ack_alarm_ms', None),
+            event_gap_alarm_ms=getattr(self.config, 'event_gap_alarm_ms', None),
+            account_label=getattr(self.config, 'account_label', None),
         )
         self._rust_client.set_order_callback(self._handle_ws_message)
+        # Further GMO accounts, each with its own keys, rate limiter and Private WS
+        for name, account in (getattr(self.config, 'accounts', None) or {}).items():
+            self._rust_client.add_account(
+                name, account.get("api_key", ""), account.get("api_secret", ""),
+                account.get("rate_limit"), account.get("group_rate_limits"),
+                credentials=account.get("credentials"),
+                passphrase=credentials_passphrase(self.config, account.get("credentials")),
+            )
+        self._strategy_accounts = getattr(self.config, 'strategy_accounts', None) or {}
+        self._heartbeat_task = None
 
         self._rest_client = gmocoin.GmocoinRestClient(
             self.config.api_key or "",
             self.config.api_secret or "",
             self.config.timeout_ms,
             self.config.proxy_url,
-            getattr(self.config, 'rate_limit_per_sec', None),
+            rate_limit_setting(self.config),
+            credentials=getattr(self.config, 'encrypted_credentials', None),
+            passphrase=passphrase,
+            circuit_breaker_threshold=getattr(self.config, 'circuit_breaker_threshold', None),
+            circuit_breaker_cooldown_ms=getattr(self.config, 'circuit_breaker_cooldown_ms', None),
+            group_rate_limits=getattr(self.config, 'group_rate_limits', None),
+            share_rate_limiter=getattr(self.config, 'share_rate_limiter', False),
+            tls_ca_file=getattr(self.config, 'tls_ca_file', None),
+            tls_native_roots=getattr(self.config, 'tls_native_roots', None),
+            tls_min_version=getattr(self.config, 'tls_min_version', None),
+            pool_max_idle_per_host=getattr(self.config, 'pool_max_idle_per_host', None),
+            pool_idle_timeout_ms=getattr(self.config, 'pool_idle_timeout_ms', None),
+            max_in_flight=getattr(self.config, 'max_in_flight', None),
+            symbols_ttl_ms=getattr(self.config, 'symbols_ttl_ms', None),
+            strict_sizes=getattr(self.config, 'strict_sizes', False),
+            prefer_http2=getattr(self.config, 'prefer_http2', False),
+            tcp_keepalive_ms=getattr(self.config, 'tcp_keepalive_ms', None),
+            venue_profile=getattr(self.config, 'venue_profile', None),
+            user_agent=getattr(self.config, 'user_agent', None),
+            extra_headers=getattr(self.config, 'extra_headers', None),
         )
         self.log = logging.getLogger("nautilus.gmocoin.execution")
 
@@ -91,6 +160,101 @@ class GmocoinExecutionClient(LiveExecutionClient):
     def account_id(self) -> AccountId:
         return self._account_id
 
+    def set_rate_limit(self, group: str, per_sec: float, account: Optional[str] = None) -> None:
+        """Change the REST rate limit of an endpoint group ("public", "account", "order") on the fly.
+
+        With *account*, only that added account's order client is changed.
+        """
+        self._rust_client.set_rate_limit(group, per_sec, account=account)
+        if account is None:
+            self._rest_client.set_rate_limit(group, per_sec)
+
+    def get_stats(self) -> dict:
+        """Runtime counters of the order/Private WebSocket client and the query REST client."""
+        return {
+            "execution": self._rust_client.get_stats(),
+            "rest": self._rest_client.get_stats(),
+        }
+
+    def health(self) -> dict:
+        """Health snapshot (REST reachability, Private WS, token age, rate limits) for monitoring probes."""
+        return self._rust_client.health()
+
+    def get_position(self, instrument_id: InstrumentId, side: Optional[str] = None, account: Optional[str] = None):
+        """Latest cached GMO position summary (`PositionSnapshot`) of the instrument, or None when flat."""
+        return self._rust_client.get_position(extract_gmo_symbol(instrument_id.symbol.value), side, account=account)
+
+    async def get_open_order_exposure(
+        self, instrument_id: Optional[InstrumentId] = None, account: Optional[str] = None,
+    ) -> list[dict]:
+        """Working order count, resting buy/sell size and best prices per symbol, without a REST call."""
+        symbol = extract_gmo_symbol(instrument_id.symbol.value) if instrument_id else None
+        return await self._rust_client.get_open_order_exposure(symbol, account=account)
+
+    def export_state(self, account: Optional[str] = None) -> dict:
+        """Order cache and submitted client order ids as a JSON-ready dict, for `import_state` after a restart."""
+        return self._rust_client.export_state(account=account)
+
+    def import_state(self, state: dict, account: Optional[str] = None) -> dict:
+        """Merge an `export_state` dict into the running client; returns the counts added."""
+        return self._rust_client.import_state(state, account=account)
+
+    def get_execution_quality(self, order_id: Optional[int] = None, account: Optional[str] = None) -> Optional[dict]:
+        """Arrival slippage and time-to-fill of submitted orders (or of one venue order id)."""
+        return self._rust_client.get_execution_quality(order_id, account=account)
+
+    async def get_order_history(
+        self,
+        instrument_id: InstrumentId,
+        start_ns: Optional[int] = None,
+        end_ns: Optional[int] = None,
+    ) -> list[dict]:
+        """Closed orders with fills in `[start_ns, end_ns)`: final status, filled size, avg price and fees."""
+        return await self._rust_client.get_order_history(
+            extract_gmo_symbol(instrument_id.symbol.value), start_ns, end_ns,
+        )
+
+    async def export_executions(
+        self,
+        path: str,
+        instrument_ids: list[InstrumentId],
+        start_ns: Optional[int] = None,
+        end_ns: Optional[int] = None,
+        format: str = "csv",
+    ) -> int:
+        """Write the execution history (fees, lossGain, settle types) to CSV or Parquet; returns the row count."""
+        symbols = [extract_gmo_symbol(i.symbol.value) for i in instrument_ids]
+        return await self._rust_client.export_executions(path, symbols, start_ns, end_ns, format)
+
+    def start_twap(
+        self,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        quantity: Quantity,
+        duration_ms: int,
+        slices: int,
+        price: Optional[Price] = None,
+        participation_cap: Optional[float] = None,
+        account: Optional[str] = None,
+    ) -> int:
+        """Slice a parent order over `duration_ms` in `slices` child orders; returns the algorithm id."""
+        instrument = self._find_instrument(instrument_id)
+        return self._rust_client.start_twap(
+            extract_gmo_symbol(instrument_id.symbol.value),
+            "BUY" if side == OrderSide.BUY else "SELL",
+            str(quantity),
+            duration_ms,
+            slices,
+            price=str(price) if price is not None else None,
+            size_step=str(instrument.size_increment) if instrument else None,
+            participation_cap=str(participation_cap) if participation_cap is not None else None,
+            account=account,
+        )
+
+    def cancel_algo(self, algo_id: int) -> bool:
+        """Stop a TWAP started with `start_twap`, cancelling its working child order."""
+        return self._rust_client.cancel_algo(algo_id)
+
     def _collect_symbols(self, instrument_id=None) -> set[str]:
         """Collect GMO base-currency symbols to query.
 
@@ -105,6 +269,11 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 symbols.add(extract_gmo_symbol(inst.id.symbol.value))
         return symbols
 
+    def _symbols_by_account(self, symbols: set[str]) -> list[tuple[str, Optional[str]]]:
+        """*symbols* paired with the main account (None) and each added one."""
+        accounts = [None, *self._rust_client.account_names()]
+        return [(symbol, account) for account in accounts for symbol in sorted(symbols)]
+
     async def _connect(self):
         # Register all currencies
         await self._register_all_currencies()
@@ -114,6 +283,11 @@ class GmocoinExecutionClient(LiveExecutionClient):
             await self._rust_client.connect()
             self.log.info("Private WebSocket started via Rust client")
 
+            # Heartbeats from the event loop: a stalled loop trips the dead-man's switch
+            deadman_timeout_ms = getattr(self.config, 'deadman_timeout_ms', None)
+            if deadman_timeout_ms and getattr(self.config, 'deadman_auto_heartbeat', True):
+                self._heartbeat_task = self.create_task(self._heartbeat_loop(deadman_timeout_ms / 3000))
+
             # Initial account state
             try:
                 reports = await self.generate_account_status_reports()
@@ -128,8 +302,21 @@ class GmocoinExecutionClient(LiveExecutionClient):
             self.log.error(f"Failed to connect: {e}")
 
     async def _disconnect(self):
+        if self._heartbeat_task:
+            self._heartbeat_task.cancel()
+            self._heartbeat_task = None
+        await self._rust_client.disconnect()
         self.log.info("GmocoinExecutionClient disconnected")
 
+    async def _heartbeat_loop(self, interval_s: float) -> None:
+        while True:
+            self._rust_client.heartbeat()
+            await asyncio.sleep(interval_s)
+
+    def heartbeat(self) -> None:
+        """Reset the dead-man's switch (for `deadman_auto_heartbeat=False`)."""
+        self._rust_client.heartbeat()
+
     def submit_order(self, command: SubmitOrder) -> None:
         self.create_task(self._submit_order(command))
 
@@ -181,13 +368,23 @@ class GmocoinExecutionClient(LiveExecutionClient):
                     elif tag_str.startswith("losscutPrice="):
                         losscut_price = tag_str.split("=", 1)[1]
 
-            resp_json = await self._rust_client.submit_order(
+            # Market at submit time, for the execution quality report
+            quote = self._cache.quote_tick(instrument_id)
+            resp = await self._rust_client.submit_order(
                 gmo_symbol, amount, side, order_type, client_id, price, tif, None,
                 losscut_price, settle_type,
+                deadline_ms=getattr(self.config, 'order_deadline_ms', None),
+                timeout_ms=getattr(self.config, 'order_timeout_ms', None),
+                arrival_bid=str(quote.bid_price) if quote else None,
+                arrival_ask=str(quote.ask_price) if quote else None,
+                account=self._account_for(order.strategy_id),
             )
 
-            resp = json.loads(resp_json)
             venue_order_id = VenueOrderId(str(resp.get("order_id")))
+            if resp.get("duplicate"):
+                # A retried SubmitOrder for an order already placed this session
+                self.log.warning(f"Order {client_id} already submitted as {venue_order_id}, not resubmitted")
+                return
 
             self.generate_order_accepted(
                 strategy_id=order.strategy_id,
@@ -214,6 +411,8 @@ class GmocoinExecutionClient(LiveExecutionClient):
             await self._rust_client.cancel_order(
                 gmo_symbol,
                 str(command.venue_order_id),
+                timeout_ms=getattr(self.config, 'order_timeout_ms', None),
+                account=self._account_for(command.strategy_id),
             )
 
             self.generate_order_canceled(
@@ -238,24 +437,39 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
             venue_order_id_str = str(command.venue_order_id)
             new_price = str(command.price) if command.price else None
+            new_size = str(command.quantity) if command.quantity else None
 
-            if not new_price:
-                self._logger.error("ModifyOrder requires price for GMO Coin changeOrder")
+            if not new_price and not new_size:
+                self._logger.error("ModifyOrder requires a price or quantity")
                 return
 
-            await self._rust_client.change_order(
-                venue_order_id_str,
-                new_price,
-                None,  # losscutPrice - v0.2
-            )
+            deadline_ms = getattr(self.config, 'order_deadline_ms', None)
+            timeout_ms = getattr(self.config, 'order_timeout_ms', None)
+            account = self._account_for(command.strategy_id)
+            if new_size:
+                # A size change is a cancel-then-resubmit under a new venue order id
+                resp = await self._rust_client.amend_order(
+                    venue_order_id_str, new_price, new_size, deadline_ms=deadline_ms, timeout_ms=timeout_ms,
+                    account=account,
+                )
+            else:
+                # Tick-aligned; unchanged prices are rejected without a request,
+                # and queued re-quotes of the same order are coalesced
+                outcome = await self._rust_client.requote(venue_order_id_str, new_price, account=account)
+                if outcome["status"] == "superseded":
+                    return
+                resp = outcome["amendment"]
 
             self.generate_order_updated(
                 strategy_id=command.strategy_id,
                 instrument_id=command.instrument_id,
                 client_order_id=command.client_order_id,
-                venue_order_id=command.venue_order_id,
+                venue_order_id=VenueOrderId(str(resp["order_id"])),
                 quantity=command.quantity if command.quantity else None,
-                price=command.price,
+                price=(
+                    Price(float(resp["price"]), command.price.precision)
+                    if command.price and resp.get("price") else command.price
+                ),
                 trigger_price=command.trigger_price,
                 ts_event=self._clock.timestamp_ns(),
             )
@@ -263,11 +477,18 @@ class GmocoinExecutionClient(LiveExecutionClient):
         except Exception as e:
             self._logger.error(f"Modify failed: {e}")
 
-    def _handle_ws_message(self, event_type: str, message: str):
-        """Handle incoming Private WebSocket message from Rust client."""
+    def _account_for(self, strategy_id) -> Optional[str]:
+        """The account a strategy's orders go through (None: the main one)."""
+        return self._strategy_accounts.get(str(strategy_id))
+
+    def _handle_ws_message(self, event_type: str, data):
+        """Handle incoming Private WebSocket message from Rust client: a dict, or
+        MessagePack bytes with callback_payload="msgpack"."""
         self.log.debug(f"WS Event Received: {event_type}")
         try:
-            data = json.loads(message)
+            if isinstance(data, bytes):
+                import msgspec
+                data = msgspec.msgpack.decode(data)
             if event_type == "OrderUpdate":
                 venue_order_id = VenueOrderId(str(data.get("orderId")))
                 self.create_task(self._process_order_update_from_data(venue_order_id, data))
@@ -281,39 +502,71 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 self.log.info(f"Received PositionUpdate via WS: positionId={data.get('positionId')}")
             elif event_type == "PositionSummaryUpdate":
                 self.log.info(f"Received PositionSummaryUpdate via WS: symbol={data.get('symbol')}")
+            elif event_type == "Maintenance":
+                self.log.warning(f"Private WS maintenance {data.get('state')} ({data.get('source')})")
+            elif event_type == "Reconnect":
+                self.log.warning(f"Private WS reconnected after {data.get('downtime_ms')}ms (reason={data.get('reason')})")
+            elif event_type == "LatencyAlarm":
+                if data.get("kind") == "order_ack":
+                    self.log.warning(f"Order {data.get('order_id')} acknowledged after {data.get('latency_ms')}ms (budget {data.get('threshold_ms')}ms)")
+                else:
+                    self.log.warning(f"Private WS silent for {data.get('latency_ms')}ms (budget {data.get('threshold_ms')}ms)")
+            elif event_type == "PollingFallback":
+                if data.get("active"):
+                    self.log.warning(f"Private WS down for {data.get('down_ms')}ms, polling REST for order updates")
+                else:
+                    self.log.info("Private WS recovered, REST polling fallback stopped")
+            elif event_type == "ParseError":
+                self.log.error(f"Undecodable Private WS {data.get('channel')} message: {data.get('error')}: {data.get('raw')}")
+            elif event_type == "RolloverProjection":
+                self.log.info(f"Leverage fees at rollover {data.get('rollover_at')}: {data.get('total')} JPY projected")
+            elif event_type == "RolloverReconciliation":
+                self.log.info(
+                    f"Leverage fees at rollover {data.get('rollover_at')}: {data.get('actual')} JPY charged, "
+                    f"{data.get('projected')} JPY projected"
+                )
+            elif event_type == "DeadManTriggered":
+                self.log.error(
+                    f"Dead-man's switch fired after {data.get('idle_ms')}ms without heartbeat: "
+                    f"cancelled orders of {len(data.get('cancelled_symbols', []))} symbols, "
+                    f"flattened {data.get('flattened')}, errors {data.get('errors')}"
+                )
+            elif event_type == "AlgoProgress":
+                self.log.info(
+                    f"TWAP {data.get('algo_id')} {data.get('symbol')} {data.get('state')}: "
+                    f"{data.get('filled')}/{data.get('quantity')} filled, slice {data.get('slices_done')}/{data.get('slices')}"
+                )
             else:
                 self.log.debug(f"Unknown WS Event: {event_type}")
         except Exception as e:
             self.log.error(f"Error handling WS message: {e}")
 
+    def _account_balance(self, asset: dict):
+        fields = gmocoin.account_balance(asset)
+        currency = None
+        if hasattr(self._instrument_provider, 'currency'):
+            currency = self._instrument_provider.currency(fields["currency"])
+        if currency is None:
+            from nautilus_trader.model import currencies
+            currency = getattr(currencies, fields["currency"], None)
+        if currency is None:
+            self.log.debug(f"Skipping unknown currency: {fields['currency']}")
+            return None
+        return AccountBalance(
+            Money(Decimal(fields["total"]), currency),
+            Money(Decimal(fields["locked"]), currency),
+            Money(Decimal(fields["free"]), currency),
+        )
+
     def _process_asset_update(self, data: dict):
         try:
-            asset_code = data.get("symbol", "").upper()
-            if not asset_code:
+            if not data.get("symbol"):
                 return
 
-            currency = None
-            if hasattr(self._instrument_provider, 'currency'):
-                currency = self._instrument_provider.currency(asset_code)
-
-            if currency is None:
-                from nautilus_trader.model import currencies
-                currency = getattr(currencies, asset_code, None)
-
-            if currency is None:
-                self.log.debug(f"Skipping unknown currency: {asset_code}")
+            balance = self._account_balance(data)
+            if balance is None:
                 return
 
-            total_val = Decimal(data.get("amount", "0"))
-            available_val = Decimal(data.get("available", "0"))
-            locked_val = total_val - available_val
-
-            balance = AccountBalance(
-                Money(total_val, currency),
-                Money(locked_val, currency),
-                Money(available_val, currency),
-            )
-
             import time
             ts_now = int(time.time() * 1_000_000_000)
 
@@ -396,25 +649,19 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 self.log.debug(f"ExecutionUpdate duplicate executionId={execution_id}, skipping")
                 return
 
-            # Extract fill data from WS executionEvents fields
-            # Use explicit None checks to avoid falsy 0 values being skipped
-            exec_price = Decimal(data.get("executionPrice") if data.get("executionPrice") is not None else data.get("price", "0"))
-            exec_size = Decimal(data.get("executionSize") if data.get("executionSize") is not None else data.get("size", "0"))
-            fee = Decimal(data.get("fee", "0"))
-
-            if exec_size <= 0:
-                self.log.warning(f"ExecutionUpdate with zero size, executionId={execution_id}")
-                return
-
-            if exec_price <= 0:
-                self.log.error(
-                    f"ExecutionUpdate with price={exec_price}, executionId={execution_id}. "
-                    f"Skipping corrupted fill to prevent position corruption."
-                )
+            try:
+                fill = gmocoin.fill_report(data, self._clock.timestamp_ns())
+            except ValueError as e:
+                self.log.error(f"Skipping ExecutionUpdate to prevent position corruption: {e}")
                 return
 
-            quote_currency = self._get_quote_currency(order.instrument_id)
-            commission = Money(fee, quote_currency)
+            exec_price = Decimal(fill["last_px"])
+            exec_size = Decimal(fill["last_qty"])
+            fee = Decimal(fill["commission"])
+            commission = Money(fee, Currency.from_str(fill["commission_currency"]))
+            liquidity_side = liquidity_side_from_str(fill["liquidity_side"])
+            if liquidity_side == LiquiditySide.NO_LIQUIDITY_SIDE:
+                liquidity_side = self._infer_liquidity_side(order)
 
             qty_precision, px_precision = self._get_instrument_precisions(order.instrument_id)
 
@@ -429,10 +676,10 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 order_type=order.order_type,
                 last_qty=Quantity(exec_size, precision=qty_precision),
                 last_px=Price(exec_price, precision=px_precision),
-                quote_currency=quote_currency,
-                liquidity_side=self._infer_liquidity_side(order),
+                quote_currency=self._get_quote_currency(order.instrument_id),
+                liquidity_side=liquidity_side,
                 commission=commission,
-                ts_event=self._clock.timestamp_ns(),
+                ts_event=fill["ts_event"],
             )
 
             # Mark as reported AFTER successful fill generation to avoid
@@ -487,7 +734,9 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
     async def _process_order_update(self, order: Order, venue_order_id: VenueOrderId, quote_currency, data: dict) -> bool:
         try:
-            status = data.get("orderStatus") if data.get("orderStatus") is not None else data.get("status")
+            gmo_status = data.get("orderStatus") if data.get("orderStatus") is not None else data.get("status")
+            # The OrderStatus name, mapped as for OrderStatusReports
+            status = gmocoin.order_status(gmo_status) if gmo_status is not None else None
             executed_qty = Decimal(data.get("orderExecutedSize") if data.get("orderExecutedSize") is not None else data.get("executedSize", "0"))
 
             # Track fill state
@@ -510,8 +759,7 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 # Try to get detailed execution info
                 new_execs = []
                 try:
-                    history_json = await self._rust_client.get_executions(str(venue_order_id))
-                    history = json.loads(history_json)
+                    history = await self._rust_client.get_executions(str(venue_order_id))
                     raw_executions = history.get("list", [])
 
                     for ex in raw_executions:
@@ -574,6 +822,9 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
             # Handle cancel
             if status in ("CANCELED",):
+                reason = data.get("cancelReason")
+                if reason not in (None, "USER_CANCELED"):
+                    self.log.warning(f"Order {venue_order_id} canceled by GMO: {reason} (cancelType={data.get('cancelType')})")
                 if order.status not in (OrderStatus.CANCELED, OrderStatus.FILLED, OrderStatus.EXPIRED):
                     self.generate_order_canceled(
                         strategy_id=order.strategy_id,
@@ -585,7 +836,19 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 self._order_states.pop(oid_str, None)
                 return True
 
-            if status == "EXECUTED":
+            if status == "EXPIRED":
+                if order.status not in (OrderStatus.CANCELED, OrderStatus.FILLED, OrderStatus.EXPIRED):
+                    self.generate_order_expired(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=order.client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=self._clock.timestamp_ns(),
+                    )
+                self._order_states.pop(oid_str, None)
+                return True
+
+            if status == "FILLED":
                 self._order_states.pop(oid_str, None)
                 return True
 
@@ -596,93 +859,39 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
     # Required abstract methods
 
-    GMO_ORDER_STATUS_MAP = {
-        "WAITING": OrderStatus.ACCEPTED,
-        "ORDERED": OrderStatus.ACCEPTED,
-        "MODIFYING": OrderStatus.ACCEPTED,
-        "CANCELLING": OrderStatus.PENDING_CANCEL,
-        "CANCELED": OrderStatus.CANCELED,
-        "EXECUTED": OrderStatus.FILLED,
-        "EXPIRED": OrderStatus.EXPIRED,
-    }
-    GMO_ORDER_TYPE_MAP = {
-        "MARKET": OrderType.MARKET,
-        "LIMIT": OrderType.LIMIT,
-        "STOP": OrderType.STOP_MARKET,
-    }
-    GMO_TIF_MAP = {
-        "FAK": TimeInForce.IOC,
-        "FAS": TimeInForce.GTC,
-        "FOK": TimeInForce.FOK,
-        "SOK": TimeInForce.GTC,
-    }
-
     def _parse_order_status_report(
         self,
         order_data: dict,
         instrument_id: InstrumentId | None = None,
         client_order_id: ClientOrderId | None = None,
     ) -> OrderStatusReport:
-        if client_order_id is None:
-            coid_str = order_data.get("clientOrderId")
-            if coid_str:
-                client_order_id = ClientOrderId(coid_str)
-
-        venue_oid = VenueOrderId(str(order_data.get("orderId")))
-        side_str = order_data.get("side", "BUY")
-        order_side = OrderSide.BUY if side_str == "BUY" else OrderSide.SELL
-
-        exec_type = order_data.get("executionType", "LIMIT")
-        order_type = self.GMO_ORDER_TYPE_MAP.get(exec_type, OrderType.LIMIT)
-
-        tif_str = order_data.get("timeInForce", "FAS")
-        time_in_force = self.GMO_TIF_MAP.get(tif_str, TimeInForce.GTC)
-
-        status_str = order_data.get("status", "ORDERED")
-        order_status = self.GMO_ORDER_STATUS_MAP.get(status_str, OrderStatus.ACCEPTED)
-
-        size = Decimal(order_data.get("size", "0"))
-        executed_size = Decimal(order_data.get("executedSize", "0"))
-
-        price_str = order_data.get("price")
-        price = Price(Decimal(price_str), precision=0) if price_str else None
-
-        # For filled orders, set avg_px to prevent ExecEngine from
-        # generating inferred fills with price=0 (#870).
-        # LIMIT orders: avg_px = order price (exact fill).
-        # MARKET orders or orders with non-positive price: avg_px remains None —
-        # NautilusTrader will use FillReports to infer the price.
-        avg_px = None
-        if price is not None and executed_size > 0:
-            if float(price) > 0:
-                avg_px = price
-            else:
-                self._log.warning(
-                    f"Order report for venue_order_id={venue_oid} has non-positive price={price}. "
-                    f"Not setting avg_px to prevent a price=0 fill."
-                )
+        fields = gmocoin.order_status_report(order_data, self._clock.timestamp_ns())
 
+        if client_order_id is None and fields["client_order_id"]:
+            client_order_id = ClientOrderId(fields["client_order_id"])
         if instrument_id is None:
-            symbol = order_data.get("symbol", "BTC")
-            instrument_id = InstrumentId(Symbol(f"{symbol}/JPY"), self.venue)
+            instrument_id = InstrumentId(Symbol(f"{fields['symbol']}/JPY"), self.venue)
 
-        ts_now = self._clock.timestamp_ns()
+        price = Price(Decimal(fields["price"]), precision=0) if fields["price"] is not None else None
+        # avg_px is only set for positively priced fills (#870); otherwise
+        # NautilusTrader infers it from FillReports
+        avg_px = Price(Decimal(fields["avg_px"]), precision=0) if fields["avg_px"] is not None else None
 
         return OrderStatusReport(
             account_id=self._account_id,
             instrument_id=instrument_id,
-            venue_order_id=venue_oid,
-            order_side=order_side,
-            order_type=order_type,
-            time_in_force=time_in_force,
-            order_status=order_status,
-            quantity=Quantity(size, precision=8),
-            filled_qty=Quantity(executed_size, precision=8),
+            venue_order_id=VenueOrderId(fields["venue_order_id"]),
+            order_side=order_side_from_str(fields["order_side"]),
+            order_type=order_type_from_str(fields["order_type"]),
+            time_in_force=time_in_force_from_str(fields["time_in_force"]),
+            order_status=order_status_from_str(fields["order_status"]),
+            quantity=Quantity(Decimal(fields["quantity"]), precision=8),
+            filled_qty=Quantity(Decimal(fields["filled_qty"]), precision=8),
             avg_px=avg_px,
             report_id=UUID4(),
-            ts_accepted=ts_now,
-            ts_last=ts_now,
-            ts_init=ts_now,
+            ts_accepted=fields["ts_accepted"],
+            ts_last=fields["ts_last"],
+            ts_init=fields["ts_init"],
             client_order_id=client_order_id,
             price=price,
         )
@@ -699,8 +908,7 @@ class GmocoinExecutionClient(LiveExecutionClient):
                 self._logger.warning("generate_order_status_report: no venue_order_id available")
                 return None
 
-            resp_json = await self._rust_client.get_order(str(venue_order_id))
-            resp = json.loads(resp_json)
+            resp = await self._rust_client.get_order(str(venue_order_id))
             orders_list = resp if isinstance(resp, list) else resp.get("list", [])
 
             if not orders_list:
@@ -724,10 +932,9 @@ class GmocoinExecutionClient(LiveExecutionClient):
             if not symbols:
                 symbols.add("BTC")
 
-            for symbol in symbols:
+            for symbol, account in self._symbols_by_account(symbols):
                 try:
-                    resp_json = await self._rust_client.get_active_orders(symbol)
-                    resp = json.loads(resp_json)
+                    resp = await self._rust_client.get_active_orders(symbol, account=account)
                     orders_list = resp if isinstance(resp, list) else resp.get("list", [])
 
                     inst_id = InstrumentId(Symbol(f"{symbol}/JPY"), self.venue)
@@ -751,10 +958,9 @@ class GmocoinExecutionClient(LiveExecutionClient):
     async def generate_account_status_reports(self, instrument_id=None, client_order_id=None):
         try:
             reports = []
-            assets_json = await self._rust_client.get_assets_py()
-            self.log.debug(f"Fetched assets: {assets_json[:200]}...")
+            assets_data = await self._rust_client.get_assets_py()
+            self.log.debug(f"Fetched assets: {str(assets_data)[:200]}...")
 
-            assets_data = json.loads(assets_json)
             if isinstance(assets_data, dict):
                 assets_data = assets_data.get("data", assets_data)
             if not isinstance(assets_data, list):
@@ -762,31 +968,12 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
             nautilus_balances = []
             for asset in assets_data:
-                currency_str = asset.get("symbol", "").upper()
                 try:
-                    currency = None
-                    if hasattr(self._instrument_provider, 'currency'):
-                        currency = self._instrument_provider.currency(currency_str)
-                    if currency is None:
-                        from nautilus_trader.model import currencies
-                        currency = getattr(currencies, currency_str, None)
-
-                    if currency is None:
-                        continue
-
-                    total = Decimal(asset.get("amount", "0"))
-                    available = Decimal(asset.get("available", "0"))
-                    locked = total - available
-
-                    nautilus_balances.append(
-                        AccountBalance(
-                            Money(total, currency),
-                            Money(locked, currency),
-                            Money(available, currency),
-                        )
-                    )
+                    balance = self._account_balance(asset)
+                    if balance is not None:
+                        nautilus_balances.append(balance)
                 except Exception as e:
-                    self._logger.error(f"Failed to parse balance for {currency_str}: {e}")
+                    self._logger.error(f"Failed to parse balance for {asset.get('symbol')}: {e}")
                     continue
 
             if not nautilus_balances:
@@ -827,8 +1014,7 @@ class GmocoinExecutionClient(LiveExecutionClient):
             # When venue_order_id is specified, fetch only that order's executions
             if venue_order_id:
                 try:
-                    resp_json = await self._rust_client.get_executions(str(venue_order_id))
-                    resp = json.loads(resp_json)
+                    resp = await self._rust_client.get_executions(str(venue_order_id))
                     exec_list = resp if isinstance(resp, list) else resp.get("list", [])
                     self._parse_fill_reports(exec_list, instrument_id, reports)
                 except Exception as e:
@@ -839,10 +1025,9 @@ class GmocoinExecutionClient(LiveExecutionClient):
             if not symbols:
                 symbols.add("BTC")
 
-            for symbol in symbols:
+            for symbol, account in self._symbols_by_account(symbols):
                 try:
-                    resp_json = await self._rust_client.get_latest_executions(symbol)
-                    resp = json.loads(resp_json)
+                    resp = await self._rust_client.get_latest_executions(symbol, account=account)
                     exec_list = resp if isinstance(resp, list) else resp.get("list", [])
                     self._parse_fill_reports(exec_list, instrument_id, reports)
                 except Exception as e:
@@ -856,51 +1041,29 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
     def _parse_fill_reports(self, exec_list: list, instrument_id, reports: list):
         from nautilus_trader.model.identifiers import InstrumentId, Symbol
+        ts_init = self._clock.timestamp_ns()
         for exec_data in exec_list:
             try:
-                venue_oid = VenueOrderId(str(exec_data.get("orderId")))
-                trade_id = TradeId(str(exec_data.get("executionId")))
-                side_str = exec_data.get("side", "BUY")
-                order_side = OrderSide.BUY if side_str == "BUY" else OrderSide.SELL
-
-                exec_size = Decimal(exec_data.get("size", "0"))
-                exec_price = Decimal(exec_data.get("price", "0"))
-                fee = Decimal(exec_data.get("fee", "0"))
-
-                if exec_price <= 0:
-                    self._logger.warning(
-                        f"Skipping fill report with price={exec_price}: "
-                        f"executionId={exec_data.get('executionId')}"
-                    )
-                    continue
-
-                symbol = exec_data.get("symbol", "BTC")
-                inst_id = instrument_id or InstrumentId(Symbol(f"{symbol}/JPY"), Venue("GMOCOIN"))
-
-                instrument = self._instrument_provider.find(inst_id)
-                quote_currency = JPY
-                if instrument:
-                    quote_currency = instrument.quote_currency
-
-                ts_now = self._clock.timestamp_ns()
+                fill = gmocoin.fill_report(exec_data, ts_init)
+                inst_id = instrument_id or InstrumentId(Symbol(f"{fill['symbol']}/JPY"), Venue("GMOCOIN"))
 
                 report = FillReport(
                     account_id=self._account_id,
                     instrument_id=inst_id,
-                    venue_order_id=venue_oid,
-                    trade_id=trade_id,
-                    order_side=order_side,
-                    last_qty=Quantity(exec_size, precision=8),
-                    last_px=Price(exec_price, precision=0),
-                    commission=Money(fee, quote_currency),
-                    liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+                    venue_order_id=VenueOrderId(fill["venue_order_id"]),
+                    trade_id=TradeId(fill["trade_id"]),
+                    order_side=order_side_from_str(fill["order_side"]),
+                    last_qty=Quantity(Decimal(fill["last_qty"]), precision=8),
+                    last_px=Price(Decimal(fill["last_px"]), precision=0),
+                    commission=Money(Decimal(fill["commission"]), Currency.from_str(fill["commission_currency"])),
+                    liquidity_side=liquidity_side_from_str(fill["liquidity_side"]),
                     report_id=UUID4(),
-                    ts_event=ts_now,
-                    ts_init=ts_now,
+                    ts_event=fill["ts_event"],
+                    ts_init=fill["ts_init"],
                 )
                 reports.append(report)
             except Exception as e:
-                self._logger.warning(f"Failed to parse fill report: {e}")
+                self._logger.warning(f"Skipping fill report for executionId={exec_data.get('executionId')}: {e}")
                 continue
 
     async def generate_position_status_reports(self, command: GeneratePositionStatusReports) -> list[PositionStatusReport]:
@@ -913,32 +1076,21 @@ class GmocoinExecutionClient(LiveExecutionClient):
 
             for symbol in symbols:
                 try:
-                    resp_json = await self._rust_client.get_open_positions(symbol)
-                    resp = json.loads(resp_json)
+                    resp = await self._rust_client.get_open_positions(symbol)
                     pos_list = resp if isinstance(resp, list) else resp.get("list", [])
 
+                    inst_id = InstrumentId(Symbol(f"{symbol}/JPY"), Venue("GMOCOIN"))
                     for pos_data in pos_list:
                         try:
-                            position_id = PositionId(str(pos_data.get("positionId")))
-                            side_str = pos_data.get("side", "BUY")
-                            position_side = PositionSide.LONG if side_str == "BUY" else PositionSide.SHORT
-
-                            size = Decimal(pos_data.get("size", "0"))
-                            avg_price = Decimal(pos_data.get("price", "0"))
-
-                            from nautilus_trader.model.identifiers import InstrumentId, Symbol
-                            inst_id = InstrumentId(Symbol(f"{symbol}/JPY"), Venue("GMOCOIN"))
-
-                            ts_now = self._clock.timestamp_ns()
-
+                            fields = gmocoin.position_status_report(pos_data, self._clock.timestamp_ns())
                             report = PositionStatusReport(
                                 account_id=self._account_id,
                                 instrument_id=inst_id,
-                                position_side=position_side,
-                                quantity=Quantity(size, precision=8),
+                                position_side=position_side_from_str(fields["position_side"]),
+                                quantity=Quantity(Decimal(fields["quantity"]), precision=8),
                                 report_id=UUID4(),
-                                ts_last=ts_now,
-                                ts_init=ts_now,
+                                ts_last=fields["ts_last"],
+                                ts_init=fields["ts_init"],
                             )
                             reports.append(report)
                         except Exception as e:
@@ -962,8 +1114,7 @@ class GmocoinExecutionClient(LiveExecutionClient):
             CurrencyType = None
 
         try:
-            symbols_json = await self._rest_client.get_symbols_py()
-            data = json.loads(symbols_json)
+            data = await self._rest_client.get_symbols_py()
             if isinstance(data, dict):
                 symbols = data.get("data", [])
             elif isinstance(data, list):
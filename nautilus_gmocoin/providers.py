@@ -10,7 +10,6 @@ GMO Coin instrument provider implementation.
 """
 
 from decimal import Decimal
-import json
 import logging
 
 from nautilus_trader.common.providers import InstrumentProvider
@@ -50,8 +49,7 @@ class GmocoinInstrumentProvider(InstrumentProvider):
         self._log.info(f"Loading all instruments{filters_str}")
 
         try:
-            symbols_json = await self._client.get_symbols_py()
-            symbols_data = json.loads(symbols_json)
+            symbols_data = await self._client.get_symbols_py()
 
             if isinstance(symbols_data, dict):
                 symbols_data = symbols_data.get("data", symbols_data)
@@ -61,7 +59,7 @@ class GmocoinInstrumentProvider(InstrumentProvider):
 
             for symbol_info in symbols_data:
                 try:
-                    instrument = self._parse_instrument(symbol_info)
+                    instrument = self.parse_instrument(symbol_info)
                     if instrument:
                         self.add(instrument=instrument)
                 except Exception as e:
@@ -103,7 +101,12 @@ class GmocoinInstrumentProvider(InstrumentProvider):
     ) -> None:
         await self.load_ids_async([instrument_id], filters)
 
-    def _parse_instrument(self, symbol_info: dict) -> CurrencyPair | None:
+    @staticmethod
+    def parse_instrument(symbol_info: dict) -> CurrencyPair | None:
+        """
+        Build the instrument for a ``/v1/symbols`` entry; ``None`` for
+        symbols this adapter does not trade.
+        """
         symbol_name = symbol_info.get("symbol", "")
         if not symbol_name:
             return None
@@ -0,0 +1,234 @@
+"""Generate `nautilus_gmocoin/_nautilus_gmocoin.pyi` from a built extension.
+
+The `python` feature compiles PyO3's introspection data into the extension:
+one JSON fragment per class, method and function, exported as
+`PYO3_INTROSPECTION_1_*` symbols. This script reads them from the ELF symbol
+table, takes the module's members from importing the extension, and writes
+the stub.
+
+    python scripts/generate_stubs.py path/to/_nautilus_gmocoin.so
+    python scripts/generate_stubs.py path/to/_nautilus_gmocoin.so --check
+
+`--check` fails if the committed stub is out of date (CI runs this after
+building the wheel).
+"""
+
+from __future__ import annotations
+
+import argparse
+import importlib.util
+import json
+import re
+import struct
+import sys
+from pathlib import Path
+
+MODULE = "_nautilus_gmocoin"
+STUB_PATH = Path(__file__).resolve().parent.parent / "nautilus_gmocoin" / f"{MODULE}.pyi"
+SYMBOL_PREFIX = b"PYO3_INTROSPECTION_1_"
+SYNC_CLIENT, SYNC_BASE = "SyncGmocoinRestClient", "GmocoinRestClient"
+
+
+# ---------------------------------------------------------------------------
+# Introspection data
+# ---------------------------------------------------------------------------
+
+
+def read_fragments(path: Path) -> list[dict]:
+    """JSON fragments of every `PYO3_INTROSPECTION_1_*` symbol in an ELF file."""
+    data = path.read_bytes()
+    if data[:4] != b"\x7fELF" or data[4] != 2 or data[5] != 1:
+        raise SystemExit(f"{path}: not a 64-bit little-endian ELF file")
+    shoff, = struct.unpack_from("<Q", data, 0x28)
+    shentsize, shnum = struct.unpack_from("<HH", data, 0x3A)
+    sections = [struct.unpack_from("<IIQQQQIIQQ", data, shoff + i * shentsize) for i in range(shnum)]
+
+    fragments = []
+    for _name, sh_type, _flags, _addr, offset, size, link, _info, _align, entsize in sections:
+        if sh_type not in (2, 11):  # SHT_SYMTAB, SHT_DYNSYM
+            continue
+        strtab = sections[link][4]
+        for pos in range(offset, offset + size, entsize):
+            st_name, _st_info, _other, shndx, value, st_size = struct.unpack_from("<IBBHQQ", data, pos)
+            if not data.startswith(SYMBOL_PREFIX, strtab + st_name) or shndx == 0 or shndx >= shnum:
+                continue
+            section = sections[shndx]
+            start = section[4] + value - section[3]
+            length, = struct.unpack_from("<I", data, start)
+            fragments.append(json.loads(data[start + 4 : start + 4 + length]))
+        if fragments:
+            break
+    if not fragments:
+        raise SystemExit(f"{path}: no introspection data (build with the `python` feature)")
+    return fragments
+
+
+# ---------------------------------------------------------------------------
+# Rendering
+# ---------------------------------------------------------------------------
+
+
+class Renderer:
+    def __init__(self, fragments: list[dict]):
+        self.names = {f["id"]: f["name"] for f in fragments if "id" in f}
+        self.modules: set[str] = set()
+
+    def expr(self, node: dict, literal: bool = False) -> str:
+        kind = node["type"]
+        if kind == "name":
+            return node["id"]
+        if kind == "attribute":
+            value = self.expr(node["value"])
+            if node["value"]["type"] == "name":
+                self.modules.add(value)
+            return f"{value}.{node['attr']}"
+        if kind == "id":
+            return self.names[node["id"]]
+        if kind == "constant":
+            return self.constant(node, literal)
+        if kind == "binop":
+            return f"{self.expr(node['left'])} | {self.expr(node['right'])}"
+        if kind == "subscript":
+            value = self.expr(node["value"])
+            inner = node["slice"]
+            elts = inner["elts"] if inner["type"] == "tuple" else [inner]
+            items = ", ".join(self.expr(e, literal=value == "typing.Literal") for e in elts)
+            return f"{value}[{items}]"
+        if kind == "tuple":
+            items = [self.expr(e) for e in node["elts"]]
+            return f"({items[0]},)" if len(items) == 1 else f"({', '.join(items)})"
+        if kind == "list":
+            return f"[{', '.join(self.expr(e) for e in node['elts'])}]"
+        raise SystemExit(f"unsupported introspection expression: {node}")
+
+    def constant(self, node: dict, literal: bool) -> str:
+        kind = node["kind"]
+        if kind == "none":
+            return "None"
+        if kind == "bool":
+            return "True" if node["value"] else "False"
+        if kind == "ellipsis":
+            return "..."
+        if kind == "str" and not literal:
+            # A `signature = (...) -> "..."` annotation: emit as written
+            self.modules.update(re.findall(r"\b(collections\.abc|typing|types|builtins)\.", node["value"]))
+            return node["value"]
+        if kind == "str":
+            return repr(node["value"])
+        return node["value"]
+
+    def arguments(self, args: dict) -> str:
+        params = [a["name"] for a in args.get("posonlyargs", [])]
+        if len(params) > 1:
+            params.append("/")
+        params += [self.parameter(a) for a in args.get("args", [])]
+        if "vararg" in args:
+            params.append("*" + self.parameter(args["vararg"]))
+        elif args.get("kwonlyargs"):
+            params.append("*")
+        params += [self.parameter(a) for a in args.get("kwonlyargs", [])]
+        if "kwarg" in args:
+            params.append("**" + self.parameter(args["kwarg"]))
+        return ", ".join(params)
+
+    def parameter(self, arg: dict) -> str:
+        text = arg["name"]
+        if "annotation" in arg:
+            text += f": {self.expr(arg['annotation'])}"
+        if "default" in arg:
+            default = self.expr(arg["default"]) if arg["default"]["type"] == "constant" else "..."
+            text += f" = {default}" if "annotation" in arg else f"={default}"
+        return text
+
+    def function(self, node: dict, indent: str, name: str | None = None, returns: str | None = None) -> list[str]:
+        lines = [f"{indent}@{self.expr(d)}" for d in node.get("decorators", [])]
+        returns = returns or self.expr(node["returns"])
+        lines.append(f"{indent}def {name or node['name']}({self.arguments(node['arguments'])}) -> {returns}: ...")
+        return lines
+
+
+def awaited(returns: dict) -> str | None:
+    """`T` for an `Awaitable[T]` return annotation, else None."""
+    value = returns.get("value", "") if returns["type"] == "constant" else ""
+    prefix = "collections.abc.Awaitable["
+    return value[len(prefix) : -1] if value.startswith(prefix) and value.endswith("]") else None
+
+
+def method_order(node: dict) -> tuple:
+    is_property = any(d.get("id") == "property" for d in node.get("decorators", []))
+    return (node["name"] != "__new__", not is_property, node["name"])
+
+
+def load_module(path: Path):
+    spec = importlib.util.spec_from_file_location(MODULE, path)
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+def render(path: Path) -> str:
+    fragments = read_fragments(path)
+    module = load_module(path)
+    renderer = Renderer(fragments)
+    classes = {f["name"]: f for f in fragments if f["type"] == "class"}
+    functions = {f["name"]: f for f in fragments if f["type"] == "function" and "parent" not in f}
+    methods: dict[str, list[dict]] = {}
+    for f in fragments:
+        if f["type"] == "function" and "parent" in f:
+            methods.setdefault(renderer.names[f["parent"]], []).append(f)
+
+    body: list[str] = []
+    for name in sorted(n for n in dir(module) if not n.startswith("__")):
+        member = getattr(module, name)
+        body.append("")
+        if name in classes:
+            node = classes[name]
+            bases = [renderer.expr(b) for b in node.get("bases", [])]
+            body += [f"@{renderer.expr(d)}" for d in node.get("decorators", [])]
+            body.append(f"class {name}({', '.join(bases)}):" if bases else f"class {name}:")
+            members = sorted(methods.get(name, []), key=method_order)
+            for method in members:
+                body += renderer.function(method, "    ")
+            if name == SYNC_CLIENT:
+                # Same methods as the base, returning the result instead of an awaitable
+                for method in sorted(methods[SYNC_BASE], key=method_order):
+                    result = awaited(method["returns"])
+                    if result is not None:
+                        body += renderer.function(method, "    ", returns=result)
+            if not members:
+                body.append("    ...")
+        elif isinstance(member, type) and issubclass(member, BaseException):
+            base = member.__bases__[0]
+            renderer.modules.add(base.__module__)
+            body.append(f"class {name}({base.__module__}.{base.__qualname__}): ...")
+        elif name in functions or f"py_{name}" in functions:
+            # PyO3 records a renamed #[pyfunction] under its Rust name (`py_*` here)
+            body += renderer.function(functions.get(name) or functions[f"py_{name}"], "", name=name)
+        else:
+            raise SystemExit(f"{name}: no introspection data for this module member")
+
+    header = [
+        f"# Generated by scripts/generate_stubs.py from the `{MODULE}` extension. Do not edit.",
+        "",
+    ]
+    header += [f"import {m}" for m in sorted(renderer.modules)]
+    return "\n".join(header + body) + "\n"
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("extension", type=Path, help="built _nautilus_gmocoin shared library")
+    parser.add_argument("--check", action="store_true", help="fail if the committed stub differs")
+    parser.add_argument("--output", type=Path, default=STUB_PATH)
+    args = parser.parse_args()
+
+    stub = render(args.extension)
+    if args.check:
+        if not args.output.exists() or args.output.read_text() != stub:
+            sys.exit(f"{args.output} is out of date; run scripts/generate_stubs.py {args.extension}")
+        return
+    args.output.write_text(stub)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,251 @@
+//! Arrow RecordBatch export for bulk REST data.
+//!
+//! Batches are handed to Python through the Arrow PyCapsule interface, so
+//! `pyarrow.record_batch(batch)`, `pyarrow.table(batch)` and `polars.from_arrow`
+//! consume them without copying. Prices and sizes are `float64` for analysis;
+//! use the JSON methods when exact decimals matter.
+
+use std::ffi::CString;
+use std::sync::Arc;
+
+use arrow_array::ffi::{to_ffi, FFI_ArrowSchema};
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use arrow_array::{
+    Array, ArrayRef, Decimal128Array, Float64Array, RecordBatch, RecordBatchIterator, StringArray, StructArray,
+    TimestampMillisecondArray, UInt64Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+use crate::client::history::EXECUTION_COLUMNS;
+use crate::model::market_data::{Kline, Trade};
+use crate::model::order::Execution;
+use crate::time;
+
+/// A RecordBatch exposed via `__arrow_c_array__` / `__arrow_c_stream__`.
+#[pyclass(frozen)]
+pub struct ArrowRecordBatch {
+    batch: RecordBatch,
+}
+
+impl ArrowRecordBatch {
+    pub fn new(batch: RecordBatch) -> Self {
+        Self { batch }
+    }
+}
+
+pub(crate) fn arrow_err(e: ArrowError) -> PyErr {
+    PyValueError::new_err(format!("Arrow export failed: {}", e))
+}
+
+fn capsule_name(name: &str) -> CString {
+    CString::new(name).expect("capsule name has no NUL")
+}
+
+#[pymethods]
+impl ArrowRecordBatch {
+    #[getter]
+    fn num_rows(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    #[getter]
+    fn column_names(&self) -> Vec<String> {
+        self.batch.schema().fields().iter().map(|f| f.name().clone()).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let schema = FFI_ArrowSchema::try_from(self.batch.schema().as_ref()).map_err(arrow_err)?;
+        PyCapsule::new(py, schema, Some(capsule_name("arrow_schema")))
+    }
+
+    /// `requested_schema` is ignored; the batch is always exported as-is.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        let data = StructArray::from(self.batch.clone()).into_data();
+        let (array, schema) = to_ffi(&data).map_err(arrow_err)?;
+        Ok((
+            PyCapsule::new(py, schema, Some(capsule_name("arrow_schema")))?,
+            PyCapsule::new(py, array, Some(capsule_name("arrow_array")))?,
+        ))
+    }
+
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+        let reader = RecordBatchIterator::new([Ok(self.batch.clone())], self.batch.schema());
+        let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+        PyCapsule::new(py, stream, Some(capsule_name("arrow_array_stream")))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArrowRecordBatch(rows={}, columns={:?})", self.batch.num_rows(), self.column_names())
+    }
+}
+
+fn timestamp_field(name: &str) -> Field {
+    Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())), true)
+}
+
+fn float_column<'a>(values: impl Iterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(values.map(|v| v.and_then(|s| s.parse::<f64>().ok())).collect::<Float64Array>())
+}
+
+fn string_column<'a>(values: impl Iterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(values.collect::<StringArray>())
+}
+
+/// ISO-8601 (`2026-02-13T08:09:23.047Z`) to epoch ms.
+fn iso_millis(value: &str) -> Option<i64> {
+    time::timestamp_ms(value).ok()
+}
+
+fn timestamp_column(values: impl Iterator<Item = Option<i64>>) -> ArrayRef {
+    Arc::new(values.collect::<TimestampMillisecondArray>().with_timezone("UTC"))
+}
+
+/// Columns: open_time, open, high, low, close, volume.
+pub fn klines_batch(klines: &[Kline]) -> Result<RecordBatch, ArrowError> {
+    let schema = Schema::new(vec![
+        timestamp_field("open_time"),
+        Field::new("open", DataType::Float64, true),
+        Field::new("high", DataType::Float64, true),
+        Field::new("low", DataType::Float64, true),
+        Field::new("close", DataType::Float64, true),
+        Field::new("volume", DataType::Float64, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            timestamp_column(klines.iter().map(|k| k.open_time.parse::<i64>().ok())),
+            float_column(klines.iter().map(|k| Some(k.open.as_str()))),
+            float_column(klines.iter().map(|k| Some(k.high.as_str()))),
+            float_column(klines.iter().map(|k| Some(k.low.as_str()))),
+            float_column(klines.iter().map(|k| Some(k.close.as_str()))),
+            float_column(klines.iter().map(|k| Some(k.volume.as_str()))),
+        ],
+    )
+}
+
+/// Columns: timestamp, price, size, side.
+pub fn trades_batch(trades: &[Trade]) -> Result<RecordBatch, ArrowError> {
+    let schema = Schema::new(vec![
+        timestamp_field("timestamp"),
+        Field::new("price", DataType::Float64, true),
+        Field::new("size", DataType::Float64, true),
+        Field::new("side", DataType::Utf8, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            timestamp_column(trades.iter().map(|t| iso_millis(&t.timestamp))),
+            float_column(trades.iter().map(|t| Some(t.price.as_str()))),
+            float_column(trades.iter().map(|t| Some(t.size.as_str()))),
+            string_column(trades.iter().map(|t| Some(t.side.as_str()))),
+        ],
+    )
+}
+
+/// Columns: execution_id, order_id, symbol, side, settle_type, size, price,
+/// loss_gain, fee, timestamp.
+pub fn executions_batch(executions: &[Execution]) -> Result<RecordBatch, ArrowError> {
+    let schema = Schema::new(vec![
+        Field::new("execution_id", DataType::UInt64, false),
+        Field::new("order_id", DataType::UInt64, false),
+        Field::new("symbol", DataType::Utf8, true),
+        Field::new("side", DataType::Utf8, true),
+        Field::new("settle_type", DataType::Utf8, true),
+        Field::new("size", DataType::Float64, true),
+        Field::new("price", DataType::Float64, true),
+        Field::new("loss_gain", DataType::Float64, true),
+        Field::new("fee", DataType::Float64, true),
+        timestamp_field("timestamp"),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(executions.iter().map(|e| e.execution_id).collect::<UInt64Array>()),
+            Arc::new(executions.iter().map(|e| e.order_id).collect::<UInt64Array>()),
+            string_column(executions.iter().map(|e| Some(e.symbol.as_str()))),
+            string_column(executions.iter().map(|e| Some(e.side.as_str()))),
+            string_column(executions.iter().map(|e| e.settle_type.as_deref())),
+            float_column(executions.iter().map(|e| Some(e.size.as_str()))),
+            float_column(executions.iter().map(|e| Some(e.price.as_str()))),
+            float_column(executions.iter().map(|e| e.loss_gain.as_deref())),
+            float_column(executions.iter().map(|e| Some(e.fee.as_str()))),
+            timestamp_column(executions.iter().map(|e| iso_millis(&e.timestamp))),
+        ],
+    )
+}
+
+/// Decimal places of the amounts in `execution_ledger_batch`.
+const LEDGER_SCALE: u32 = 8;
+
+fn decimal_column<'a>(name: &str, values: impl Iterator<Item = Option<&'a str>>) -> Result<ArrayRef, ArrowError> {
+    let values = values
+        .map(|v| match v.filter(|s| !s.is_empty()) {
+            None => Ok(None),
+            Some(s) => {
+                let mut d = Decimal::from_str(s).map_err(|e| ArrowError::ParseError(format!("{} '{}': {}", name, s, e)))?;
+                if d.normalize().scale() > LEDGER_SCALE {
+                    return Err(ArrowError::InvalidArgumentError(format!("{} '{}' has more than {} decimals", name, s, LEDGER_SCALE)));
+                }
+                d.rescale(LEDGER_SCALE);
+                Ok(Some(d.mantissa()))
+            }
+        })
+        .collect::<Result<Decimal128Array, ArrowError>>()?;
+    Ok(Arc::new(values.with_precision_and_scale(38, LEDGER_SCALE as i8)?))
+}
+
+/// Executions with exact amounts for bookkeeping: the `EXECUTION_COLUMNS`,
+/// with size, price, loss_gain and fee as `decimal128(38, 8)`.
+pub fn execution_ledger_batch(executions: &[Execution]) -> Result<RecordBatch, ArrowError> {
+    let amount = DataType::Decimal128(38, LEDGER_SCALE as i8);
+    let [execution_id, order_id, symbol, side, settle_type, size, price, loss_gain, fee, timestamp] = EXECUTION_COLUMNS;
+    let schema = Schema::new(vec![
+        Field::new(execution_id, DataType::UInt64, false),
+        Field::new(order_id, DataType::UInt64, false),
+        Field::new(symbol, DataType::Utf8, false),
+        Field::new(side, DataType::Utf8, false),
+        Field::new(settle_type, DataType::Utf8, true),
+        Field::new(size, amount.clone(), false),
+        Field::new(price, amount.clone(), false),
+        Field::new(loss_gain, amount.clone(), true),
+        Field::new(fee, amount, false),
+        timestamp_field(timestamp),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(executions.iter().map(|e| e.execution_id).collect::<UInt64Array>()),
+            Arc::new(executions.iter().map(|e| e.order_id).collect::<UInt64Array>()),
+            string_column(executions.iter().map(|e| Some(e.symbol.as_str()))),
+            string_column(executions.iter().map(|e| Some(e.side.as_str()))),
+            string_column(executions.iter().map(|e| e.settle_type.as_deref())),
+            decimal_column(size, executions.iter().map(|e| Some(e.size.as_str())))?,
+            decimal_column(price, executions.iter().map(|e| Some(e.price.as_str())))?,
+            decimal_column(loss_gain, executions.iter().map(|e| e.loss_gain.as_deref()))?,
+            decimal_column(fee, executions.iter().map(|e| Some(e.fee.as_str())))?,
+            timestamp_column(executions.iter().map(|e| iso_millis(&e.timestamp))),
+        ],
+    )
+}
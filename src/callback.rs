@@ -0,0 +1,250 @@
+use pyo3::call::PyCallArgs;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::sync::MutexExt;
+use pyo3::types::{PyBytes, PyCFunction};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use tracing::error;
+
+use crate::py_json::PyJson;
+use crate::stats::ClientStats;
+
+/// How WS event payloads are handed to Python callbacks.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CallbackPayload {
+    /// Model objects (data client) or dicts (execution clients).
+    #[default]
+    Object,
+    /// MessagePack bytes for the callback to decode (e.g. `msgspec.msgpack.decode`),
+    /// cheaper than building Python objects on high-frequency streams.
+    MsgPack,
+}
+
+impl CallbackPayload {
+    /// MessagePack with field names, so it decodes to the same dict shape as JSON.
+    pub fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+        rmp_serde::to_vec_named(value).expect("WS payloads serialize to MessagePack")
+    }
+}
+
+/// Accepts `"object"` (default) or `"msgpack"`.
+impl<'a, 'py> FromPyObject<'a, 'py> for CallbackPayload {
+    type Error = PyErr;
+
+    const INPUT_TYPE: pyo3::inspect::PyStaticExpr = pyo3::type_hint_identifier!("builtins", "str");
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let name = obj.extract::<String>()?;
+        match name.to_ascii_lowercase().as_str() {
+            "object" => Ok(CallbackPayload::Object),
+            "msgpack" => Ok(CallbackPayload::MsgPack),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown callback_payload '{}' (expected 'object' or 'msgpack')",
+                name
+            ))),
+        }
+    }
+}
+
+/// Which market data updates reach a data callback, checked before any
+/// Python object is built; `None` lets every symbol or channel through.
+/// Status events (`"maintenance"`, `"reconnect"`, ...) are not filtered.
+#[derive(Clone, Debug, Default)]
+pub struct CallbackFilter {
+    pub symbols: Option<HashSet<String>>,
+    pub channels: Option<HashSet<String>>,
+}
+
+impl CallbackFilter {
+    pub fn allows(&self, channel: &str, symbol: &str) -> bool {
+        self.channels.as_ref().is_none_or(|channels| channels.contains(channel))
+            && self.symbols.as_ref().is_none_or(|symbols| symbols.contains(symbol))
+    }
+}
+
+/// A Python callback. `async def` callbacks remember the event loop they were
+/// set from and run there as tasks, since events arrive on Tokio threads.
+pub struct Callback {
+    func: Py<PyAny>,
+    event_loop: Option<Py<PyAny>>,
+}
+
+impl Callback {
+    fn new(py: Python<'_>, func: Py<PyAny>) -> PyResult<Self> {
+        let is_async = py
+            .import("inspect")?
+            .call_method1("iscoroutinefunction", (&func,))?
+            .is_truthy()?;
+        let event_loop = if is_async {
+            let event_loop = py.import("asyncio")?.call_method0("get_running_loop").map_err(|_| {
+                PyRuntimeError::new_err(
+                    "async callbacks must be set from a running event loop (call set_*_callback inside a coroutine)",
+                )
+            })?;
+            Some(event_loop.unbind())
+        } else {
+            None
+        };
+        Ok(Self { func, event_loop })
+    }
+
+    /// Call a sync callback directly; an async one is scheduled on its loop
+    /// with `call_soon_threadsafe`, as a task whose exception goes to `slot`.
+    fn call1<'py, A: PyCallArgs<'py>>(
+        &self,
+        py: Python<'py>,
+        slot: &CallbackSlot,
+        event_type: &'static str,
+        args: A,
+    ) -> PyResult<()> {
+        let result = self.func.bind(py).call1(args)?;
+        let Some(event_loop) = &self.event_loop else { return Ok(()) };
+        let event_loop = event_loop.bind(py);
+        let slot = slot.clone();
+        // Runs on the loop thread
+        let start_task = PyCFunction::new_closure(py, None, None, move |args, _kwargs| -> PyResult<()> {
+            let py = args.py();
+            let task = py.import("asyncio")?.call_method1("ensure_future", (args.get_item(0)?,))?;
+            let slot = slot.clone();
+            let on_done = PyCFunction::new_closure(py, None, None, move |args, _kwargs| -> PyResult<()> {
+                let task = args.get_item(0)?;
+                if !task.call_method0("cancelled")?.is_truthy()? {
+                    let exc = task.call_method0("exception")?;
+                    if !exc.is_none() {
+                        slot.report(args.py(), event_type, PyErr::from_value(exc));
+                    }
+                }
+                Ok(())
+            })?;
+            task.call_method1("add_done_callback", (on_done,))?;
+            Ok(())
+        })?;
+        if let Err(e) = event_loop.call_method1("call_soon_threadsafe", (start_task, &result)) {
+            // Loop closed: drop the coroutine without a "never awaited" warning
+            let _ = result.call_method0("close");
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+/// A replaceable Python callback shared with the WebSocket loops.
+///
+/// The slot's mutex is only held long enough to clone the `Arc`; the
+/// callback itself is invoked afterwards, so a slow (or re-entrant)
+/// Python handler never blocks `set_*_callback` or other dispatchers.
+///
+/// Deliveries are serialized per slot (see `dispatch_turn`): on
+/// free-threaded Python the GIL no longer keeps two WS tasks from running
+/// the same handler at once.
+///
+/// An exception from the callback is logged with its traceback, counted
+/// in the client's stats (error class `"callback"`) and passed to the
+/// error handler if one is set; the next event is still delivered.
+#[derive(Clone, Default)]
+pub struct CallbackSlot {
+    callback: Arc<Mutex<Option<Arc<Callback>>>>,
+    error_handler: Arc<Mutex<Option<Arc<Py<PyAny>>>>>,
+    dispatch: Arc<Mutex<()>>,
+    filter: Arc<RwLock<CallbackFilter>>,
+    payload: CallbackPayload,
+    stats: ClientStats,
+}
+
+impl CallbackSlot {
+    /// `stats` is the owning client's, so callback failures show in its `get_stats()`.
+    pub fn new(payload: CallbackPayload, stats: ClientStats) -> Self {
+        Self { payload, stats, ..Self::default() }
+    }
+
+    /// Fails for an `async def` callback when no event loop is running.
+    pub fn set(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<()> {
+        let callback = Callback::new(py, callback)?;
+        *self.callback.lock().unwrap() = Some(Arc::new(callback));
+        Ok(())
+    }
+
+    /// `handler(event_type, exception)`, or `None` to only log and count.
+    pub fn set_error_handler(&self, handler: Option<Py<PyAny>>) {
+        *self.error_handler.lock().unwrap() = handler.map(Arc::new);
+    }
+
+    pub fn get(&self) -> Option<Arc<Callback>> {
+        self.callback.lock().unwrap().clone()
+    }
+
+    pub fn payload(&self) -> CallbackPayload {
+        self.payload
+    }
+
+    pub fn set_filter(&self, filter: CallbackFilter) {
+        *self.filter.write().unwrap() = filter;
+    }
+
+    /// Whether a `channel` update for `symbol` passes the filter.
+    pub fn allows(&self, channel: &str, symbol: &str) -> bool {
+        self.filter.read().unwrap().allows(channel, symbol)
+    }
+
+    /// Hold while delivering events so the callback never runs concurrently
+    /// with itself. Waits detached from the interpreter, so a thread holding
+    /// the GIL (or blocking a stop-the-world pause) is never waited on.
+    /// The callback must not synchronously trigger a delivery on the same slot.
+    pub fn dispatch_turn(&self, py: Python<'_>) -> MutexGuard<'_, ()> {
+        self.dispatch.lock_py_attached(py).unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Call `cb` (from `get`) with `args`, reporting any exception.
+    pub fn invoke<'py, A: PyCallArgs<'py>>(&self, py: Python<'py>, cb: &Callback, event_type: &'static str, args: A) {
+        if let Err(err) = cb.call1(py, self, event_type, args) {
+            self.report(py, event_type, err);
+        }
+    }
+
+    fn report(&self, py: Python<'_>, event_type: &'static str, err: PyErr) {
+        let traceback = err.traceback(py).and_then(|tb| tb.format().ok()).unwrap_or_default();
+        error!("GMO: Callback raised on '{}' event: {}\n{}", event_type, err, traceback.trim_end());
+        self.stats.callback_error(format!("{} ({})", err, event_type));
+
+        let handler = self.error_handler.lock().unwrap().clone();
+        if let Some(handler) = handler {
+            if let Err(handler_err) = handler.call1(py, (event_type, err.value(py))) {
+                error!("GMO: Callback error handler raised: {}", handler_err);
+            }
+        }
+    }
+
+    /// Call with `(event_type, data)` for each event, `data` as a dict or
+    /// MessagePack bytes. Encoding happens before the GIL is taken; only the
+    /// conversion to Python and the calls hold it.
+    pub fn call_events<I>(&self, events: I)
+    where
+        I: IntoIterator<Item = (&'static str, serde_json::Value)>,
+    {
+        let Some(cb) = self.get() else { return };
+        match self.payload {
+            CallbackPayload::Object => {
+                Python::try_attach(|py| {
+                    let _turn = self.dispatch_turn(py);
+                    for (event_type, data) in events {
+                        self.invoke(py, &cb, event_type, (event_type, PyJson(data)));
+                    }
+                });
+            }
+            CallbackPayload::MsgPack => {
+                let encoded: Vec<_> = events
+                    .into_iter()
+                    .map(|(event_type, data)| (event_type, CallbackPayload::encode(&data)))
+                    .collect();
+                Python::try_attach(|py| {
+                    let _turn = self.dispatch_turn(py);
+                    for (event_type, bytes) in encoded {
+                        self.invoke(py, &cb, event_type, (event_type, PyBytes::new(py, &bytes)));
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+use crate::client::endpoint::EndpointGroup;
+use crate::error::GmocoinError;
+
+/// Per endpoint-group circuit breaker.
+///
+/// After `threshold` consecutive venue-side failures (transport errors,
+/// maintenance / system error responses) the group is opened for `cooldown`
+/// and every call fails fast with `GmocoinError::CircuitOpen`. Once the
+/// cooldown elapses a single trial request is let through (half-open) while
+/// the others keep failing fast; success closes the circuit, another failure
+/// re-opens it immediately. A trial whose outcome is never recorded (the
+/// call was dropped) gives way to a new one after another cooldown.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    inner: Arc<Mutex<HashMap<EndpointGroup, CircuitState>>>,
+    threshold: u32,
+    cooldown: Duration,
+}
+
+#[derive(Default)]
+struct CircuitState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// Half-open: the trial request is out; `open_until` bounds the wait
+    /// for its outcome.
+    trial_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// `threshold == 0` disables the breaker.
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+            threshold,
+            cooldown,
+        }
+    }
+
+    /// Fail fast if the circuit for `group` is open.
+    pub fn check(&self, group: EndpointGroup) -> Result<(), GmocoinError> {
+        if self.threshold == 0 {
+            return Ok(());
+        }
+        let mut states = self.inner.lock().unwrap();
+        let state = states.entry(group).or_default();
+        if let Some(until) = state.open_until {
+            let now = Instant::now();
+            if now < until {
+                return Err(GmocoinError::CircuitOpen {
+                    group: group.as_str(),
+                    retry_after_ms: until.duration_since(now).as_millis() as u64,
+                });
+            }
+            // Half-open: this call is the trial; one more failure re-opens.
+            state.open_until = Some(now + self.cooldown);
+            state.trial_in_flight = true;
+            state.consecutive_failures = self.threshold - 1;
+        }
+        Ok(())
+    }
+
+    /// Groups failing fast right now.
+    pub fn open_groups(&self) -> Vec<&'static str> {
+        let now = Instant::now();
+        let mut open: Vec<_> = self
+            .inner
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, state)| state.open_until.is_some_and(|until| now < until))
+            .map(|(group, _)| group.as_str())
+            .collect();
+        open.sort_unstable();
+        open
+    }
+
+    /// Record the outcome of a request made to `group`.
+    pub fn record<T>(&self, group: EndpointGroup, result: &Result<T, GmocoinError>) {
+        if self.threshold == 0 {
+            return;
+        }
+        let mut states = self.inner.lock().unwrap();
+        let state = states.entry(group).or_default();
+        match result {
+            Err(e) if e.is_venue_failure() => {
+                state.consecutive_failures += 1;
+                if state.consecutive_failures >= self.threshold && (state.open_until.is_none() || state.trial_in_flight) {
+                    state.open_until = Some(Instant::now() + self.cooldown);
+                    state.trial_in_flight = false;
+                    warn!(
+                        "GMO: Circuit opened for '{}' after {} consecutive failures; failing fast for {}s",
+                        group, state.consecutive_failures, self.cooldown.as_secs()
+                    );
+                }
+            }
+            // Shed, or cut short by the caller's deadline: says nothing
+            // about the venue's health, so the next call makes the trial
+            Err(GmocoinError::Shed(_) | GmocoinError::DeadlineExceeded(_)) => {
+                if state.trial_in_flight {
+                    state.trial_in_flight = false;
+                    state.open_until = Some(Instant::now());
+                }
+            }
+            _ => {
+                if state.consecutive_failures >= self.threshold {
+                    info!("GMO: Circuit closed for '{}'", group);
+                }
+                state.consecutive_failures = 0;
+                state.open_until = None;
+                state.trial_in_flight = false;
+            }
+        }
+    }
+}
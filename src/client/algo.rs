@@ -0,0 +1,339 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, Notify};
+use tokio::time::{sleep_until, Duration, Instant};
+use tracing::{info, warn};
+
+use crate::client::data_client::MarketEvent;
+use crate::client::execution_client::OrderEvent;
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::order::Execution;
+
+/// How long fills of the last children are awaited after the window ends.
+const SETTLE: Duration = Duration::from_secs(5);
+
+/// A parent order sliced evenly over a time window.
+#[derive(Clone, Debug)]
+pub struct TwapConfig {
+    pub symbol: String,
+    /// `"BUY"` or `"SELL"`
+    pub side: String,
+    pub quantity: Decimal,
+    pub duration: Duration,
+    pub slices: u32,
+    /// Children are LIMIT orders at this price, cancelled at the next slice
+    /// when unfilled; MARKET orders otherwise.
+    pub price: Option<Decimal>,
+    /// Child sizes are rounded down to this step (the symbol's `sizeStep`).
+    pub size_step: Option<Decimal>,
+    /// At most this fraction of the market volume traded since the start
+    /// is executed; needs a data client receiving the symbol's trades.
+    pub participation_cap: Option<Decimal>,
+    /// `settleType` of leverage children (`"OPEN"` / `"CLOSE"`).
+    pub settle_type: Option<String>,
+}
+
+impl TwapConfig {
+    pub fn validate(&self) -> Result<(), GmocoinError> {
+        let invalid = |msg: &str| Err(GmocoinError::InvalidInput(format!("Invalid TWAP: {}", msg)));
+        if !matches!(self.side.as_str(), "BUY" | "SELL") {
+            return invalid("side must be BUY or SELL");
+        }
+        if self.quantity <= Decimal::ZERO {
+            return invalid("quantity must be positive");
+        }
+        if self.slices == 0 {
+            return invalid("slices must be at least 1");
+        }
+        if self.price.is_some_and(|p| p <= Decimal::ZERO) || self.size_step.is_some_and(|s| s <= Decimal::ZERO) {
+            return invalid("price and size_step must be positive");
+        }
+        if self.participation_cap.is_some_and(|c| c <= Decimal::ZERO || c > Decimal::ONE) {
+            return invalid("participation_cap must be in (0, 1]");
+        }
+        Ok(())
+    }
+
+    /// Cumulative quantity due by the end of slice `index` (0-based).
+    fn scheduled(&self, index: u32) -> Decimal {
+        self.quantity * Decimal::from(index + 1) / Decimal::from(self.slices)
+    }
+
+    fn round(&self, size: Decimal) -> Decimal {
+        match self.size_step {
+            Some(step) => (size / step).floor() * step,
+            None => size.round_dp_with_strategy(8, rust_decimal::RoundingStrategy::ToZero),
+        }
+        .normalize()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgoState {
+    Running,
+    /// The whole quantity filled.
+    Completed,
+    /// The window ended with quantity left.
+    Expired,
+    Cancelled,
+}
+
+/// Parent-level progress, emitted as `"AlgoProgress"`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AlgoProgress {
+    pub algo_id: u64,
+    pub symbol: String,
+    pub side: String,
+    pub quantity: Decimal,
+    pub filled: Decimal,
+    pub remaining: Decimal,
+    pub avg_price: Option<Decimal>,
+    pub slices: u32,
+    pub slices_done: u32,
+    /// Venue order ids of the child orders.
+    pub children: Vec<u64>,
+    pub state: AlgoState,
+}
+
+impl AlgoProgress {
+    pub fn new(algo_id: u64, config: &TwapConfig) -> Self {
+        Self {
+            algo_id,
+            symbol: config.symbol.clone(),
+            side: config.side.clone(),
+            quantity: config.quantity,
+            filled: Decimal::ZERO,
+            remaining: config.quantity,
+            avg_price: None,
+            slices: config.slices,
+            slices_done: 0,
+            children: Vec::new(),
+            state: AlgoState::Running,
+        }
+    }
+}
+
+struct Child {
+    order_id: u64,
+    size: Decimal,
+    filled: Decimal,
+    cancelled: bool,
+}
+
+impl Child {
+    /// What the child may still execute counts against the schedule until
+    /// it is cancelled.
+    fn committed(&self) -> Decimal {
+        if self.cancelled { self.filled } else { self.size }
+    }
+}
+
+/// A running algorithm: stopped through `stop`, observed through
+/// `progress`.
+#[derive(Clone)]
+pub(crate) struct AlgoHandle {
+    pub stop: Arc<Notify>,
+    pub progress: Arc<Mutex<AlgoProgress>>,
+}
+
+struct Twap<'a> {
+    rest: &'a GmocoinRestClient,
+    config: &'a TwapConfig,
+    progress: &'a Mutex<AlgoProgress>,
+    emit: &'a (dyn Fn(&AlgoProgress) + Send + Sync),
+    children: Vec<Child>,
+    notional: Decimal,
+    market_volume: Decimal,
+}
+
+impl Twap<'_> {
+    fn update(&self, f: impl FnOnce(&mut AlgoProgress)) {
+        let snapshot = {
+            let mut progress = self.progress.lock().unwrap();
+            f(&mut progress);
+            progress.clone()
+        };
+        (self.emit)(&snapshot);
+    }
+
+    fn filled(&self) -> Decimal {
+        self.children.iter().map(|c| c.filled).sum()
+    }
+
+    fn on_fill(&mut self, execution: &Execution) {
+        let Some(child) = self.children.iter_mut().find(|c| c.order_id == execution.order_id) else { return };
+        let (Ok(price), Ok(size)) = (Decimal::from_str(&execution.price), Decimal::from_str(&execution.size)) else { return };
+        child.filled += size;
+        self.notional += price * size;
+        let filled = self.filled();
+        let avg_price = (self.notional / filled).round_dp(8).normalize();
+        self.update(|p| {
+            p.filled = filled.normalize();
+            p.remaining = (p.quantity - filled).max(Decimal::ZERO).normalize();
+            p.avg_price = Some(avg_price);
+        });
+    }
+
+    /// Cancel the working LIMIT child, if any. A failed cancel leaves it
+    /// committed, as it most likely filled already.
+    async fn cancel_working(&mut self) {
+        let Some(child) = self.children.last_mut().filter(|c| !c.cancelled && c.filled < c.size) else { return };
+        if self.config.price.is_none() {
+            return;
+        }
+        match self.rest.cancel_order(child.order_id).await {
+            Ok(_) => child.cancelled = true,
+            Err(e) => warn!("GMO: TWAP child {} cancel failed: {}", child.order_id, e),
+        }
+    }
+
+    async fn slice(&mut self, index: u32) {
+        self.cancel_working().await;
+        let mut due = self.config.scheduled(index);
+        if let Some(cap) = self.config.participation_cap {
+            due = due.min(cap * self.market_volume);
+        }
+        let committed: Decimal = self.children.iter().map(Child::committed).sum();
+        let size = self.config.round(due - committed);
+        if size > Decimal::ZERO {
+            let execution_type = if self.config.price.is_some() { "LIMIT" } else { "MARKET" };
+            let price = self.config.price.map(|p| p.to_string());
+            let result = self
+                .rest
+                .submit_order(
+                    &self.config.symbol,
+                    &self.config.side,
+                    execution_type,
+                    &size.to_string(),
+                    price.as_deref(),
+                    None,
+                    None,
+                    None,
+                    self.config.settle_type.as_deref(),
+                )
+                .await;
+            match result.map(|r| r.as_str().and_then(|id| id.parse::<u64>().ok())) {
+                Ok(Some(order_id)) => {
+                    self.children.push(Child { order_id, size, filled: Decimal::ZERO, cancelled: false });
+                }
+                Ok(None) => warn!("GMO: TWAP child order returned no order id"),
+                // The shortfall carries over to the next slice
+                Err(e) => warn!("GMO: TWAP child order failed: {}", e),
+            }
+        }
+        let children: Vec<u64> = self.children.iter().map(|c| c.order_id).collect();
+        self.update(|p| {
+            p.slices_done = index + 1;
+            p.children = children;
+        });
+    }
+
+    fn on_market(&mut self, event: MarketEvent) {
+        if let MarketEvent::Trade(trade) = event {
+            if trade.symbol.as_deref().is_some_and(|s| s != self.config.symbol) {
+                return;
+            }
+            if let Ok(size) = Decimal::from_str(&trade.size) {
+                self.market_volume += size;
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.filled() >= self.config.quantity
+    }
+}
+
+async fn next_market(market: &mut Option<broadcast::Receiver<MarketEvent>>) -> Option<MarketEvent> {
+    let Some(receiver) = market.as_mut() else { return std::future::pending().await };
+    loop {
+        match receiver.recv().await {
+            Ok(event) => return Some(event),
+            Err(broadcast::error::RecvError::Lagged(n)) => warn!("GMO: TWAP missed {} market events", n),
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// Slice `config.quantity` over `config.duration`: at the start of each of
+/// `config.slices` intervals, cancel the unfilled LIMIT child and submit the
+/// quantity due so far less what is filled or working. Fills are read from
+/// `orders` (the Private WS events); `market` carries the trades for the
+/// participation cap. Every change is reported through `emit`.
+pub(crate) async fn run_twap(
+    rest: GmocoinRestClient,
+    config: TwapConfig,
+    mut orders: broadcast::Receiver<OrderEvent>,
+    mut market: Option<broadcast::Receiver<MarketEvent>>,
+    handle: AlgoHandle,
+    emit: impl Fn(&AlgoProgress) + Send + Sync,
+) {
+    let mut twap = Twap {
+        rest: &rest,
+        config: &config,
+        progress: &handle.progress,
+        emit: &emit,
+        children: Vec::new(),
+        notional: Decimal::ZERO,
+        market_volume: Decimal::ZERO,
+    };
+    let start = Instant::now();
+    let interval = config.duration / config.slices;
+    let end = start + config.duration;
+    let mut index = 0;
+    let state = loop {
+        if twap.done() {
+            break AlgoState::Completed;
+        }
+        let next_slice = if index < config.slices { start + interval * index } else { end };
+        let ended = index >= config.slices && Instant::now() >= end;
+        if ended {
+            break AlgoState::Expired;
+        }
+        tokio::select! {
+            _ = handle.stop.notified() => break AlgoState::Cancelled,
+            _ = sleep_until(next_slice) => {
+                if index < config.slices {
+                    twap.slice(index).await;
+                    index += 1;
+                }
+            }
+            event = orders.recv() => match event {
+                Ok(OrderEvent { event_type: "ExecutionUpdate", data }) => {
+                    if let Ok(execution) = Execution::deserialize(&data) {
+                        twap.on_fill(&execution);
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(n)) => warn!("GMO: TWAP missed {} order events", n),
+                Err(broadcast::error::RecvError::Closed) => break AlgoState::Cancelled,
+            },
+            Some(event) = next_market(&mut market) => twap.on_market(event),
+        }
+    };
+
+    // Stop the working child, then give in-flight fills a moment to arrive
+    if state != AlgoState::Completed {
+        twap.cancel_working().await;
+        let settle = Instant::now() + SETTLE;
+        let committed: Decimal = twap.children.iter().map(Child::committed).sum();
+        while twap.filled() < committed {
+            match tokio::time::timeout_at(settle, orders.recv()).await {
+                Ok(Ok(OrderEvent { event_type: "ExecutionUpdate", data })) => {
+                    if let Ok(execution) = Execution::deserialize(&data) {
+                        twap.on_fill(&execution);
+                    }
+                }
+                Ok(Ok(_)) | Ok(Err(broadcast::error::RecvError::Lagged(_))) => {}
+                _ => break,
+            }
+        }
+    }
+    let state = if twap.done() { AlgoState::Completed } else { state };
+    info!("GMO: TWAP {} {:?}: filled {} of {}", config.symbol, state, twap.filled(), config.quantity);
+    twap.update(|p| p.state = state);
+}
@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::model::order::Order;
+use crate::model::report::decimal;
+
+/// Lifecycle of a `changeOrder` sent by this client: `"OrderAmendPending"`
+/// once GMO accepts it, then `"OrderAmended"` or `"OrderAmendRejected"`
+/// from the order's next `orderEvents` message.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AmendEvent {
+    pub order_id: u64,
+    /// The requested price and losscut price.
+    pub price: Decimal,
+    pub losscut_price: Option<Decimal>,
+    /// `orderStatus` and `orderPrice` of the resolving `orderEvents`
+    /// message; `None` while pending.
+    pub status: Option<String>,
+    pub order_price: Option<String>,
+}
+
+struct Pending {
+    event: AmendEvent,
+    acked: bool,
+    /// An outcome seen before GMO's response to the change arrived.
+    outcome: Option<(&'static str, AmendEvent)>,
+}
+
+/// Price changes in flight, by order id.
+#[derive(Clone, Default)]
+pub struct AmendTracker {
+    pending: Arc<Mutex<HashMap<u64, Pending>>>,
+}
+
+impl AmendTracker {
+    /// Record a change about to be sent; a later one for the same order
+    /// replaces it.
+    pub fn begin(&self, order_id: u64, price: Decimal, losscut_price: Option<Decimal>) {
+        let event = AmendEvent { order_id, price, losscut_price, status: None, order_price: None };
+        self.pending.lock().unwrap().insert(order_id, Pending { event, acked: false, outcome: None });
+    }
+
+    /// The change failed to send.
+    pub fn cancel(&self, order_id: u64) {
+        self.pending.lock().unwrap().remove(&order_id);
+    }
+
+    /// GMO accepted the change: the events to emit, with the outcome if
+    /// `orderEvents` already reported it.
+    pub fn ack(&self, order_id: u64) -> Vec<(&'static str, AmendEvent)> {
+        let mut pending = self.pending.lock().unwrap();
+        let Some(entry) = pending.get_mut(&order_id) else {
+            return Vec::new();
+        };
+        let mut events = vec![("OrderAmendPending", entry.event.clone())];
+        match entry.outcome.take() {
+            Some(outcome) => {
+                events.push(outcome);
+                pending.remove(&order_id);
+            }
+            None => entry.acked = true,
+        }
+        events
+    }
+
+    /// The outcome `order` (an `orderEvents` message) gives a pending
+    /// change: amended when it shows the requested price, rejected
+    /// otherwise. `MODIFYING` messages are passed over.
+    pub fn resolve(&self, order: &Order) -> Option<(&'static str, AmendEvent)> {
+        if order.status == "MODIFYING" {
+            return None;
+        }
+        let mut pending = self.pending.lock().unwrap();
+        let entry = pending.get_mut(&order.order_id)?;
+        let price = order.price.as_deref().and_then(|p| decimal("orderPrice", p).ok());
+        let event_type = if price == Some(entry.event.price) { "OrderAmended" } else { "OrderAmendRejected" };
+        let event = AmendEvent {
+            status: Some(order.status.clone()),
+            order_price: order.price.clone(),
+            ..entry.event.clone()
+        };
+        if !entry.acked {
+            entry.outcome.get_or_insert((event_type, event));
+            return None;
+        }
+        pending.remove(&order.order_id);
+        Some((event_type, event))
+    }
+}
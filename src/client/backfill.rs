@@ -0,0 +1,112 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio::time::Duration;
+
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::market_data::Trade;
+
+/// `/v1/trades` page size.
+const PAGE: usize = 100;
+
+/// How long a backfill waits for the first live trade before checking the
+/// seam without it.
+pub const SEAM_WAIT: Duration = Duration::from_secs(3);
+
+/// Trades possibly missed between a backfill and the live stream: newer than
+/// the backfill, older than anything REST still returns, and not received
+/// live. Delivered on the `"trade_gap"` channel.
+#[derive(Clone, Debug, Serialize)]
+pub struct TradeGap {
+    pub symbol: String,
+    /// Timestamp of the newest backfilled trade.
+    pub after: String,
+    /// Timestamp of the oldest trade REST returned when re-checked.
+    pub before: String,
+}
+
+type TradeKey = (Option<u64>, String, String);
+
+fn key(trade: &Trade) -> TradeKey {
+    (trade.ts_event, trade.price.clone(), trade.size.clone())
+}
+
+/// Trades already delivered where a REST backfill meets the live stream.
+/// GMO trades carry no id, so they are matched on timestamp, price and
+/// size; each delivered trade cancels at most one later copy, so distinct
+/// trades that happen to look alike still go through.
+#[derive(Clone, Default)]
+pub struct Delivered {
+    keys: HashMap<TradeKey, usize>,
+}
+
+impl Delivered {
+    pub fn add(&mut self, trades: &[Trade]) {
+        for trade in trades {
+            *self.keys.entry(key(trade)).or_default() += 1;
+        }
+    }
+
+    /// Whether `trade` was delivered, consuming the match.
+    pub fn take(&mut self, trade: &Trade) -> bool {
+        match self.keys.get_mut(&key(trade)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// `trades` less those delivered, without consuming the matches.
+    pub fn not_in(&self, trades: Vec<Trade>) -> Vec<Trade> {
+        let mut delivered = self.clone();
+        trades.into_iter().filter(|trade| !delivered.take(trade)).collect()
+    }
+}
+
+/// The `count` most recent trades of `symbol`, oldest first, flagged
+/// `historical`, and whether that is all REST has (a short last page).
+pub async fn recent_trades(rest: &GmocoinRestClient, symbol: &str, count: usize) -> Result<(Vec<Trade>, bool), GmocoinError> {
+    let mut trades = Vec::new();
+    let mut page = 1;
+    let mut exhausted = false;
+    while trades.len() < count {
+        let list = rest.get_trades(symbol, page, PAGE as i32).await?.list;
+        exhausted = list.len() < PAGE;
+        trades.extend(list);
+        if exhausted {
+            break;
+        }
+        page += 1;
+    }
+    exhausted &= trades.len() <= count;
+    trades.truncate(count);
+    trades.reverse();
+    for trade in &mut trades {
+        trade.symbol = Some(symbol.to_string());
+        trade.historical = true;
+    }
+    Ok((trades, exhausted))
+}
+
+/// Re-check the seam after `newest` (the newest backfilled trade) with a
+/// fresh page: the trades not yet `delivered` from `newest` on, and a gap
+/// when the page no longer reaches back to `newest`.
+pub async fn fill_seam(
+    rest: &GmocoinRestClient,
+    symbol: &str,
+    newest: &Trade,
+    delivered: &mut Delivered,
+) -> Result<(Vec<Trade>, Option<TradeGap>), GmocoinError> {
+    let (page, exhausted) = recent_trades(rest, symbol, PAGE).await?;
+    let reaches = exhausted || page.first().is_some_and(|oldest| oldest.ts_event <= newest.ts_event);
+    let gap = (!reaches).then(|| TradeGap {
+        symbol: symbol.to_string(),
+        after: newest.timestamp.clone(),
+        before: page.first().map(|oldest| oldest.timestamp.clone()).unwrap_or_default(),
+    });
+    let fill = delivered.not_in(page.into_iter().filter(|trade| trade.ts_event >= newest.ts_event).collect());
+    delivered.add(&fill);
+    Ok((fill, gap))
+}
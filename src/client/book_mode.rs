@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::model::orderbook::{BestBidOffer, BookDelta, OrderBook};
+
+/// What an `orderbooks` subscription delivers to the data callback. The
+/// book is kept in Rust either way (`book()`, the market feed).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BookMode {
+    /// Every book as received (`OrderBook`, `"orderbooks"` channel).
+    #[default]
+    Snapshot,
+    /// The levels changed since the last emission (`BookDelta`,
+    /// `"book_deltas"` channel), starting with the whole book.
+    Delta,
+    /// The best ask and bid, only when they change (`BestBidOffer`,
+    /// `"bbo"` channel).
+    Bbo,
+}
+
+/// Accepts `"snapshot"` (default), `"delta"` or `"bbo"`.
+#[cfg(feature = "python")]
+impl<'a, 'py> FromPyObject<'a, 'py> for BookMode {
+    type Error = PyErr;
+
+    const INPUT_TYPE: pyo3::inspect::PyStaticExpr = pyo3::type_hint_identifier!("builtins", "str");
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let name = obj.extract::<String>()?;
+        match name.to_ascii_lowercase().as_str() {
+            "snapshot" => Ok(BookMode::Snapshot),
+            "delta" => Ok(BookMode::Delta),
+            "bbo" => Ok(BookMode::Bbo),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown book_mode '{}' (expected 'snapshot', 'delta' or 'bbo')",
+                name
+            ))),
+        }
+    }
+}
+
+/// A book update as the symbol's mode delivers it.
+pub enum BookEmission {
+    Snapshot(OrderBook),
+    Delta(BookDelta),
+    Bbo(BestBidOffer),
+}
+
+/// Book modes per symbol, with what was last emitted in each so deltas
+/// and BBO changes are relative to what the callback has seen.
+#[derive(Default)]
+pub struct BookModes {
+    modes: HashMap<String, BookMode>,
+    /// Delta mode: the book the last delta brought the receiver to.
+    delta_base: HashMap<String, OrderBook>,
+    /// BBO mode: the last best ask and bid emitted.
+    last_bbo: HashMap<String, BestBidOffer>,
+}
+
+impl BookModes {
+    pub fn mode(&self, symbol: &str) -> BookMode {
+        self.modes.get(symbol).copied().unwrap_or_default()
+    }
+
+    /// Use `mode` for `symbol`; the next emission starts afresh (a delta
+    /// snapshot, or the current BBO).
+    pub fn set(&mut self, symbol: &str, mode: BookMode) {
+        self.clear(symbol);
+        if mode != BookMode::Snapshot {
+            self.modes.insert(symbol.to_string(), mode);
+        }
+    }
+
+    /// Back to the default mode, forgetting what was emitted.
+    pub fn clear(&mut self, symbol: &str) {
+        self.modes.remove(symbol);
+        self.delta_base.remove(symbol);
+        self.last_bbo.remove(symbol);
+    }
+
+    /// What to deliver for `book`; `None` when nothing the mode covers
+    /// changed.
+    pub fn emission(&mut self, book: OrderBook) -> Option<BookEmission> {
+        match self.mode(&book.symbol) {
+            BookMode::Snapshot => Some(BookEmission::Snapshot(book)),
+            BookMode::Delta => {
+                let delta = book.delta(self.delta_base.get(&book.symbol));
+                self.delta_base.insert(book.symbol.clone(), book);
+                (delta.snapshot || !delta.asks.is_empty() || !delta.bids.is_empty()).then_some(BookEmission::Delta(delta))
+            }
+            BookMode::Bbo => {
+                let bbo = book.bbo();
+                let unchanged = self.last_bbo.get(&book.symbol).is_some_and(|last| {
+                    (&last.ask_price, &last.ask_size, &last.bid_price, &last.bid_size)
+                        == (&bbo.ask_price, &bbo.ask_size, &bbo.bid_price, &bbo.bid_size)
+                });
+                if unchanged {
+                    return None;
+                }
+                self.last_bbo.insert(book.symbol, bbo.clone());
+                Some(BookEmission::Bbo(bbo))
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
+use serde::Serialize;
+use tokio::time::Duration;
+
+use crate::client::maintenance::MaintenanceWindow;
+use crate::error::GmocoinError;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+#[cfg(feature = "python")]
+use crate::time::from_ns;
+use crate::time::{from_jst, jst, to_ns};
+
+/// GMO Coin's trading schedule. Crypto trades around the clock, weekends
+/// included, except during maintenance windows (daily, or weekly such as
+/// `"Sat 09:00-11:00"`). Leverage positions held past the daily rollover
+/// (06:00 JST) are charged the leverage fee, and trading days run from
+/// one rollover to the next.
+#[cfg_attr(feature = "python", pyclass(from_py_object, eq))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradingCalendar {
+    windows: Vec<MaintenanceWindow>,
+    rollover: NaiveTime,
+}
+
+impl Default for TradingCalendar {
+    fn default() -> Self {
+        Self {
+            windows: Vec::new(),
+            rollover: NaiveTime::from_hms_opt(6, 0, 0).expect("06:00 is valid"),
+        }
+    }
+}
+
+/// Where a timestamp falls in the calendar.
+#[derive(Clone, Debug, Serialize)]
+pub struct Session {
+    /// Outside every maintenance window.
+    pub open: bool,
+    /// JST date of the trading day, which starts at the rollover.
+    pub trading_day: NaiveDate,
+    /// UNIX ns when the current maintenance ends; `None` while open.
+    pub reopens_at: Option<u64>,
+}
+
+impl TradingCalendar {
+    pub fn new(windows: Vec<MaintenanceWindow>) -> Self {
+        Self { windows, ..Self::default() }
+    }
+
+    /// From `"HH:MM-HH:MM"` / `"Sat HH:MM-HH:MM"` window specs (JST).
+    pub fn parse(windows: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, GmocoinError> {
+        let windows = windows
+            .into_iter()
+            .map(|spec| spec.as_ref().parse())
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(windows))
+    }
+
+    /// Replace the 06:00 JST rollover.
+    pub fn with_rollover(mut self, rollover: NaiveTime) -> Self {
+        self.rollover = rollover;
+        self
+    }
+
+    pub fn windows(&self) -> &[MaintenanceWindow] {
+        &self.windows
+    }
+
+    /// Time left in the maintenance at `now`, or `None` when open.
+    pub fn maintenance_remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.windows.iter().filter_map(|w| w.remaining(now)).max()
+    }
+
+    pub fn is_open(&self, ts: DateTime<Utc>) -> bool {
+        self.maintenance_remaining(ts).is_none()
+    }
+
+    /// When the next maintenance after `now` starts; `None` without
+    /// windows.
+    pub fn next_close(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.windows.iter().map(|w| w.next_start(now)).min()
+    }
+
+    /// When trading resumes: `now` when open, else the end of the
+    /// maintenance, following windows that overlap it (for at most a week,
+    /// should the windows cover it all).
+    pub fn next_open(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut at = now;
+        while at - now < TimeDelta::weeks(1) {
+            let Some(left) = self.maintenance_remaining(at) else { break };
+            at += TimeDelta::from_std(left).unwrap_or_default();
+        }
+        at
+    }
+
+    /// The next leverage rollover after `now`.
+    pub fn next_rollover(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let local = now.with_timezone(&jst()).naive_local();
+        let today = local.date().and_time(self.rollover);
+        from_jst(if today > local { today } else { today + TimeDelta::days(1) })
+    }
+
+    /// The trading day (JST date, starting at the rollover) of `ts`.
+    pub fn trading_day(&self, ts: DateTime<Utc>) -> NaiveDate {
+        let local = ts.with_timezone(&jst()).naive_local();
+        if local.time() < self.rollover { local.date() - TimeDelta::days(1) } else { local.date() }
+    }
+
+    pub fn session(&self, ts: DateTime<Utc>) -> Session {
+        let open = self.is_open(ts);
+        Session {
+            open,
+            trading_day: self.trading_day(ts),
+            reopens_at: (!open).then(|| to_ns(self.next_open(ts))),
+        }
+    }
+}
+
+/// `ts` in UNIX ns, or now.
+#[cfg(feature = "python")]
+fn at(ts: Option<u64>) -> DateTime<Utc> {
+    ts.map(from_ns).unwrap_or_else(Utc::now)
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl TradingCalendar {
+    /// `maintenance_windows`: `"HH:MM-HH:MM"` (daily) or
+    /// `"Sat HH:MM-HH:MM"` (weekly) in JST. `rollover`: `"HH:MM"` JST,
+    /// default `"06:00"`. Timestamps are UNIX ns; `None` means now.
+    #[new]
+    #[pyo3(signature = (maintenance_windows=None, rollover=None))]
+    fn py_new(maintenance_windows: Option<Vec<String>>, rollover: Option<String>) -> PyResult<Self> {
+        let calendar = Self::parse(maintenance_windows.unwrap_or_default())?;
+        let Some(rollover) = rollover else { return Ok(calendar) };
+        let rollover = NaiveTime::parse_from_str(&rollover, "%H:%M").map_err(|_| {
+            GmocoinError::InvalidInput(format!("Invalid rollover '{}' (expected \"HH:MM\" in JST)", rollover))
+        })?;
+        Ok(calendar.with_rollover(rollover))
+    }
+
+    #[pyo3(name = "is_open", signature = (ts=None))]
+    fn py_is_open(&self, ts: Option<u64>) -> bool {
+        self.is_open(at(ts))
+    }
+
+    #[pyo3(name = "next_close", signature = (ts=None))]
+    fn py_next_close(&self, ts: Option<u64>) -> Option<u64> {
+        self.next_close(at(ts)).map(to_ns)
+    }
+
+    #[pyo3(name = "next_open", signature = (ts=None))]
+    fn py_next_open(&self, ts: Option<u64>) -> u64 {
+        to_ns(self.next_open(at(ts)))
+    }
+
+    #[pyo3(name = "next_rollover", signature = (ts=None))]
+    fn py_next_rollover(&self, ts: Option<u64>) -> u64 {
+        to_ns(self.next_rollover(at(ts)))
+    }
+
+    /// `"YYYY-MM-DD"`
+    #[pyo3(name = "trading_day", signature = (ts=None))]
+    fn py_trading_day(&self, ts: Option<u64>) -> String {
+        self.trading_day(at(ts)).to_string()
+    }
+
+    /// `{"open", "trading_day", "reopens_at"}`
+    #[pyo3(name = "session", signature = (ts=None))]
+    fn py_session(&self, ts: Option<u64>) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.session(at(ts)))
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::{Entry, HashMap};
+
+use crate::client::dedupe::{SubmissionEntry, SubmissionLog};
+use crate::error::GmocoinError;
+use crate::model::order::Order;
+use crate::time::now_ns;
+
+/// Format version of `ExecutionState`; imports of other versions are
+/// refused.
+pub const STATE_VERSION: u32 = 1;
+
+/// Execution client state carried across a restart or to another process:
+/// the order cache and the client order ids submitted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExecutionState {
+    pub version: u32,
+    /// UNIX ns
+    pub exported_at: u64,
+    /// Orders as last seen on `orderEvents`, by order id.
+    pub orders: Vec<Order>,
+    /// Oldest first.
+    pub submissions: Vec<SubmissionEntry>,
+}
+
+/// What an import added.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ImportedState {
+    pub orders: usize,
+    pub submissions: usize,
+}
+
+impl ExecutionState {
+    pub fn capture(orders: &HashMap<u64, Order>, submissions: &SubmissionLog) -> Self {
+        let mut orders: Vec<Order> = orders.values().cloned().collect();
+        orders.sort_by_key(|o| o.order_id);
+        Self {
+            version: STATE_VERSION,
+            exported_at: now_ns(),
+            orders,
+            submissions: submissions.entries(),
+        }
+    }
+
+    /// Merge into a running client's state; what it already holds is newer
+    /// and kept.
+    pub fn restore(&self, orders: &mut HashMap<u64, Order>, submissions: &SubmissionLog) -> Result<ImportedState, GmocoinError> {
+        if self.version != STATE_VERSION {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Execution state version {} is not supported (expected {})",
+                self.version, STATE_VERSION
+            )));
+        }
+        let mut imported = ImportedState::default();
+        for order in &self.orders {
+            if let Entry::Vacant(entry) = orders.entry(order.order_id) {
+                entry.insert(order.clone());
+                imported.orders += 1;
+            }
+        }
+        imported.submissions = submissions.restore(&self.submissions);
+        Ok(imported)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{BTreeSet, HashMap};
+use tokio::time::{Duration, Instant};
+
+use crate::model::market_data::Ticker;
+
+struct Slot {
+    last_sent: Instant,
+    /// Latest update held back since `last_sent`.
+    pending: Option<Ticker>,
+}
+
+/// Per-symbol ticker conflation: at most one update per `interval` goes
+/// out for each symbol. Updates arriving sooner replace the held one, which
+/// goes out once the symbol's interval has passed, so the latest ticker is
+/// always delivered.
+pub struct TickerConflator {
+    interval: Duration,
+    slots: HashMap<String, Slot>,
+}
+
+impl TickerConflator {
+    /// At most `max_per_sec` updates per second and symbol.
+    pub fn new(max_per_sec: f64) -> Self {
+        Self { interval: Duration::from_secs_f64(1.0 / max_per_sec), slots: HashMap::new() }
+    }
+
+    /// `ticker` when it may go out now; otherwise it is held.
+    pub fn offer(&mut self, ticker: Ticker, now: Instant) -> Option<Ticker> {
+        match self.slots.get_mut(&ticker.symbol) {
+            Some(slot) if now < slot.last_sent + self.interval => {
+                slot.pending = Some(ticker);
+                None
+            }
+            Some(slot) => {
+                slot.last_sent = now;
+                slot.pending = None;
+                Some(ticker)
+            }
+            None => {
+                self.slots.insert(ticker.symbol.clone(), Slot { last_sent: now, pending: None });
+                Some(ticker)
+            }
+        }
+    }
+
+    /// When the next held update is due.
+    pub fn next_due(&self) -> Option<Instant> {
+        self.slots.values()
+            .filter(|slot| slot.pending.is_some())
+            .map(|slot| slot.last_sent + self.interval)
+            .min()
+    }
+
+    /// Held updates whose interval has passed.
+    pub fn take_due(&mut self, now: Instant) -> Vec<Ticker> {
+        let interval = self.interval;
+        self.slots.values_mut()
+            .filter(|slot| slot.pending.is_some() && now >= slot.last_sent + interval)
+            .filter_map(|slot| {
+                slot.last_sent = now;
+                slot.pending.take()
+            })
+            .collect()
+    }
+}
+
+/// Fixed-interval book emission: the books updated since the last emission
+/// are emitted together, in their latest state, at most once per `interval`.
+pub struct BookSampler {
+    interval: Duration,
+    next_at: Instant,
+    updated: BTreeSet<String>,
+}
+
+impl BookSampler {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, next_at: Instant::now(), updated: BTreeSet::new() }
+    }
+
+    /// Note an update of `symbol`'s book.
+    pub fn mark(&mut self, symbol: &str) {
+        if !self.updated.contains(symbol) {
+            self.updated.insert(symbol.to_string());
+        }
+    }
+
+    /// When the next emission is due, if any book was updated.
+    pub fn next_due(&self) -> Option<Instant> {
+        (!self.updated.is_empty()).then_some(self.next_at)
+    }
+
+    /// The updated symbols, in order, once the emission is due.
+    pub fn take_due(&mut self, now: Instant) -> Vec<String> {
+        if self.updated.is_empty() || now < self.next_at {
+            return Vec::new();
+        }
+        self.next_at = now + self.interval;
+        std::mem::take(&mut self.updated).into_iter().collect()
+    }
+}
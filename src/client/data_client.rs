@@ -1,165 +1,1028 @@
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{connect_async_tls_with_config, tungstenite::Message};
 use futures_util::{SinkExt, StreamExt};
 use std::sync::Arc;
-use serde_json::Value;
 use std::collections::HashSet;
-use tokio::time::{sleep, Duration};
+use serde::Serialize;
+use tokio::time::{sleep, sleep_until, Duration, Instant};
 use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::{info, warn, error};
 
+#[cfg(feature = "python")]
+use crate::callback::{CallbackFilter, CallbackPayload, CallbackSlot};
+use crate::json::{Envelope, FrameDecoder, ParseErrorEvent, WsCommand};
+use crate::model::market_data::{Depth, Ticker, Trade};
 use crate::model::orderbook::OrderBook;
 use crate::rate_limit::TokenBucket;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+use crate::client::backfill::{self, Delivered, TradeGap};
+use crate::client::calendar::TradingCalendar;
+use crate::client::book_mode::{BookMode, BookModes};
+#[cfg(feature = "python")]
+use crate::client::book_mode::BookEmission;
+use crate::client::conflate::{BookSampler, TickerConflator};
+use crate::client::endpoint::EndpointGroup;
+use crate::client::headers::StaticHeaders;
+use crate::client::instruments::{self, InstrumentEvent};
+use crate::client::latency::{self, LatencyAlarm};
+use crate::client::maintenance::{MaintenanceConfig, MaintenanceEvent, MaintenanceGuard};
+use crate::client::profile::VenueProfile;
+use crate::client::reconnect::{Backoff, BackoffPolicy, DisconnectReason, Outage, ReconnectEvent};
+use crate::client::rest::{GmocoinRestClient, RestClientConfig};
+use crate::error::GmocoinError;
+use crate::health::{Health, WsHealth};
+use crate::runtime;
+use crate::stats::ClientStats;
+use crate::tls::TlsSettings;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
 
-#[pyclass(from_py_object)]
+/// `(channel, symbol, option)` of a Public WS subscription.
+pub type Subscription = (String, String, Option<String>);
+
+/// A stored subscription key, with `""` for no option.
+fn subscription((channel, symbol, option): (String, String, String)) -> Subscription {
+    (channel, symbol, Some(option).filter(|option| !option.is_empty()))
+}
+
+/// What `set_subscriptions` sent, each sorted.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SubscriptionChanges {
+    pub subscribed: Vec<Subscription>,
+    pub unsubscribed: Vec<Subscription>,
+}
+
+/// Market data updates forwarded to in-process consumers such as
+/// `GmocoinSimExecutionClient` or a Rust strategy.
+#[derive(Clone)]
+pub enum MarketEvent {
+    Ticker(Ticker),
+    Book(OrderBook),
+    Trade(Trade),
+    Maintenance(MaintenanceEvent),
+    Reconnect(ReconnectEvent),
+    ParseError(ParseErrorEvent),
+    Instrument(InstrumentEvent),
+    LatencyAlarm(LatencyAlarm),
+    TradeGap(TradeGap),
+}
+
+/// Construction options of a `GmocoinDataClient`.
+#[derive(Clone)]
+pub struct DataClientConfig {
+    /// WebSocket subscription rate limit (commands/sec).
+    pub ws_rate_limit_per_sec: f64,
+    pub tls: TlsSettings,
+    pub venue_profile: VenueProfile,
+    /// Static headers sent on the WS handshake.
+    pub headers: StaticHeaders,
+    /// When reconnects wait for venue maintenance to end.
+    pub maintenance: MaintenanceConfig,
+    /// Delay between reconnect attempts.
+    pub reconnect: BackoffPolicy,
+    /// Reconnect when nothing is received for this long (off by default).
+    pub stale_timeout: Option<Duration>,
+    /// Poll `/v1/symbols` this often for listing changes (off by default).
+    pub instrument_refresh: Option<Duration>,
+    /// Alarm when connected but nothing is received for this long (off by
+    /// default).
+    pub event_gap_alarm: Option<Duration>,
+    /// Deliver at most this many tickers per second and symbol to the data
+    /// callback, always ending on the latest (off by default).
+    pub ticker_max_rate: Option<f64>,
+    /// Emit updated books to the data callback at most this often, in their
+    /// latest state, instead of on every update (off by default).
+    pub book_interval: Option<Duration>,
+}
+
+impl Default for DataClientConfig {
+    fn default() -> Self {
+        Self {
+            ws_rate_limit_per_sec: 1.0,
+            tls: TlsSettings::default(),
+            venue_profile: VenueProfile::default(),
+            headers: StaticHeaders::default(),
+            maintenance: MaintenanceConfig::default(),
+            reconnect: BackoffPolicy::PUBLIC,
+            stale_timeout: None,
+            instrument_refresh: None,
+            event_gap_alarm: None,
+            ticker_max_rate: None,
+            book_interval: None,
+        }
+    }
+}
+
+/// Where the WS loop delivers parsed updates: the market feed and, in the
+/// extension module, the Python data callback.
+#[derive(Clone)]
+struct DataSinks {
+    #[cfg(feature = "python")]
+    callback: CallbackSlot,
+    market_feed: broadcast::Sender<MarketEvent>,
+    /// Live trades held back per symbol while its backfill is delivered
+    held_trades: Arc<std::sync::Mutex<std::collections::HashMap<String, Vec<Trade>>>>,
+    book_modes: Arc<std::sync::Mutex<BookModes>>,
+}
+
+impl DataSinks {
+    fn publish(&self, event: impl FnOnce() -> MarketEvent) {
+        if self.market_feed.receiver_count() > 0 {
+            let _ = self.market_feed.send(event());
+        }
+    }
+
+    /// Hand a parsed model to the data callback, as the model object or
+    /// MessagePack bytes. Only the Python object creation and the call
+    /// itself run with the GIL held.
+    #[cfg(feature = "python")]
+    fn emit<T>(&self, channel: &'static str, symbol: &str, value: T)
+    where
+        T: pyo3::PyClass + Into<pyo3::PyClassInitializer<T>> + serde::Serialize,
+    {
+        if !self.callback.allows(channel, symbol) {
+            return;
+        }
+        let Some(cb) = self.callback.get() else { return };
+        match self.callback.payload() {
+            CallbackPayload::Object => {
+                Python::try_attach(|py| {
+                    let py_obj = Py::new(py, value).expect("Failed to create Python object");
+                    let _turn = self.callback.dispatch_turn(py);
+                    self.callback.invoke(py, &cb, channel, (channel, py_obj));
+                });
+            }
+            CallbackPayload::MsgPack => {
+                let bytes = CallbackPayload::encode(&value);
+                Python::try_attach(|py| {
+                    let _turn = self.callback.dispatch_turn(py);
+                    self.callback.invoke(py, &cb, channel, (channel, pyo3::types::PyBytes::new(py, &bytes)));
+                });
+            }
+        }
+    }
+
+    #[cfg(not(feature = "python"))]
+    fn emit<T>(&self, _channel: &'static str, _symbol: &str, _value: T) {}
+
+    /// A book for the callback, as its subscription's `BookMode` delivers
+    /// it. Deltas and BBO changes only advance with what the callback
+    /// actually receives.
+    #[cfg(feature = "python")]
+    fn emit_book(&self, book: OrderBook) {
+        let mut book_modes = self.book_modes.lock().unwrap();
+        let channel = match book_modes.mode(&book.symbol) {
+            BookMode::Snapshot => "orderbooks",
+            BookMode::Delta => "book_deltas",
+            BookMode::Bbo => "bbo",
+        };
+        if !self.callback.allows(channel, &book.symbol) || self.callback.get().is_none() {
+            return;
+        }
+        let emission = book_modes.emission(book);
+        drop(book_modes);
+        match emission {
+            Some(BookEmission::Snapshot(book)) => self.emit(channel, &book.symbol.clone(), book),
+            Some(BookEmission::Delta(delta)) => self.emit(channel, &delta.symbol.clone(), delta),
+            Some(BookEmission::Bbo(bbo)) => self.emit(channel, &bbo.symbol.clone(), bbo),
+            None => {}
+        }
+    }
+
+    #[cfg(not(feature = "python"))]
+    fn emit_book(&self, _book: OrderBook) {}
+
+    /// A live trade for the callback, held while `symbol` is backfilling.
+    fn emit_trade(&self, symbol: &str, trade: Trade) {
+        let trade = match self.held_trades.lock().unwrap().get_mut(symbol) {
+            Some(held) => {
+                held.push(trade);
+                None
+            }
+            None => Some(trade),
+        };
+        if let Some(trade) = trade {
+            self.emit("trades", symbol, trade);
+        }
+    }
+
+    /// Hold `symbol`'s live trades until `release_trades`.
+    fn hold_trades(&self, symbol: &str) {
+        self.held_trades.lock().unwrap().entry(symbol.to_string()).or_default();
+    }
+
+    /// The oldest live trade held for `symbol`.
+    fn first_held_trade(&self, symbol: &str) -> Option<Trade> {
+        self.held_trades.lock().unwrap().get(symbol).and_then(|held| held.first().cloned())
+    }
+
+    /// Deliver the trades held for `symbol`, except those already
+    /// `delivered`, and stop holding. Batches are delivered outside the
+    /// lock; holding only stops once no more arrived, so order is kept.
+    fn release_trades(&self, symbol: &str, delivered: &mut Delivered) {
+        loop {
+            let batch = {
+                let mut held_trades = self.held_trades.lock().unwrap();
+                match held_trades.get_mut(symbol) {
+                    Some(held) if !held.is_empty() => std::mem::take(held),
+                    _ => {
+                        held_trades.remove(symbol);
+                        return;
+                    }
+                }
+            };
+            for trade in batch {
+                if !delivered.take(&trade) {
+                    self.emit("trades", symbol, trade);
+                }
+            }
+        }
+    }
+
+    /// Maintenance start / end, as a `"maintenance"` dict for the callback.
+    fn maintenance(&self, event: MaintenanceEvent) {
+        #[cfg(feature = "python")]
+        self.callback.call_events([("maintenance", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::Maintenance(event));
+    }
+
+    /// Connection restored after a drop, as a `"reconnect"` dict for the
+    /// callback.
+    fn reconnect(&self, event: ReconnectEvent) {
+        #[cfg(feature = "python")]
+        self.callback.call_events([("reconnect", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::Reconnect(event));
+    }
+
+    /// A frame that failed to decode, as a `"parse_error"` dict for the
+    /// callback.
+    fn parse_error(&self, event: ParseErrorEvent) {
+        warn!("GMO: Failed to decode Public WS {} frame: {}", event.channel.as_deref().unwrap_or("unknown"), event.error);
+        #[cfg(feature = "python")]
+        self.callback.call_events([("parse_error", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::ParseError(event));
+    }
+
+    /// A listing change from the instrument refresh, as an `"instrument"`
+    /// dict for the callback.
+    fn instrument(&self, event: InstrumentEvent) {
+        #[cfg(feature = "python")]
+        self.callback.call_events([("instrument", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::Instrument(event));
+    }
+
+    /// Trades a backfill could not cover, as a `"trade_gap"` dict for the
+    /// callback.
+    fn trade_gap(&self, event: TradeGap) {
+        warn!("GMO: Trades of {} after {} and before {} may be missing", event.symbol, event.after, event.before);
+        #[cfg(feature = "python")]
+        self.callback.call_events([("trade_gap", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::TradeGap(event));
+    }
+
+    /// A breached latency budget, as a `"latency_alarm"` dict for the
+    /// callback.
+    fn latency_alarm(&self, event: LatencyAlarm) {
+        event.log();
+        #[cfg(feature = "python")]
+        self.callback.call_events([("latency_alarm", serde_json::to_value(&event).expect("event serializes"))]);
+        self.publish(|| MarketEvent::LatencyAlarm(event));
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(from_py_object))]
 #[derive(Clone)]
 pub struct GmocoinDataClient {
-    data_callback: Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
+    sinks: DataSinks,
     /// (channel, symbol, option) - option is e.g. "TAKER_ONLY" for trades
     subscriptions: Arc<std::sync::Mutex<HashSet<(String, String, String)>>>,
-    outgoing: Arc<std::sync::Mutex<Vec<String>>>,
+    /// Commands for the live connection; the WS loop holds the receiver
+    outgoing_tx: mpsc::UnboundedSender<String>,
+    outgoing_rx: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<String>>>,
     books: Arc<std::sync::Mutex<std::collections::HashMap<String, OrderBook>>>,
     shutdown: Arc<AtomicBool>,
     connected: Arc<AtomicBool>,
     ws_rate_limit: TokenBucket,
+    ws_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    tls: TlsSettings,
+    stats: ClientStats,
+    ws_url: String,
+    headers: StaticHeaders,
+    maintenance: MaintenanceGuard,
+    reconnect: BackoffPolicy,
+    stale_timeout: Option<Duration>,
+    /// Public REST client for `/v1/status` and `/v1/symbols`
+    public_rest: Option<GmocoinRestClient>,
+    instrument_refresh: Option<Duration>,
+    refresh_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    event_gap_alarm: Option<Duration>,
+    gap_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    ticker_max_rate: Option<f64>,
+    book_interval: Option<Duration>,
 }
 
-#[pymethods]
 impl GmocoinDataClient {
-    /// Create a new GmocoinDataClient.
-    ///
-    /// `ws_rate_limit_per_sec`: WebSocket subscription rate limit (commands/sec).
-    ///   Default 0.5 (1 command per 2 seconds) for safety.
-    #[new]
-    pub fn new(ws_rate_limit_per_sec: Option<f64>) -> Self {
-        let ws_rate = ws_rate_limit_per_sec.unwrap_or(1.0);
+    pub fn from_config(config: DataClientConfig) -> Self {
+        let (outgoing_tx, outgoing_rx) = mpsc::unbounded_channel();
+        let stats = ClientStats::default();
+        // `/v1/status` and `/v1/symbols` are public endpoints; failing to
+        // build the client only disables the status check and refresh
+        let public_rest = GmocoinRestClient::from_config(RestClientConfig {
+            tls: config.tls.clone(),
+            venue_profile: config.venue_profile.clone(),
+            headers: config.headers.clone(),
+            ..RestClientConfig::default()
+        })
+        .map_err(|e| warn!("GMO: Maintenance status check and instrument refresh disabled: {}", e))
+        .ok();
         Self {
-            data_callback: Arc::new(std::sync::Mutex::new(None)),
+            sinks: DataSinks {
+                #[cfg(feature = "python")]
+                callback: CallbackSlot::new(CallbackPayload::default(), stats.clone()),
+                market_feed: broadcast::channel(1024).0,
+                held_trades: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
+                book_modes: Arc::new(std::sync::Mutex::new(BookModes::default())),
+            },
             subscriptions: Arc::new(std::sync::Mutex::new(HashSet::new())),
-            outgoing: Arc::new(std::sync::Mutex::new(Vec::new())),
+            outgoing_tx,
+            outgoing_rx: Arc::new(tokio::sync::Mutex::new(outgoing_rx)),
             books: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
             shutdown: Arc::new(AtomicBool::new(false)),
             connected: Arc::new(AtomicBool::new(false)),
-            ws_rate_limit: TokenBucket::new(1.0, ws_rate),
+            ws_rate_limit: TokenBucket::new(1.0, config.ws_rate_limit_per_sec),
+            ws_task: Arc::new(std::sync::Mutex::new(None)),
+            tls: config.tls,
+            stats,
+            ws_url: config.venue_profile.ws_public,
+            headers: config.headers,
+            maintenance: MaintenanceGuard::new(config.maintenance, public_rest.clone()),
+            reconnect: config.reconnect,
+            stale_timeout: config.stale_timeout,
+            public_rest,
+            instrument_refresh: config.instrument_refresh,
+            refresh_task: Arc::new(std::sync::Mutex::new(None)),
+            event_gap_alarm: config.event_gap_alarm,
+            gap_task: Arc::new(std::sync::Mutex::new(None)),
+            ticker_max_rate: config.ticker_max_rate,
+            book_interval: config.book_interval,
+        }
+    }
+
+    /// Start the Public WS loop (replacing a running one); it reconnects
+    /// and resubscribes until `disconnect`. Also starts the instrument
+    /// refresh and event gap alarm when configured.
+    pub fn connect(&self) {
+        self.shutdown.store(false, Ordering::SeqCst);
+        self.connected.store(false, Ordering::SeqCst);
+        self.stats.ws_connected(false);
+
+        let handle = runtime::spawn(Self::ws_loop(
+            self.subscriptions.clone(),
+            self.outgoing_rx.clone(),
+            self.sinks.clone(),
+            self.books.clone(),
+            self.shutdown.clone(),
+            self.connected.clone(),
+            self.ws_rate_limit.clone(),
+            self.tls.ws_connector(),
+            self.stats.clone(),
+            self.ws_url.clone(),
+            self.headers.clone(),
+            self.maintenance.clone(),
+            self.reconnect,
+            self.stale_timeout,
+            self.ticker_max_rate,
+            self.book_interval,
+        ));
+        if let Some(previous) = self.ws_task.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+
+        if let (Some(interval), Some(client)) = (self.instrument_refresh, self.public_rest.clone()) {
+            let sinks = self.sinks.clone();
+            let handle = runtime::spawn(instruments::refresh_loop(client, interval, move |event| sinks.instrument(event)));
+            if let Some(previous) = self.refresh_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        if let Some(budget) = self.event_gap_alarm {
+            let sinks = self.sinks.clone();
+            let handle = runtime::spawn(latency::gap_watchdog(self.stats.clone(), budget, move |alarm| sinks.latency_alarm(alarm)));
+            if let Some(previous) = self.gap_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
         }
     }
 
-    pub fn set_data_callback(&self, callback: Py<PyAny>) {
-        let mut lock = self.data_callback.lock().unwrap();
-        *lock = Some(callback);
+    /// Subscribe to a channel for a symbol, with an optional option (e.g. "TAKER_ONLY" for trades).
+    /// Kept for reconnects; sent right away when connected.
+    pub fn subscribe(&self, channel: &str, symbol: &str, option: Option<&str>) {
+        Self::store_subscription(&self.subscriptions, &self.outgoing_tx, &self.connected, channel, symbol, option);
     }
 
-    pub fn connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let data_cb_arc = self.data_callback.clone();
-        let subs_arc = self.subscriptions.clone();
-        let outgoing_arc = self.outgoing.clone();
-        let books_arc = self.books.clone();
-        let shutdown = self.shutdown.clone();
-        let connected = self.connected.clone();
-        let ws_rate_limit = self.ws_rate_limit.clone();
+    /// Subscribe `orderbooks` for `symbol`, delivering books to the data
+    /// callback as `mode` says (see `BookMode`).
+    pub fn subscribe_book(&self, symbol: &str, mode: BookMode) {
+        self.sinks.book_modes.lock().unwrap().set(symbol, mode);
+        self.subscribe("orderbooks", symbol, None);
+    }
 
-        shutdown.store(false, Ordering::SeqCst);
-        connected.store(false, Ordering::SeqCst);
+    /// Subscribe `channel` for every symbol `/v1/symbols` lists (from the
+    /// REST client's cache when fresh). The commands go out one by one
+    /// under the WS rate limit. Returns the symbols, in listing order.
+    pub async fn subscribe_all(&self, channel: &str, option: Option<&str>) -> Result<Vec<String>, GmocoinError> {
+        let rest = self.public_rest.as_ref().ok_or_else(|| {
+            GmocoinError::InvalidInput("No REST client to list symbols with".to_string())
+        })?;
+        let symbols: Vec<String> = rest.get_symbols().await?.into_iter().map(|info| info.symbol).collect();
+        for symbol in &symbols {
+            self.subscribe(channel, symbol, option);
+        }
+        info!("GMO: Subscribing {} for {} symbols", channel, symbols.len());
+        Ok(symbols)
+    }
 
-        let future = async move {
-            std::thread::Builder::new()
-                .name("gmocoin-ws-public".to_string())
-                .spawn(move || {
-                    let rt = tokio::runtime::Builder::new_current_thread()
-                        .enable_all()
-                        .build()
-                        .expect("Failed to build tokio runtime for WS");
-
-                    rt.block_on(Self::ws_loop(
-                        subs_arc, outgoing_arc, data_cb_arc, books_arc, shutdown, connected, ws_rate_limit,
-                    ));
-                })
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to spawn WS thread: {}", e)
-                ))?;
+    /// Subscribe `trades` for `symbol`, first delivering its `count` most
+    /// recent trades from REST `/v1/trades` to the data callback, oldest
+    /// first and flagged `historical`, as indicator warm-up. Live trades are
+    /// held meanwhile. Unless the first of them (awaited up to `SEAM_WAIT`)
+    /// overlaps the backfill, a fresh REST page fills in the trades between
+    /// the two, and a `"trade_gap"` event reports any it no longer reaches.
+    /// Held trades then follow, less those already delivered, so each trade
+    /// is delivered once. The market feed only gets live trades. Returns the
+    /// number of trades backfilled; if the REST fetch fails the
+    /// subscription stands and live trades flow as usual.
+    pub async fn subscribe_trades_with_backfill(
+        &self,
+        symbol: &str,
+        option: Option<&str>,
+        count: usize,
+    ) -> Result<usize, GmocoinError> {
+        self.sinks.hold_trades(symbol);
+        self.subscribe("trades", symbol, option);
+        // A task of its own, so held trades are released even if the caller
+        // stops waiting
+        let (sinks, rest, symbol_owned) = (self.sinks.clone(), self.public_rest.clone(), symbol.to_string());
+        let backfilled = runtime::spawn(async move {
+            let mut delivered = Delivered::default();
+            let backfilled = Self::backfill_trades(&sinks, rest.as_ref(), &symbol_owned, count, &mut delivered).await;
+            sinks.release_trades(&symbol_owned, &mut delivered);
+            backfilled
+        })
+        .await
+        .map_err(|e| GmocoinError::InvalidInput(format!("Trade backfill task failed: {}", e)))?;
+        match backfilled {
+            Ok(backfilled) => {
+                info!("GMO: Backfilled {} trades for {}", backfilled, symbol);
+                Ok(backfilled)
+            }
+            Err(e) => {
+                warn!("GMO: Trade backfill for {} failed: {}", symbol, e);
+                Err(e)
+            }
+        }
+    }
 
-            Ok("Connected")
+    /// Deliver the backfill and, when the live stream does not overlap it,
+    /// the trades in between (see `subscribe_trades_with_backfill`).
+    async fn backfill_trades(
+        sinks: &DataSinks,
+        rest: Option<&GmocoinRestClient>,
+        symbol: &str,
+        count: usize,
+        delivered: &mut Delivered,
+    ) -> Result<usize, GmocoinError> {
+        let rest = rest.ok_or_else(|| GmocoinError::InvalidInput("No REST client to backfill trades with".to_string()))?;
+        let (trades, _) = backfill::recent_trades(rest, symbol, count).await?;
+        delivered.add(&trades);
+        let mut backfilled = trades.len();
+        let newest = trades.last().cloned();
+        for trade in trades {
+            sinks.emit("trades", symbol, trade);
+        }
+        let Some(newest) = newest else { return Ok(0) };
+
+        let deadline = Instant::now() + backfill::SEAM_WAIT;
+        let first_live = loop {
+            if let Some(first) = sinks.first_held_trade(symbol) {
+                break Some(first);
+            }
+            if Instant::now() >= deadline {
+                break None;
+            }
+            sleep(Duration::from_millis(50)).await;
         };
+        // Live from before the newest backfilled trade: nothing in between
+        if first_live.is_some_and(|first| first.ts_event <= newest.ts_event) {
+            return Ok(backfilled);
+        }
+        match backfill::fill_seam(rest, symbol, &newest, delivered).await {
+            Ok((fill, gap)) => {
+                if let Some(gap) = gap {
+                    sinks.trade_gap(gap);
+                }
+                backfilled += fill.len();
+                for trade in fill {
+                    sinks.emit("trades", symbol, trade);
+                }
+            }
+            Err(e) => warn!("GMO: Could not check the trade backfill seam for {}: {}", symbol, e),
+        }
+        Ok(backfilled)
+    }
 
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    /// An error for a `symbol` that `/v1/symbols` does not list (see
+    /// `GmocoinRestClient::check_symbol`).
+    pub async fn check_symbol(&self, symbol: &str) -> Result<(), GmocoinError> {
+        match &self.public_rest {
+            Some(rest) => rest.check_symbol(symbol).await,
+            None => Ok(()),
+        }
     }
 
-    /// Subscribe to a channel for a symbol, with an optional option (e.g. "TAKER_ONLY" for trades).
-    #[pyo3(signature = (channel, symbol, option = None))]
-    pub fn subscribe<'py>(&self, py: Python<'py>, channel: String, symbol: String, option: Option<String>) -> PyResult<Bound<'py, PyAny>> {
-        let subs_arc = self.subscriptions.clone();
-        let outgoing_arc = self.outgoing.clone();
-        let connected = self.connected.clone();
+    /// Stop the Public WS loop; resolves once the task has exited.
+    pub async fn disconnect(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Some(refresh) = self.refresh_task.lock().unwrap().take() {
+            refresh.abort();
+        }
+        if let Some(watchdog) = self.gap_task.lock().unwrap().take() {
+            watchdog.abort();
+        }
+        // The loop only sees the flag between frames; stop it right away
+        let handle = self.ws_task.lock().unwrap().take();
+        if let Some(handle) = handle {
+            handle.abort();
+            let _ = handle.await;
+        }
+        self.connected.store(false, Ordering::SeqCst);
+        self.stats.ws_connected(false);
+    }
 
-        let future = async move {
-            let opt_str = option.clone().unwrap_or_default();
+    /// `disconnect`, giving up after `timeout`. Returns whether the WS task
+    /// stopped in time.
+    pub async fn close(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, self.disconnect()).await.is_ok()
+    }
 
-            // Always store for reconnection
-            {
-                let mut subs = subs_arc.lock().unwrap();
-                subs.insert((channel.clone(), symbol.clone(), opt_str));
-            }
+    pub fn stats(&self) -> &ClientStats {
+        &self.stats
+    }
 
-            // If already connected, queue the subscribe message for immediate sending.
-            if connected.load(Ordering::SeqCst) {
-                let msg = Self::build_subscribe_msg(&channel, &symbol, option.as_deref());
-                let mut queue = outgoing_arc.lock().unwrap();
-                queue.push(msg);
+    /// Public WS connection state and last message time.
+    pub fn ws_health(&self) -> WsHealth {
+        WsHealth::new(&self.stats, false)
+    }
+
+    /// The Public WS and the public REST client used for `/v1/status` and
+    /// `/v1/symbols`.
+    pub fn health(&self) -> Health {
+        let (rest, rate_limits) = match &self.public_rest {
+            Some(client) => {
+                let mut limits = client.rate_limit_health();
+                limits.retain(|group, _| *group == EndpointGroup::Public.as_str());
+                (Some(client.rest_health()), limits)
             }
+            None => (None, Default::default()),
+        };
+        Health::new(rest, Some(self.ws_health()), None, rate_limits)
+    }
 
+    /// Maintenance windows and rollover the WS loop reconnects by.
+    pub fn calendar(&self) -> &TradingCalendar {
+        self.maintenance.calendar()
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl GmocoinDataClient {
+    /// Create a new GmocoinDataClient.
+    ///
+    /// `ws_rate_limit_per_sec`: WebSocket subscription rate limit (commands/sec).
+    ///   Default 0.5 (1 command per 2 seconds) for safety.
+    /// `tls_ca_file` / `tls_native_roots` / `tls_min_version`: see `TlsSettings`.
+    /// `venue_profile`: `"production"` (default) or a dict of alternate base URLs.
+    /// `user_agent` / `extra_headers`: static headers sent on the WS handshake.
+    /// `callback_payload`: `"object"` (default) passes `Ticker` / `OrderBook` /
+    ///   `Trade` objects to the data callback, `"msgpack"` the same fields as
+    ///   MessagePack bytes.
+    /// `maintenance_windows`: daily `"HH:MM-HH:MM"` or weekly
+    ///   `"Sat HH:MM-HH:MM"` windows (JST) during which reconnects wait
+    ///   instead of retrying.
+    /// `maintenance_status_check`: before reconnecting, wait while `/v1/status`
+    ///   reports `MAINTENANCE`. Default true.
+    /// `reconnect_initial_ms` / `reconnect_max_ms` / `reconnect_multiplier` /
+    ///   `reconnect_jitter`: reconnect backoff (default 1000 / 64000 / 2.0 /
+    ///   0.0); see `BackoffPolicy`.
+    /// `stale_timeout_ms`: reconnect when nothing is received for this long
+    ///   (default or 0: never).
+    /// `instrument_refresh_ms`: poll `/v1/symbols` this often while connected
+    ///   and deliver listing changes on the `"instrument"` channel (default
+    ///   or 0: never).
+    /// `event_gap_alarm_ms`: deliver a `"latency_alarm"` when connected but
+    ///   nothing is received for this long (default or 0: never).
+    /// `callback_symbols` / `callback_channels`: see `set_callback_filter`.
+    /// `ticker_max_rate`: deliver at most this many `"ticker"` updates per
+    ///   second and symbol, holding back the rest and always delivering the
+    ///   latest (default or 0: every update).
+    /// `book_interval_ms`: emit `"orderbooks"` at most this often, each
+    ///   updated book once in its latest state (default or 0: every update).
+    #[new]
+    #[pyo3(signature = (ws_rate_limit_per_sec, tls_ca_file=None, tls_native_roots=None, tls_min_version=None, venue_profile=None, user_agent=None, extra_headers=None, callback_payload=None, maintenance_windows=None, maintenance_status_check=None, reconnect_initial_ms=None, reconnect_max_ms=None, reconnect_multiplier=None, reconnect_jitter=None, stale_timeout_ms=None, instrument_refresh_ms=None, event_gap_alarm_ms=None, callback_symbols=None, callback_channels=None, ticker_max_rate=None, book_interval_ms=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(
+        ws_rate_limit_per_sec: Option<f64>,
+        tls_ca_file: Option<String>,
+        tls_native_roots: Option<bool>,
+        tls_min_version: Option<String>,
+        venue_profile: Option<VenueProfile>,
+        user_agent: Option<String>,
+        extra_headers: Option<std::collections::HashMap<String, String>>,
+        callback_payload: Option<CallbackPayload>,
+        maintenance_windows: Option<Vec<String>>,
+        maintenance_status_check: Option<bool>,
+        reconnect_initial_ms: Option<u64>,
+        reconnect_max_ms: Option<u64>,
+        reconnect_multiplier: Option<f64>,
+        reconnect_jitter: Option<f64>,
+        stale_timeout_ms: Option<u64>,
+        instrument_refresh_ms: Option<u64>,
+        event_gap_alarm_ms: Option<u64>,
+        callback_symbols: Option<Vec<String>>,
+        callback_channels: Option<Vec<String>>,
+        ticker_max_rate: Option<f64>,
+        book_interval_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        let defaults = DataClientConfig::default();
+        let mut client = Self::from_config(DataClientConfig {
+            ws_rate_limit_per_sec: ws_rate_limit_per_sec.unwrap_or(defaults.ws_rate_limit_per_sec),
+            tls: TlsSettings::new(tls_ca_file.as_deref(), tls_native_roots, tls_min_version.as_deref())?,
+            venue_profile: venue_profile.unwrap_or_default(),
+            headers: StaticHeaders::new(user_agent, extra_headers)?,
+            maintenance: MaintenanceConfig::new(maintenance_windows, maintenance_status_check)?,
+            reconnect: BackoffPolicy::new(
+                defaults.reconnect, reconnect_initial_ms, reconnect_max_ms, reconnect_multiplier, reconnect_jitter,
+            )?,
+            stale_timeout: stale_timeout_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+            instrument_refresh: instrument_refresh_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+            event_gap_alarm: event_gap_alarm_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+            ticker_max_rate: ticker_max_rate.filter(|rate| *rate > 0.0),
+            book_interval: book_interval_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+        });
+        client.sinks.callback = CallbackSlot::new(callback_payload.unwrap_or_default(), client.stats.clone());
+        client.set_callback_filter(callback_symbols, callback_channels);
+        Ok(client)
+    }
+
+    /// Deliver only `ticker` / `orderbooks` / `book_deltas` / `bbo` /
+    /// `trades` updates of these `symbols` and `channels` to the data
+    /// callback (`None`: all), however broad the subscriptions; the rest
+    /// are dropped before any Python object is built. Books are still kept
+    /// and the Rust market feed still gets everything. Status channels (`"maintenance"`, `"reconnect"`,
+    /// ...) always pass.
+    #[pyo3(signature = (symbols=None, channels=None))]
+    pub fn set_callback_filter(&self, symbols: Option<Vec<String>>, channels: Option<Vec<String>>) {
+        self.sinks.callback.set_filter(CallbackFilter {
+            symbols: symbols.map(HashSet::from_iter),
+            channels: channels.map(HashSet::from_iter),
+        });
+    }
+
+    /// `callback(channel, data)`; `async def` callbacks are scheduled on
+    /// the event loop running at this call.
+    pub fn set_data_callback(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<()> {
+        self.sinks.callback.set(py, callback)
+    }
+
+    /// The `TradingCalendar` of `maintenance_windows`.
+    #[getter(calendar)]
+    fn py_calendar(&self) -> TradingCalendar {
+        self.calendar().clone()
+    }
+
+    /// `handler(event_type, exception)` for exceptions raised by the callback,
+    /// which are always logged and counted in `get_stats()`. `None` clears it.
+    #[pyo3(signature = (handler=None))]
+    pub fn set_callback_error_handler(&self, handler: Option<Py<PyAny>>) {
+        self.sinks.callback.set_error_handler(handler);
+    }
+
+    /// Snapshot of the Public WS counters: reconnects, errors by class, last
+    /// message time per channel and, once books update, `book_churn` per
+    /// symbol (`updates`, `updates_per_sec`, `touch_change_ratio`,
+    /// `avg_levels_changed`).
+    pub fn get_stats(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.stats.snapshot())
+    }
+
+    /// Health snapshot for monitoring probes: `{"status", "checked_at_ms",
+    /// "rest", "public_ws", "rate_limits"}` (see
+    /// `GmocoinExecutionClient.health`).
+    #[pyo3(name = "health")]
+    fn py_health(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.health())
+    }
+
+    #[pyo3(name = "connect", signature = () -> "collections.abc.Awaitable[str]")]
+    fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.connect();
+            Ok("Connected")
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Subscribe to a channel for a symbol, with an optional option (e.g. "TAKER_ONLY" for trades).
+    /// `backfill` (`trades` only): first deliver that many recent trades
+    /// from REST, flagged `historical` (see `subscribe_trades_with_backfill`).
+    /// `book_mode` (`orderbooks` only): `"snapshot"` (default), `"delta"`
+    /// or `"bbo"` (see `BookMode`).
+    #[pyo3(name = "subscribe", signature = (channel, symbol, option = None, backfill = None, book_mode = None) -> "collections.abc.Awaitable[str]")]
+    fn py_subscribe<'py>(
+        &self,
+        py: Python<'py>,
+        channel: String,
+        symbol: String,
+        option: Option<String>,
+        backfill: Option<usize>,
+        book_mode: Option<BookMode>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if backfill.is_some() && channel != "trades" {
+            return Err(GmocoinError::InvalidInput(format!("backfill is only supported for trades, not {}", channel)).into());
+        }
+        if book_mode.is_some() && channel != "orderbooks" {
+            return Err(GmocoinError::InvalidInput(format!("book_mode is only supported for orderbooks, not {}", channel)).into());
+        }
+        let client = self.clone();
+        let future = async move {
+            client.check_symbol(&symbol).await?;
+            match backfill {
+                Some(count) => {
+                    client.subscribe_trades_with_backfill(&symbol, option.as_deref(), count).await?;
+                }
+                None if channel == "orderbooks" => client.subscribe_book(&symbol, book_mode.unwrap_or_default()),
+                None => client.subscribe(&channel, &symbol, option.as_deref()),
+            }
             Ok("Subscribe command stored")
         };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
 
+    /// Subscribe `channel` for every listed symbol (see `subscribe`);
+    /// resolves to the symbols.
+    #[pyo3(name = "subscribe_all", signature = (channel, option = None) -> "collections.abc.Awaitable[list[str]]")]
+    fn py_subscribe_all<'py>(&self, py: Python<'py>, channel: String, option: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move { Ok(client.subscribe_all(&channel, option.as_deref()).await?) };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    pub fn disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let shutdown = self.shutdown.clone();
+    /// Stop `channel` for `symbol`.
+    #[pyo3(name = "unsubscribe", signature = (channel, symbol) -> "collections.abc.Awaitable[str]")]
+    fn py_unsubscribe<'py>(&self, py: Python<'py>, channel: String, symbol: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
         let future = async move {
-            shutdown.store(true, Ordering::SeqCst);
+            client.unsubscribe(&channel, &symbol);
+            Ok("Unsubscribe command stored")
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Reconcile the subscriptions to `target`, a list of `(channel,
+    /// symbol)` or `(channel, symbol, option)` tuples, sending only the
+    /// subscribe / unsubscribe commands needed. Returns `{"subscribed",
+    /// "unsubscribed"}` lists of `[channel, symbol, option]`. Unknown
+    /// symbols raise `ValueError` as for `subscribe`.
+    #[pyo3(name = "set_subscriptions", signature = (target) -> "collections.abc.Awaitable[dict[str, list[list[str | None]]]]")]
+    fn py_set_subscriptions<'py>(&self, py: Python<'py>, target: Vec<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+        let target = target
+            .iter()
+            .map(|item| {
+                item.extract::<(String, String)>()
+                    .map(|(channel, symbol)| (channel, symbol, None))
+                    .or_else(|_| item.extract::<Subscription>())
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let client = self.clone();
+        let future = async move {
+            // Nothing changes if any symbol is unknown
+            let symbols: HashSet<&str> = target.iter().map(|(_, symbol, _)| symbol.as_str()).collect();
+            for symbol in symbols {
+                client.check_symbol(symbol).await?;
+            }
+            PyJson::from_serialize(&client.set_subscriptions(&target))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(name = "disconnect", signature = () -> "collections.abc.Awaitable[str]")]
+    fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.disconnect().await;
             Ok("Disconnected")
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
+
+    /// Stop the WS loop and wait up to `timeout_ms` (default 5000) for it to
+    /// exit. Resolves to whether it did.
+    #[pyo3(name = "close", signature = (timeout_ms=None) -> "collections.abc.Awaitable[bool]")]
+    fn py_close<'py>(&self, py: Python<'py>, timeout_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let timeout = Duration::from_millis(timeout_ms.unwrap_or(5000));
+        let future = async move { Ok(client.close(timeout).await) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// `async with GmocoinDataClient(...) as client:` connects on entry.
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Self]")]
+    fn __aenter__<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        let client = slf.borrow().clone();
+        let this = slf.clone().unbind();
+        let future = async move {
+            client.connect();
+            Ok(this)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(slf.py(), future)
+    }
+
+    /// Disconnects and waits for the WS task to stop, even on error.
+    #[pyo3(signature = (_exc_type, _exc_value, _traceback) -> "collections.abc.Awaitable[bool]")]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.disconnect().await;
+            Ok(false)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
 }
 
 impl GmocoinDataClient {
+    /// Receive every book snapshot and trade the client dispatches.
+    pub fn market_events(&self) -> broadcast::Receiver<MarketEvent> {
+        self.sinks.market_feed.subscribe()
+    }
+
+    /// Latest book for `symbol`, if one has been received.
+    pub fn book(&self, symbol: &str) -> Option<OrderBook> {
+        self.books.lock().unwrap().get(symbol).cloned()
+    }
+
+    /// Subscribe to `channel` for `symbol` unless already subscribed.
+    pub fn ensure_subscribed(&self, channel: &str, symbol: &str) {
+        let known = self.subscriptions.lock().unwrap()
+            .iter()
+            .any(|(c, s, _)| c == channel && s == symbol);
+        if !known {
+            Self::store_subscription(&self.subscriptions, &self.outgoing_tx, &self.connected, channel, symbol, None);
+        }
+    }
+
+    fn store_subscription(
+        subs_arc: &std::sync::Mutex<HashSet<(String, String, String)>>,
+        outgoing_tx: &mpsc::UnboundedSender<String>,
+        connected: &AtomicBool,
+        channel: &str,
+        symbol: &str,
+        option: Option<&str>,
+    ) {
+        // Always store for reconnection
+        {
+            let mut subs = subs_arc.lock().unwrap();
+            subs.insert((channel.to_string(), symbol.to_string(), option.unwrap_or_default().to_string()));
+        }
+
+        // If already connected, hand the subscribe message to the WS loop.
+        if connected.load(Ordering::SeqCst) {
+            let _ = outgoing_tx.send(Self::build_subscribe_msg(channel, symbol, option));
+        }
+    }
+
+    /// Stop `channel` for `symbol` (with any option) and forget it for
+    /// reconnects; an `orderbooks` unsubscribe also drops the cached book.
+    pub fn unsubscribe(&self, channel: &str, symbol: &str) {
+        let removed = {
+            let mut subs = self.subscriptions.lock().unwrap();
+            let before = subs.len();
+            subs.retain(|(c, s, _)| c != channel || s != symbol);
+            subs.len() < before
+        };
+        if channel == "orderbooks" {
+            self.books.lock().unwrap().remove(symbol);
+            self.sinks.book_modes.lock().unwrap().clear(symbol);
+        }
+        if removed && self.connected.load(Ordering::SeqCst) {
+            let command = WsCommand { command: "unsubscribe", channel, symbol: Some(symbol), option: None };
+            let _ = self.outgoing_tx.send(command.to_text());
+        }
+    }
+
+    /// Make `target` (`(channel, symbol, option)`) the subscriptions,
+    /// sending only the commands that takes: unsubscribes for the ones not
+    /// in it, then subscribes for the new ones. A changed option counts as
+    /// both.
+    pub fn set_subscriptions(&self, target: &[(String, String, Option<String>)]) -> SubscriptionChanges {
+        let target: HashSet<(String, String, String)> = target
+            .iter()
+            .map(|(channel, symbol, option)| (channel.clone(), symbol.clone(), option.clone().unwrap_or_default()))
+            .collect();
+        let current = self.subscriptions.lock().unwrap().clone();
+        let mut changes = SubscriptionChanges {
+            subscribed: target.difference(&current).cloned().map(subscription).collect(),
+            unsubscribed: current.difference(&target).cloned().map(subscription).collect(),
+        };
+        changes.subscribed.sort();
+        changes.unsubscribed.sort();
+        for (channel, symbol, _) in &changes.unsubscribed {
+            self.unsubscribe(channel, symbol);
+        }
+        for (channel, symbol, option) in &changes.subscribed {
+            self.subscribe(channel, symbol, option.as_deref());
+        }
+        if !changes.subscribed.is_empty() || !changes.unsubscribed.is_empty() {
+            info!(
+                "GMO: Subscriptions reconciled, {} added, {} removed",
+                changes.subscribed.len(), changes.unsubscribed.len()
+            );
+        }
+        changes
+    }
+
     fn build_subscribe_msg(channel: &str, symbol: &str, option: Option<&str>) -> String {
-        let mut msg = serde_json::json!({
-            "command": "subscribe",
-            "channel": channel,
-            "symbol": symbol,
-        });
-        if let Some(opt) = option {
-            if !opt.is_empty() {
-                msg["option"] = serde_json::Value::String(opt.to_string());
-            }
+        WsCommand {
+            command: "subscribe",
+            channel,
+            symbol: Some(symbol),
+            option: option.filter(|opt| !opt.is_empty()),
         }
-        msg.to_string()
+        .to_text()
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn ws_loop(
         subs_arc: Arc<std::sync::Mutex<HashSet<(String, String, String)>>>,
-        outgoing_arc: Arc<std::sync::Mutex<Vec<String>>>,
-        data_cb_arc: Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
+        outgoing_rx: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<String>>>,
+        sinks: DataSinks,
         books_arc: Arc<std::sync::Mutex<std::collections::HashMap<String, OrderBook>>>,
         shutdown: Arc<AtomicBool>,
         connected: Arc<AtomicBool>,
         ws_rate_limit: TokenBucket,
+        ws_connector: Option<tokio_tungstenite::Connector>,
+        stats: ClientStats,
+        ws_url: String,
+        headers: StaticHeaders,
+        maintenance: MaintenanceGuard,
+        reconnect: BackoffPolicy,
+        stale_timeout: Option<Duration>,
+        ticker_max_rate: Option<f64>,
+        book_interval: Option<Duration>,
     ) {
-        let mut backoff_sec = 1u64;
-        let max_backoff = 64u64;
+        let mut backoff = Backoff::new(reconnect);
+        let mut conflator = ticker_max_rate.map(TickerConflator::new);
+        let mut sampler = book_interval.map(BookSampler::new);
+        let mut outgoing = outgoing_rx.lock().await;
+        let mut decoder = FrameDecoder::default();
+        let mut outage: Option<Outage> = None;
 
         loop {
             if shutdown.load(Ordering::SeqCst) { return; }
+            if let Some(outage) = outage.as_mut() {
+                outage.attempt();
+            }
 
-            let ws_url = "wss://api.coin.z.com/ws/public/v1";
+            let connecting = async {
+                let request = headers.ws_request(&ws_url)?;
+                connect_async_tls_with_config(request, None, false, ws_connector.clone()).await
+            };
 
-            match connect_async(ws_url).await {
+            match connecting.await {
                 Ok((ws, _)) => {
                     info!("GMO: Connected to Public WebSocket");
-                    backoff_sec = 1;
+                    backoff.reset();
+                    if let Some(outage) = outage.take() {
+                        let event = outage.into_event();
+                        info!(
+                            "GMO: Public WS reconnected after {}ms ({}, {} attempts)",
+                            event.downtime_ms, event.reason.as_str(), event.attempts
+                        );
+                        stats.reconnect(event.reason);
+                        sinks.reconnect(event);
+                    }
                     connected.store(true, Ordering::SeqCst);
+                    stats.ws_connected(true);
 
                     // Split WebSocket into independent read/write halves
                     // to avoid mutable borrow conflicts in tokio::select!
@@ -180,10 +1043,9 @@ impl GmocoinDataClient {
                         }
                     }
 
-                    // Queued outgoing messages
-                    {
-                        let mut queue = outgoing_arc.lock().unwrap();
-                        to_send.extend(queue.drain(..));
+                    // Commands queued while (re)connecting
+                    while let Ok(msg) = outgoing.try_recv() {
+                        to_send.push(msg);
                     }
 
                     // Deduplicate subscriptions
@@ -198,39 +1060,87 @@ impl GmocoinDataClient {
                         }
                     }
 
-                    // Main message loop with non-blocking outgoing queue drain
-                    let mut outgoing_check = tokio::time::interval(Duration::from_millis(500));
-                    outgoing_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                    // Main message loop: socket reads and queued commands are
+                    // awaited together, so commands go out as soon as the
+                    // rate limiter allows, even on a quiet connection.
+                    let mut pending: Option<String> = None;
+                    let mut last_recv = Instant::now();
 
                     loop {
+                        let ticker_due = conflator.as_ref().and_then(TickerConflator::next_due);
+                        let books_due = sampler.as_ref().and_then(BookSampler::next_due);
                         if shutdown.load(Ordering::SeqCst) {
                             let _ = ws_write.send(Message::Close(None)).await;
                             connected.store(false, Ordering::SeqCst);
+                            stats.ws_connected(false);
                             return;
                         }
 
-                        let has_outgoing = !outgoing_arc.lock().unwrap().is_empty();
-
                         tokio::select! {
                             biased;
 
+                            // Commands and timers first, so a socket that is
+                            // always ready cannot starve them
+                            Some(msg) = outgoing.recv(), if pending.is_none() => {
+                                pending = Some(msg);
+                            },
+
+                            _ = ws_rate_limit.acquire(), if pending.is_some() => {
+                                if let Some(msg) = pending.take() {
+                                    if let Err(e) = ws_write.send(Message::Text(msg.into())).await {
+                                        error!("GMO: Failed to send msg: {}", e);
+                                    }
+                                }
+                            },
+
+                            _ = sleep_until(last_recv + stale_timeout.unwrap_or_default()), if stale_timeout.is_some() => {
+                                warn!("GMO: Public WS silent for {:?}, reconnecting", last_recv.elapsed());
+                                outage = Some(Outage::new(DisconnectReason::Stale));
+                                break;
+                            },
+
+                            _ = sleep_until(ticker_due.unwrap_or_else(Instant::now)), if ticker_due.is_some() => {
+                                if let Some(conflator) = conflator.as_mut() {
+                                    Self::emit_due_tickers(conflator, &sinks);
+                                }
+                            },
+
+                            _ = sleep_until(books_due.unwrap_or_else(Instant::now)), if books_due.is_some() => {
+                                if let Some(sampler) = sampler.as_mut() {
+                                    Self::emit_due_books(sampler, &sinks, &books_arc);
+                                }
+                            },
+
                             msg = ws_read.next() => {
+                                last_recv = Instant::now();
                                 match msg {
                                     Some(Ok(Message::Text(txt))) => {
                                         let txt_str: &str = txt.as_ref();
-                                        if let Ok(val) = serde_json::from_str::<Value>(txt_str) {
-                                            // Check for error responses (ERR-5003 rate limit, etc.)
-                                            if val.get("error").is_some() {
-                                                warn!("GMO: WS error response: {}", txt_str);
+                                        let envelope = match Envelope::parse(txt_str) {
+                                            Ok(envelope) => envelope,
+                                            Err(e) => {
+                                                stats.error_class("parse");
+                                                sinks.parse_error(ParseErrorEvent::new(None, e, txt_str));
                                                 continue;
                                             }
+                                        };
+                                        // Check for error responses (ERR-5003 rate limit, etc.)
+                                        if let Some(err) = envelope.error {
+                                            warn!("GMO: WS error response: {}", txt_str);
+                                            if err.starts_with("ERR-5003") {
+                                                stats.error_class("rate_limited");
+                                                ws_rate_limit.throttle();
+                                            } else {
+                                                stats.error_class("exchange");
+                                            }
+                                            continue;
+                                        }
 
-                                            let channel = val.get("channel")
-                                                .and_then(|c| c.as_str())
-                                                .unwrap_or("")
-                                                .to_string();
-                                            if !channel.is_empty() {
-                                                Self::dispatch_message(&channel, val, &data_cb_arc, &books_arc);
+                                        if let Some(channel) = envelope.channel {
+                                            stats.message(&channel);
+                                            if let Err(e) = Self::dispatch_message(&channel, txt_str, &mut decoder, &sinks, &stats, &books_arc, &mut conflator, &mut sampler) {
+                                                stats.error_class("parse");
+                                                sinks.parse_error(ParseErrorEvent::new(Some(&channel), e, txt_str));
                                             }
                                         }
                                     }
@@ -239,98 +1149,131 @@ impl GmocoinDataClient {
                                     }
                                     Some(Ok(Message::Close(_))) => {
                                         warn!("GMO: Public WS closed by server");
+                                        outage = Some(Outage::new(DisconnectReason::ServerClose));
                                         break;
                                     }
                                     Some(Err(e)) => {
                                         error!("GMO: Public WS error: {}", e);
+                                        stats.error_class("websocket");
+                                        outage = Some(Outage::new(DisconnectReason::ReadError));
                                         break;
                                     }
                                     None => {
                                         warn!("GMO: Public WS stream ended");
+                                        outage = Some(Outage::new(DisconnectReason::StreamEnded));
                                         break;
                                     }
                                     _ => {}
                                 }
                             },
-
-                            _ = outgoing_check.tick(), if !has_outgoing => {
-                                // Keep loop alive to check for newly added subscriptions
-                            },
-
-                            _ = async {
-                                ws_rate_limit.acquire().await;
-                                if let Some(msg) = outgoing_arc.lock().unwrap().pop() {
-                                    if let Err(e) = ws_write.send(Message::Text(msg.into())).await {
-                                        error!("GMO: Failed to send msg: {}", e);
-                                    }
-                                }
-                            }, if has_outgoing => {}
                         }
                     }
 
                     connected.store(false, Ordering::SeqCst);
+                    stats.ws_connected(false);
                 }
                 Err(e) => {
-                    error!("GMO: Public WS connection failed: {}. Retrying in {}s...", e, backoff_sec);
+                    error!("GMO: Public WS connection failed: {}. Retrying in {:?}...", e, backoff.current());
+                    stats.error_class("websocket");
                 }
             }
 
             if shutdown.load(Ordering::SeqCst) { return; }
-            sleep(Duration::from_secs(backoff_sec)).await;
-            backoff_sec = (backoff_sec * 2).min(max_backoff);
+            if maintenance.wait_out("Public WS", |event| sinks.maintenance(event)).await {
+                backoff.reset();
+                continue;
+            }
+            sleep(backoff.next_delay()).await;
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn dispatch_message(
         channel: &str,
-        val: Value,
-        data_cb_arc: &Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
+        txt: &str,
+        decoder: &mut FrameDecoder,
+        sinks: &DataSinks,
+        stats: &ClientStats,
         books_arc: &Arc<std::sync::Mutex<std::collections::HashMap<String, OrderBook>>>,
-    ) {
+        conflator: &mut Option<TickerConflator>,
+        sampler: &mut Option<BookSampler>,
+    ) -> Result<(), String> {
+        // Parse and prepare everything before touching the GIL
         match channel {
             "ticker" => {
-                if let Ok(ticker) = serde_json::from_value::<crate::model::market_data::Ticker>(val) {
-                    Python::try_attach(|py| {
-                        let lock = data_cb_arc.lock().unwrap();
-                        if let Some(cb) = lock.as_ref() {
-                            let py_obj = Py::new(py, ticker).expect("Failed to create Python object");
-                            let _ = cb.call1(py, ("ticker", py_obj)).ok();
-                        }
-                    });
+                let ticker = decoder.decode::<Ticker>(txt)?;
+                sinks.publish(|| MarketEvent::Ticker(ticker.clone()));
+                // Conflation only spares the callback; the feed gets every update
+                let ticker = match conflator.as_mut() {
+                    Some(conflator) => {
+                        // A busy socket can keep the timer branch from firing
+                        Self::emit_due_tickers(conflator, sinks);
+                        conflator.offer(ticker, Instant::now())
+                    }
+                    None => Some(ticker),
+                };
+                if let Some(ticker) = ticker {
+                    let symbol = ticker.symbol.clone();
+                    sinks.emit("ticker", &symbol, ticker);
                 }
             }
             "orderbooks" => {
-                if let Ok(depth) = serde_json::from_value::<crate::model::market_data::Depth>(val) {
-                    let symbol = depth.symbol.clone();
-                    let book_clone = {
-                        let mut books = books_arc.lock().unwrap();
-                        let book = books.entry(symbol.clone())
-                            .or_insert_with(|| OrderBook::new(symbol.clone()));
-                        book.apply_snapshot(depth);
-                        book.clone()
-                    };
-
-                    Python::try_attach(|py| {
-                        let lock = data_cb_arc.lock().unwrap();
-                        if let Some(cb) = lock.as_ref() {
-                            let py_obj = Py::new(py, book_clone).expect("Failed to create Python object");
-                            let _ = cb.call1(py, ("orderbooks", py_obj)).ok();
-                        }
-                    });
+                let depth = decoder.decode::<Depth>(txt)?;
+                let symbol = depth.symbol.clone();
+                let book_clone = {
+                    let mut books = books_arc.lock().unwrap();
+                    let book = books.entry(symbol.clone())
+                        .or_insert_with(|| OrderBook::new(symbol.clone()));
+                    let (touch_changed, levels_changed) = book.diff(&depth);
+                    stats.book_update(&symbol, touch_changed, levels_changed);
+                    book.apply_snapshot(depth);
+                    book.clone()
+                };
+                sinks.publish(|| MarketEvent::Book(book_clone.clone()));
+                match sampler.as_mut() {
+                    Some(sampler) => {
+                        sampler.mark(&symbol);
+                        Self::emit_due_books(sampler, sinks, books_arc);
+                    }
+                    None => sinks.emit_book(book_clone),
                 }
             }
             "trades" => {
-                if let Ok(trade) = serde_json::from_value::<crate::model::market_data::Trade>(val) {
-                    Python::try_attach(|py| {
-                        let lock = data_cb_arc.lock().unwrap();
-                        if let Some(cb) = lock.as_ref() {
-                            let py_obj = Py::new(py, trade).expect("Failed to create Python object");
-                            let _ = cb.call1(py, ("trades", py_obj)).ok();
-                        }
-                    });
-                }
+                let trade = decoder.decode::<Trade>(txt)?;
+                sinks.publish(|| MarketEvent::Trade(trade.clone()));
+                let symbol = trade.symbol.clone().unwrap_or_default();
+                sinks.emit_trade(&symbol, trade);
             }
             _ => {}
         }
+        Ok(())
+    }
+
+    /// Deliver the conflated tickers whose interval has passed.
+    fn emit_due_tickers(conflator: &mut TickerConflator, sinks: &DataSinks) {
+        for ticker in conflator.take_due(Instant::now()) {
+            let symbol = ticker.symbol.clone();
+            sinks.emit("ticker", &symbol, ticker);
+        }
+    }
+
+    /// Emit the latest state of the books updated since the last sample,
+    /// once it is due. Unsubscribed books are skipped.
+    fn emit_due_books(
+        sampler: &mut BookSampler,
+        sinks: &DataSinks,
+        books_arc: &Arc<std::sync::Mutex<std::collections::HashMap<String, OrderBook>>>,
+    ) {
+        let symbols = sampler.take_due(Instant::now());
+        if symbols.is_empty() {
+            return;
+        }
+        let books: Vec<OrderBook> = {
+            let books = books_arc.lock().unwrap();
+            symbols.iter().filter_map(|symbol| books.get(symbol).cloned()).collect()
+        };
+        for book in books {
+            sinks.emit_book(book);
+        }
     }
 }
@@ -0,0 +1,114 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tokio::time::{sleep_until, Duration, Instant};
+use tracing::{error, warn};
+
+use crate::client::rest::GmocoinRestClient;
+
+/// When the dead-man's switch fires.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeadManConfig {
+    /// Fire after this long without a heartbeat.
+    pub timeout: Duration,
+    /// Also close every open position at market.
+    pub flatten: bool,
+}
+
+/// Heartbeats from the strategy side. Disarmed until the first heartbeat,
+/// and again after firing.
+#[derive(Clone, Default)]
+pub struct DeadManSwitch {
+    last: Arc<Mutex<Option<Instant>>>,
+}
+
+impl DeadManSwitch {
+    pub fn heartbeat(&self) {
+        *self.last.lock().unwrap() = Some(Instant::now());
+    }
+
+    pub fn disarm(&self) {
+        *self.last.lock().unwrap() = None;
+    }
+
+    pub fn last_heartbeat(&self) -> Option<Instant> {
+        *self.last.lock().unwrap()
+    }
+}
+
+/// What the switch did when it fired.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DeadManEvent {
+    /// Time since the last heartbeat.
+    pub idle_ms: u64,
+    /// Symbols whose orders were bulk-cancelled.
+    pub cancelled_symbols: Vec<String>,
+    /// `"SYMBOL SIDE SIZE"` of each position closed.
+    pub flattened: Vec<String>,
+    /// Steps that failed; the switch does not retry them.
+    pub errors: Vec<String>,
+}
+
+fn opposite(side: &str) -> &'static str {
+    if side.eq_ignore_ascii_case("BUY") { "SELL" } else { "BUY" }
+}
+
+/// Cancel every open order (one `cancelBulkOrder` over all listed symbols)
+/// and, when `flatten`, close every position summary at market.
+pub async fn fire(client: &GmocoinRestClient, flatten: bool, idle: Duration) -> DeadManEvent {
+    let mut event = DeadManEvent { idle_ms: idle.as_millis() as u64, ..Default::default() };
+    match client.get_symbols().await {
+        Ok(symbols) => {
+            let symbols: Vec<String> = symbols.into_iter().map(|s| s.symbol).collect();
+            match client.cancel_bulk_order(&symbols).await {
+                Ok(_) => event.cancelled_symbols = symbols,
+                Err(e) => event.errors.push(format!("cancelBulkOrder: {}", e)),
+            }
+        }
+        Err(e) => event.errors.push(format!("symbols: {}", e)),
+    }
+    if flatten {
+        match client.get_position_summary(None).await {
+            Ok(summary) => {
+                for position in summary.list {
+                    let side = opposite(&position.side);
+                    let size = &position.sum_position_quantity;
+                    match client.close_bulk_order(&position.symbol, side, "MARKET", size, None, None).await {
+                        Ok(_) => event.flattened.push(format!("{} {} {}", position.symbol, side, size)),
+                        Err(e) => event.errors.push(format!("closeBulkOrder {}: {}", position.symbol, e)),
+                    }
+                }
+            }
+            Err(e) => event.errors.push(format!("positionSummary: {}", e)),
+        }
+    }
+    event
+}
+
+/// Fire `config.timeout` after the last heartbeat of `switch`, reporting
+/// through `emit`, then wait for the next heartbeat to re-arm.
+pub(crate) async fn watchdog(
+    client: GmocoinRestClient,
+    switch: DeadManSwitch,
+    config: DeadManConfig,
+    emit: impl Fn(DeadManEvent),
+) {
+    loop {
+        let deadline = match switch.last_heartbeat() {
+            Some(last) => last + config.timeout,
+            None => Instant::now() + config.timeout,
+        };
+        sleep_until(deadline).await;
+        let Some(last) = switch.last_heartbeat() else { continue };
+        let idle = last.elapsed();
+        if idle < config.timeout {
+            continue;
+        }
+        switch.disarm();
+        error!("GMO: No heartbeat for {:?}, cancelling all orders{}", idle, if config.flatten { " and closing positions" } else { "" });
+        let event = fire(&client, config.flatten, idle).await;
+        for e in &event.errors {
+            warn!("GMO: Dead-man's switch step failed: {}", e);
+        }
+        emit(event);
+    }
+}
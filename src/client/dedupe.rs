@@ -0,0 +1,239 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::time::{sleep, Duration};
+
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::order::{ExecutionsList, Order, OrdersList};
+use crate::time;
+
+/// Wait before looking for an ambiguously submitted order, so GMO has
+/// processed it if it arrived.
+const RECOVERY_DELAY: Duration = Duration::from_millis(500);
+
+/// Tolerance for the venue clock running behind ours.
+const CLOCK_SLACK: TimeDelta = TimeDelta::seconds(2);
+
+/// Client order ids remembered per session; the oldest are forgotten
+/// beyond this.
+const MAX_SUBMISSIONS: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Submission {
+    InFlight,
+    Accepted(u64),
+    /// Failed in a way that may still have placed the order.
+    Ambiguous,
+}
+
+/// A remembered client order id, as exported with the execution state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionEntry {
+    pub client_order_id: String,
+    /// The venue order id; `None` when the submission failed ambiguously or
+    /// was still in flight, so the id stays refused after an import.
+    pub order_id: Option<u64>,
+}
+
+#[derive(Default)]
+struct Entries {
+    by_id: HashMap<String, Submission>,
+    /// Oldest first, for eviction.
+    order: VecDeque<String>,
+}
+
+/// Client order ids submitted in this session, so a retried submission
+/// returns the venue order id it already got instead of placing the order
+/// twice. Ids whose submission failed ambiguously (e.g. timed out after
+/// sending) are refused until `forget` is called for them.
+#[derive(Clone, Default)]
+pub struct SubmissionLog {
+    inner: Arc<Mutex<Entries>>,
+}
+
+impl SubmissionLog {
+    /// Claim `client_order_id` for a new submission. `Some(order_id)` when it
+    /// was already accepted; an error while it is in flight or ambiguous.
+    pub fn begin(&self, client_order_id: &str) -> Result<Option<u64>, GmocoinError> {
+        let mut entries = self.inner.lock().unwrap();
+        match entries.by_id.get(client_order_id) {
+            Some(Submission::Accepted(order_id)) => Ok(Some(*order_id)),
+            Some(Submission::InFlight) => Err(GmocoinError::InvalidInput(format!(
+                "Client order id {} is already being submitted",
+                client_order_id
+            ))),
+            Some(Submission::Ambiguous) => Err(GmocoinError::InvalidInput(format!(
+                "Client order id {} may already be placed (its submission failed ambiguously); reconcile it and forget_submission() before retrying",
+                client_order_id
+            ))),
+            None => {
+                entries.by_id.insert(client_order_id.to_string(), Submission::InFlight);
+                entries.order.push_back(client_order_id.to_string());
+                while entries.order.len() > MAX_SUBMISSIONS {
+                    if let Some(oldest) = entries.order.pop_front() {
+                        entries.by_id.remove(&oldest);
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn accept(&self, client_order_id: &str, order_id: u64) {
+        self.set(client_order_id, Submission::Accepted(order_id));
+    }
+
+    /// Record a failed submission: kept as ambiguous when the order may
+    /// have been placed, else released for a retry.
+    pub fn fail(&self, client_order_id: &str, error: &GmocoinError) {
+        if error.is_ambiguous() {
+            self.set(client_order_id, Submission::Ambiguous);
+        } else {
+            self.forget(client_order_id);
+        }
+    }
+
+    /// Release `client_order_id`, e.g. once an ambiguous submission was
+    /// reconciled.
+    pub fn forget(&self, client_order_id: &str) {
+        let mut entries = self.inner.lock().unwrap();
+        if entries.by_id.remove(client_order_id).is_some() {
+            entries.order.retain(|id| id != client_order_id);
+        }
+    }
+
+    /// Venue order id an accepted `client_order_id` got.
+    pub fn order_id(&self, client_order_id: &str) -> Option<u64> {
+        match self.inner.lock().unwrap().by_id.get(client_order_id) {
+            Some(Submission::Accepted(order_id)) => Some(*order_id),
+            _ => None,
+        }
+    }
+
+    /// Whether `order_id` was accepted for some client order id.
+    pub fn contains_order(&self, order_id: u64) -> bool {
+        self.inner.lock().unwrap().by_id.values().any(|s| *s == Submission::Accepted(order_id))
+    }
+
+    /// Point the client order ids of `old` at its replacement `new`.
+    pub fn remap(&self, old: u64, new: u64) {
+        for submission in self.inner.lock().unwrap().by_id.values_mut() {
+            if *submission == Submission::Accepted(old) {
+                *submission = Submission::Accepted(new);
+            }
+        }
+    }
+
+    /// Every remembered client order id, oldest first.
+    pub fn entries(&self) -> Vec<SubmissionEntry> {
+        let entries = self.inner.lock().unwrap();
+        entries
+            .order
+            .iter()
+            .filter_map(|id| {
+                let order_id = match entries.by_id.get(id)? {
+                    Submission::Accepted(order_id) => Some(*order_id),
+                    Submission::InFlight | Submission::Ambiguous => None,
+                };
+                Some(SubmissionEntry { client_order_id: id.clone(), order_id })
+            })
+            .collect()
+    }
+
+    /// Remember exported `entries` ahead of this session's own, which win
+    /// on conflict. Returns how many were added.
+    pub fn restore(&self, restored: &[SubmissionEntry]) -> usize {
+        let mut entries = self.inner.lock().unwrap();
+        let mut added = VecDeque::new();
+        for entry in restored {
+            if entries.by_id.contains_key(&entry.client_order_id) {
+                continue;
+            }
+            let submission = entry.order_id.map_or(Submission::Ambiguous, Submission::Accepted);
+            entries.by_id.insert(entry.client_order_id.clone(), submission);
+            added.push_back(entry.client_order_id.clone());
+        }
+        let count = added.len();
+        added.append(&mut entries.order);
+        entries.order = added;
+        while entries.order.len() > MAX_SUBMISSIONS {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.by_id.remove(&oldest);
+            }
+        }
+        count
+    }
+
+    fn set(&self, client_order_id: &str, submission: Submission) {
+        if let Some(entry) = self.inner.lock().unwrap().by_id.get_mut(client_order_id) {
+            *entry = submission;
+        }
+    }
+}
+
+/// What an order submission asked for, to recognize it on the venue.
+#[derive(Clone, Debug)]
+pub struct SubmittedOrder<'a> {
+    pub symbol: &'a str,
+    pub side: &'a str,
+    pub execution_type: &'a str,
+    pub size: Decimal,
+    pub price: Option<Decimal>,
+    /// When it was sent.
+    pub sent_at: DateTime<Utc>,
+}
+
+fn same(value: &str, expected: Decimal) -> bool {
+    Decimal::from_str(value).is_ok_and(|v| v == expected)
+}
+
+impl SubmittedOrder<'_> {
+    fn matches(&self, order: &Order) -> bool {
+        order.symbol == self.symbol
+            && order.side.eq_ignore_ascii_case(self.side)
+            && order.execution_type.eq_ignore_ascii_case(self.execution_type)
+            && same(&order.size, self.size)
+            && self.price.is_none_or(|price| order.price.as_deref().is_some_and(|p| same(p, price)))
+            && time::parse(&order.timestamp).is_ok_and(|ts| ts >= self.sent_at - CLOCK_SLACK)
+    }
+}
+
+/// Look for `submitted` on the venue after its submission failed
+/// ambiguously: among the symbol's active orders, then among the orders of
+/// its latest executions (for orders that filled at once), skipping orders
+/// `known` to belong to other submissions. `Ok(None)` means neither list
+/// has it, i.e. it never reached GMO; an error means that could not be
+/// established.
+pub async fn find_submitted(
+    rest: &GmocoinRestClient,
+    submitted: &SubmittedOrder<'_>,
+    known: impl Fn(u64) -> bool,
+) -> Result<Option<u64>, GmocoinError> {
+    sleep(RECOVERY_DELAY).await;
+    let active = OrdersList::deserialize(rest.get_active_orders(submitted.symbol, 1, 100).await?)?;
+    let found = active.list.iter().filter(|o| !known(o.order_id) && submitted.matches(o)).map(|o| o.order_id).min();
+    if found.is_some() {
+        return Ok(found);
+    }
+
+    let executions = ExecutionsList::deserialize(rest.get_latest_executions(submitted.symbol, 1, 100).await?)?;
+    let mut candidates: Vec<u64> = executions
+        .list
+        .iter()
+        .filter(|e| e.side.eq_ignore_ascii_case(submitted.side) && !known(e.order_id))
+        .filter(|e| time::parse(&e.timestamp).is_ok_and(|ts| ts >= submitted.sent_at - CLOCK_SLACK))
+        .map(|e| e.order_id)
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+    for order_id in candidates {
+        if rest.get_order(order_id).await?.list.iter().any(|o| submitted.matches(o)) {
+            return Ok(Some(order_id));
+        }
+    }
+    Ok(None)
+}
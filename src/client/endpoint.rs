@@ -0,0 +1,66 @@
+use crate::rate_limit::Priority;
+
+/// REST endpoint families.
+///
+/// GMO Coin applies limits and fails per endpoint family, so the REST client
+/// tracks health (and throttling) per group rather than per HTTP method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    /// `/public/v1/*` market data
+    Public,
+    /// Private account, position and query endpoints
+    Account,
+    /// Private order mutations (new / change / cancel / close)
+    Order,
+}
+
+impl EndpointGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EndpointGroup::Public => "public",
+            EndpointGroup::Account => "account",
+            EndpointGroup::Order => "order",
+        }
+    }
+
+    /// Parse a group name as used in configuration (`"public"`, `"account"`, `"order"`).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "public" => Some(EndpointGroup::Public),
+            "account" => Some(EndpointGroup::Account),
+            "order" => Some(EndpointGroup::Order),
+            _ => None,
+        }
+    }
+
+    /// Rate limiter lane for a private endpoint: order mutations and the
+    /// order/fill lookups the execution flow waits on go ahead of polling.
+    pub fn priority(endpoint: &str) -> Priority {
+        match endpoint {
+            "/v1/orders" | "/v1/executions" => Priority::High,
+            _ if Self::for_private(endpoint) == EndpointGroup::Order => Priority::High,
+            _ => Priority::Normal,
+        }
+    }
+
+    /// Classify a private endpoint path (e.g. `/v1/order`).
+    pub fn for_private(endpoint: &str) -> Self {
+        match endpoint {
+            "/v1/order"
+            | "/v1/changeOrder"
+            | "/v1/cancelOrder"
+            | "/v1/cancelOrders"
+            | "/v1/cancelBulkOrder"
+            | "/v1/closeOrder"
+            | "/v1/closeBulkOrder"
+            | "/v1/changeLosscutPrice" => EndpointGroup::Order,
+            _ => EndpointGroup::Account,
+        }
+    }
+}
+
+impl std::fmt::Display for EndpointGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
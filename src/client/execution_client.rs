@@ -1,79 +1,1551 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 use tokio::time::{sleep, Duration};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{connect_async_tls_with_config, tungstenite::Message};
 use futures_util::{SinkExt, StreamExt};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "python")]
+use crate::callback::{CallbackPayload, CallbackSlot};
+use crate::json::{ParseErrorEvent, WsCommand};
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use tracing::{info, warn, error};
-use crate::client::rest::GmocoinRestClient;
-use crate::model::order::Order;
+#[cfg(feature = "python")]
+use crate::client::profile::VenueProfile;
+use crate::client::algo::{self, AlgoHandle, AlgoProgress, TwapConfig};
+use crate::client::amends::AmendTracker;
+use crate::client::data_client::GmocoinDataClient;
+#[cfg(feature = "python")]
+use crate::client::checkpoint::ExecutionState;
+#[cfg(feature = "python")]
+use crate::client::dedupe::{find_submitted, SubmissionLog, SubmittedOrder};
+use crate::client::deadman::{self, DeadManConfig, DeadManSwitch};
+use crate::client::exposure::{self, OrderExposure};
+use crate::client::fallback::{self, FallbackConfig, StreamHealth};
+#[cfg(feature = "python")]
+use crate::client::fallback::DEFAULT_FALLBACK_INTERVAL;
+use crate::client::history::{self, ClosedOrder};
+use crate::client::latency::{self, LatencyAlarm, LatencyBudgets};
+use crate::client::positions::{self, LosscutChanges, PositionCache, PositionSnapshot, DEFAULT_POSITION_REFRESH};
+#[cfg(feature = "python")]
+use crate::arrow::{self as arrow_export, ArrowRecordBatch};
+use crate::client::maintenance::{MaintenanceConfig, MaintenanceGuard};
+use crate::client::quality::{Arrival, ExecutionQuality};
+use crate::client::throttle::{RequoteOutcome, RequoteQueue, DEFAULT_REQUOTE_RESERVE};
+use crate::client::endpoint::EndpointGroup;
+use crate::client::reconnect::{Backoff, BackoffPolicy, DisconnectReason, Outage};
+use crate::client::replay::{ReplayControl, ReplayPosition};
+use crate::client::rollover::{self, RolloverConfig};
+use crate::client::rest::{GmocoinRestClient, RestClientConfig};
+#[cfg(feature = "python")]
+use crate::client::rest::parse_group;
+use crate::client::session::{self, SessionRecorder};
+use crate::error::GmocoinError;
+use crate::health::{Health, WsHealth};
+#[cfg(feature = "python")]
+use crate::credentials::Credentials;
+#[cfg(feature = "python")]
+use crate::rate_limit::RateLimitTier;
+use crate::model::report::decimal;
+#[cfg(feature = "python")]
+use crate::model::report::from_json;
+use crate::model::order::{CloseOrderAck, Execution, LosscutPriceAck, Order, Position, PositionSummary};
+use crate::runtime;
+use crate::stats::ClientStats;
+#[cfg(feature = "python")]
+use crate::time;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
 
-#[pyclass]
+/// `"account"` of the events of a client without `with_account_label`.
+pub const DEFAULT_ACCOUNT_LABEL: &str = "main";
+
+/// A Private WS event: `event_type` is `"OrderUpdate"`, `"ExecutionUpdate"`,
+/// `"PositionUpdate"` or `"PositionSummaryUpdate"`, `data` the raw message;
+/// or `"Maintenance"` / `"Reconnect"` / `"ParseError"` /
+/// `"RolloverProjection"` / `"RolloverReconciliation"` with a serialized
+/// `MaintenanceEvent` / `ReconnectEvent` / `ParseErrorEvent` /
+/// `RolloverProjection` / `RolloverReconciliation`; or `"AlgoProgress"` /
+/// `"DeadManTriggered"` with an `AlgoProgress` / `DeadManEvent`; or
+/// `"PollingFallback"` when REST polling stands in for the stream; or
+/// `"LatencyAlarm"` with a `LatencyAlarm`. Every `data` object carries
+/// `"account"`, the label of the account it comes from.
+#[derive(Clone, Debug)]
+pub struct OrderEvent {
+    pub event_type: &'static str,
+    pub data: serde_json::Value,
+}
+
+/// Where Private WS events are delivered: the event feed and, in the
+/// extension module, the Python order callback.
+#[derive(Clone)]
+struct OrderSinks {
+    // Callback for order/execution/asset updates: (event_type, data: dict)
+    #[cfg(feature = "python")]
+    callback: CallbackSlot,
+    feed: broadcast::Sender<OrderEvent>,
+    /// Label of the account the events come from, added to them as
+    /// `"account"`.
+    account: Arc<str>,
+}
+
+impl OrderSinks {
+    fn emit(&self, event_type: &'static str, mut data: serde_json::Value) {
+        if let Some(fields) = data.as_object_mut() {
+            fields.insert("account".to_string(), self.account.as_ref().into());
+        }
+        if self.feed.receiver_count() > 0 {
+            let _ = self.feed.send(OrderEvent { event_type, data: data.clone() });
+        }
+        #[cfg(feature = "python")]
+        self.callback.call_events([(event_type, data)]);
+    }
+
+    fn latency_alarm(&self, alarm: LatencyAlarm) {
+        alarm.log();
+        self.emit("LatencyAlarm", serde_json::to_value(&alarm).expect("event serializes"));
+    }
+}
+
+/// Result of `amend_order`: the order now working and whether it replaced
+/// `previous_order_id`.
+#[derive(Clone, Debug, Serialize)]
+pub struct Amendment {
+    pub order_id: u64,
+    pub previous_order_id: u64,
+    /// Cancelled and resubmitted, rather than changed in place.
+    pub replaced: bool,
+    /// Price of the working order; `None` for MARKET orders.
+    pub price: Option<Decimal>,
+    /// Size of the working order; after a replacement, the new total size
+    /// less what the previous order had executed.
+    pub size: Option<Decimal>,
+}
+
+#[cfg_attr(feature = "python", pyclass(skip_from_py_object))]
+#[derive(Clone)]
 pub struct GmocoinExecutionClient {
     rest_client: GmocoinRestClient,
-    // Callback for order/execution/asset updates: (event_type, data_json)
-    order_callback: Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
-    // Order state tracking
-    orders: Arc<RwLock<HashMap<u64, Order>>>,
-    client_oid_map: Arc<RwLock<HashMap<String, u64>>>,
+    sinks: OrderSinks,
+    // Order state tracking; a std lock, never held across an await, so
+    // pymethods can read it from callbacks on the runtime's threads
+    orders: Arc<std::sync::RwLock<HashMap<u64, Order>>>,
+    /// Client order ids submitted through `submit_order`, with their venue
+    /// order ids
+    #[cfg(feature = "python")]
+    submissions: SubmissionLog,
     shutdown: Arc<AtomicBool>,
+    ws_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    recorder: SessionRecorder,
+    replay: ReplayControl,
+    maintenance: MaintenanceGuard,
+    reconnect: BackoffPolicy,
+    stale_timeout: Option<Duration>,
+    rollover: Option<RolloverConfig>,
+    rollover_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    dead_man: DeadManSwitch,
+    dead_man_config: Option<DeadManConfig>,
+    dead_man_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    quality: ExecutionQuality,
+    /// Books read for the arrival state of submitted orders
+    market_data: Arc<std::sync::Mutex<Option<GmocoinDataClient>>>,
+    requotes: RequoteQueue<Amendment>,
+    algos: Arc<std::sync::Mutex<HashMap<u64, AlgoHandle>>>,
+    next_algo_id: Arc<AtomicU64>,
+    positions: PositionCache,
+    losscuts: LosscutChanges,
+    amends: AmendTracker,
+    position_refresh: Option<Duration>,
+    position_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    stream: StreamHealth,
+    fallback: Option<FallbackConfig>,
+    fallback_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    latency: LatencyBudgets,
+    gap_task: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    /// Further accounts routed through this client, by name
+    accounts: Arc<std::sync::Mutex<HashMap<String, GmocoinExecutionClient>>>,
 }
 
-#[pymethods]
 impl GmocoinExecutionClient {
-    #[new]
-    pub fn new(api_key: String, api_secret: String, timeout_ms: u64, proxy_url: Option<String>, rate_limit_per_sec: Option<f64>) -> Self {
+    pub fn from_config(config: RestClientConfig) -> Result<Self, GmocoinError> {
+        Ok(Self::from_rest_client(GmocoinRestClient::from_config(config)?))
+    }
+
+    /// Wrap an existing REST client, sharing its limiter and counters.
+    pub fn from_rest_client(rest_client: GmocoinRestClient) -> Self {
         Self {
-            rest_client: GmocoinRestClient::new(api_key, api_secret, timeout_ms, proxy_url, rate_limit_per_sec),
-            order_callback: Arc::new(std::sync::Mutex::new(None)),
-            orders: Arc::new(RwLock::new(HashMap::new())),
-            client_oid_map: Arc::new(RwLock::new(HashMap::new())),
+            sinks: OrderSinks {
+                #[cfg(feature = "python")]
+                callback: CallbackSlot::new(CallbackPayload::default(), rest_client.stats().clone()),
+                feed: broadcast::channel(1024).0,
+                account: DEFAULT_ACCOUNT_LABEL.into(),
+            },
+            orders: Arc::new(std::sync::RwLock::new(HashMap::new())),
+            #[cfg(feature = "python")]
+            submissions: SubmissionLog::default(),
             shutdown: Arc::new(AtomicBool::new(false)),
+            ws_task: Arc::new(std::sync::Mutex::new(None)),
+            recorder: SessionRecorder::default(),
+            replay: ReplayControl::default(),
+            maintenance: MaintenanceGuard::new(MaintenanceConfig::default(), Some(rest_client.clone())),
+            reconnect: BackoffPolicy::PRIVATE,
+            stale_timeout: None,
+            rollover: None,
+            rollover_task: Arc::new(std::sync::Mutex::new(None)),
+            dead_man: DeadManSwitch::default(),
+            dead_man_config: None,
+            dead_man_task: Arc::new(std::sync::Mutex::new(None)),
+            quality: ExecutionQuality::default(),
+            market_data: Arc::new(std::sync::Mutex::new(None)),
+            requotes: RequoteQueue::new(DEFAULT_REQUOTE_RESERVE),
+            algos: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            next_algo_id: Arc::new(AtomicU64::new(1)),
+            positions: PositionCache::default(),
+            losscuts: LosscutChanges::default(),
+            amends: AmendTracker::default(),
+            position_refresh: Some(DEFAULT_POSITION_REFRESH),
+            position_task: Arc::new(std::sync::Mutex::new(None)),
+            stream: StreamHealth::default(),
+            fallback: None,
+            fallback_task: Arc::new(std::sync::Mutex::new(None)),
+            latency: LatencyBudgets::default(),
+            gap_task: Arc::new(std::sync::Mutex::new(None)),
+            accounts: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            rest_client,
         }
     }
 
-    pub fn set_order_callback(&self, callback: Py<PyAny>) {
-        let mut lock = self.order_callback.lock().unwrap();
-        *lock = Some(callback);
+    /// When Private WS reconnects wait for venue maintenance to end
+    /// (`/v1/status` is queried through this client's REST client).
+    pub fn with_maintenance(mut self, config: MaintenanceConfig) -> Self {
+        self.maintenance = MaintenanceGuard::new(config, Some(self.rest_client.clone()));
+        self
     }
 
-    /// Connect to Private WebSocket (with token refresh loop)
-    pub fn connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
-        let order_cb_arc = self.order_callback.clone();
-        let orders_arc = self.orders.clone();
-        let shutdown = self.shutdown.clone();
+    /// Delay between Private WS reconnect attempts (default
+    /// `BackoffPolicy::PRIVATE`).
+    pub fn with_reconnect(mut self, policy: BackoffPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// Reconnect the Private WS when nothing is received for `timeout`.
+    pub fn with_stale_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.stale_timeout = timeout;
+        self
+    }
+
+    /// Project leverage fees before each rollover of the maintenance
+    /// calendar and reconcile them afterwards (off by default).
+    pub fn with_rollover(mut self, config: Option<RolloverConfig>) -> Self {
+        self.rollover = config;
+        self
+    }
+
+    /// Cancel all open orders (and optionally close all positions) when
+    /// `heartbeat` is not called for `config.timeout` while connected
+    /// (off by default).
+    pub fn with_dead_man(mut self, config: Option<DeadManConfig>) -> Self {
+        self.dead_man_config = config;
+        self
+    }
+
+    /// Keep the dead-man's switch from firing, and those of the added
+    /// accounts; the first call arms them.
+    pub fn heartbeat(&self) {
+        self.dead_man.heartbeat();
+        for account in self.account_clients() {
+            account.heartbeat();
+        }
+    }
+
+    /// Queue re-quotes once the order bucket has `reserve` tokens or fewer
+    /// left (default `DEFAULT_REQUOTE_RESERVE`).
+    pub fn with_requote_reserve(mut self, reserve: f64) -> Self {
+        self.requotes = RequoteQueue::new(reserve);
+        self
+    }
+
+    /// Reload the position cache from `/v1/positionSummary` on connect and
+    /// every `interval` while connected (default
+    /// `DEFAULT_POSITION_REFRESH`); `None` leaves it to
+    /// `positionSummaryEvents`.
+    pub fn with_position_refresh(mut self, interval: Option<Duration>) -> Self {
+        self.position_refresh = interval;
+        self
+    }
+
+    /// Emit `"LatencyAlarm"` when an order is answered later than
+    /// `budgets.order_ack` or the connected Private WS receives nothing for
+    /// `budgets.event_gap` (off by default).
+    pub fn with_latency_alarms(mut self, budgets: LatencyBudgets) -> Self {
+        self.latency = budgets;
+        self
+    }
+
+    /// REST reachability, the Private WS (and the Public WS of the data
+    /// client attached with `attach_market_data`) and the REST rate limits.
+    pub fn health(&self) -> Health {
+        let public_ws = self.market_data.lock().unwrap().as_ref().map(GmocoinDataClient::ws_health);
+        Health::new(
+            Some(self.rest_client.rest_health()),
+            public_ws,
+            Some(WsHealth::new(self.rest_client.stats(), true)),
+            self.rest_client.rate_limit_health(),
+        )
+    }
+
+    /// Poll REST for missed order and execution events while the Private
+    /// WS is down (off by default); see `fallback::poll_once`.
+    pub fn with_polling_fallback(mut self, config: Option<FallbackConfig>) -> Self {
+        self.fallback = config;
+        self
+    }
+
+    /// Label this client's events carry as `"account"` (default
+    /// `DEFAULT_ACCOUNT_LABEL`), e.g. to tell nodes apart downstream.
+    pub fn with_account_label(mut self, label: &str) -> Result<Self, GmocoinError> {
+        if label.is_empty() {
+            return Err(GmocoinError::InvalidInput("Account label must not be empty".to_string()));
+        }
+        self.sinks.account = label.into();
+        Ok(self)
+    }
+
+    /// Label of this client's events (see `with_account_label`).
+    pub fn account_label(&self) -> &str {
+        &self.sinks.account
+    }
+
+    /// Route another API key pair through this client as account `name`,
+    /// e.g. a separate spot or leverage account, or per-strategy keys. It
+    /// gets its own REST rate limiter (see `GmocoinRestClient::for_account`),
+    /// Private WS, order and position caches, with this client's settings;
+    /// its events reach this client's callback and feed with `name` as
+    /// `"account"`. It connects and disconnects with this client.
+    pub fn add_account(&self, name: &str, rest_client: GmocoinRestClient) -> Result<(), GmocoinError> {
+        let mut accounts = self.accounts.lock().unwrap();
+        if name.is_empty() || name == self.account_label() || accounts.contains_key(name) {
+            return Err(GmocoinError::InvalidInput(format!("Account name '{}' is empty or already in use", name)));
+        }
+        let mut account = Self::from_rest_client(rest_client)
+            .with_reconnect(self.reconnect)
+            .with_stale_timeout(self.stale_timeout)
+            .with_rollover(self.rollover.clone())
+            .with_dead_man(self.dead_man_config)
+            .with_requote_reserve(self.requotes.reserve())
+            .with_position_refresh(self.position_refresh)
+            .with_polling_fallback(self.fallback)
+            .with_latency_alarms(self.latency);
+        // `/v1/status` is public: the account waits out maintenance like this client
+        account.maintenance = self.maintenance.clone();
+        account.market_data = self.market_data.clone();
+        // One id space, so `cancel_algo` / `algo_progress` find every account's
+        account.algos = self.algos.clone();
+        account.next_algo_id = self.next_algo_id.clone();
+        account.sinks = OrderSinks { account: name.into(), ..self.sinks.clone() };
+        if self.ws_task.lock().unwrap().is_some() {
+            account.connect();
+        }
+        info!("GMO: Account '{}' added", name);
+        accounts.insert(name.to_string(), account);
+        Ok(())
+    }
+
+    /// The client of account `name` (see `add_account`), or this one for
+    /// `None` or its own `account_label`, so the `"account"` of an event
+    /// selects the client that sent it.
+    pub fn account(&self, name: Option<&str>) -> Result<Self, GmocoinError> {
+        match name {
+            None => Ok(self.clone()),
+            Some(name) if name == self.account_label() => Ok(self.clone()),
+            Some(name) => self.accounts.lock().unwrap().get(name).cloned().ok_or_else(|| {
+                GmocoinError::InvalidInput(format!("Unknown account '{}'", name))
+            }),
+        }
+    }
+
+    /// Names of the accounts added with `add_account`, sorted.
+    pub fn account_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.accounts.lock().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn account_clients(&self) -> Vec<Self> {
+        self.accounts.lock().unwrap().values().cloned().collect()
+    }
+
+    /// The latest summary of the position in `symbol` on `side` (or on the
+    /// one side held), from `positionSummaryEvents` and the periodic
+    /// refresh; no REST call is made.
+    pub fn position(&self, symbol: &str, side: Option<&str>) -> Result<Option<PositionSnapshot>, GmocoinError> {
+        self.positions.get(symbol, side)
+    }
+
+    /// Close the given positions (`(position_id, size)`) with one order,
+    /// tracked like a submitted one.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        execution_type: &str,
+        settle_position: &[(u64, &str)],
+        price: Option<&str>,
+        time_in_force: Option<&str>,
+        client_order_id: Option<&str>,
+    ) -> Result<CloseOrderAck, GmocoinError> {
+        let mut sizes = Vec::with_capacity(settle_position.len());
+        for (position_id, size) in settle_position {
+            sizes.push((*position_id, self.rest_client.format_size(symbol, size).await?));
+        }
+        let settle_position: Vec<(u64, &str)> = sizes.iter().map(|(position_id, size)| (*position_id, size.as_str())).collect();
+        let size = sizes.iter().try_fold(Decimal::ZERO, |total, (_, size)| Ok::<_, GmocoinError>(total + decimal("size", size)?))?;
+        self.check_closable(symbol, side, size)?;
+        let sent = std::time::Instant::now();
+        let ack = self.rest_client.close_order(symbol, side, execution_type, &settle_position, price, time_in_force).await?;
+        self.track_close(&ack, symbol, side, execution_type, size, price, time_in_force, client_order_id, sent).await;
+        Ok(ack)
+    }
+
+    /// Close `size` of the positions on the opposite side of `side` with one
+    /// order, tracked like a submitted one.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_bulk_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        execution_type: &str,
+        size: &str,
+        price: Option<&str>,
+        time_in_force: Option<&str>,
+        client_order_id: Option<&str>,
+    ) -> Result<CloseOrderAck, GmocoinError> {
+        let size = self.rest_client.format_size(symbol, size).await?;
+        let total = decimal("size", &size)?;
+        self.check_closable(symbol, side, total)?;
+        let sent = std::time::Instant::now();
+        let ack = self.rest_client.close_bulk_order(symbol, side, execution_type, &size, price, time_in_force).await?;
+        self.track_close(&ack, symbol, side, execution_type, total, price, time_in_force, client_order_id, sent).await;
+        Ok(ack)
+    }
+
+    /// An error when closing `size` with a `side` order is more than the
+    /// cached opposite position has left beyond the closing orders already
+    /// working against it. Skipped until the position cache has been
+    /// loaded from REST.
+    fn check_closable(&self, symbol: &str, side: &str, size: Decimal) -> Result<(), GmocoinError> {
+        if self.positions.refreshed_at().is_none() {
+            return Ok(());
+        }
+        let held_side = if side.eq_ignore_ascii_case("BUY") { "SELL" } else { "BUY" };
+        let available = match self.positions.get(symbol, Some(held_side))? {
+            Some(position) => {
+                let working = position.order_size.as_deref().map(|s| decimal("sumOrderQuantity", s)).transpose()?;
+                decimal("sumPositionQuantity", &position.size)? - working.unwrap_or_default()
+            }
+            None => Decimal::ZERO,
+        };
+        if size > available {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Close size {} of {} exceeds the {} open on the {} side",
+                size, symbol, available.normalize(), held_side
+            )));
+        }
+        Ok(())
+    }
+
+    /// Cache a closing order until its `orderEvents` arrive and feed it to
+    /// the execution quality report.
+    #[allow(clippy::too_many_arguments)]
+    async fn track_close(
+        &self,
+        ack: &CloseOrderAck,
+        symbol: &str,
+        side: &str,
+        execution_type: &str,
+        size: Decimal,
+        price: Option<&str>,
+        time_in_force: Option<&str>,
+        client_order_id: Option<&str>,
+        sent: std::time::Instant,
+    ) {
+        let order = Order {
+            order_id: ack.order_id,
+            root_order_id: Some(ack.order_id),
+            client_order_id: client_order_id.map(str::to_string),
+            symbol: symbol.to_string(),
+            side: side.to_string(),
+            execution_type: execution_type.to_string(),
+            settle_type: Some("CLOSE".to_string()),
+            size: size.normalize().to_string(),
+            executed_size: "0".to_string(),
+            price: price.map(str::to_string),
+            losscut_price: None,
+            status: if execution_type == "STOP" { "WAITING" } else { "ORDERED" }.to_string(),
+            time_in_force: time_in_force.map(str::to_string),
+            timestamp: Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            msg_type: None,
+            cancel_type: None,
+        };
+        // An orderEvents message that arrived first is kept
+        self.orders.write().unwrap().entry(ack.order_id).or_insert(order);
+        self.quality.record_submit(ack.order_id, symbol, side, size, self.arrival(symbol), sent);
+        if let Some(alarm) = LatencyAlarm::order_ack(self.latency.order_ack, sent.elapsed(), ack.order_id, symbol) {
+            self.sinks.latency_alarm(alarm);
+        }
+    }
+
+    /// Change the losscut price of a leverage position, rounded to the tick
+    /// of its `symbol` when given; `"LosscutUpdated"` is emitted once
+    /// `positionEvents` shows the new price.
+    pub async fn change_losscut_price(&self, position_id: u64, symbol: Option<&str>, losscut_price: &str) -> Result<LosscutPriceAck, GmocoinError> {
+        let ack = self.rest_client.change_losscut_price(position_id, symbol, losscut_price).await?;
+        self.losscuts.request(&ack);
+        Ok(ack)
+    }
+
+    /// The position summary cache.
+    pub fn positions(&self) -> &PositionCache {
+        &self.positions
+    }
+
+    /// Take the arrival state of submitted orders from the books of
+    /// `data_client`, which must be subscribed to their `orderbooks`.
+    pub fn with_market_data(self, data_client: GmocoinDataClient) -> Self {
+        *self.market_data.lock().unwrap() = Some(data_client);
+        self
+    }
+
+    /// Arrival, fills and latencies of the orders submitted through
+    /// `submit_order`.
+    pub fn execution_quality(&self) -> &ExecutionQuality {
+        &self.quality
+    }
+
+    /// Best bid / ask of the attached data client's book for `symbol`.
+    pub fn arrival(&self, symbol: &str) -> Option<Arrival> {
+        let book = self.market_data.lock().unwrap().as_ref()?.book(symbol)?;
+        Arrival::from_book(&book)
+    }
+
+    /// Start slicing a parent order per `config`; child orders go through
+    /// the REST client's rate limiter and their fills are read from the
+    /// Private WS, so the client should be connected. Progress is emitted as
+    /// `"AlgoProgress"` events. Returns the algorithm id.
+    pub fn start_twap(&self, config: TwapConfig) -> Result<u64, GmocoinError> {
+        config.validate()?;
+        let market = match config.participation_cap {
+            Some(_) => {
+                let guard = self.market_data.lock().unwrap();
+                let data_client = guard.as_ref().ok_or_else(|| {
+                    GmocoinError::InvalidInput("A participation cap needs an attached data client".to_string())
+                })?;
+                data_client.ensure_subscribed("trades", &config.symbol);
+                Some(data_client.market_events())
+            }
+            None => None,
+        };
+        let algo_id = self.next_algo_id.fetch_add(1, Ordering::Relaxed);
+        let handle = AlgoHandle {
+            stop: Arc::new(tokio::sync::Notify::new()),
+            progress: Arc::new(std::sync::Mutex::new(AlgoProgress::new(algo_id, &config))),
+        };
+        self.algos.lock().unwrap().insert(algo_id, handle.clone());
+        let sinks = self.sinks.clone();
+        runtime::spawn(algo::run_twap(
+            self.rest_client.clone(),
+            config,
+            self.order_events(),
+            market,
+            handle,
+            move |progress| sinks.emit("AlgoProgress", serde_json::to_value(progress).expect("progress serializes")),
+        ));
+        Ok(algo_id)
+    }
+
+    /// Stop an algorithm (of any account), cancelling its working child
+    /// order. Returns whether it was running.
+    pub fn cancel_algo(&self, algo_id: u64) -> bool {
+        let algos = self.algos.lock().unwrap();
+        let Some(handle) = algos.get(&algo_id) else { return false };
+        let running = handle.progress.lock().unwrap().state == algo::AlgoState::Running;
+        if running {
+            handle.stop.notify_one();
+        }
+        running
+    }
+
+    /// Latest progress of an algorithm started by this client or one of its
+    /// accounts.
+    pub fn algo_progress(&self, algo_id: u64) -> Option<AlgoProgress> {
+        self.algos.lock().unwrap().get(&algo_id).map(|h| h.progress.lock().unwrap().clone())
+    }
+
+    /// Change the price and/or total size of an order. GMO's `changeOrder`
+    /// only changes the price, so a size change cancels the order and
+    /// submits the rest (`size` less what executed before the cancel) with
+    /// the same parameters. Nothing is resubmitted when the cancel fails; if
+    /// the resubmit fails, the original order stays cancelled. A resubmit
+    /// answered without an order id is `MissingOrderId`: the replacement
+    /// may be live.
+    pub async fn amend_order(&self, order_id: u64, price: Option<&str>, size: Option<&str>) -> Result<Amendment, GmocoinError> {
+        let order = self.fetch_order(&self.rest_client, order_id).await?;
+        let Some(size) = size else {
+            let price = price.ok_or_else(|| GmocoinError::InvalidInput("Amend needs a price or a size".to_string()))?;
+            return self.change_price(&order, price).await;
+        };
+        let size = decimal("size", &self.rest_client.format_size(&order.symbol, size).await?)?;
+        if size == decimal("size", &order.size)? {
+            return match price {
+                Some(price) => self.change_price(&order, price).await,
+                None => Err(GmocoinError::InvalidInput(format!("Order {} already has size {}", order_id, size))),
+            };
+        }
+        let executed = decimal("executedSize", &order.executed_size)?;
+        if size <= executed {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Size {} is not above the {} order {} already executed",
+                size, executed, order_id
+            )));
+        }
+
+        self.rest_client.cancel_order(order_id).await?;
+        // Fills may have landed before the cancel took effect
+        let executed = match self.rest_client.get_order(order_id).await {
+            Ok(list) => list.list.first().map_or(Ok(executed), |o| decimal("executedSize", &o.executed_size))?,
+            Err(e) => {
+                warn!("GMO: Order {} re-query after cancel failed, using cached executed size: {}", order_id, e);
+                executed
+            }
+        };
+        let remaining = size - executed;
+        if remaining <= Decimal::ZERO {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Order {} executed {} before it was cancelled; nothing left to resubmit",
+                order_id, executed
+            )));
+        }
+        let price = price.or(order.price.as_deref());
+        let parsed_price = price.map(|p| decimal("price", p)).transpose()?;
+        let response = self
+            .rest_client
+            .submit_order(
+                &order.symbol,
+                &order.side,
+                &order.execution_type,
+                &remaining.normalize().to_string(),
+                price,
+                order.time_in_force.as_deref(),
+                None,
+                order.losscut_price.as_deref(),
+                order.settle_type.as_deref(),
+            )
+            .await
+            .inspect_err(|e| error!("GMO: Order {} was cancelled but its replacement failed: {}", order_id, e))?;
+        let Some(new_id) = response.as_str().and_then(|id| id.parse::<u64>().ok()) else {
+            error!("GMO: Order {} was cancelled but its replacement returned no order id: {}", order_id, response);
+            return Err(GmocoinError::MissingOrderId(response));
+        };
+        info!("GMO: Order {} replaced by {} (size {})", order_id, new_id, remaining);
+        Ok(Amendment {
+            order_id: new_id,
+            previous_order_id: order_id,
+            replaced: true,
+            price: parsed_price,
+            size: Some(remaining.normalize()),
+        })
+    }
+
+    async fn change_price(&self, order: &Order, price: &str) -> Result<Amendment, GmocoinError> {
+        let (_, price) = self.send_change(&self.rest_client, order, price, None).await?;
+        let order_id = order.order_id;
+        Ok(Amendment { order_id, previous_order_id: order_id, replaced: false, price: Some(price), size: None })
+    }
+
+    /// `changeOrder` through `rest`, emitting `"OrderAmendPending"` once
+    /// GMO accepts it; `"OrderAmended"` or `"OrderAmendRejected"` follows
+    /// from the order's next `orderEvents` message. Returns the response
+    /// and the price as sent.
+    async fn send_change(&self, rest: &GmocoinRestClient, order: &Order, price: &str, losscut_price: Option<&str>) -> Result<(serde_json::Value, Decimal), GmocoinError> {
+        let order_id = order.order_id;
+        let price = rest.format_price(&order.symbol, Some(&order.side), price).await?;
+        let losscut_price = match losscut_price {
+            Some(lp) => Some(rest.format_price(&order.symbol, None, lp).await?),
+            None => None,
+        };
+        let parsed = decimal("price", &price)?;
+        let losscut = losscut_price.as_deref().map(|p| decimal("losscut_price", p)).transpose()?;
+        // Tracked before sending: orderEvents may beat the response
+        self.amends.begin(order_id, parsed, losscut);
+        let res = rest
+            .change_order(order_id, Some(&order.symbol), Some(&order.side), &price, losscut_price.as_deref())
+            .await
+            .inspect_err(|_| self.amends.cancel(order_id))?;
+        for (event_type, event) in self.amends.ack(order_id) {
+            self.sinks.emit(event_type, serde_json::to_value(&event).expect("event serializes"));
+        }
+        Ok((res, parsed))
+    }
+
+    /// Change an order's price to `price` moved onto its symbol's tick grid
+    /// (down for buys, up for sells, so the order never becomes more
+    /// aggressive than asked). An amend that would leave the price unchanged
+    /// is rejected without calling GMO.
+    pub async fn amend_price(&self, order_id: u64, price: &str) -> Result<Amendment, GmocoinError> {
+        let order = self.fetch_order(&self.rest_client, order_id).await?;
+        let price = self.rest_client.format_price(&order.symbol, Some(&order.side), price).await?;
+        let price = decimal("price", &price)?;
+        if price <= Decimal::ZERO {
+            return Err(GmocoinError::InvalidInput(format!("Price {} is below one tick", price)));
+        }
+        let current = order.price.as_deref().map(|p| decimal("price", p)).transpose()?;
+        if current == Some(price) {
+            return Err(GmocoinError::InvalidInput(format!("Order {} is already at price {}", order_id, price)));
+        }
+        self.change_price(&order, &price.to_string()).await
+    }
+
+    /// A low-priority `amend_price`: sent now while the order bucket has
+    /// headroom, else queued until it does. A queued re-quote resolves as
+    /// `Superseded` when a newer one for the same order replaces it, so
+    /// only the latest price is sent.
+    pub async fn requote(&self, order_id: u64, price: &str) -> Result<RequoteOutcome<Amendment>, GmocoinError> {
+        if self.requotes.can_send(order_id, self.rest_client.rate_bucket(EndpointGroup::Order)) {
+            return Ok(RequoteOutcome::Sent { amendment: self.amend_price(order_id, price).await? });
+        }
+        let (outcome, start) = self.requotes.enqueue(order_id, price.to_string());
+        if start {
+            let client = self.clone();
+            runtime::spawn(async move { client.drain_requotes().await });
+        }
+        outcome
+            .await
+            .unwrap_or_else(|_| Err(GmocoinError::Unknown("Re-quote dropped before it was sent".to_string())))
+    }
+
+    async fn drain_requotes(&self) {
+        let bucket = self.rest_client.rate_bucket(EndpointGroup::Order);
+        while let Some((order_id, price, reply)) = self.requotes.next(bucket).await {
+            let outcome = self.amend_price(order_id, &price).await.map(|amendment| RequoteOutcome::Sent { amendment });
+            let _ = reply.send(outcome);
+        }
+    }
+
+    /// The cached order, else `/v1/orders` through `rest`.
+    async fn fetch_order(&self, rest: &GmocoinRestClient, order_id: u64) -> Result<Order, GmocoinError> {
+        if let Some(order) = self.cached_order(order_id).await {
+            return Ok(order);
+        }
+        rest.get_order(order_id)
+            .await?
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| GmocoinError::InvalidInput(format!("Order {} not found", order_id)))
+    }
+
+    /// Closed orders of `symbol` with fills in `[start, end)`, with filled
+    /// size, average price and fees (see `history::order_history`).
+    pub async fn order_history(
+        &self,
+        symbol: &str,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<ClosedOrder>, GmocoinError> {
+        history::order_history(&self.rest_client, symbol, start, end).await
+    }
+
+    /// Executions of `symbols` in `[start, end)`, oldest first (see
+    /// `history::execution_history`).
+    pub async fn execution_history(
+        &self,
+        symbols: &[String],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Execution>, GmocoinError> {
+        history::execution_history(&self.rest_client, symbols, start, end).await
+    }
+
+    /// REST client used for order operations and queries.
+    pub fn rest(&self) -> &GmocoinRestClient {
+        &self.rest_client
+    }
+
+    /// Receive every Private WS event the client dispatches.
+    pub fn order_events(&self) -> broadcast::Receiver<OrderEvent> {
+        self.sinks.feed.subscribe()
+    }
+
+    /// Latest state of an order seen on `orderEvents`.
+    pub async fn cached_order(&self, order_id: u64) -> Option<Order> {
+        self.orders.read().unwrap().get(&order_id).cloned()
+    }
+
+    /// Working orders per symbol (count, unexecuted buy / sell size, best
+    /// LIMIT prices), from the orders seen on `orderEvents`; no REST call
+    /// is made. `symbol` narrows it to one symbol.
+    pub async fn open_order_exposure(&self, symbol: Option<&str>) -> Vec<OrderExposure> {
+        let orders = self.orders.read().unwrap();
+        exposure::summarize(orders.values().filter(|o| symbol.is_none_or(|s| o.symbol == s)))
+    }
+
+    /// Record the Private WS session (auth, subscribes, received frames) to a
+    /// JSONL fixture at `path`, or stop recording with `None`. The access
+    /// token is never written.
+    pub fn record_session(&self, path: Option<&str>) -> Result<(), GmocoinError> {
+        self.recorder.set_path(path)
+    }
+
+    /// Feed the received frames of a recorded session through the same
+    /// handling as live Private WS messages (order cache, stats, events).
+    /// `speed` paces them by their recorded times (2.0: twice as fast);
+    /// `None` replays as fast as possible. The replay can be paused, sped
+    /// up and seeked while it runs (see `ReplayControl`). Returns the number
+    /// of frames delivered, counting those a backward seek repeats.
+    pub async fn replay_session(&self, path: &str, speed: Option<f64>) -> Result<usize, GmocoinError> {
+        let entries = session::read_received_entries(path)?;
+        self.replay_entries(entries, speed).await
+    }
+
+    async fn replay_entries(&self, entries: Vec<(i64, String)>, speed: Option<f64>) -> Result<usize, GmocoinError> {
+        let generation = self.replay.start(entries.iter().map(|(ts, _)| *ts).collect(), speed)?;
+        let ws_sub_limiter = crate::rate_limit::TokenBucket::new(1.0, 0.5);
+        let mut delivered = 0;
+        while let Some(index) = self.replay.next(generation).await {
+            Self::process_ws_message(&entries[index].1, &self.sinks, &self.orders, &self.quality, &self.positions, &self.losscuts, &self.amends, &self.stream, &ws_sub_limiter, self.rest_client.stats()).await;
+            delivered += 1;
+        }
+        Ok(delivered)
+    }
+
+    /// Hold the running replay before its next frame.
+    pub fn pause_replay(&self) {
+        self.replay.pause();
+    }
+
+    /// Continue a paused replay, pacing from the current frame.
+    pub fn resume_replay(&self) {
+        self.replay.resume();
+    }
+
+    /// Change the replay speed multiplier; `None`: as fast as possible.
+    pub fn set_replay_speed(&self, speed: Option<f64>) -> Result<(), GmocoinError> {
+        self.replay.set_speed(speed)
+    }
+
+    /// Continue the running replay from the first frame recorded at or after
+    /// `ts_ms` (epoch ms). Forward, the skipped frames are delivered at once
+    /// (even while paused) so the order cache stays consistent; backward,
+    /// frames are delivered again from there without unwinding state.
+    pub fn seek_replay(&self, ts_ms: i64) {
+        self.replay.seek(ts_ms);
+    }
+
+    /// Where the replay stands.
+    pub fn replay_position(&self) -> ReplayPosition {
+        self.replay.position()
+    }
+
+    /// Run frames that did not come off the live stream (replayed or
+    /// synthesized) through the Private WS message handling.
+    async fn handle_frames(&self, frames: &[String]) {
+        let ws_sub_limiter = crate::rate_limit::TokenBucket::new(1.0, 0.5);
+        for frame in frames {
+            Self::process_ws_message(frame, &self.sinks, &self.orders, &self.quality, &self.positions, &self.losscuts, &self.amends, &self.stream, &ws_sub_limiter, self.rest_client.stats()).await;
+        }
+    }
+
+    /// Start the Private WS loop (with token refresh), replacing a running
+    /// one, and the rollover scheduler, dead-man's switch, REST polling
+    /// fallback and event gap alarm when configured.
+    pub fn connect(&self) {
+        self.shutdown.store(false, Ordering::SeqCst);
+        self.stream.reset();
+        let handle = runtime::spawn(Self::ws_loop(
+            self.rest_client.clone(), self.sinks.clone(), self.orders.clone(), self.shutdown.clone(), self.recorder.clone(),
+            self.quality.clone(),
+            self.positions.clone(),
+            self.losscuts.clone(),
+            self.amends.clone(),
+            self.stream.clone(),
+            self.maintenance.clone(),
+            self.reconnect,
+            self.stale_timeout,
+        ));
+        if let Some(previous) = self.ws_task.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+
+        if let Some(config) = self.rollover.clone() {
+            let sinks = self.sinks.clone();
+            let handle = runtime::spawn(rollover::scheduler(
+                self.rest_client.clone(),
+                self.maintenance.calendar().clone(),
+                config,
+                move |event_type, data| sinks.emit(event_type, data),
+            ));
+            if let Some(previous) = self.rollover_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        if let Some(interval) = self.position_refresh {
+            let handle = runtime::spawn(positions::refresher(self.rest_client.clone(), self.positions.clone(), interval));
+            if let Some(previous) = self.position_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        if let Some(config) = self.dead_man_config {
+            let sinks = self.sinks.clone();
+            let handle = runtime::spawn(deadman::watchdog(
+                self.rest_client.clone(),
+                self.dead_man.clone(),
+                config,
+                move |event| sinks.emit("DeadManTriggered", serde_json::to_value(&event).expect("event serializes")),
+            ));
+            if let Some(previous) = self.dead_man_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        if let Some(config) = self.fallback {
+            let sinks = self.sinks.clone();
+            let client = self.clone();
+            let handle = runtime::spawn(fallback::poller(
+                self.rest_client.clone(),
+                self.stream.clone(),
+                self.orders.clone(),
+                self.quality.clone(),
+                config,
+                move |event_type, data| sinks.emit(event_type, data),
+                move |frames| {
+                    let client = client.clone();
+                    async move { client.handle_frames(&frames).await }
+                },
+            ));
+            if let Some(previous) = self.fallback_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        if let Some(budget) = self.latency.event_gap {
+            let sinks = self.sinks.clone();
+            let handle = runtime::spawn(latency::gap_watchdog(self.rest_client.stats().clone(), budget, move |alarm| sinks.latency_alarm(alarm)));
+            if let Some(previous) = self.gap_task.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+        }
+
+        for account in self.account_clients() {
+            account.connect();
+        }
+    }
 
-        shutdown.store(false, Ordering::SeqCst);
+    /// Close the REST clients (failing their pending requests) and stop the
+    /// Private WS loops, of the added accounts too, giving up after
+    /// `timeout`. Returns whether the WS tasks stopped in time.
+    pub async fn close(&self, timeout: Duration) -> bool {
+        self.rest_client.close();
+        for account in self.account_clients() {
+            account.rest_client.close();
+        }
+        tokio::time::timeout(timeout, self.disconnect()).await.is_ok()
+    }
 
+    /// Stop the Private WS loop, and those of the added accounts; resolves
+    /// once the tasks have exited.
+    pub async fn disconnect(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        for handle in self.algos.lock().unwrap().values() {
+            handle.stop.notify_one();
+        }
+        if let Some(rollover) = self.rollover_task.lock().unwrap().take() {
+            rollover.abort();
+        }
+        if let Some(watchdog) = self.dead_man_task.lock().unwrap().take() {
+            watchdog.abort();
+        }
+        if let Some(refresher) = self.position_task.lock().unwrap().take() {
+            refresher.abort();
+        }
+        if let Some(poller) = self.fallback_task.lock().unwrap().take() {
+            poller.abort();
+        }
+        if let Some(watchdog) = self.gap_task.lock().unwrap().take() {
+            watchdog.abort();
+        }
+        self.dead_man.disarm();
+        let handle = self.ws_task.lock().unwrap().take();
+        if let Some(handle) = handle {
+            handle.abort();
+            let _ = handle.await;
+        }
+        self.rest_client.stats().ws_connected(false);
+        for account in self.account_clients() {
+            Box::pin(account.disconnect()).await;
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+impl GmocoinExecutionClient {
+    /// Run a close order request for Python, deduplicating
+    /// `client_order_id` like `submit_order`.
+    fn close_py<'py, F, Fut>(&self, py: Python<'py>, client_order_id: Option<String>, close: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: FnOnce(Option<String>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<CloseOrderAck, GmocoinError>> + Send + 'static,
+    {
+        if let Some(client_order_id) = &client_order_id {
+            if let Some(order_id) = self.submissions.begin(client_order_id)? {
+                warn!("GMO: Duplicate close order {} ignored (order {})", client_order_id, order_id);
+                let future = async move { Ok(PyJson(serde_json::json!({"order_id": order_id, "duplicate": true}))) };
+                return pyo3_async_runtimes::tokio::future_into_py(py, future);
+            }
+        }
+        let submissions = self.submissions.clone();
         let future = async move {
-            std::thread::Builder::new()
-                .name("gmocoin-ws-private".to_string())
-                .spawn(move || {
-                    let rt = tokio::runtime::Builder::new_current_thread()
-                        .enable_all()
-                        .build()
-                        .expect("Failed to build tokio runtime for Private WS");
-
-                    rt.block_on(Self::ws_loop(
-                        rest_client, order_cb_arc, orders_arc, shutdown,
-                    ));
-                })
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to spawn Private WS thread: {}", e)
-                ))?;
+            let result = close(client_order_id.clone()).await;
+            if let Some(client_order_id) = &client_order_id {
+                match &result {
+                    Ok(ack) => submissions.accept(client_order_id, ack.order_id),
+                    Err(e) => submissions.fail(client_order_id, e),
+                }
+            }
+            let ack = result?;
+            Ok(PyJson(serde_json::json!({"order_id": ack.order_id, "duplicate": false})))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+}
 
+#[cfg(feature = "python")]
+#[pymethods]
+impl GmocoinExecutionClient {
+    /// Arguments as for `GmocoinRestClient`, plus `callback_payload`:
+    /// `"object"` (default) passes dicts to the order callback, `"msgpack"`
+    /// the same data as MessagePack bytes, and `maintenance_windows` /
+    /// `maintenance_status_check` / `reconnect_*` / `stale_timeout_ms` as for
+    /// `GmocoinDataClient` (backoff default 5000 / 60000 / 2.0 / 0.0).
+    /// `rollover_lead_ms`: emit `"RolloverProjection"` this long before each
+    /// 06:00 JST rollover and `"RolloverReconciliation"` a minute after it
+    /// (default or 0: off); `leverage_fee_rate` defaults to 0.0004.
+    /// `requote_reserve`: order rate limit tokens kept free before
+    /// `requote` calls are queued (default 2). `deadman_timeout_ms`: once
+    /// `heartbeat()` has been called, cancel all open orders (and close all
+    /// positions with `deadman_flatten`) if it is not called again within
+    /// this long (default or 0: off). `position_refresh_ms`: reload the
+    /// position cache from REST this often while connected (default 60000,
+    /// 0: `positionSummaryEvents` only). `fallback_after_ms`: once the
+    /// Private WS has been down this long, poll REST every
+    /// `fallback_interval_ms` (default 5000) for the order and execution
+    /// events it missed (default or 0: off). `order_ack_alarm_ms` /
+    /// `event_gap_alarm_ms`: emit `"LatencyAlarm"` when an order is answered
+    /// later than this, or the connected Private WS receives nothing for
+    /// this long (default or 0: off). `account_label`: the `"account"` of
+    /// every order callback event (default `"main"`). `credentials` /
+    /// `passphrase`: see `GmocoinRestClient`.
+    #[new]
+    #[pyo3(signature = (api_key, api_secret, timeout_ms, proxy_url, rate_limit, circuit_breaker_threshold=None, circuit_breaker_cooldown_ms=None, group_rate_limits=None, share_rate_limiter=None, tls_ca_file=None, tls_native_roots=None, tls_min_version=None, pool_max_idle_per_host=None, pool_idle_timeout_ms=None, prefer_http2=None, tcp_keepalive_ms=None, venue_profile=None, user_agent=None, extra_headers=None, callback_payload=None, maintenance_windows=None, maintenance_status_check=None, reconnect_initial_ms=None, reconnect_max_ms=None, reconnect_multiplier=None, reconnect_jitter=None, stale_timeout_ms=None, rollover_lead_ms=None, leverage_fee_rate=None, requote_reserve=None, deadman_timeout_ms=None, deadman_flatten=None, position_refresh_ms=None, fallback_after_ms=None, fallback_interval_ms=None, order_ack_alarm_ms=None, event_gap_alarm_ms=None, max_in_flight=None, symbols_ttl_ms=None, strict_sizes=None, account_label=None, credentials=None, passphrase=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(
+        api_key: String,
+        api_secret: String,
+        timeout_ms: u64,
+        proxy_url: Option<String>,
+        rate_limit: Option<RateLimitTier>,
+        circuit_breaker_threshold: Option<u32>,
+        circuit_breaker_cooldown_ms: Option<u64>,
+        group_rate_limits: Option<HashMap<String, f64>>,
+        share_rate_limiter: Option<bool>,
+        tls_ca_file: Option<String>,
+        tls_native_roots: Option<bool>,
+        tls_min_version: Option<String>,
+        pool_max_idle_per_host: Option<usize>,
+        pool_idle_timeout_ms: Option<u64>,
+        prefer_http2: Option<bool>,
+        tcp_keepalive_ms: Option<u64>,
+        venue_profile: Option<VenueProfile>,
+        user_agent: Option<String>,
+        extra_headers: Option<HashMap<String, String>>,
+        callback_payload: Option<CallbackPayload>,
+        maintenance_windows: Option<Vec<String>>,
+        maintenance_status_check: Option<bool>,
+        reconnect_initial_ms: Option<u64>,
+        reconnect_max_ms: Option<u64>,
+        reconnect_multiplier: Option<f64>,
+        reconnect_jitter: Option<f64>,
+        stale_timeout_ms: Option<u64>,
+        rollover_lead_ms: Option<u64>,
+        leverage_fee_rate: Option<f64>,
+        requote_reserve: Option<f64>,
+        deadman_timeout_ms: Option<u64>,
+        deadman_flatten: Option<bool>,
+        position_refresh_ms: Option<u64>,
+        fallback_after_ms: Option<u64>,
+        fallback_interval_ms: Option<u64>,
+        order_ack_alarm_ms: Option<u64>,
+        event_gap_alarm_ms: Option<u64>,
+        max_in_flight: Option<usize>,
+        symbols_ttl_ms: Option<u64>,
+        strict_sizes: Option<bool>,
+        account_label: Option<String>,
+        credentials: Option<String>,
+        passphrase: Option<String>,
+    ) -> PyResult<Self> {
+        let rollover = match rollover_lead_ms.filter(|ms| *ms > 0) {
+            Some(lead_ms) => Some(RolloverConfig::new(Some(lead_ms), leverage_fee_rate)?),
+            None => None,
+        };
+        let mut client = Self::from_rest_client(GmocoinRestClient::py_new(
+            api_key, api_secret, timeout_ms, proxy_url, rate_limit,
+            circuit_breaker_threshold, circuit_breaker_cooldown_ms, group_rate_limits,
+            share_rate_limiter, tls_ca_file, tls_native_roots, tls_min_version,
+            pool_max_idle_per_host, pool_idle_timeout_ms, prefer_http2, tcp_keepalive_ms,
+            venue_profile, user_agent, extra_headers, max_in_flight, symbols_ttl_ms,
+            strict_sizes, credentials, passphrase,
+        )?)
+        .with_maintenance(MaintenanceConfig::new(maintenance_windows, maintenance_status_check)?)
+        .with_reconnect(BackoffPolicy::new(
+            BackoffPolicy::PRIVATE, reconnect_initial_ms, reconnect_max_ms, reconnect_multiplier, reconnect_jitter,
+        )?)
+        .with_stale_timeout(stale_timeout_ms.filter(|ms| *ms > 0).map(Duration::from_millis))
+        .with_rollover(rollover)
+        .with_requote_reserve(requote_reserve.unwrap_or(DEFAULT_REQUOTE_RESERVE).max(0.0))
+        .with_dead_man(deadman_timeout_ms.filter(|ms| *ms > 0).map(|ms| DeadManConfig {
+            timeout: Duration::from_millis(ms),
+            flatten: deadman_flatten.unwrap_or(false),
+        }))
+        .with_position_refresh(match position_refresh_ms {
+            Some(0) => None,
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None => Some(DEFAULT_POSITION_REFRESH),
+        })
+        .with_polling_fallback(fallback_after_ms.filter(|ms| *ms > 0).map(|ms| FallbackConfig {
+            after: Duration::from_millis(ms),
+            interval: fallback_interval_ms
+                .filter(|ms| *ms > 0)
+                .map_or(DEFAULT_FALLBACK_INTERVAL, Duration::from_millis),
+        }))
+        .with_latency_alarms(LatencyBudgets {
+            order_ack: order_ack_alarm_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+            event_gap: event_gap_alarm_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+        })
+        .with_account_label(account_label.as_deref().unwrap_or(DEFAULT_ACCOUNT_LABEL))?;
+        client.sinks.callback = CallbackSlot::new(callback_payload.unwrap_or_default(), client.rest_client.stats().clone());
+        Ok(client)
+    }
+
+    /// Reset the dead-man's switch timer (arming it on the first call), of
+    /// the added accounts too. Cheap and thread-safe; call it from the
+    /// strategy's own loop.
+    #[pyo3(name = "heartbeat")]
+    fn py_heartbeat(&self) {
+        self.heartbeat();
+    }
+
+    /// Change the REST rate limit of an endpoint group on the fly.
+    #[pyo3(signature = (group, per_sec, account=None))]
+    pub fn set_rate_limit(&self, group: &str, per_sec: f64, account: Option<String>) -> PyResult<()> {
+        self.account(account.as_deref())?.rest_client.py_set_rate_limit(group, per_sec)
+    }
+
+    /// Snapshot of the REST and Private WS counters (see
+    /// `GmocoinRestClient.get_stats`) plus the number of cached orders;
+    /// with accounts added, theirs under `"accounts"` by name.
+    pub fn get_stats(&self) -> PyResult<PyJson> {
+        let snapshot = |client: &Self| {
+            let mut snapshot = client.rest_client.stats().snapshot();
+            snapshot.orders_cached = Some(client.orders.read().unwrap().len());
+            serde_json::to_value(snapshot).map_err(GmocoinError::from)
+        };
+        let mut stats = snapshot(self)?;
+        let accounts = self.accounts.lock().unwrap().clone();
+        if !accounts.is_empty() {
+            let accounts = accounts
+                .iter()
+                .map(|(name, account)| Ok((name.clone(), snapshot(account)?)))
+                .collect::<Result<serde_json::Map<_, _>, GmocoinError>>()?;
+            stats["accounts"] = accounts.into();
+        }
+        Ok(PyJson(stats))
+    }
+
+    /// Route orders for another API key pair through this client as
+    /// account `name` (see `GmocoinExecutionClient::add_account`), with its
+    /// own rate limiter at `rate_limit` / `group_rate_limits` (default
+    /// tier1) and Private WS. Order methods select it with `account=name`;
+    /// its order callback events carry `"account": name`. `credentials` /
+    /// `passphrase` stand in for the key pair as in the constructor.
+    #[pyo3(name = "add_account", signature = (name, api_key, api_secret, rate_limit=None, group_rate_limits=None, credentials=None, passphrase=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_add_account(
+        &self,
+        name: &str,
+        api_key: String,
+        api_secret: String,
+        rate_limit: Option<RateLimitTier>,
+        group_rate_limits: Option<HashMap<String, f64>>,
+        credentials: Option<String>,
+        passphrase: Option<String>,
+    ) -> PyResult<()> {
+        let mut overrides = HashMap::new();
+        for (group, rate) in group_rate_limits.unwrap_or_default() {
+            overrides.insert(parse_group(&group)?, rate);
+        }
+        let Credentials { api_key, api_secret } =
+            Credentials::resolve(api_key, api_secret, credentials.as_deref(), passphrase.as_deref())?;
+        let rest_client = self.rest_client.for_account(api_key, api_secret, rate_limit.unwrap_or_default(), &overrides)?;
+        Ok(self.add_account(name, rest_client)?)
+    }
+
+    /// The `"account"` of this client's own events.
+    #[pyo3(name = "account_label")]
+    fn py_account_label(&self) -> String {
+        self.account_label().to_string()
+    }
+
+    /// Names of the accounts added with `add_account`.
+    #[pyo3(name = "account_names")]
+    fn py_account_names(&self) -> Vec<String> {
+        self.account_names()
+    }
+
+    /// Health snapshot for monitoring probes: `{"status", "checked_at_ms",
+    /// "rest", "public_ws", "private_ws", "rate_limits"}`. `status` is
+    /// `"down"` while REST is unreachable, `"degraded"` while a WS is
+    /// disconnected, a circuit is open or a rate limit is 90% used or
+    /// throttled, else `"ok"`. `public_ws` is that of the attached data
+    /// client, `None` without one.
+    #[pyo3(name = "health")]
+    fn py_health(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.health())
+    }
+
+    /// The order cache and the client order ids submitted, as a JSON-ready
+    /// dict `{"version", "exported_at", "orders", "submissions"}` for
+    /// `import_state` in a later or another process. Each account has its
+    /// own; `account` selects one.
+    #[pyo3(signature = (account=None))]
+    pub fn export_state(&self, account: Option<String>) -> PyResult<PyJson> {
+        let client = self.account(account.as_deref())?;
+        let state = ExecutionState::capture(&client.orders.read().unwrap(), &client.submissions);
+        PyJson::from_serialize(&state)
+    }
+
+    /// Merge a state from `export_state` into `account` (default: this
+    /// client), keeping what it already holds; ids submitted ambiguously or
+    /// still in flight at export stay refused until `forget_submission`.
+    /// Other accounts are left as they are. Returns `{"orders",
+    /// "submissions"}` added.
+    #[pyo3(signature = (state, account=None))]
+    pub fn import_state(&self, state: PyJson, account: Option<String>) -> PyResult<PyJson> {
+        let client = self.account(account.as_deref())?;
+        let state: ExecutionState = from_json("execution state", state)?;
+        let imported = state.restore(&mut client.orders.write().unwrap(), &client.submissions)?;
+        PyJson::from_serialize(&imported)
+    }
+
+    /// Read the arrival state of submitted orders from the books of
+    /// `data_client` (subscribed to their `orderbooks`); `None` detaches.
+    #[pyo3(signature = (data_client=None))]
+    pub fn attach_market_data(&self, data_client: Option<GmocoinDataClient>) {
+        *self.market_data.lock().unwrap() = data_client;
+    }
+
+    /// Execution quality of the orders submitted through this client (or
+    /// `account`): `{"orders": [...], "summary": {...}}`, or the report of
+    /// `order_id` alone (`None` when untracked). Slippage is in basis points
+    /// against the arrival mid, positive when worse for the order.
+    #[pyo3(signature = (order_id=None, account=None))]
+    pub fn get_execution_quality(&self, order_id: Option<u64>, account: Option<String>) -> PyResult<Option<PyJson>> {
+        let quality = self.account(account.as_deref())?.quality;
+        match order_id {
+            Some(order_id) => quality.order(order_id).map(|o| PyJson::from_serialize(&o)).transpose(),
+            None => PyJson::from_serialize(&serde_json::json!({
+                "orders": quality.report(),
+                "summary": quality.summary(),
+            }))
+            .map(Some),
+        }
+    }
+
+    /// Slice `quantity` of `symbol` over `duration_ms` in `slices` child
+    /// orders: LIMIT at `price` (unfilled children are cancelled at the next
+    /// slice) or MARKET without one. `size_step` rounds child sizes down;
+    /// `participation_cap` limits execution to that fraction of the market
+    /// volume seen by the attached data client. Returns the algorithm id;
+    /// progress arrives as `"AlgoProgress"` events. `account` places the
+    /// child orders on that account.
+    #[pyo3(name = "start_twap", signature = (symbol, side, quantity, duration_ms, slices, price=None, size_step=None, participation_cap=None, settle_type=None, account=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_start_twap(
+        &self,
+        symbol: String,
+        side: String,
+        quantity: String,
+        duration_ms: u64,
+        slices: u32,
+        price: Option<String>,
+        size_step: Option<String>,
+        participation_cap: Option<String>,
+        settle_type: Option<String>,
+        account: Option<String>,
+    ) -> PyResult<u64> {
+        let optional = |name, value: Option<String>| value.map(|v| decimal(name, &v)).transpose();
+        let config = TwapConfig {
+            symbol,
+            side: side.to_uppercase(),
+            quantity: decimal("quantity", &quantity)?,
+            duration: Duration::from_millis(duration_ms),
+            slices,
+            price: optional("price", price)?,
+            size_step: optional("size_step", size_step)?,
+            participation_cap: optional("participation_cap", participation_cap)?,
+            settle_type,
+        };
+        Ok(self.account(account.as_deref())?.start_twap(config)?)
+    }
+
+    /// Stop an algorithm (of any account) and cancel its working child
+    /// order. Returns whether it was running.
+    #[pyo3(name = "cancel_algo")]
+    fn py_cancel_algo(&self, algo_id: u64) -> bool {
+        self.cancel_algo(algo_id)
+    }
+
+    /// `AlgoProgress` of an algorithm, or `None` when unknown.
+    pub fn get_algo(&self, algo_id: u64) -> PyResult<Option<PyJson>> {
+        self.algo_progress(algo_id).map(|p| PyJson::from_serialize(&p)).transpose()
+    }
+
+    /// The latest position summary of `symbol` on `side` (`"BUY"` /
+    /// `"SELL"`; `None`: the one side held, `ValueError` if both are), or
+    /// `None` when flat. Served from the cache kept by
+    /// `positionSummaryEvents` and the periodic REST refresh.
+    #[pyo3(name = "get_position", signature = (symbol, side=None, account=None))]
+    pub fn py_get_position(&self, symbol: &str, side: Option<&str>, account: Option<String>) -> PyResult<Option<PositionSnapshot>> {
+        Ok(self.account(account.as_deref())?.position(symbol, side)?)
+    }
+
+    /// `[{"symbol", "open_orders", "buy_orders", "sell_orders", "buy_size",
+    /// "sell_size", "best_buy_price", "best_sell_price"}]` over the working
+    /// orders seen on `orderEvents`, for one `symbol` or all; no REST call
+    /// is made.
+    #[pyo3(signature = (symbol=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_open_order_exposure<'py>(&self, py: Python<'py>, symbol: Option<String>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let future = async move { PyJson::from_serialize(&client.open_order_exposure(symbol.as_deref()).await) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Every cached position summary.
+    #[pyo3(signature = (account=None))]
+    pub fn get_positions(&self, account: Option<String>) -> PyResult<Vec<PositionSnapshot>> {
+        Ok(self.account(account.as_deref())?.positions.all())
+    }
+
+    /// Reload the position cache from `/v1/positionSummary` now.
+    #[pyo3(signature = (account=None) -> "collections.abc.Awaitable[None]")]
+    pub fn refresh_positions<'py>(&self, py: Python<'py>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let (positions, rest_client) = (client.positions.clone(), client.rest_client.clone());
+        let future = async move {
+            positions.refresh(&rest_client).await?;
+            Ok(())
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Allow `client_order_id` to be submitted again, e.g. after confirming
+    /// an ambiguously failed submission did not place an order.
+    #[pyo3(signature = (client_order_id, account=None))]
+    pub fn forget_submission(&self, client_order_id: &str, account: Option<String>) -> PyResult<()> {
+        self.account(account.as_deref())?.submissions.forget(client_order_id);
+        Ok(())
+    }
+
+    /// Venue order id `submit_order` got for `client_order_id` in this
+    /// session.
+    #[pyo3(signature = (client_order_id, account=None))]
+    pub fn venue_order_id(&self, client_order_id: &str, account: Option<String>) -> PyResult<Option<u64>> {
+        Ok(self.account(account.as_deref())?.submissions.order_id(client_order_id))
+    }
+
+    /// Forget the orders tracked for `get_execution_quality`.
+    #[pyo3(signature = (account=None))]
+    pub fn clear_execution_quality(&self, account: Option<String>) -> PyResult<()> {
+        self.account(account.as_deref())?.quality.clear();
+        Ok(())
+    }
+
+    /// `callback(event_type, data)`; `async def` callbacks are scheduled on
+    /// the event loop running at this call.
+    pub fn set_order_callback(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<()> {
+        self.sinks.callback.set(py, callback)
+    }
+
+    /// `handler(event_type, exception)` for exceptions raised by the callback,
+    /// which are always logged and counted in `get_stats()`. `None` clears it.
+    #[pyo3(signature = (handler=None))]
+    pub fn set_callback_error_handler(&self, handler: Option<Py<PyAny>>) {
+        self.sinks.callback.set_error_handler(handler);
+    }
+
+    /// Record the Private WS session (auth, subscribes, received frames) to a
+    /// JSONL fixture at `path`, or stop recording with `None`. The access
+    /// token is never written. Each account has its own session; `account`
+    /// selects one.
+    #[pyo3(name = "record_session", signature = (path=None, account=None))]
+    fn py_record_session(&self, path: Option<String>, account: Option<String>) -> PyResult<()> {
+        Ok(self.account(account.as_deref())?.record_session(path.as_deref())?)
+    }
+
+    /// Feed the received frames of a recorded session through the same
+    /// handling as live Private WS messages (order cache, stats, order
+    /// callback). `speed` paces them by their recorded times (2.0: twice as
+    /// fast, default: as fast as possible); while it runs, `pause_replay`,
+    /// `resume_replay`, `set_replay_speed` and `seek_replay` control it.
+    /// Resolves to the number of frames delivered.
+    #[pyo3(name = "replay_session", signature = (path, speed=None) -> "collections.abc.Awaitable[int]")]
+    fn py_replay_session<'py>(&self, py: Python<'py>, path: String, speed: Option<f64>) -> PyResult<Bound<'py, PyAny>> {
+        // Read up front so a missing or malformed fixture raises immediately
+        let entries = session::read_received_entries(&path)?;
+        let client = self.clone();
+        let future = async move { Ok(client.replay_entries(entries, speed).await?) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(name = "pause_replay")]
+    fn py_pause_replay(&self) {
+        self.pause_replay();
+    }
+
+    #[pyo3(name = "resume_replay")]
+    fn py_resume_replay(&self) {
+        self.resume_replay();
+    }
+
+    #[pyo3(name = "set_replay_speed", signature = (speed=None))]
+    fn py_set_replay_speed(&self, speed: Option<f64>) -> PyResult<()> {
+        Ok(self.set_replay_speed(speed)?)
+    }
+
+    /// See `GmocoinExecutionClient::seek_replay`; `ts_ms` is epoch ms, as
+    /// recorded in the session file.
+    #[pyo3(name = "seek_replay")]
+    fn py_seek_replay(&self, ts_ms: i64) {
+        self.seek_replay(ts_ms);
+    }
+
+    /// `{"active", "index", "total", "ts", "paused", "speed"}` of the
+    /// replay; `ts` is the recorded time of the next frame.
+    #[pyo3(name = "replay_position")]
+    fn py_replay_position(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.replay_position())
+    }
+
+    /// Connect to Private WebSocket (with token refresh loop)
+    #[pyo3(name = "connect", signature = () -> "collections.abc.Awaitable[str]")]
+    fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.connect();
             Ok("Connected")
         };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Stop the Private WebSocket loop
+    #[pyo3(name = "disconnect", signature = () -> "collections.abc.Awaitable[str]")]
+    fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.disconnect().await;
+            Ok("Disconnected")
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
 
+    /// Fail pending REST requests, stop the Private WS loop and wait up to
+    /// `timeout_ms` (default 5000) for it to exit. Resolves to whether it did.
+    #[pyo3(name = "close", signature = (timeout_ms=None) -> "collections.abc.Awaitable[bool]")]
+    fn py_close<'py>(&self, py: Python<'py>, timeout_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let timeout = Duration::from_millis(timeout_ms.unwrap_or(5000));
+        let future = async move { Ok(client.close(timeout).await) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// `async with GmocoinExecutionClient(...) as client:` connects on entry.
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Self]")]
+    fn __aenter__<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        let client = slf.borrow().clone();
+        let this = slf.clone().unbind();
+        let future = async move {
+            client.connect();
+            Ok(this)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(slf.py(), future)
+    }
+
+    /// Disconnects and waits for the WS task to stop, even on error.
+    #[pyo3(signature = (_exc_type, _exc_value, _traceback) -> "collections.abc.Awaitable[bool]")]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            client.disconnect().await;
+            Ok(false)
+        };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
     // ========== Order Operations (Python) ==========
 
-    #[pyo3(signature = (symbol, amount, side, execution_type, client_order_id, price=None, time_in_force=None, cancel_before=None, losscut_price=None, settle_type=None))]
+    /// `deadline_ms` on order operations: abandon the request with
+    /// `TimeoutError` if it cannot complete within this many ms (see
+    /// `GmocoinRestClient.with_deadline`). `timeout_ms` overrides the
+    /// client-wide HTTP timeout for the call (see `with_timeout`).
+    /// `arrival_bid` / `arrival_ask`: the market at submit time for the
+    /// execution quality report, used when no data client with a book for
+    /// `symbol` is attached.
+    ///
+    /// `client_order_id`s are deduplicated per session: resubmitting an
+    /// accepted one resolves to its existing order with `"duplicate": true`
+    /// without sending anything, and one still in flight or whose
+    /// submission failed ambiguously (timed out after sending, or accepted
+    /// without an order id in the response) raises `ValueError` until
+    /// `forget_submission` releases it.
+    ///
+    /// An ambiguous failure is checked against the symbol's active orders
+    /// and latest executions first: a matching order sent since is taken as
+    /// the result (`"recovered": true`), and the order is sent once more
+    /// only when neither list has it. If the check itself fails, the
+    /// original error is raised.
+    ///
+    /// An `amount` over the symbol's `maxOrderSize` raises
+    /// `OrderTooLargeError` without sending anything, or with `split` is
+    /// sent as several orders of at most that size, one after another (a
+    /// rest below `minOrderSize` is topped up from the last full one); their
+    /// ids are returned as `"order_ids"` and `"order_id"` is the
+    /// first. A failure part way leaves the orders already placed, and the
+    /// client order id resolves to the first of them.
+    #[pyo3(signature = (symbol, amount, side, execution_type, client_order_id, price=None, time_in_force=None, cancel_before=None, losscut_price=None, settle_type=None, deadline_ms=None, timeout_ms=None, arrival_bid=None, arrival_ask=None, split=false, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn submit_order<'py>(
         &self,
         py: Python<'py>,
@@ -87,66 +1559,166 @@ impl GmocoinExecutionClient {
         cancel_before: Option<bool>,
         losscut_price: Option<String>,
         settle_type: Option<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        arrival_bid: Option<String>,
+        arrival_ask: Option<String>,
+        split: bool,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
-        let client_oid_map_arc = self.client_oid_map.clone();
+        let this = self.account(account.as_deref())?;
+        let rest_client = this.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms);
+        let submissions = this.submissions.clone();
+        let quality = this.quality.clone();
+        let sinks = this.sinks.clone();
+        let ack_budget = this.latency.order_ack;
+        let arrival = match this.arrival(&symbol) {
+            Some(arrival) => Some(arrival),
+            None => match (arrival_bid, arrival_ask) {
+                (Some(bid), Some(ask)) => Some(Arrival::new(decimal("arrival_bid", &bid)?, decimal("arrival_ask", &ask)?)),
+                _ => None,
+            },
+        };
+        decimal("amount", &amount)?;
+        // A retry of an accepted client order id gets the existing order
+        if let Some(order_id) = this.submissions.begin(&client_order_id)? {
+            warn!("GMO: Duplicate submission of {} ignored (order {})", client_order_id, order_id);
+            let future = async move { Ok(PyJson(serde_json::json!({"order_id": order_id, "duplicate": true}))) };
+            return pyo3_async_runtimes::tokio::future_into_py(py, future);
+        }
+        let recovery = this.rest_client.clone();
+        let sent = std::time::Instant::now();
+        let sent_at = Utc::now();
 
         let future = async move {
+            // Recovery looks for the size and price as GMO received them
+            let formatted = async {
+                let amount = rest_client.format_size(&symbol, &amount).await?;
+                let amounts = if split {
+                    rest_client.split_order_size(&symbol, &amount).await?
+                } else {
+                    rest_client.check_order_size(&symbol, &amount).await?;
+                    vec![amount]
+                };
+                let Some(price) = &price else { return Ok((amounts, None, None)) };
+                let price = rest_client.format_price(&symbol, Some(&side), price).await?;
+                let parsed = decimal("price", &price)?;
+                Ok::<_, GmocoinError>((amounts, Some(price), Some(parsed)))
+            };
+            let (amounts, price, limit_price) = match formatted.await {
+                Ok(formatted) => formatted,
+                Err(e) => {
+                    submissions.fail(&client_order_id, &e);
+                    return Err(e.into());
+                }
+            };
             let price_ref = price.as_deref();
             let tif_ref = time_in_force.as_deref();
             let lp_ref = losscut_price.as_deref();
             let st_ref = settle_type.as_deref();
-            let res = rest_client
-                .submit_order(&symbol, &side, &execution_type, &amount, price_ref, tif_ref, cancel_before, lp_ref, st_ref)
-                .await
-                .map_err(PyErr::from)?;
+            let mut order_ids: Vec<u64> = Vec::with_capacity(amounts.len());
+            let mut recovered = false;
+            // Orders already placed keep the client order id resolvable
+            let give_up = |e: GmocoinError, placed: &[u64]| -> PyErr {
+                match placed.first() {
+                    Some(first) => {
+                        error!("GMO: Submission of {} failed after placing orders {:?}: {}", client_order_id, placed, e);
+                        submissions.accept(&client_order_id, *first);
+                    }
+                    None => submissions.fail(&client_order_id, &e),
+                }
+                e.into()
+            };
+            for amount in &amounts {
+                let size = decimal("amount", amount)?;
+                let send = || rest_client.submit_order(&symbol, &side, &execution_type, amount, price_ref, tif_ref, cancel_before, lp_ref, st_ref);
+                let res = match send().await {
+                    Ok(res) => res,
+                    // Resubmit only once GMO shows the order never arrived
+                    Err(e) if e.is_ambiguous() => {
+                        let submitted = SubmittedOrder { symbol: &symbol, side: &side, execution_type: &execution_type, size, price: limit_price, sent_at };
+                        match find_submitted(&recovery, &submitted, |id| submissions.contains_order(id) || order_ids.contains(&id)).await {
+                            Ok(Some(order_id)) => {
+                                warn!("GMO: Submission of {} failed ({}) but reached GMO as order {}", client_order_id, e, order_id);
+                                recovered = true;
+                                serde_json::Value::String(order_id.to_string())
+                            }
+                            Ok(None) => {
+                                warn!("GMO: Submission of {} failed ({}) and never reached GMO, resubmitting", client_order_id, e);
+                                match send().await {
+                                    Ok(res) => res,
+                                    Err(e) => return Err(give_up(e, &order_ids)),
+                                }
+                            }
+                            Err(check) => {
+                                warn!("GMO: Could not check whether {} reached GMO: {}", client_order_id, check);
+                                return Err(give_up(e, &order_ids));
+                            }
+                        }
+                    }
+                    Err(e) => return Err(give_up(e, &order_ids)),
+                };
 
-            // The response "data" is the orderId as a string
-            let order_id_str = res.as_str().unwrap_or("").to_string();
-            let order_id: u64 = order_id_str.parse().unwrap_or(0);
+                // The response "data" is the orderId as a string. Without it
+                // the order was placed but cannot be tracked, so the client
+                // order id stays refused like any ambiguous submission.
+                let Some(order_id) = res.as_str().and_then(|s| s.parse::<u64>().ok()) else {
+                    return Err(give_up(GmocoinError::MissingOrderId(res), &order_ids));
+                };
+                quality.record_submit(order_id, &symbol, &side, size, arrival.clone(), sent);
+                if let Some(alarm) = LatencyAlarm::order_ack(ack_budget, sent.elapsed(), order_id, &symbol) {
+                    sinks.latency_alarm(alarm);
+                }
+                order_ids.push(order_id);
+            }
 
+            let order_id = order_ids.first().copied().unwrap_or(0);
             if order_id > 0 {
-                let mut map = client_oid_map_arc.write().await;
-                map.insert(client_order_id, order_id);
+                submissions.accept(&client_order_id, order_id);
+            } else {
+                submissions.forget(&client_order_id);
             }
 
-            let result = serde_json::json!({"order_id": order_id});
-            serde_json::to_string(&result)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let mut result = serde_json::json!({"order_id": order_id, "duplicate": false, "recovered": recovered});
+            if split {
+                result["order_ids"] = serde_json::json!(order_ids);
+            }
+            Ok(PyJson(result))
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    pub fn cancel_order<'py>(&self, py: Python<'py>, _symbol: String, order_id: String) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+    #[pyo3(signature = (_symbol, order_id, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn cancel_order<'py>(&self, py: Python<'py>, _symbol: String, order_id: String, deadline_ms: Option<u64>, timeout_ms: Option<u64>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let rest_client = self.account(account.as_deref())?.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms);
         let future = async move {
             let oid = order_id.parse::<u64>().map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
             })?;
 
             let res = rest_client.cancel_order(oid).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    pub fn get_order<'py>(&self, py: Python<'py>, order_id: String) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+    #[pyo3(signature = (order_id, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_order<'py>(&self, py: Python<'py>, order_id: String, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let rest_client = self.account(account.as_deref())?.rest_client;
         let future = async move {
             let oid = order_id.parse::<u64>().map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
             })?;
 
             let res = rest_client.get_order(oid).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    pub fn get_executions<'py>(&self, py: Python<'py>, order_id: String) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+    #[pyo3(signature = (order_id, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_executions<'py>(&self, py: Python<'py>, order_id: String, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let rest_client = self.account(account.as_deref())?.rest_client;
         let future = async move {
             let oid = order_id.parse::<u64>().map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
@@ -156,42 +1728,124 @@ impl GmocoinExecutionClient {
                 .get_executions_for_order(oid)
                 .await
                 .map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
+    /// Change an order's price. Once GMO accepts the change an
+    /// `"OrderAmendPending"` event `{"order_id", "price", "losscut_price",
+    /// "status", "order_price"}` is emitted, followed by `"OrderAmended"`
+    /// or `"OrderAmendRejected"` when the order's next `orderEvents`
+    /// message does or does not show the new price.
+    #[pyo3(signature = (order_id, price, losscut_price=None, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn change_order<'py>(
         &self,
         py: Python<'py>,
         order_id: String,
         price: String,
         losscut_price: Option<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+        let this = self.account(account.as_deref())?;
+        let rest_client = this.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms);
+        let client = this.clone();
         let future = async move {
             let oid = order_id.parse::<u64>().map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
             })?;
 
-            let lp_ref = losscut_price.as_deref();
-            let res = rest_client
-                .change_order(oid, &price, lp_ref)
-                .await
-                .map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let order = client.fetch_order(&rest_client, oid).await?;
+            let (res, _) = client.send_change(&rest_client, &order, &price, losscut_price.as_deref()).await?;
+            PyJson::from_serialize(&res)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Change the price and/or total size of an order; a size change cancels
+    /// and resubmits it (see `GmocoinExecutionClient.amend_order`), moving
+    /// its client order id to the new order. Resolves to `{"order_id",
+    /// "previous_order_id", "replaced", "size"}`.
+    #[pyo3(name = "amend_order", signature = (order_id, price=None, size=None, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    fn py_amend_order<'py>(
+        &self,
+        py: Python<'py>,
+        order_id: String,
+        price: Option<String>,
+        size: Option<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let this = self.account(account.as_deref())?;
+        let client = Self { rest_client: this.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms), ..this };
+        let future = async move {
+            let oid = order_id.parse::<u64>().map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
+            })?;
+            let amendment = client.amend_order(oid, price.as_deref(), size.as_deref()).await?;
+            if amendment.replaced {
+                client.submissions.remap(oid, amendment.order_id);
+            }
+            PyJson::from_serialize(&amendment)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Change an order's price, moved onto the symbol's `tickSize` grid
+    /// (down for buys, up for sells). Raises `ValueError` without calling
+    /// GMO when the price would not change. Resolves as `amend_order`.
+    #[pyo3(name = "amend_price", signature = (order_id, price, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    fn py_amend_price<'py>(
+        &self,
+        py: Python<'py>,
+        order_id: String,
+        price: String,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let this = self.account(account.as_deref())?;
+        let client = Self { rest_client: this.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms), ..this };
+        let future = async move {
+            let oid = order_id.parse::<u64>().map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
+            })?;
+            PyJson::from_serialize(&client.amend_price(oid, &price).await?)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
+    /// A low-priority `amend_price` for re-quotes: queued while the order
+    /// rate limit is nearly exhausted, with a newer re-quote of the same
+    /// order replacing a queued one. Resolves to `{"status": "sent",
+    /// "amendment": {...}}`, or `{"status": "superseded"}` when replaced.
+    #[pyo3(name = "requote", signature = (order_id, price, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    fn py_requote<'py>(&self, py: Python<'py>, order_id: String, price: String, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let future = async move {
+            let oid = order_id.parse::<u64>().map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
+            })?;
+            PyJson::from_serialize(&client.requote(oid, &price).await?)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (order_ids, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn cancel_orders<'py>(
         &self,
         py: Python<'py>,
         order_ids: Vec<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+        let rest_client = self.account(account.as_deref())?.rest_client.with_deadline(deadline_ms).with_timeout(timeout_ms);
         let future = async move {
             let oids: Vec<u64> = order_ids.iter()
                 .map(|s| s.parse::<u64>())
@@ -204,73 +1858,180 @@ impl GmocoinExecutionClient {
                 .cancel_orders(&oids)
                 .await
                 .map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    #[pyo3(signature = (symbol, page=None, count=None))]
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_active_orders<'py>(
         &self,
         py: Python<'py>,
         symbol: String,
         page: Option<i32>,
         count: Option<i32>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+        let rest_client = self.account(account.as_deref())?.rest_client;
         let future = async move {
             let res = rest_client
                 .get_active_orders(&symbol, page.unwrap_or(1), count.unwrap_or(100))
                 .await
                 .map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    #[pyo3(signature = (symbol, page=None, count=None))]
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_latest_executions<'py>(
         &self,
         py: Python<'py>,
         symbol: String,
         page: Option<i32>,
         count: Option<i32>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let rest_client = self.rest_client.clone();
+        let rest_client = self.account(account.as_deref())?.rest_client;
         let future = async move {
             let res = rest_client
                 .get_latest_executions(&symbol, page.unwrap_or(1), count.unwrap_or(100))
                 .await
                 .map_err(PyErr::from)?;
-            serde_json::to_string(&res)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
         pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 
-    pub fn get_assets_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.get_assets_py(py)
+    /// Closed orders of `symbol` with fills between `start` and `end` (UNIX
+    /// ns, end exclusive), oldest first, each with its final status, filled
+    /// size, average price, fees and realized P&L.
+    #[pyo3(signature = (symbol, start=None, end=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_order_history<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        start: Option<u64>,
+        end: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let at = |ns: Option<u64>| ns.map(time::from_ns);
+        let (start, end) = (at(start), at(end));
+        let future = async move {
+            let history = client.order_history(&symbol, start, end).await?;
+            PyJson::from_serialize(&history)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Executions of `symbols` between `start` and `end` (UNIX ns, end
+    /// exclusive) as an Arrow RecordBatch with exact `decimal128(38, 8)`
+    /// amounts.
+    #[pyo3(signature = (symbols, start=None, end=None, account=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_execution_history_arrow<'py>(
+        &self,
+        py: Python<'py>,
+        symbols: Vec<String>,
+        start: Option<u64>,
+        end: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let at = |ns: Option<u64>| ns.map(time::from_ns);
+        let (start, end) = (at(start), at(end));
+        let future = async move {
+            let executions = client.execution_history(&symbols, start, end).await?;
+            Ok(ArrowRecordBatch::new(arrow_export::execution_ledger_batch(&executions).map_err(arrow_export::arrow_err)?))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Write the executions of `symbols` between `start` and `end` (UNIX ns,
+    /// end exclusive) to `path` as `"csv"` or `"parquet"` (needs pyarrow),
+    /// in the fixed column order of `history::EXECUTION_COLUMNS`. Returns
+    /// the number of executions written.
+    #[pyo3(signature = (path, symbols, start=None, end=None, format="csv", account=None) -> "collections.abc.Awaitable[int]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn export_executions<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+        symbols: Vec<String>,
+        start: Option<u64>,
+        end: Option<u64>,
+        format: &str,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let parquet = match format {
+            "csv" => false,
+            "parquet" => true,
+            other => return Err(GmocoinError::InvalidInput(format!("Unknown export format '{}' (csv or parquet)", other)).into()),
+        };
+        let client = self.account(account.as_deref())?;
+        let at = |ns: Option<u64>| ns.map(time::from_ns);
+        let (start, end) = (at(start), at(end));
+        let future = async move {
+            let executions = client.execution_history(&symbols, start, end).await?;
+            if parquet {
+                let batch = arrow_export::execution_ledger_batch(&executions).map_err(arrow_export::arrow_err)?;
+                Python::attach(|py| -> PyResult<()> {
+                    let pq = py.import("pyarrow.parquet").map_err(|_| {
+                        pyo3::exceptions::PyImportError::new_err("Parquet export needs pyarrow")
+                    })?;
+                    let table = py.import("pyarrow")?.call_method1("table", (ArrowRecordBatch::new(batch),))?;
+                    pq.call_method1("write_table", (table, path.as_str()))?;
+                    Ok(())
+                })?;
+            } else {
+                let file = std::fs::File::create(&path).map_err(GmocoinError::from)?;
+                history::write_csv(&executions, std::io::BufWriter::new(file)).map_err(GmocoinError::from)?;
+            }
+            Ok(executions.len())
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_latest_executions_arrow<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        page: Option<i32>,
+        count: Option<i32>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.account(account.as_deref())?.rest_client.get_latest_executions_arrow(py, symbol, page, count)
+    }
+
+    #[pyo3(signature = (account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_assets_py<'py>(&self, py: Python<'py>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        self.account(account.as_deref())?.rest_client.get_assets_py(py)
     }
 
     // ========== Position Operations (Python) ==========
 
-    pub fn get_margin_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.get_margin_py(py)
+    #[pyo3(signature = (account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_margin_py<'py>(&self, py: Python<'py>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        self.account(account.as_deref())?.rest_client.get_margin_py(py)
     }
 
-    #[pyo3(signature = (symbol, page=None, count=None))]
-    pub fn get_open_positions<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.get_open_positions_py(py, symbol, page, count)
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_open_positions<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        self.account(account.as_deref())?.rest_client.get_open_positions_py(py, symbol, page, count)
     }
 
-    pub fn get_position_summary<'py>(&self, py: Python<'py>, symbol: Option<String>) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.get_position_summary_py(py, symbol)
+    #[pyo3(signature = (symbol, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_position_summary<'py>(&self, py: Python<'py>, symbol: Option<String>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        self.account(account.as_deref())?.rest_client.get_position_summary_py(py, symbol)
     }
 
-    #[pyo3(signature = (symbol, side, execution_type, settle_position, price=None, time_in_force=None))]
-    pub fn close_order<'py>(
+    /// `{"order_id", "duplicate"}` like `submit_order`: the closing order
+    /// is cached, reported on and, with a `client_order_id`, deduplicated
+    /// like a submitted one.
+    #[pyo3(name = "close_order", signature = (symbol, side, execution_type, settle_position, price=None, time_in_force=None, client_order_id=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_close_order<'py>(
         &self,
         py: Python<'py>,
         symbol: String,
@@ -279,12 +2040,21 @@ impl GmocoinExecutionClient {
         settle_position: Vec<(u64, String)>,
         price: Option<String>,
         time_in_force: Option<String>,
+        client_order_id: Option<String>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.post_close_order_py(py, symbol, side, execution_type, settle_position, price, time_in_force)
+        let this = self.account(account.as_deref())?;
+        let client = this.clone();
+        this.close_py(py, client_order_id, move |client_order_id| async move {
+            let settle_position: Vec<(u64, &str)> = settle_position.iter().map(|(id, size)| (*id, size.as_str())).collect();
+            client.close_order(&symbol, &side, &execution_type, &settle_position, price.as_deref(), time_in_force.as_deref(), client_order_id.as_deref()).await
+        })
     }
 
-    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None))]
-    pub fn close_bulk_order<'py>(
+    /// `{"order_id", "duplicate"}`, tracked like `close_order`.
+    #[pyo3(name = "close_bulk_order", signature = (symbol, side, execution_type, size, price=None, time_in_force=None, client_order_id=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_close_bulk_order<'py>(
         &self,
         py: Python<'py>,
         symbol: String,
@@ -293,59 +2063,116 @@ impl GmocoinExecutionClient {
         size: String,
         price: Option<String>,
         time_in_force: Option<String>,
+        client_order_id: Option<String>,
+        account: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.post_close_bulk_order_py(py, symbol, side, execution_type, size, price, time_in_force)
+        let this = self.account(account.as_deref())?;
+        let client = this.clone();
+        this.close_py(py, client_order_id, move |client_order_id| async move {
+            client.close_bulk_order(&symbol, &side, &execution_type, &size, price.as_deref(), time_in_force.as_deref(), client_order_id.as_deref()).await
+        })
     }
 
-    pub fn change_losscut_price<'py>(&self, py: Python<'py>, position_id: u64, losscut_price: String) -> PyResult<Bound<'py, PyAny>> {
-        self.rest_client.put_losscut_price_py(py, position_id, losscut_price)
+    /// `{"position_id", "losscut_price"}` once GMO accepts the change; a
+    /// `"LosscutUpdated"` event `{"position_id", "symbol",
+    /// "losscut_price", "requested", "confirmed"}` follows when
+    /// `positionEvents` reflects it. `symbol`: the position's, to round
+    /// `losscut_price` to its tick; without it the price is sent as given.
+    #[pyo3(name = "change_losscut_price", signature = (position_id, losscut_price, account=None, symbol=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn py_change_losscut_price<'py>(&self, py: Python<'py>, position_id: u64, losscut_price: String, account: Option<String>, symbol: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.account(account.as_deref())?;
+        let future = async move {
+            PyJson::from_serialize(&client.change_losscut_price(position_id, symbol.as_deref(), &losscut_price).await?)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
     }
 }
 
 impl GmocoinExecutionClient {
+    #[allow(clippy::too_many_arguments)]
     async fn ws_loop(
         rest_client: GmocoinRestClient,
-        order_cb_arc: Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
-        orders_arc: Arc<RwLock<HashMap<u64, Order>>>,
+        sinks: OrderSinks,
+        orders_arc: Arc<std::sync::RwLock<HashMap<u64, Order>>>,
         shutdown: Arc<AtomicBool>,
+        recorder: SessionRecorder,
+        quality: ExecutionQuality,
+        positions: PositionCache,
+        losscuts: LosscutChanges,
+        amends: AmendTracker,
+        stream: StreamHealth,
+        maintenance: MaintenanceGuard,
+        reconnect: BackoffPolicy,
+        stale_timeout: Option<Duration>,
     ) {
-        let mut backoff_sec = 5u64;
-        let max_backoff = 60u64;
+        let mut backoff = Backoff::new(reconnect);
+        // Shared across reconnects so an ERR-5003 throttle carries over
+        let ws_sub_limiter = crate::rate_limit::TokenBucket::new(1.0, 0.5);
+        let stats = rest_client.stats().clone();
+        let mut outage: Option<Outage> = None;
+        let on_maintenance = |event| {
+            sinks.emit("Maintenance", serde_json::to_value(&event).expect("event serializes"));
+        };
 
         loop {
             if shutdown.load(Ordering::SeqCst) { return; }
+            stream.mark_down();
+            stats.ws_connected(false);
+            if let Some(outage) = outage.as_mut() {
+                outage.attempt();
+            }
 
             // 1. Get access token
             let token = match rest_client.post_ws_auth().await {
                 Ok(t) => t,
                 Err(e) => {
-                    error!("GMO: Failed to get Private WS auth token: {}. Retrying in {}s...", e, backoff_sec);
-                    sleep(Duration::from_secs(backoff_sec)).await;
-                    backoff_sec = (backoff_sec * 2).min(max_backoff);
+                    if maintenance.wait_out("Private WS", on_maintenance).await {
+                        backoff.reset();
+                        continue;
+                    }
+                    error!("GMO: Failed to get Private WS auth token: {}. Retrying in {:?}...", e, backoff.current());
+                    sleep(backoff.next_delay()).await;
                     continue;
                 }
             };
 
             info!("GMO: Got Private WS token");
+            stats.token_issued();
+            recorder.record("auth", "<redacted>");
 
             // 2. Connect to Private WS
-            let ws_url = format!("wss://api.coin.z.com/ws/private/v1/{}", token);
+            let ws_url = rest_client.venue().ws_private_url(&token);
+
+            let connecting = async {
+                let request = rest_client.ws_request(&ws_url)?;
+                Ok::<_, GmocoinError>(
+                    connect_async_tls_with_config(request, None, false, rest_client.ws_connector()).await?,
+                )
+            };
 
-            match connect_async(ws_url.as_str()).await {
+            match connecting.await {
                 Ok((mut ws, _)) => {
                     info!("GMO: Connected to Private WebSocket");
-                    backoff_sec = 5;
+                    backoff.reset();
+                    stream.mark_up();
+                    stats.ws_connected(true);
+                    if let Some(outage) = outage.take() {
+                        let event = outage.into_event();
+                        info!(
+                            "GMO: Private WS reconnected after {}ms ({}, {} attempts)",
+                            event.downtime_ms, event.reason.as_str(), event.attempts
+                        );
+                        stats.reconnect(event.reason);
+                        sinks.emit("Reconnect", serde_json::to_value(&event).expect("event serializes"));
+                    }
 
                     // Subscribe to execution and order events with rate limiting
-                    let ws_sub_limiter = crate::rate_limit::TokenBucket::new(1.0, 0.5);
                     let channels = vec!["executionEvents", "orderEvents", "positionEvents", "positionSummaryEvents"];
                     for ch in &channels {
                         ws_sub_limiter.acquire().await;
-                        let sub_msg = serde_json::json!({
-                            "command": "subscribe",
-                            "channel": ch,
-                        });
-                        if let Err(e) = ws.send(Message::Text(sub_msg.to_string().into())).await {
+                        let sub_msg = WsCommand { command: "subscribe", channel: ch, symbol: None, option: None }.to_text();
+                        recorder.record("send", &sub_msg);
+                        if let Err(e) = ws.send(Message::Text(sub_msg.into())).await {
                             error!("GMO: Failed to subscribe to {}: {}", ch, e);
                         }
                     }
@@ -353,6 +2180,7 @@ impl GmocoinExecutionClient {
                     // Token refresh tracking
                     let mut last_refresh = std::time::Instant::now();
                     let refresh_interval = Duration::from_secs(900); // 15 minutes
+                    let mut last_recv = tokio::time::Instant::now();
 
                     // Main message loop
                     loop {
@@ -365,30 +2193,51 @@ impl GmocoinExecutionClient {
                         if last_refresh.elapsed() >= refresh_interval {
                             if let Err(e) = rest_client.put_ws_auth(&token).await {
                                 error!("GMO: Failed to extend Private WS token: {}. Reconnecting...", e);
+                                outage = Some(Outage::new(DisconnectReason::TokenRefreshFailed));
                                 break;
                             }
                             info!("GMO: Extended Private WS token");
+                            stats.token_refresh();
                             last_refresh = std::time::Instant::now();
                         }
 
-                        match ws.next().await {
+                        let next = match stale_timeout {
+                            Some(limit) => match tokio::time::timeout_at(last_recv + limit, ws.next()).await {
+                                Ok(next) => next,
+                                Err(_) => {
+                                    warn!("GMO: Private WS silent for {:?}, reconnecting", last_recv.elapsed());
+                                    outage = Some(Outage::new(DisconnectReason::Stale));
+                                    break;
+                                }
+                            },
+                            None => ws.next().await,
+                        };
+                        last_recv = tokio::time::Instant::now();
+
+                        match next {
                             Some(Ok(Message::Text(txt))) => {
                                 let txt_str: &str = txt.as_ref();
-                                Self::process_ws_message(txt_str, &order_cb_arc, &orders_arc).await;
+                                recorder.record("recv", txt_str);
+                                stream.received();
+                                Self::process_ws_message(txt_str, &sinks, &orders_arc, &quality, &positions, &losscuts, &amends, &stream, &ws_sub_limiter, &stats).await;
                             }
                             Some(Ok(Message::Ping(data))) => {
                                 let _ = ws.send(Message::Pong(data)).await;
                             }
                             Some(Ok(Message::Close(_))) => {
                                 warn!("GMO: Private WS closed by server");
+                                outage = Some(Outage::new(DisconnectReason::ServerClose));
                                 break;
                             }
                             Some(Err(e)) => {
                                 error!("GMO: Private WS error: {}", e);
+                                stats.error_class("websocket");
+                                outage = Some(Outage::new(DisconnectReason::ReadError));
                                 break;
                             }
                             None => {
                                 warn!("GMO: Private WS stream ended");
+                                outage = Some(Outage::new(DisconnectReason::StreamEnded));
                                 break;
                             }
                             _ => {}
@@ -396,53 +2245,141 @@ impl GmocoinExecutionClient {
                     }
                 }
                 Err(e) => {
-                    error!("GMO: Failed to connect Private WS: {}. Retrying in {}s...", e, backoff_sec);
+                    error!("GMO: Failed to connect Private WS: {}. Retrying in {:?}...", e, backoff.current());
+                    stats.error_class("websocket");
                 }
             }
 
             if shutdown.load(Ordering::SeqCst) { return; }
-            sleep(Duration::from_secs(backoff_sec)).await;
-            backoff_sec = (backoff_sec * 2).min(max_backoff);
+            stream.mark_down();
+            stats.ws_connected(false);
+            if maintenance.wait_out("Private WS", on_maintenance).await {
+                backoff.reset();
+                continue;
+            }
+            sleep(backoff.next_delay()).await;
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn process_ws_message(
         msg_json: &str,
-        order_cb_arc: &Arc<std::sync::Mutex<Option<Py<PyAny>>>>,
-        orders_arc: &Arc<RwLock<HashMap<u64, Order>>>,
+        sinks: &OrderSinks,
+        orders_arc: &Arc<std::sync::RwLock<HashMap<u64, Order>>>,
+        quality: &ExecutionQuality,
+        positions: &PositionCache,
+        losscuts: &LosscutChanges,
+        amends: &AmendTracker,
+        stream: &StreamHealth,
+        ws_sub_limiter: &crate::rate_limit::TokenBucket,
+        stats: &ClientStats,
     ) {
-        if let Ok(val) = serde_json::from_str::<serde_json::Value>(msg_json) {
-            // Check for error responses
-            if val.get("error").is_some() {
-                warn!("GMO: Private WS error response: {}", msg_json);
+        let mut val = match serde_json::from_str::<serde_json::Value>(msg_json) {
+            Ok(val) => val,
+            Err(e) => {
+                Self::parse_error(sinks, stats, ParseErrorEvent::new(None, e.to_string(), msg_json));
                 return;
             }
+        };
+        // Check for error responses
+        if let Some(err) = val.get("error") {
+            warn!("GMO: Private WS error response: {}", msg_json);
+            if err.as_str().is_some_and(|e| e.starts_with("ERR-5003")) {
+                stats.error_class("rate_limited");
+                ws_sub_limiter.throttle();
+            } else {
+                stats.error_class("exchange");
+            }
+            return;
+        }
 
-            let channel = val.get("channel").and_then(|c| c.as_str()).unwrap_or("unknown");
+        let channel = val.get("channel").and_then(|c| c.as_str()).unwrap_or("unknown");
+        stats.message(channel);
 
-            let event_type = match channel {
-                "executionEvents" => "ExecutionUpdate",
-                "orderEvents" => "OrderUpdate",
-                "positionEvents" => "PositionUpdate",
-                "positionSummaryEvents" => "PositionSummaryUpdate",
-                _ => "Unknown",
-            };
+        let event_type = match channel {
+            "executionEvents" => "ExecutionUpdate",
+            "orderEvents" => "OrderUpdate",
+            "positionEvents" => "PositionUpdate",
+            "positionSummaryEvents" => "PositionSummaryUpdate",
+            _ => "Unknown",
+        };
 
-            // For OrderUpdate, try to cache the order
-            if event_type == "OrderUpdate" {
-                if let Ok(order) = serde_json::from_value::<Order>(val.clone()) {
-                    let mut orders = orders_arc.write().await;
-                    orders.insert(order.order_id, order);
+        // For OrderUpdate, try to cache the order; the raw update is
+        // delivered either way, ahead of any amend outcome it resolves
+        let mut amended = None;
+        let mut order_event = None;
+        if event_type == "OrderUpdate" {
+            match Order::deserialize(&val) {
+                Ok(order) => {
+                    order_event = order.event_kind().map(|kind| (kind, order.cancel_reason()));
+                    amended = amends.resolve(&order);
+                    orders_arc.write().unwrap().insert(order.order_id, order);
+                }
+                Err(e) => {
+                    Self::parse_error(sinks, stats, ParseErrorEvent::new(Some(channel), e.to_string(), msg_json));
                 }
             }
+        }
+
+        if event_type == "PositionSummaryUpdate" {
+            match PositionSummary::deserialize(&val) {
+                Ok(summary) => positions.apply(&summary),
+                Err(e) => Self::parse_error(sinks, stats, ParseErrorEvent::new(Some(channel), e.to_string(), msg_json)),
+            }
+        }
 
-            // Call Python callback
-            Python::try_attach(|py| {
-                let lock = order_cb_arc.lock().unwrap();
-                if let Some(cb) = lock.as_ref() {
-                    let _ = cb.call1(py, (event_type, msg_json.to_string())).ok();
+        // Losscut price changes are reported after the position update
+        let losscut = match event_type {
+            "PositionUpdate" => match Position::deserialize(&val) {
+                Ok(position) => losscuts.observe(&position),
+                Err(e) => {
+                    Self::parse_error(sinks, stats, ParseErrorEvent::new(Some(channel), e.to_string(), msg_json));
+                    None
                 }
-            });
+            },
+            _ => None,
+        };
+
+        // Fills of tracked orders feed the execution quality report, and
+        // delivered ones are not synthesized again by the polling fallback
+        if event_type == "ExecutionUpdate" {
+            match Execution::deserialize(&val) {
+                Ok(execution) => {
+                    stream.record_execution(execution.execution_id);
+                    if let (Ok(price), Ok(size)) = (execution.price.parse(), execution.size.parse()) {
+                        quality.record_fill(execution.order_id, price, size);
+                    }
+                }
+                Err(e) => {
+                    // The raw update is still delivered below
+                    if let Some(execution_id) = val.get("executionId").and_then(|id| id.as_u64()) {
+                        stream.record_execution(execution_id);
+                    }
+                    Self::parse_error(sinks, stats, ParseErrorEvent::new(Some(channel), e.to_string(), msg_json));
+                }
+            }
         }
+
+        // What an orderEvents message means travels with it as "orderEvent",
+        // and why it cancelled the order as "cancelReason"
+        if let (Some((kind, reason)), Some(fields)) = (order_event, val.as_object_mut()) {
+            fields.insert("orderEvent".to_string(), serde_json::to_value(kind).expect("kind serializes"));
+            if let Some(reason) = reason {
+                fields.insert("cancelReason".to_string(), serde_json::to_value(reason).expect("reason serializes"));
+            }
+        }
+        sinks.emit(event_type, val);
+        if let Some(update) = losscut {
+            sinks.emit("LosscutUpdated", serde_json::to_value(&update).expect("event serializes"));
+        }
+        if let Some((event_type, event)) = amended {
+            sinks.emit(event_type, serde_json::to_value(&event).expect("event serializes"));
+        }
+    }
+
+    fn parse_error(sinks: &OrderSinks, stats: &ClientStats, event: ParseErrorEvent) {
+        warn!("GMO: Failed to decode Private WS {} frame: {}", event.channel.as_deref().unwrap_or("unknown"), event.error);
+        stats.error_class("parse");
+        sinks.emit("ParseError", serde_json::to_value(&event).expect("event serializes"));
     }
 }
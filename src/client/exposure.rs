@@ -0,0 +1,64 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::model::order::Order;
+
+/// Statuses of an order that can still execute.
+const WORKING_STATUSES: [&str; 4] = ["WAITING", "ORDERED", "MODIFYING", "CANCELLING"];
+
+/// Working orders of one symbol.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OrderExposure {
+    pub symbol: String,
+    pub open_orders: usize,
+    pub buy_orders: usize,
+    pub sell_orders: usize,
+    /// Unexecuted size of the working buy / sell orders.
+    pub buy_size: Decimal,
+    pub sell_size: Decimal,
+    /// Highest working buy and lowest working sell LIMIT price.
+    pub best_buy_price: Option<Decimal>,
+    pub best_sell_price: Option<Decimal>,
+}
+
+pub fn is_working(order: &Order) -> bool {
+    WORKING_STATUSES.contains(&order.status.as_str())
+}
+
+fn parse(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap_or_default()
+}
+
+/// Working orders among `orders`, summarized per symbol in symbol order.
+pub fn summarize<'a>(orders: impl IntoIterator<Item = &'a Order>) -> Vec<OrderExposure> {
+    let mut by_symbol: BTreeMap<&str, OrderExposure> = BTreeMap::new();
+    for order in orders.into_iter().filter(|o| is_working(o)) {
+        let exposure = by_symbol.entry(&order.symbol).or_insert_with(|| OrderExposure { symbol: order.symbol.clone(), ..Default::default() });
+        let remaining = (parse(&order.size) - parse(&order.executed_size)).max(Decimal::ZERO);
+        let price = order.price.as_deref().filter(|_| order.execution_type == "LIMIT").map(parse);
+        exposure.open_orders += 1;
+        if order.side == "BUY" {
+            exposure.buy_orders += 1;
+            exposure.buy_size += remaining;
+            exposure.best_buy_price = exposure.best_buy_price.max(price);
+        } else {
+            exposure.sell_orders += 1;
+            exposure.sell_size += remaining;
+            if let Some(price) = price {
+                exposure.best_sell_price = Some(exposure.best_sell_price.map_or(price, |best| best.min(price)));
+            }
+        }
+    }
+    by_symbol
+        .into_values()
+        .map(|mut e| {
+            e.buy_size = e.buy_size.normalize();
+            e.sell_size = e.sell_size.normalize();
+            e.best_buy_price = e.best_buy_price.map(|p| p.normalize());
+            e.best_sell_price = e.best_sell_price.map(|p| p.normalize());
+            e
+        })
+        .collect()
+}
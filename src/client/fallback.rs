@@ -0,0 +1,232 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::time::{sleep, Duration, Instant};
+use tracing::{info, warn};
+
+use crate::client::exposure::is_working;
+use crate::client::quality::ExecutionQuality;
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::order::{Execution, ExecutionsList, Order, OrdersList};
+use crate::model::report::decimal;
+use crate::time::{timestamp_ns, to_ns};
+
+/// Default interval of the REST polls while the fallback is active.
+pub const DEFAULT_FALLBACK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Executions remembered as delivered, for telling a missed one apart.
+const MAX_SEEN_EXECUTIONS: usize = 10_000;
+
+/// How far before the last received frame a fill may be and still count
+/// as missed (venue and local clocks differ).
+const CLOCK_SLACK: chrono::Duration = chrono::Duration::seconds(2);
+
+/// Poll REST for order and execution changes once the Private WS has been
+/// down for `after`, every `interval` until it is back.
+#[derive(Clone, Copy, Debug)]
+pub struct FallbackConfig {
+    pub after: Duration,
+    pub interval: Duration,
+}
+
+struct StreamState {
+    down_since: Option<Instant>,
+    /// Wall clock of the last frame received (or of connecting).
+    last_frame: DateTime<Utc>,
+    executions: HashSet<u64>,
+    execution_order: VecDeque<u64>,
+}
+
+/// Private WS state the fallback poller works from: whether the stream is
+/// down, since when, and which executions it has delivered.
+#[derive(Clone)]
+pub struct StreamHealth {
+    state: Arc<Mutex<StreamState>>,
+}
+
+impl Default for StreamHealth {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(StreamState {
+                down_since: None,
+                last_frame: Utc::now(),
+                executions: HashSet::new(),
+                execution_order: VecDeque::new(),
+            })),
+        }
+    }
+}
+
+impl StreamHealth {
+    /// Start over as down, with nothing received yet.
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.down_since = Some(Instant::now());
+        state.last_frame = Utc::now();
+    }
+
+    /// The stream is not delivering; an earlier start of the outage is kept.
+    pub fn mark_down(&self) {
+        self.state.lock().unwrap().down_since.get_or_insert_with(Instant::now);
+    }
+
+    pub fn mark_up(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.down_since = None;
+        state.last_frame = Utc::now();
+    }
+
+    /// A frame arrived on the stream.
+    pub fn received(&self) {
+        self.state.lock().unwrap().last_frame = Utc::now();
+    }
+
+    /// How long the stream has been down, `None` while up.
+    pub fn down_for(&self) -> Option<Duration> {
+        self.state.lock().unwrap().down_since.map(|since| since.elapsed())
+    }
+
+    /// An execution was delivered; `false` if it already had been.
+    pub fn record_execution(&self, execution_id: u64) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if !state.executions.insert(execution_id) {
+            return false;
+        }
+        state.execution_order.push_back(execution_id);
+        while state.execution_order.len() > MAX_SEEN_EXECUTIONS {
+            if let Some(oldest) = state.execution_order.pop_front() {
+                state.executions.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    fn missed(&self, execution: &Execution) -> bool {
+        let state = self.state.lock().unwrap();
+        let since = to_ns(state.last_frame - CLOCK_SLACK);
+        !state.executions.contains(&execution.execution_id)
+            && timestamp_ns(&execution.timestamp).is_ok_and(|ts| ts >= since)
+    }
+
+    fn is_new(&self, order: &Order) -> bool {
+        let since = to_ns(self.state.lock().unwrap().last_frame - CLOCK_SLACK);
+        timestamp_ns(&order.timestamp).is_ok_and(|ts| ts >= since)
+    }
+}
+
+fn same_amount(a: &str, b: &str) -> bool {
+    match (decimal("amount", a), decimal("amount", b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Whether `polled` shows a change the cache has not seen.
+fn changed(cached: Option<&Order>, polled: &Order) -> bool {
+    let Some(cached) = cached else {
+        return true;
+    };
+    cached.status != polled.status
+        || !same_amount(&cached.executed_size, &polled.executed_size)
+        || match (&cached.price, &polled.price) {
+            (Some(a), Some(b)) => !same_amount(a, b),
+            (a, b) => a.is_some() != b.is_some(),
+        }
+}
+
+/// A REST record as a Private WS frame of `channel`, marked `"synthetic"`.
+fn frame(channel: &str, record: impl serde::Serialize) -> String {
+    let mut value = serde_json::to_value(record).expect("record serializes");
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("channel".to_string(), channel.into());
+        fields.insert("synthetic".to_string(), true.into());
+    }
+    value.to_string()
+}
+
+/// One poll: the `executionEvents` frames the stream missed, oldest first,
+/// then an `orderEvents` frame for each order whose status, executed size
+/// or price moved away from the cache. Symbols polled are those of working
+/// cached orders and of orders submitted through the client.
+pub async fn poll_once(
+    rest: &GmocoinRestClient,
+    health: &StreamHealth,
+    orders: &RwLock<HashMap<u64, Order>>,
+    quality: &ExecutionQuality,
+) -> Result<Vec<String>, GmocoinError> {
+    let cached = orders.read().unwrap().clone();
+    let mut symbols: BTreeSet<String> = cached.values().filter(|o| is_working(o)).map(|o| o.symbol.clone()).collect();
+    symbols.extend(quality.symbols());
+
+    let mut executions = Vec::new();
+    let mut updates = Vec::new();
+    for symbol in &symbols {
+        let latest = ExecutionsList::deserialize(rest.get_latest_executions(symbol, 1, 100).await?)?.list;
+        executions.extend(latest.into_iter().rev().filter(|e| health.missed(e)));
+
+        let active = OrdersList::deserialize(rest.get_active_orders(symbol, 1, 100).await?)?.list;
+        let active_ids: HashSet<u64> = active.iter().map(|o| o.order_id).collect();
+        updates.extend(active.into_iter().filter(|o| match cached.get(&o.order_id) {
+            Some(known) => changed(Some(known), o),
+            None => health.is_new(o),
+        }));
+
+        let gone: Vec<u64> = cached
+            .values()
+            .filter(|o| o.symbol == *symbol && is_working(o) && !active_ids.contains(&o.order_id))
+            .map(|o| o.order_id)
+            .collect();
+        if !gone.is_empty() {
+            let finished = rest.get_orders(&gone).await?.list;
+            updates.extend(finished.into_iter().filter(|o| changed(cached.get(&o.order_id), o)));
+        }
+    }
+
+    Ok(executions
+        .iter()
+        .map(|e| frame("executionEvents", e))
+        .chain(updates.iter().map(|o| frame("orderEvents", o)))
+        .collect())
+}
+
+/// Watch `health` and, while the stream has been down for `config.after`,
+/// poll REST every `config.interval` and hand the synthesized frames to
+/// `deliver`. `"PollingFallback"` is emitted when polling starts and stops;
+/// failed polls are logged and retried at the next interval.
+pub(crate) async fn poller<E, D, Fut>(
+    rest: GmocoinRestClient,
+    health: StreamHealth,
+    orders: Arc<RwLock<HashMap<u64, Order>>>,
+    quality: ExecutionQuality,
+    config: FallbackConfig,
+    emit: E,
+    deliver: D,
+) where
+    E: Fn(&'static str, serde_json::Value),
+    D: Fn(Vec<String>) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let mut active = false;
+    loop {
+        sleep(config.interval).await;
+        let Some(down) = health.down_for().filter(|down| *down >= config.after) else {
+            if active {
+                active = false;
+                info!("GMO: Private WS is back, REST polling fallback stopped");
+                emit("PollingFallback", serde_json::json!({"active": false, "down_ms": 0}));
+            }
+            continue;
+        };
+        if !active {
+            active = true;
+            warn!("GMO: Private WS down for {:?}, polling REST for order updates", down);
+            emit("PollingFallback", serde_json::json!({"active": true, "down_ms": down.as_millis() as u64}));
+        }
+        match poll_once(&rest, &health, &orders, &quality).await {
+            Ok(frames) => deliver(frames).await,
+            Err(e) => warn!("GMO: REST polling fallback failed: {}", e),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use std::collections::HashMap;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::client::Request;
+
+use crate::error::GmocoinError;
+
+/// Headers the adapter manages itself; overriding them would break
+/// request signing or the WebSocket handshake.
+const RESERVED: [&str; 6] = ["api-key", "api-sign", "api-timestamp", "host", "connection", "upgrade"];
+
+/// Static identification headers (e.g. for corporate gateways) sent on
+/// every REST request and WebSocket handshake.
+#[derive(Clone, Debug, Default)]
+pub struct StaticHeaders {
+    headers: HeaderMap,
+}
+
+impl StaticHeaders {
+    pub fn new(
+        user_agent: Option<String>,
+        extra_headers: Option<HashMap<String, String>>,
+    ) -> Result<Self, GmocoinError> {
+        let invalid = GmocoinError::InvalidInput;
+        let mut headers = HeaderMap::new();
+        for (name, value) in extra_headers.unwrap_or_default() {
+            let header = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| invalid(format!("Invalid header name '{}': {}", name, e)))?;
+            if RESERVED.contains(&header.as_str()) || header.as_str().starts_with("sec-websocket-") {
+                return Err(invalid(format!("Header '{}' is managed by the adapter", name)));
+            }
+            let value = HeaderValue::from_str(&value)
+                .map_err(|e| invalid(format!("Invalid value for header '{}': {}", name, e)))?;
+            headers.insert(header, value);
+        }
+        if let Some(ua) = user_agent {
+            let value = HeaderValue::from_str(&ua)
+                .map_err(|e| invalid(format!("Invalid user_agent: {}", e)))?;
+            headers.insert(USER_AGENT, value);
+        }
+        Ok(Self { headers })
+    }
+
+    /// Apply to a reqwest client builder.
+    pub fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        if self.headers.is_empty() {
+            builder
+        } else {
+            builder.default_headers(self.headers.clone())
+        }
+    }
+
+    /// WebSocket handshake request for `url` carrying these headers.
+    pub fn ws_request(&self, url: &str) -> Result<Request, tokio_tungstenite::tungstenite::Error> {
+        let mut request = url.into_client_request()?;
+        request.headers_mut().extend(self.headers.clone());
+        Ok(request)
+    }
+}
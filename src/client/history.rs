@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::order::{Execution, ExecutionsList, Order};
+use crate::model::report::decimal;
+use crate::time::timestamp_ns;
+
+/// Executions per `latestExecutions` page (the API maximum).
+const PAGE_SIZE: i32 = 100;
+
+/// Order ids per `/v1/orders` call (the API maximum).
+const ORDERS_PER_CALL: usize = 10;
+
+/// Statuses after which an order no longer changes.
+const FINAL_STATUSES: [&str; 3] = ["EXECUTED", "CANCELED", "EXPIRED"];
+
+/// A finished order with its fills rolled up.
+#[derive(Clone, Debug, Serialize)]
+pub struct ClosedOrder {
+    pub order_id: u64,
+    pub symbol: String,
+    pub side: String,
+    pub execution_type: String,
+    pub settle_type: Option<String>,
+    /// `EXECUTED`, `CANCELED` or `EXPIRED`.
+    pub status: String,
+    pub price: Option<Decimal>,
+    pub size: Decimal,
+    pub filled: Decimal,
+    /// Size-weighted over the fills.
+    pub avg_price: Option<Decimal>,
+    pub fee: Decimal,
+    /// Realized profit and loss of closing fills.
+    pub loss_gain: Decimal,
+    pub executions: usize,
+    /// UNIX ns of the order.
+    pub ordered_at: u64,
+    /// UNIX ns of the last fill.
+    pub last_fill_at: Option<u64>,
+}
+
+impl ClosedOrder {
+    fn new(order: &Order, fills: &[Execution]) -> Result<Self, GmocoinError> {
+        let mut filled = Decimal::ZERO;
+        let mut notional = Decimal::ZERO;
+        let mut fee = Decimal::ZERO;
+        let mut loss_gain = Decimal::ZERO;
+        let mut last_fill_at = None;
+        for fill in fills {
+            let size = decimal("size", &fill.size)?;
+            filled += size;
+            notional += size * decimal("price", &fill.price)?;
+            fee += decimal("fee", &fill.fee)?;
+            if let Some(value) = fill.loss_gain.as_deref() {
+                loss_gain += decimal("lossGain", value)?;
+            }
+            last_fill_at = last_fill_at.max(Some(timestamp_ns(&fill.timestamp)?));
+        }
+        Ok(Self {
+            order_id: order.order_id,
+            symbol: order.symbol.clone(),
+            side: order.side.clone(),
+            execution_type: order.execution_type.clone(),
+            settle_type: order.settle_type.clone(),
+            status: order.status.clone(),
+            price: order.price.as_deref().filter(|p| !p.is_empty()).map(|p| decimal("price", p)).transpose()?,
+            size: decimal("size", &order.size)?,
+            filled: filled.normalize(),
+            avg_price: (filled > Decimal::ZERO).then(|| (notional / filled).round_dp(8).normalize()),
+            fee: fee.normalize(),
+            loss_gain: loss_gain.normalize(),
+            executions: fills.len(),
+            ordered_at: timestamp_ns(&order.timestamp)?,
+            last_fill_at,
+        })
+    }
+}
+
+fn within(timestamp: &str, start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Result<bool, GmocoinError> {
+    let ns = timestamp_ns(timestamp)? as i64;
+    Ok(start.is_none_or(|s| ns >= s.timestamp_nanos_opt().unwrap_or(i64::MIN))
+        && end.is_none_or(|e| ns < e.timestamp_nanos_opt().unwrap_or(i64::MAX)))
+}
+
+/// Every execution of `symbol` from `latestExecutions`, newest first,
+/// paging until one older than `start` or a short page.
+async fn executions_since(
+    rest: &GmocoinRestClient,
+    symbol: &str,
+    start: Option<DateTime<Utc>>,
+) -> Result<Vec<Execution>, GmocoinError> {
+    let mut executions = Vec::new();
+    for page in 1.. {
+        let list = ExecutionsList::deserialize(rest.get_latest_executions(symbol, page, PAGE_SIZE).await?)?.list;
+        let full = list.len() == PAGE_SIZE as usize;
+        let mut reached_start = false;
+        for execution in list {
+            if !within(&execution.timestamp, start, None)? {
+                reached_start = true;
+                continue;
+            }
+            executions.push(execution);
+        }
+        if !full || reached_start {
+            break;
+        }
+    }
+    Ok(executions)
+}
+
+/// Columns of an execution export, in order; the same in CSV and Parquet.
+pub const EXECUTION_COLUMNS: [&str; 10] =
+    ["execution_id", "order_id", "symbol", "side", "settle_type", "size", "price", "loss_gain", "fee", "timestamp"];
+
+/// Every execution of `symbols` in `[start, end)`, oldest first. Like
+/// `order_history`, limited to what `latestExecutions` keeps.
+pub async fn execution_history(
+    rest: &GmocoinRestClient,
+    symbols: &[String],
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+) -> Result<Vec<Execution>, GmocoinError> {
+    let mut executions = Vec::new();
+    for symbol in symbols {
+        for execution in executions_since(rest, symbol, start).await? {
+            if within(&execution.timestamp, None, end)? {
+                executions.push(execution);
+            }
+        }
+    }
+    let mut keyed = executions.into_iter().map(|e| Ok((timestamp_ns(&e.timestamp)?, e))).collect::<Result<Vec<_>, GmocoinError>>()?;
+    keyed.sort_by_key(|(ts, e)| (*ts, e.execution_id));
+    Ok(keyed.into_iter().map(|(_, e)| e).collect())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write `executions` as CSV with an `EXECUTION_COLUMNS` header. Amounts
+/// are GMO's decimal strings unchanged; `timestamp` is GMO's UTC ISO-8601.
+pub fn write_csv(executions: &[Execution], mut out: impl Write) -> io::Result<()> {
+    writeln!(out, "{}", EXECUTION_COLUMNS.join(","))?;
+    for e in executions {
+        let row = [
+            e.execution_id.to_string(),
+            e.order_id.to_string(),
+            e.symbol.clone(),
+            e.side.clone(),
+            e.settle_type.clone().unwrap_or_default(),
+            e.size.clone(),
+            e.price.clone(),
+            e.loss_gain.clone().unwrap_or_default(),
+            e.fee.clone(),
+            e.timestamp.clone(),
+        ];
+        writeln!(out, "{}", row.iter().map(|v| csv_field(v)).collect::<Vec<_>>().join(","))?;
+    }
+    out.flush()
+}
+
+/// Closed orders of `symbol` with fills in `[start, end)`, oldest first.
+///
+/// GMO has no closed-order listing: fills come from `latestExecutions`
+/// (paged), their orders from `/v1/orders`, and only orders in a final
+/// status are returned. Orders cancelled or expired without a fill, and
+/// fills older than `latestExecutions` keeps, cannot be found this way.
+pub async fn order_history(
+    rest: &GmocoinRestClient,
+    symbol: &str,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+) -> Result<Vec<ClosedOrder>, GmocoinError> {
+    let mut fills: BTreeMap<u64, Vec<Execution>> = BTreeMap::new();
+    for execution in executions_since(rest, symbol, start).await? {
+        fills.entry(execution.order_id).or_default().push(execution);
+    }
+    // An order is in the window by its fills
+    let mut ids = Vec::new();
+    for (order_id, order_fills) in &fills {
+        let mut in_window = false;
+        for fill in order_fills {
+            in_window |= within(&fill.timestamp, None, end)?;
+        }
+        if in_window {
+            ids.push(*order_id);
+        }
+    }
+    let mut history = Vec::new();
+    for chunk in ids.chunks(ORDERS_PER_CALL) {
+        for order in rest.get_orders(chunk).await?.list {
+            let Some(order_fills) = fills.get(&order.order_id) else { continue };
+            if !FINAL_STATUSES.contains(&order.status.as_str()) {
+                continue;
+            }
+            // Fills before `start` were not paged in; fetch the order's own
+            let executed = decimal("executedSize", &order.executed_size)?;
+            let seen: Decimal = order_fills.iter().map(|f| decimal("size", &f.size)).sum::<Result<_, _>>()?;
+            if seen < executed {
+                let all = rest.get_executions_for_order(order.order_id).await?.list;
+                history.push(ClosedOrder::new(&order, &all)?);
+            } else {
+                history.push(ClosedOrder::new(&order, order_fills)?);
+            }
+        }
+    }
+    history.sort_by_key(|o| (o.ordered_at, o.order_id));
+    Ok(history)
+}
@@ -0,0 +1,126 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+use crate::client::rest::GmocoinRestClient;
+use crate::model::market_data::SymbolInfo;
+
+/// How a symbol differs between two `/v1/symbols` polls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstrumentAction {
+    /// Newly listed.
+    Added,
+    /// No longer listed.
+    Removed,
+    /// Tick, size or fee parameters changed.
+    Changed,
+}
+
+/// A parameter before and after a change.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// A symbol GMO added, delisted or re-parameterized.
+#[derive(Clone, Debug, Serialize)]
+pub struct InstrumentEvent {
+    pub action: InstrumentAction,
+    pub symbol: String,
+    /// Changed parameters by their `/v1/symbols` name; empty unless
+    /// `changed`.
+    pub changes: BTreeMap<&'static str, FieldChange>,
+    /// Current parameters; `None` once removed.
+    pub info: Option<SymbolInfo>,
+}
+
+fn parameters(info: &SymbolInfo) -> [(&'static str, &Option<String>); 7] {
+    [
+        ("tickSize", &info.tick_size),
+        ("sizeStep", &info.size_step),
+        ("minOrderSize", &info.min_order_size),
+        ("maxOrderSize", &info.max_order_size),
+        ("minCloseOrderSize", &info.min_close_order_size),
+        ("takerFee", &info.taker_fee),
+        ("makerFee", &info.maker_fee),
+    ]
+}
+
+/// Equal as numbers where both parse, so `"0.0010"` matches `"0.001"`.
+fn same(old: &Option<String>, new: &Option<String>) -> bool {
+    match (old.as_deref(), new.as_deref()) {
+        (Some(a), Some(b)) => match (Decimal::from_str(a), Decimal::from_str(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        },
+        (a, b) => a == b,
+    }
+}
+
+/// The events turning the `old` symbol list into `new`, by symbol.
+pub fn diff_symbols(old: &[SymbolInfo], new: &[SymbolInfo]) -> Vec<InstrumentEvent> {
+    let old: BTreeMap<&str, &SymbolInfo> = old.iter().map(|s| (s.symbol.as_str(), s)).collect();
+    let new: BTreeMap<&str, &SymbolInfo> = new.iter().map(|s| (s.symbol.as_str(), s)).collect();
+    let mut events = Vec::new();
+    for (symbol, current) in &new {
+        let Some(previous) = old.get(symbol) else {
+            events.push(InstrumentEvent {
+                action: InstrumentAction::Added,
+                symbol: symbol.to_string(),
+                changes: BTreeMap::new(),
+                info: Some((*current).clone()),
+            });
+            continue;
+        };
+        let changes: BTreeMap<_, _> = parameters(previous)
+            .into_iter()
+            .zip(parameters(current))
+            .filter(|((_, old), (_, new))| !same(old, new))
+            .map(|((name, old), (_, new))| (name, FieldChange { old: old.clone(), new: new.clone() }))
+            .collect();
+        if !changes.is_empty() {
+            events.push(InstrumentEvent {
+                action: InstrumentAction::Changed,
+                symbol: symbol.to_string(),
+                changes,
+                info: Some((*current).clone()),
+            });
+        }
+    }
+    events.extend(old.keys().filter(|symbol| !new.contains_key(*symbol)).map(|symbol| InstrumentEvent {
+        action: InstrumentAction::Removed,
+        symbol: symbol.to_string(),
+        changes: BTreeMap::new(),
+        info: None,
+    }));
+    events
+}
+
+/// Poll `/v1/symbols` every `interval`, reporting differences from the
+/// previous poll through `on_event`. The first poll only sets the
+/// baseline; failed or empty polls are skipped rather than read as
+/// delistings.
+pub(crate) async fn refresh_loop(client: GmocoinRestClient, interval: Duration, on_event: impl Fn(InstrumentEvent)) {
+    let mut known: Option<Vec<SymbolInfo>> = None;
+    loop {
+        match client.refresh_symbols().await {
+            Ok(symbols) if symbols.is_empty() => warn!("GMO: Instrument refresh returned no symbols, ignoring"),
+            Ok(symbols) => {
+                if let Some(previous) = &known {
+                    for event in diff_symbols(previous, &symbols) {
+                        info!("GMO: Instrument {} {:?} {:?}", event.symbol, event.action, event.changes.keys());
+                        on_event(event);
+                    }
+                }
+                known = Some(symbols);
+            }
+            Err(e) => warn!("GMO: Instrument refresh failed: {}", e),
+        }
+        sleep(interval).await;
+    }
+}
@@ -0,0 +1,112 @@
+//! Kline downloads over a time range.
+//!
+//! `/v1/klines` is queried per `date`: a trading day (`YYYYMMDD`, 06:00 JST
+//! to 06:00 JST the next day) for minute and hour intervals, a year
+//! (`YYYY`, JST) for 4 hours and longer. A UTC range therefore maps onto
+//! JST dates, not the UTC dates it spans.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, Utc};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::market_data::Kline;
+use crate::time::jst;
+
+/// Intervals requested per trading day.
+const DAILY_INTERVALS: [&str; 6] = ["1min", "5min", "10min", "15min", "30min", "1hour"];
+
+/// Intervals requested per year.
+const YEARLY_INTERVALS: [&str; 6] = ["4hour", "8hour", "12hour", "1day", "1week", "1month"];
+
+/// Start of the day a `YYYYMMDD` request covers (JST).
+fn day_start() -> NaiveTime {
+    NaiveTime::from_hms_opt(6, 0, 0).expect("valid time")
+}
+
+/// How `/v1/klines` groups `interval`: `true` per trading day, `false` per
+/// year.
+fn is_daily(interval: &str) -> Result<bool, GmocoinError> {
+    if DAILY_INTERVALS.contains(&interval) {
+        Ok(true)
+    } else if YEARLY_INTERVALS.contains(&interval) {
+        Ok(false)
+    } else {
+        Err(GmocoinError::InvalidInput(format!("Unknown kline interval '{}'", interval)))
+    }
+}
+
+/// The trading day `ts` falls in, as `/v1/klines` counts them.
+fn trading_day(ts: DateTime<Utc>) -> NaiveDate {
+    let local = ts.with_timezone(&jst()).naive_local();
+    if local.time() < day_start() { local.date() - TimeDelta::days(1) } else { local.date() }
+}
+
+fn jst_year(ts: DateTime<Utc>) -> i32 {
+    ts.with_timezone(&jst()).year()
+}
+
+/// The `date` holding the `interval` kline that contains `ts`.
+pub fn kline_date(interval: &str, ts: DateTime<Utc>) -> Result<String, GmocoinError> {
+    Ok(if is_daily(interval)? {
+        trading_day(ts).format("%Y%m%d").to_string()
+    } else {
+        jst_year(ts).to_string()
+    })
+}
+
+/// The `date`s to request for the `interval` klines opening in
+/// `[start, end)`, oldest first.
+pub fn kline_dates(interval: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<String>, GmocoinError> {
+    if end <= start {
+        return Ok(Vec::new());
+    }
+    let last = end - TimeDelta::nanoseconds(1);
+    Ok(if is_daily(interval)? {
+        let last = trading_day(last);
+        trading_day(start)
+            .iter_days()
+            .take_while(|day| *day <= last)
+            .map(|day| day.format("%Y%m%d").to_string())
+            .collect()
+    } else {
+        (jst_year(start)..=jst_year(last)).map(|year| year.to_string()).collect()
+    })
+}
+
+/// Every `interval` kline of `symbol` opening in `[start, end)`, oldest
+/// first, from one request per `kline_dates` date.
+pub async fn klines_between(
+    rest: &GmocoinRestClient,
+    symbol: &str,
+    interval: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Kline>, GmocoinError> {
+    let (start_ms, end_ms) = (start.timestamp_millis(), end.timestamp_millis());
+    let mut klines = Vec::new();
+    for date in kline_dates(interval, start, end)? {
+        for kline in rest.get_klines(symbol, interval, &date).await? {
+            let open_ms: i64 = kline.open_time.parse().map_err(|_| {
+                GmocoinError::InvalidInput(format!("Invalid kline openTime '{}'", kline.open_time))
+            })?;
+            if (start_ms..end_ms).contains(&open_ms) {
+                klines.push((open_ms, kline));
+            }
+        }
+    }
+    klines.sort_by_key(|(open_ms, _)| *open_ms);
+    klines.dedup_by_key(|(open_ms, _)| *open_ms);
+    Ok(klines.into_iter().map(|(_, kline)| kline).collect())
+}
+
+/// The `/v1/klines` `date` for the `interval` kline containing `ts` (UNIX
+/// ns, default now): the trading day starting 06:00 JST as `YYYYMMDD`, or
+/// the JST year as `YYYY` for 4 hours and longer.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "kline_date", signature = (interval, ts=None))]
+pub fn py_kline_date(interval: &str, ts: Option<u64>) -> PyResult<String> {
+    Ok(kline_date(interval, ts.map(crate::time::from_ns).unwrap_or_else(Utc::now))?)
+}
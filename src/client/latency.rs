@@ -0,0 +1,92 @@
+use serde::Serialize;
+use tokio::time::{sleep, Duration};
+use tracing::warn;
+
+use crate::stats::ClientStats;
+
+/// Longest check interval of the event gap watchdog.
+const MAX_GAP_CHECK: Duration = Duration::from_secs(1);
+
+/// Latency budgets of an execution client; `None` is unchecked.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LatencyBudgets {
+    pub order_ack: Option<Duration>,
+    pub event_gap: Option<Duration>,
+}
+
+/// Which latency budget an alarm is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyKind {
+    /// From sending an order to GMO's response.
+    OrderAck,
+    /// Time since the last WS message while connected.
+    EventGap,
+}
+
+/// A latency budget breached, emitted as `"LatencyAlarm"` (execution
+/// client) or on the `"latency_alarm"` channel (data client).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LatencyAlarm {
+    pub kind: LatencyKind,
+    pub latency_ms: u64,
+    pub threshold_ms: u64,
+    /// The order answered late (`order_ack`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl LatencyAlarm {
+    /// `order_id` was answered `ack` after it was sent; an alarm if that is
+    /// over `budget`.
+    pub fn order_ack(budget: Option<Duration>, ack: Duration, order_id: u64, symbol: &str) -> Option<Self> {
+        let budget = budget.filter(|budget| ack > *budget)?;
+        Some(Self {
+            kind: LatencyKind::OrderAck,
+            latency_ms: ack.as_millis() as u64,
+            threshold_ms: budget.as_millis() as u64,
+            order_id: Some(order_id),
+            symbol: Some(symbol.to_string()),
+        })
+    }
+
+    pub fn log(&self) {
+        match self.kind {
+            LatencyKind::OrderAck => warn!(
+                "GMO: Order {} answered after {}ms (budget {}ms)",
+                self.order_id.unwrap_or_default(), self.latency_ms, self.threshold_ms
+            ),
+            LatencyKind::EventGap => warn!("GMO: No WS message for {}ms (budget {}ms)", self.latency_ms, self.threshold_ms),
+        }
+    }
+}
+
+/// Emit an `EventGap` alarm once per gap when the WS of `stats` is
+/// connected but has received nothing for `budget`.
+pub(crate) async fn gap_watchdog(stats: ClientStats, budget: Duration, emit: impl Fn(LatencyAlarm)) {
+    let mut alarmed_since = None;
+    loop {
+        sleep((budget / 4).min(MAX_GAP_CHECK)).await;
+        let liveness = stats.liveness();
+        if liveness.ws_connected != Some(true) {
+            continue;
+        }
+        // A fresh connection counts as the start of a gap
+        let Some(since) = stats.last_message_ms().max(liveness.ws_changed_ms) else {
+            continue;
+        };
+        let gap = chrono::Utc::now().timestamp_millis() - since;
+        if gap > budget.as_millis() as i64 && alarmed_since != Some(since) {
+            alarmed_since = Some(since);
+            emit(LatencyAlarm {
+                kind: LatencyKind::EventGap,
+                latency_ms: gap as u64,
+                threshold_ms: budget.as_millis() as u64,
+                order_id: None,
+                symbol: None,
+            });
+        }
+    }
+}
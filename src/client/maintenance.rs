@@ -0,0 +1,175 @@
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc, Weekday};
+use serde::Serialize;
+use std::str::FromStr;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+use crate::client::calendar::TradingCalendar;
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::time::{from_jst, jst};
+
+/// A maintenance window, `"HH:MM-HH:MM"` in JST: daily, or weekly with a
+/// weekday prefix (`"Sat 09:00-11:00"`). May wrap past midnight
+/// (`"23:30-00:30"`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MaintenanceWindow {
+    weekday: Option<Weekday>,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl FromStr for MaintenanceWindow {
+    type Err = GmocoinError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || GmocoinError::InvalidInput(format!(
+            "Invalid maintenance window '{}' (expected \"HH:MM-HH:MM\" or \"Sat HH:MM-HH:MM\" in JST)",
+            spec
+        ));
+        let prefix = spec.trim().split_once(' ').filter(|(day, _)| day.chars().all(char::is_alphabetic));
+        let (weekday, times) = match prefix {
+            Some((day, times)) => (Some(day.parse::<Weekday>().map_err(|_| invalid())?), times),
+            None => (None, spec),
+        };
+        let (start, end) = times.split_once('-').ok_or_else(invalid)?;
+        let parse = |t: &str| NaiveTime::parse_from_str(t.trim(), "%H:%M").map_err(|_| invalid());
+        let (start, end) = (parse(start)?, parse(end)?);
+        if start == end {
+            return Err(invalid());
+        }
+        Ok(Self { weekday, start, end })
+    }
+}
+
+impl MaintenanceWindow {
+    fn length(&self) -> TimeDelta {
+        let length = self.end.signed_duration_since(self.start);
+        if length < TimeDelta::zero() { length + TimeDelta::days(1) } else { length }
+    }
+
+    /// Start of the occurrence beginning on `day` (JST), if any.
+    fn start_on(&self, day: NaiveDate) -> Option<NaiveDateTime> {
+        self.weekday.is_none_or(|w| day.weekday() == w).then(|| day.and_time(self.start))
+    }
+
+    /// Time left in the window at `now`, or `None` outside it.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let now = now.with_timezone(&jst()).naive_local();
+        [now.date() - TimeDelta::days(1), now.date()]
+            .into_iter()
+            .filter_map(|day| self.start_on(day))
+            .map(|start| start + self.length() - now)
+            .find(|left| *left > TimeDelta::zero() && *left <= self.length())
+            .and_then(|left| left.to_std().ok())
+    }
+
+    /// The next time the window opens after `now`.
+    pub fn next_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let local = now.with_timezone(&jst()).naive_local();
+        let start = (0..=7)
+            .filter_map(|days| self.start_on(local.date() + TimeDelta::days(days)))
+            .find(|start| *start > local)
+            .expect("a weekly window starts within 8 days");
+        from_jst(start)
+    }
+}
+
+/// When the WS loops should hold off reconnecting.
+#[derive(Clone, Debug)]
+pub struct MaintenanceConfig {
+    /// Scheduled maintenance windows.
+    pub calendar: TradingCalendar,
+    /// Ask `/v1/status` before reconnecting, and keep waiting while it
+    /// reports `MAINTENANCE`.
+    pub status_check: bool,
+    /// How often `/v1/status` is polled during maintenance.
+    pub status_poll: Duration,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            calendar: TradingCalendar::default(),
+            status_check: true,
+            status_poll: Duration::from_secs(60),
+        }
+    }
+}
+
+impl MaintenanceConfig {
+    /// Parse `"HH:MM-HH:MM"` / `"Sat HH:MM-HH:MM"` window specs; defaults
+    /// for anything unset.
+    pub fn new(windows: Option<Vec<String>>, status_check: Option<bool>) -> Result<Self, GmocoinError> {
+        Ok(Self {
+            calendar: TradingCalendar::parse(windows.unwrap_or_default())?,
+            status_check: status_check.unwrap_or(true),
+            ..Self::default()
+        })
+    }
+}
+
+/// Start or end of a maintenance period seen by a WS loop.
+#[derive(Clone, Debug, Serialize)]
+pub struct MaintenanceEvent {
+    /// `"started"` or `"ended"`
+    pub state: &'static str,
+    /// `"schedule"` (a configured window) or `"status"` (`/v1/status`)
+    pub source: &'static str,
+    /// Scheduled end (ISO 8601) for windows; unknown for `"status"`
+    pub until: Option<String>,
+}
+
+/// Waits out maintenance between reconnect attempts of a WS loop.
+#[derive(Clone)]
+pub(crate) struct MaintenanceGuard {
+    config: MaintenanceConfig,
+    status_client: Option<GmocoinRestClient>,
+}
+
+impl MaintenanceGuard {
+    pub(crate) fn new(config: MaintenanceConfig, status_client: Option<GmocoinRestClient>) -> Self {
+        let status_client = status_client.filter(|_| config.status_check);
+        Self { config, status_client }
+    }
+
+    pub(crate) fn calendar(&self) -> &TradingCalendar {
+        &self.config.calendar
+    }
+
+    async fn venue_in_maintenance(&self) -> bool {
+        let Some(client) = &self.status_client else { return false };
+        match client.get_status().await {
+            Ok(data) => data.get("status").and_then(|s| s.as_str()) == Some("MAINTENANCE"),
+            Err(e) => e.is_maintenance(),
+        }
+    }
+
+    /// Hold off while a scheduled window is open or the venue reports
+    /// maintenance, reporting its start and end through `on_event`.
+    /// Returns whether it waited, so the caller can reset its backoff.
+    pub(crate) async fn wait_out(&self, name: &str, on_event: impl Fn(MaintenanceEvent)) -> bool {
+        let mut started: Option<&'static str> = None;
+        loop {
+            let now = Utc::now();
+            let (source, until, pause) = if let Some(left) = self.config.calendar.maintenance_remaining(now) {
+                let end = now + TimeDelta::from_std(left).unwrap_or_default();
+                ("schedule", Some(end.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()), left)
+            } else if self.venue_in_maintenance().await {
+                ("status", None, self.config.status_poll)
+            } else {
+                break;
+            };
+            if started.is_none() {
+                warn!("GMO: {} in maintenance ({}), holding off reconnects", name, source);
+                on_event(MaintenanceEvent { state: "started", source, until });
+                started = Some(source);
+            }
+            sleep(pause).await;
+        }
+        let Some(source) = started else { return false };
+        info!("GMO: Maintenance over, reconnecting {}", name);
+        on_event(MaintenanceEvent { state: "ended", source, until: None });
+        true
+    }
+}
@@ -1,3 +1,31 @@
+pub mod algo;
+pub mod amends;
+pub mod backfill;
+pub mod book_mode;
+pub mod calendar;
+pub mod checkpoint;
+pub mod conflate;
+pub mod deadman;
+pub mod dedupe;
+pub mod endpoint;
+pub mod exposure;
+pub mod fallback;
+pub mod headers;
+pub mod history;
+pub mod instruments;
+pub mod klines;
+pub mod latency;
+pub mod maintenance;
+pub mod positions;
+pub mod profile;
+pub mod quality;
+pub mod reconnect;
+pub mod replay;
 pub mod rest;
+pub mod rollover;
+pub mod session;
+pub mod throttle;
 pub mod data_client;
 pub mod execution_client;
+#[cfg(feature = "python")]
+pub mod sim_execution_client;
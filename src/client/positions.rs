@@ -0,0 +1,200 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::time::{sleep, Duration};
+use tracing::warn;
+
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::order::{LosscutPriceAck, Position, PositionSummary};
+use crate::model::report::decimal;
+use crate::time::now_ns;
+
+/// Default interval of the REST refresh of the position cache.
+pub const DEFAULT_POSITION_REFRESH: Duration = Duration::from_secs(60);
+
+/// Latest position summary of one symbol and side.
+#[cfg_attr(feature = "python", pyclass(skip_from_py_object, frozen, get_all))]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PositionSnapshot {
+    pub symbol: String,
+    /// `"BUY"` or `"SELL"`
+    pub side: String,
+    /// `sumPositionQuantity`
+    pub size: String,
+    /// `sumOrderQuantity`: size of the closing orders working against it.
+    pub order_size: Option<String>,
+    /// `averagePositionRate`
+    pub avg_price: String,
+    /// `positionLossGain`: unrealized P&L.
+    pub loss_gain: String,
+    /// UNIX ns when the cache took it in.
+    pub updated_at: u64,
+    /// `"ws"` (`positionSummaryEvents`) or `"rest"` (`/v1/positionSummary`).
+    pub source: String,
+}
+
+impl PositionSnapshot {
+    fn new(summary: &PositionSummary, source: &str, updated_at: u64) -> Self {
+        Self {
+            symbol: summary.symbol.clone(),
+            side: summary.side.clone(),
+            size: summary.sum_position_quantity.clone(),
+            order_size: summary.sum_order_quantity.clone(),
+            avg_price: summary.average_position_rate.clone(),
+            loss_gain: summary.position_loss_gain.clone(),
+            updated_at,
+            source: source.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PositionSnapshot {
+    fn __repr__(&self) -> String {
+        format!("PositionSnapshot(symbol={}, side={}, size={}, avg_price={})", self.symbol, self.side, self.size, self.avg_price)
+    }
+}
+
+fn is_flat(summary: &PositionSummary) -> bool {
+    decimal("sumPositionQuantity", &summary.sum_position_quantity).is_ok_and(|size| size.is_zero())
+}
+
+/// Position summaries by symbol and side, kept from `positionSummaryEvents`
+/// and replaced by periodic `/v1/positionSummary` snapshots. Flat sides
+/// are dropped.
+#[derive(Clone, Default)]
+pub struct PositionCache {
+    positions: Arc<Mutex<HashMap<(String, String), PositionSnapshot>>>,
+    refreshed_at: Arc<Mutex<Option<u64>>>,
+}
+
+impl PositionCache {
+    /// Take in a `positionSummaryEvents` message.
+    pub fn apply(&self, summary: &PositionSummary) {
+        let key = (summary.symbol.clone(), summary.side.clone());
+        let mut positions = self.positions.lock().unwrap();
+        if is_flat(summary) {
+            positions.remove(&key);
+        } else {
+            positions.insert(key, PositionSnapshot::new(summary, "ws", now_ns()));
+        }
+    }
+
+    /// Replace the cache with a REST snapshot requested at `requested_at`
+    /// (UNIX ns); sides updated by the WS since then are kept.
+    pub fn replace(&self, summaries: &[PositionSummary], requested_at: u64) {
+        let now = now_ns();
+        let mut positions = self.positions.lock().unwrap();
+        let mut fresh: HashMap<_, _> = summaries
+            .iter()
+            .filter(|s| !is_flat(s))
+            .map(|s| ((s.symbol.clone(), s.side.clone()), PositionSnapshot::new(s, "rest", now)))
+            .collect();
+        for (key, snapshot) in positions.drain() {
+            if snapshot.updated_at > requested_at {
+                fresh.insert(key, snapshot);
+            }
+        }
+        *positions = fresh;
+        *self.refreshed_at.lock().unwrap() = Some(now);
+    }
+
+    /// The position of `symbol` on `side`, or on the one side held when
+    /// `side` is `None`; an error if both sides are held.
+    pub fn get(&self, symbol: &str, side: Option<&str>) -> Result<Option<PositionSnapshot>, GmocoinError> {
+        let positions = self.positions.lock().unwrap();
+        if let Some(side) = side {
+            return Ok(positions.get(&(symbol.to_string(), side.to_uppercase())).cloned());
+        }
+        let mut held = positions.values().filter(|p| p.symbol == symbol);
+        match (held.next(), held.next()) {
+            (Some(_), Some(_)) => Err(GmocoinError::InvalidInput(format!("{} is held on both sides; pass side", symbol))),
+            (position, _) => Ok(position.cloned()),
+        }
+    }
+
+    /// Every cached position, by symbol then side.
+    pub fn all(&self) -> Vec<PositionSnapshot> {
+        let mut all: Vec<_> = self.positions.lock().unwrap().values().cloned().collect();
+        all.sort_by(|a, b| (&a.symbol, &a.side).cmp(&(&b.symbol, &b.side)));
+        all
+    }
+
+    /// UNIX ns of the last REST refresh.
+    pub fn refreshed_at(&self) -> Option<u64> {
+        *self.refreshed_at.lock().unwrap()
+    }
+
+    /// Load `/v1/positionSummary` into the cache.
+    pub async fn refresh(&self, rest: &GmocoinRestClient) -> Result<(), GmocoinError> {
+        let requested_at = now_ns();
+        let summary = rest.get_position_summary(None).await?;
+        self.replace(&summary.list, requested_at);
+        Ok(())
+    }
+}
+
+/// Refresh `cache` from REST now and every `interval`; failures are logged
+/// and retried at the next interval.
+pub(crate) async fn refresher(rest: GmocoinRestClient, cache: PositionCache, interval: Duration) {
+    loop {
+        if let Err(e) = cache.refresh(&rest).await {
+            warn!("GMO: Position summary refresh failed: {}", e);
+        }
+        sleep(interval).await;
+    }
+}
+
+/// A losscut price change seen on `positionEvents`, emitted as
+/// `"LosscutUpdated"`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LosscutUpdate {
+    pub position_id: u64,
+    pub symbol: String,
+    pub losscut_price: String,
+    /// The price last requested through this client for the position.
+    pub requested: Option<String>,
+    /// Whether the new price is the requested one.
+    pub confirmed: bool,
+}
+
+/// Losscut price changes acknowledged by GMO, awaiting `positionEvents`.
+#[derive(Clone, Default)]
+pub struct LosscutChanges {
+    pending: Arc<Mutex<HashMap<u64, String>>>,
+}
+
+impl LosscutChanges {
+    pub fn request(&self, ack: &LosscutPriceAck) {
+        self.pending.lock().unwrap().insert(ack.position_id, ack.losscut_price.clone());
+    }
+
+    /// The change `position` reports: a pending change it now shows, or any
+    /// `ULO` (losscut price changed) message.
+    pub fn observe(&self, position: &Position) -> Option<LosscutUpdate> {
+        let price = position.losscut_price.as_deref()?;
+        let mut pending = self.pending.lock().unwrap();
+        let requested = pending.get(&position.position_id).cloned();
+        let same = |a: &str, b: &str| match (decimal("losscutPrice", a), decimal("losscutPrice", b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        };
+        let confirmed = requested.as_deref().is_some_and(|r| same(r, price));
+        if confirmed {
+            pending.remove(&position.position_id);
+        } else if position.msg_type.as_deref() != Some("ULO") {
+            return None;
+        }
+        Some(LosscutUpdate {
+            position_id: position.position_id,
+            symbol: position.symbol.clone(),
+            losscut_price: price.to_string(),
+            requested,
+            confirmed,
+        })
+    }
+}
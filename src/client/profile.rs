@@ -0,0 +1,107 @@
+#[cfg(feature = "python")]
+use pyo3::{type_hint_identifier, type_hint_subscript, type_hint_union};
+
+/// The set of base URLs a client talks to.
+///
+/// Every REST and WebSocket URL is derived from here, so pointing the
+/// adapter at another environment (a sandbox, a recording proxy) is a
+/// single setting rather than a source edit.
+#[derive(Clone, Debug)]
+pub struct VenueProfile {
+    pub name: String,
+    /// e.g. `https://api.coin.z.com/public`
+    pub rest_public: String,
+    /// e.g. `https://api.coin.z.com/private`
+    pub rest_private: String,
+    /// e.g. `wss://api.coin.z.com/ws/public/v1`
+    pub ws_public: String,
+    /// Private WS URL without the access token, e.g. `wss://api.coin.z.com/ws/private/v1`
+    pub ws_private: String,
+}
+
+#[cfg(feature = "python")]
+const URL_KEYS: [&str; 4] = ["rest_public", "rest_private", "ws_public", "ws_private"];
+
+impl VenueProfile {
+    pub fn production() -> Self {
+        Self {
+            name: "production".to_string(),
+            rest_public: "https://api.coin.z.com/public".to_string(),
+            rest_private: "https://api.coin.z.com/private".to_string(),
+            ws_public: "wss://api.coin.z.com/ws/public/v1".to_string(),
+            ws_private: "wss://api.coin.z.com/ws/private/v1".to_string(),
+        }
+    }
+
+    /// Alternate environment (a sandbox, a recording proxy).
+    pub fn custom(rest_public: &str, rest_private: &str, ws_public: &str, ws_private: &str) -> Self {
+        Self {
+            name: "custom".to_string(),
+            rest_public: rest_public.trim_end_matches('/').to_string(),
+            rest_private: rest_private.trim_end_matches('/').to_string(),
+            ws_public: ws_public.to_string(),
+            ws_private: ws_private.to_string(),
+        }
+    }
+
+    pub fn ws_private_url(&self, token: &str) -> String {
+        format!("{}/{}", self.ws_private.trim_end_matches('/'), token)
+    }
+}
+
+impl Default for VenueProfile {
+    fn default() -> Self {
+        Self::production()
+    }
+}
+
+/// Accepts a profile name (`"production"`) or a dict with all four of
+/// `rest_public`, `rest_private`, `ws_public` and `ws_private`. Partial
+/// dicts are rejected so an alternate environment can never silently fall
+/// back to production URLs.
+#[cfg(feature = "python")]
+impl<'a, 'py> pyo3::FromPyObject<'a, 'py> for VenueProfile {
+    type Error = pyo3::PyErr;
+
+    const INPUT_TYPE: pyo3::inspect::PyStaticExpr = type_hint_union!(
+        type_hint_identifier!("builtins", "str"),
+        type_hint_subscript!(
+            type_hint_identifier!("collections.abc", "Mapping"),
+            type_hint_identifier!("builtins", "str"),
+            type_hint_identifier!("builtins", "str")
+        )
+    );
+
+    fn extract(obj: pyo3::Borrowed<'a, 'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(name) = obj.extract::<String>() {
+            return match name.to_ascii_lowercase().as_str() {
+                "production" => Ok(Self::production()),
+                _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown venue profile '{}' (expected 'production' or a dict of URLs)",
+                    name
+                ))),
+            };
+        }
+        let mut urls = obj.extract::<std::collections::HashMap<String, String>>()?;
+        if let Some(unknown) = urls.keys().find(|k| !URL_KEYS.contains(&k.as_str())) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown venue profile key '{}' (expected {:?})",
+                unknown, URL_KEYS
+            )));
+        }
+        let mut take = |key: &str| {
+            urls.remove(key).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Venue profile is missing '{}' (all of {:?} are required)",
+                    key, URL_KEYS
+                ))
+            })
+        };
+        Ok(Self::custom(
+            &take("rest_public")?,
+            &take("rest_private")?,
+            &take("ws_public")?,
+            &take("ws_private")?,
+        ))
+    }
+}
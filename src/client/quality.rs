@@ -0,0 +1,186 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::model::orderbook::OrderBook;
+
+/// Orders kept for the report; the oldest are dropped beyond this.
+const MAX_TRACKED_ORDERS: usize = 10_000;
+
+/// The market when an order was sent.
+#[derive(Clone, Debug, Serialize)]
+pub struct Arrival {
+    pub bid: Decimal,
+    pub ask: Decimal,
+    pub mid: Decimal,
+    /// Top-of-book sizes, when taken from a book.
+    pub bid_size: Option<Decimal>,
+    pub ask_size: Option<Decimal>,
+}
+
+impl Arrival {
+    pub fn new(bid: Decimal, ask: Decimal) -> Self {
+        Self { bid, ask, mid: ((bid + ask) / Decimal::TWO).normalize(), bid_size: None, ask_size: None }
+    }
+
+    /// Best bid / ask of `book`, or `None` while either side is empty.
+    pub fn from_book(book: &OrderBook) -> Option<Self> {
+        let (bid, ask) = (book.bids().first()?, book.asks().first()?);
+        let parse = |v: &str| Decimal::from_str(v).ok();
+        Some(Self {
+            bid_size: parse(&bid.size),
+            ask_size: parse(&ask.size),
+            ..Self::new(parse(&bid.price)?, parse(&ask.price)?)
+        })
+    }
+}
+
+struct Tracked {
+    symbol: String,
+    side: String,
+    size: Decimal,
+    submitted_at: String,
+    sent: Instant,
+    ack_latency: Duration,
+    arrival: Option<Arrival>,
+    /// `(price, size, time since sent)`
+    fills: Vec<(Decimal, Decimal, Duration)>,
+}
+
+/// Arrival, fills and latencies of one order.
+#[derive(Clone, Debug, Serialize)]
+pub struct OrderQuality {
+    pub order_id: u64,
+    pub symbol: String,
+    pub side: String,
+    pub size: Decimal,
+    /// ISO 8601 (UTC)
+    pub submitted_at: String,
+    pub arrival: Option<Arrival>,
+    /// From sending the order to GMO's response.
+    pub ack_latency_ms: u64,
+    pub filled_size: Decimal,
+    pub avg_fill_price: Option<Decimal>,
+    /// Average fill price against the arrival mid, in basis points;
+    /// positive when worse for the order's side.
+    pub arrival_slippage_bps: Option<Decimal>,
+    /// From sending the order to receiving its first / completing fill.
+    pub time_to_first_fill_ms: Option<u64>,
+    pub time_to_fill_ms: Option<u64>,
+}
+
+/// Averages over the tracked orders.
+#[derive(Clone, Debug, Serialize)]
+pub struct QualitySummary {
+    pub orders: usize,
+    pub filled_orders: usize,
+    pub mean_ack_latency_ms: Option<u64>,
+    pub mean_arrival_slippage_bps: Option<Decimal>,
+    pub mean_time_to_fill_ms: Option<u64>,
+}
+
+impl Tracked {
+    fn report(&self, order_id: u64) -> OrderQuality {
+        let filled_size: Decimal = self.fills.iter().map(|(_, size, _)| size).sum();
+        let avg_fill_price = (!filled_size.is_zero()).then(|| {
+            let value: Decimal = self.fills.iter().map(|(price, size, _)| price * size).sum();
+            (value / filled_size).round_dp(8).normalize()
+        });
+        let direction = if self.side.eq_ignore_ascii_case("SELL") { -Decimal::ONE } else { Decimal::ONE };
+        let arrival_slippage_bps = avg_fill_price.zip(self.arrival.as_ref()).and_then(|(avg, arrival)| {
+            (!arrival.mid.is_zero())
+                .then(|| (direction * (avg - arrival.mid) / arrival.mid * Decimal::from(10_000)).round_dp(4).normalize())
+        });
+        let ms = |d: &Duration| d.as_millis() as u64;
+        let mut filled = Decimal::ZERO;
+        let time_to_fill = self.fills.iter().find_map(|(_, size, at)| {
+            filled += size;
+            (filled >= self.size).then_some(at)
+        });
+        OrderQuality {
+            order_id,
+            symbol: self.symbol.clone(),
+            side: self.side.clone(),
+            size: self.size,
+            submitted_at: self.submitted_at.clone(),
+            arrival: self.arrival.clone(),
+            ack_latency_ms: ms(&self.ack_latency),
+            filled_size: filled_size.normalize(),
+            avg_fill_price,
+            arrival_slippage_bps,
+            time_to_first_fill_ms: self.fills.first().map(|(_, _, at)| ms(at)),
+            time_to_fill_ms: time_to_fill.map(ms),
+        }
+    }
+}
+
+/// Per-order execution quality: the market at submit time against the
+/// fills that followed. Latencies are measured on the local clock.
+#[derive(Clone, Default)]
+pub struct ExecutionQuality {
+    orders: Arc<Mutex<BTreeMap<u64, Tracked>>>,
+}
+
+impl ExecutionQuality {
+    /// An order GMO accepted: sent at `sent`, answered now.
+    pub fn record_submit(&self, order_id: u64, symbol: &str, side: &str, size: Decimal, arrival: Option<Arrival>, sent: Instant) {
+        let mut orders = self.orders.lock().unwrap();
+        orders.insert(order_id, Tracked {
+            symbol: symbol.to_string(),
+            side: side.to_string(),
+            size,
+            submitted_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            sent,
+            ack_latency: sent.elapsed(),
+            arrival,
+            fills: Vec::new(),
+        });
+        while orders.len() > MAX_TRACKED_ORDERS {
+            orders.pop_first();
+        }
+    }
+
+    /// A fill received for a tracked order; others are ignored.
+    pub fn record_fill(&self, order_id: u64, price: Decimal, size: Decimal) {
+        if let Some(order) = self.orders.lock().unwrap().get_mut(&order_id) {
+            let at = order.sent.elapsed();
+            order.fills.push((price, size, at));
+        }
+    }
+
+    pub fn order(&self, order_id: u64) -> Option<OrderQuality> {
+        self.orders.lock().unwrap().get(&order_id).map(|t| t.report(order_id))
+    }
+
+    /// Every tracked order, oldest first.
+    pub fn report(&self) -> Vec<OrderQuality> {
+        self.orders.lock().unwrap().iter().map(|(id, t)| t.report(*id)).collect()
+    }
+
+    /// Symbols of the tracked orders.
+    pub fn symbols(&self) -> BTreeSet<String> {
+        self.orders.lock().unwrap().values().map(|t| t.symbol.clone()).collect()
+    }
+
+    pub fn summary(&self) -> QualitySummary {
+        let report = self.report();
+        let mean_ms = |values: Vec<u64>| (!values.is_empty()).then(|| values.iter().sum::<u64>() / values.len() as u64);
+        let slippage: Vec<Decimal> = report.iter().filter_map(|o| o.arrival_slippage_bps).collect();
+        QualitySummary {
+            orders: report.len(),
+            filled_orders: report.iter().filter(|o| o.time_to_fill_ms.is_some()).count(),
+            mean_ack_latency_ms: mean_ms(report.iter().map(|o| o.ack_latency_ms).collect()),
+            mean_arrival_slippage_bps: (!slippage.is_empty()).then(|| {
+                (slippage.iter().sum::<Decimal>() / Decimal::from(slippage.len())).round_dp(4).normalize()
+            }),
+            mean_time_to_fill_ms: mean_ms(report.iter().filter_map(|o| o.time_to_fill_ms).collect()),
+        }
+    }
+
+    pub fn clear(&self) {
+        self.orders.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,176 @@
+use serde::Serialize;
+use tokio::time::{Duration, Instant};
+
+use crate::error::GmocoinError;
+
+/// Delay between WS reconnect attempts: `initial`, multiplied by
+/// `multiplier` after each failure up to `max`. `jitter` (0..=1) shortens
+/// each delay by a random fraction of up to that much, so clients cut off
+/// together do not reconnect in lockstep.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackoffPolicy {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+}
+
+impl BackoffPolicy {
+    /// Public WS default: 1s doubling up to 64s.
+    pub const PUBLIC: Self = Self {
+        initial: Duration::from_secs(1),
+        max: Duration::from_secs(64),
+        multiplier: 2.0,
+        jitter: 0.0,
+    };
+
+    /// Private WS default: 5s doubling up to 60s.
+    pub const PRIVATE: Self = Self {
+        initial: Duration::from_secs(5),
+        max: Duration::from_secs(60),
+        multiplier: 2.0,
+        jitter: 0.0,
+    };
+
+    /// `defaults` with any of the given settings replaced.
+    pub fn new(
+        defaults: Self,
+        initial_ms: Option<u64>,
+        max_ms: Option<u64>,
+        multiplier: Option<f64>,
+        jitter: Option<f64>,
+    ) -> Result<Self, GmocoinError> {
+        let policy = Self {
+            initial: initial_ms.map(Duration::from_millis).unwrap_or(defaults.initial),
+            max: max_ms.map(Duration::from_millis).unwrap_or(defaults.max),
+            multiplier: multiplier.unwrap_or(defaults.multiplier),
+            jitter: jitter.unwrap_or(defaults.jitter),
+        };
+        if policy.initial.is_zero() || policy.max < policy.initial {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Reconnect backoff needs 0 < initial <= max (got {}ms / {}ms)",
+                policy.initial.as_millis(),
+                policy.max.as_millis()
+            )));
+        }
+        if !(policy.multiplier >= 1.0 && policy.multiplier.is_finite()) {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Reconnect backoff multiplier must be >= 1 (got {})",
+                policy.multiplier
+            )));
+        }
+        if !(0.0..=1.0).contains(&policy.jitter) {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Reconnect backoff jitter must be between 0 and 1 (got {})",
+                policy.jitter
+            )));
+        }
+        Ok(policy)
+    }
+}
+
+/// Backoff state of one WS loop.
+pub(crate) struct Backoff {
+    policy: BackoffPolicy,
+    current: Duration,
+    seed: u64,
+}
+
+impl Backoff {
+    pub(crate) fn new(policy: BackoffPolicy) -> Self {
+        let seed = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(1) as u64 | 1;
+        Self { policy, current: policy.initial, seed }
+    }
+
+    /// Back to `initial` after a successful connect.
+    pub(crate) fn reset(&mut self) {
+        self.current = self.policy.initial;
+    }
+
+    /// The next delay before jitter, for logging.
+    pub(crate) fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// The delay to sleep now; grows the next one.
+    pub(crate) fn next_delay(&mut self) -> Duration {
+        let delay = self.current.mul_f64(1.0 - self.policy.jitter * self.sample());
+        self.current = self.current.mul_f64(self.policy.multiplier).min(self.policy.max);
+        delay
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn sample(&mut self) -> f64 {
+        if self.policy.jitter == 0.0 {
+            return 0.0;
+        }
+        // xorshift64, as in `sim::LatencyModel`
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        (self.seed >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Why a WS connection was dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisconnectReason {
+    /// The server sent a Close frame.
+    ServerClose,
+    /// The stream ended without a Close frame.
+    StreamEnded,
+    /// Reading from the socket failed.
+    ReadError,
+    /// Extending the Private WS token failed.
+    TokenRefreshFailed,
+    /// Nothing was received within the stale timeout.
+    Stale,
+}
+
+impl DisconnectReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ServerClose => "server_close",
+            Self::StreamEnded => "stream_ended",
+            Self::ReadError => "read_error",
+            Self::TokenRefreshFailed => "token_refresh_failed",
+            Self::Stale => "stale",
+        }
+    }
+}
+
+/// A WS loop is connected again after a drop.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReconnectEvent {
+    pub reason: DisconnectReason,
+    /// From the drop to the new connection.
+    pub downtime_ms: u64,
+    /// Connection attempts it took, including the successful one.
+    pub attempts: u32,
+}
+
+/// The drop a WS loop is recovering from.
+pub(crate) struct Outage {
+    reason: DisconnectReason,
+    since: Instant,
+    attempts: u32,
+}
+
+impl Outage {
+    pub(crate) fn new(reason: DisconnectReason) -> Self {
+        Self { reason, since: Instant::now(), attempts: 0 }
+    }
+
+    pub(crate) fn attempt(&mut self) {
+        self.attempts += 1;
+    }
+
+    pub(crate) fn into_event(self) -> ReconnectEvent {
+        ReconnectEvent {
+            reason: self.reason,
+            downtime_ms: self.since.elapsed().as_millis() as u64,
+            attempts: self.attempts,
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::time::{sleep_until, Duration, Instant};
+
+use crate::error::GmocoinError;
+
+/// Where a session replay stands, for `replay_position`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReplayPosition {
+    pub active: bool,
+    /// Index of the next frame to deliver.
+    pub index: usize,
+    pub total: usize,
+    /// Recorded time of the next frame (epoch ms), or of the last one once
+    /// the replay is done.
+    pub ts: Option<i64>,
+    pub paused: bool,
+    /// `None`: as fast as possible.
+    pub speed: Option<f64>,
+}
+
+#[derive(Default)]
+struct ReplayState {
+    /// Bumped by each replay; an older replay stops at its next frame.
+    generation: u64,
+    /// Until the current replay has delivered its last frame.
+    running: bool,
+    timestamps: Vec<i64>,
+    cursor: usize,
+    /// Frames before this index are delivered at once (a forward seek).
+    fast_until: usize,
+    speed: Option<f64>,
+    paused: bool,
+    /// Wall time and recorded time that pacing is measured from; reset by
+    /// resume, speed changes and seeks.
+    anchor: Option<(Instant, i64)>,
+}
+
+enum Step {
+    Deliver(usize),
+    Wait(Option<Instant>),
+    Done,
+}
+
+/// Controls of a session replay: speed multiplier, pause / resume and seek.
+/// Shared by all clones; one replay runs at a time, and starting another
+/// takes the controls over.
+///
+/// Frames are paced by their recorded times divided by the speed. A seek
+/// forward delivers the skipped frames at once (even while paused), so the
+/// order cache stays consistent; a seek backward delivers frames again from
+/// the target, without unwinding state.
+#[derive(Clone, Default)]
+pub struct ReplayControl {
+    state: Arc<Mutex<ReplayState>>,
+    changed: Arc<Notify>,
+}
+
+impl ReplayControl {
+    /// Start a replay of frames recorded at `timestamps`, unpaused. Returns
+    /// its generation for `next`.
+    pub fn start(&self, timestamps: Vec<i64>, speed: Option<f64>) -> Result<u64, GmocoinError> {
+        check_speed(speed)?;
+        let mut state = self.state.lock().unwrap();
+        let generation = state.generation + 1;
+        *state = ReplayState { generation, running: true, timestamps, speed, ..ReplayState::default() };
+        drop(state);
+        self.changed.notify_one();
+        Ok(generation)
+    }
+
+    /// Wait until the next frame is due and return its index; `None` once
+    /// the replay is done or taken over.
+    pub async fn next(&self, generation: u64) -> Option<usize> {
+        loop {
+            match self.step(generation) {
+                Step::Deliver(index) => return Some(index),
+                Step::Done => return None,
+                Step::Wait(Some(due)) => {
+                    tokio::select! {
+                        _ = self.changed.notified() => {},
+                        _ = sleep_until(due) => {},
+                    }
+                }
+                Step::Wait(None) => self.changed.notified().await,
+            }
+        }
+    }
+
+    fn step(&self, generation: u64) -> Step {
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            return Step::Done;
+        }
+        if state.cursor >= state.timestamps.len() {
+            state.running = false;
+            return Step::Done;
+        }
+        let index = state.cursor;
+        let ts = state.timestamps[index];
+        if index >= state.fast_until {
+            if state.paused {
+                return Step::Wait(None);
+            }
+            if let (Some(speed), Some((wall, recorded))) = (state.speed, state.anchor) {
+                let ahead = Duration::try_from_secs_f64((ts - recorded).max(0) as f64 / 1000.0 / speed);
+                // Too far out to schedule: wait for a control change
+                match ahead.ok().and_then(|ahead| wall.checked_add(ahead)) {
+                    Some(due) if due > Instant::now() => return Step::Wait(Some(due)),
+                    None => return Step::Wait(None),
+                    Some(_) => {}
+                }
+            }
+            // The first frame paced after a start, resume, speed change or seek
+            if state.anchor.is_none() {
+                state.anchor = Some((Instant::now(), ts));
+            }
+        }
+        state.cursor += 1;
+        Step::Deliver(index)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ReplayState)) {
+        f(&mut self.state.lock().unwrap());
+        self.changed.notify_one();
+    }
+
+    pub fn pause(&self) {
+        self.update(|state| state.paused = true);
+    }
+
+    pub fn resume(&self) {
+        self.update(|state| {
+            state.paused = false;
+            state.anchor = None;
+        });
+    }
+
+    /// Speed multiplier (2.0: twice as fast as recorded); `None` delivers
+    /// frames as fast as possible.
+    pub fn set_speed(&self, speed: Option<f64>) -> Result<(), GmocoinError> {
+        check_speed(speed)?;
+        self.update(|state| {
+            state.speed = speed;
+            state.anchor = None;
+        });
+        Ok(())
+    }
+
+    /// Continue the running replay from the first frame recorded at or
+    /// after `ts_ms`.
+    pub fn seek(&self, ts_ms: i64) {
+        self.update(|state| {
+            let target = state.timestamps.partition_point(|ts| *ts < ts_ms);
+            if target >= state.cursor {
+                state.fast_until = target;
+            } else {
+                state.cursor = target;
+                state.fast_until = target;
+            }
+            state.anchor = None;
+        });
+    }
+
+    pub fn position(&self) -> ReplayPosition {
+        let state = self.state.lock().unwrap();
+        let total = state.timestamps.len();
+        ReplayPosition {
+            active: state.running,
+            index: state.cursor,
+            total,
+            ts: state.timestamps.get(state.cursor).or(state.timestamps.last()).copied(),
+            paused: state.paused,
+            speed: state.speed,
+        }
+    }
+}
+
+fn check_speed(speed: Option<f64>) -> Result<(), GmocoinError> {
+    match speed {
+        Some(speed) if speed.is_nan() || speed <= 0.0 => {
+            Err(GmocoinError::InvalidInput(format!("Replay speed must be positive, got {}", speed)))
+        }
+        _ => Ok(()),
+    }
+}
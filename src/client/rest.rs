@@ -1,98 +1,480 @@
-use reqwest::{Client, Method};
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use reqwest::{Client, Method, RequestBuilder};
 use serde::de::DeserializeOwned;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use crate::error::GmocoinError;
+use crate::health::{Health, LimiterHealth, RestHealth};
 use crate::model::{
-    market_data::{Ticker, Depth, SymbolInfo},
-    order::{OrdersList, ExecutionsList, PositionsList, PositionSummaryList},
-    account::{Asset, Margin},
+    market_data::{Ticker, Depth, Kline, SymbolInfo, TradesList},
+    order::{OrdersList, ExecutionsList, CloseOrderAck, LosscutPriceAck, PositionsList, PositionSummaryList},
+    account::{Asset, Margin, TradingVolume},
 };
-use crate::rate_limit::TokenBucket;
-use std::time::{SystemTime, UNIX_EPOCH};
+use crate::model::report::decimal;
+use crate::rate_limit::{check_rate, Priority, RateLimiter, RateLimitTier, TokenBucket};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::client::endpoint::EndpointGroup;
+use crate::client::headers::StaticHeaders;
+use crate::client::klines;
+use crate::client::profile::VenueProfile;
+use crate::runtime::{self, CloseSignal};
+use tokio_tungstenite::tungstenite::handshake::client::Request as WsRequest;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::IntoPyObjectExt;
+#[cfg(feature = "python")]
+use crate::arrow::{self, ArrowRecordBatch};
+#[cfg(feature = "python")]
+use crate::credentials::Credentials;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+use crate::stats::ClientStats;
+use crate::time;
+use crate::tls::TlsSettings;
+use tracing::{debug, info, warn, Instrument};
+use zeroize::Zeroizing;
 
 type HmacSha256 = Hmac<Sha256>;
 
-#[pyclass(from_py_object)]
+/// Default `symbols_ttl`.
+pub const DEFAULT_SYMBOLS_TTL: Duration = Duration::from_secs(60);
+
+/// `price` on the `tick` grid, with exactly the decimal places of `tick`,
+/// rounded away from the market for `side` (down for buys, up for sells)
+/// so the order is never more aggressive than asked, or to the nearest
+/// tick without a side.
+pub fn round_to_tick(price: Decimal, tick: Decimal, side: Option<&str>) -> Decimal {
+    let strategy = match side {
+        Some(side) if side.eq_ignore_ascii_case("SELL") => RoundingStrategy::ToPositiveInfinity,
+        Some(_) => RoundingStrategy::ToNegativeInfinity,
+        None => RoundingStrategy::MidpointAwayFromZero,
+    };
+    let mut rounded = (price / tick).round_dp_with_strategy(0, strategy) * tick;
+    rounded.rescale(tick.normalize().scale());
+    rounded
+}
+
+/// `size` rounded down to a multiple of `step` (never more than asked),
+/// with exactly the decimal places of `step`.
+pub fn to_size_step(size: Decimal, step: Decimal) -> Decimal {
+    let mut rounded = (size / step).floor() * step;
+    rounded.rescale(step.normalize().scale());
+    rounded
+}
+
+/// Last `/v1/symbols` response and when it was fetched.
+type SymbolsCache = Arc<tokio::sync::Mutex<Option<(tokio::time::Instant, Vec<SymbolInfo>)>>>;
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, subclass))]
 #[derive(Clone)]
 pub struct GmocoinRestClient {
     client: Client,
     api_key: String,
-    api_secret: String,
-    base_url_public: String,
-    base_url_private: String,
-    rate_limit_get: TokenBucket,
-    rate_limit_post: TokenBucket,
+    /// Shared with clones, wiped when the last is dropped.
+    api_secret: Arc<Zeroizing<String>>,
+    venue: Arc<VenueProfile>,
+    rate_limiter: RateLimiter,
+    circuit_breaker: CircuitBreaker,
+    /// Request slots when `max_in_flight` caps outstanding requests; shared
+    /// with clones.
+    in_flight: Option<Arc<Semaphore>>,
+    max_in_flight: Option<usize>,
+    /// Shared with clones.
+    symbols_cache: SymbolsCache,
+    symbols_ttl: Duration,
+    strict_sizes: bool,
+    tls: TlsSettings,
+    headers: StaticHeaders,
+    stats: ClientStats,
+    /// Server clock minus local clock (ms), learned from skew rejections.
+    clock_offset_ms: Arc<AtomicI64>,
+    /// Longest a request may wait for a rate limit token before being shed
+    /// (`None` waits indefinitely). Set per call via `with_max_wait`.
+    max_wait: Option<Duration>,
+    /// Point by which a request must have completed, or be abandoned: with
+    /// `GmocoinError::Shed` before it is sent, `DeadlineExceeded` once it
+    /// has been. Set per call via `with_deadline`.
+    deadline: Option<tokio::time::Instant>,
+    /// HTTP timeout overriding the client-wide `timeout_ms`. Set per call
+    /// via `with_timeout`.
+    request_timeout: Option<Duration>,
+    /// Set by `close`; shared with clones.
+    closed: CloseSignal,
+    /// Python methods return results instead of awaitables (`SyncGmocoinRestClient`).
+    #[cfg(feature = "python")]
+    blocking: bool,
+}
+
+/// Construction options of a `GmocoinRestClient`.
+#[derive(Clone)]
+pub struct RestClientConfig {
+    pub api_key: String,
+    pub api_secret: Zeroizing<String>,
+    pub timeout_ms: u64,
+    pub proxy_url: Option<String>,
+    /// API tier, or a custom requests/sec number. Applies to every endpoint group.
+    pub rate_limit: RateLimitTier,
+    /// Per endpoint group overrides of `rate_limit`, each with its own bucket.
+    pub group_rate_limits: HashMap<EndpointGroup, f64>,
+    /// Share one limiter with every other client in this process using the same API key.
+    pub share_rate_limiter: bool,
+    /// Consecutive venue failures per endpoint group before failing fast (0 disables).
+    pub circuit_breaker_threshold: u32,
+    pub circuit_breaker_cooldown: Duration,
+    /// Most requests outstanding at once, across endpoint groups (`None`:
+    /// unlimited). Requests beyond it wait like for a rate limit token.
+    pub max_in_flight: Option<usize>,
+    /// How long a `/v1/symbols` response is served from cache (zero: always
+    /// fetched).
+    pub symbols_ttl: Duration,
+    /// Reject order sizes that are not a multiple of the symbol's
+    /// `sizeStep` instead of rounding them down.
+    pub strict_sizes: bool,
+    /// Also used for the Private WebSocket of an execution client.
+    pub tls: TlsSettings,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout: Duration,
+    /// Offer HTTP/2 via ALPN, falling back to HTTP/1.1.
+    pub prefer_http2: bool,
+    /// TCP keepalive interval (also the HTTP/2 ping interval when `prefer_http2`).
+    pub tcp_keepalive: Duration,
+    pub venue_profile: VenueProfile,
+    /// Static headers sent on every request (and the Private WS handshake).
+    pub headers: StaticHeaders,
+}
+
+impl Default for RestClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            api_secret: Zeroizing::default(),
+            timeout_ms: 10_000,
+            proxy_url: None,
+            rate_limit: RateLimitTier::default(),
+            group_rate_limits: HashMap::new(),
+            share_rate_limiter: false,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown: Duration::from_millis(30_000),
+            max_in_flight: None,
+            symbols_ttl: DEFAULT_SYMBOLS_TTL,
+            strict_sizes: false,
+            tls: TlsSettings::default(),
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Duration::from_millis(90_000),
+            prefer_http2: false,
+            tcp_keepalive: Duration::from_millis(15_000),
+            venue_profile: VenueProfile::default(),
+            headers: StaticHeaders::default(),
+        }
+    }
+}
+
+impl GmocoinRestClient {
+    pub fn from_config(config: RestClientConfig) -> Result<Self, GmocoinError> {
+        let RestClientConfig {
+            api_key, api_secret, timeout_ms, proxy_url, rate_limit, group_rate_limits,
+            share_rate_limiter, circuit_breaker_threshold, circuit_breaker_cooldown, max_in_flight, symbols_ttl,
+            strict_sizes, tls, pool_max_idle_per_host, pool_idle_timeout, prefer_http2, tcp_keepalive,
+            venue_profile, headers,
+        } = config;
+        let mut builder = headers.apply(tls.apply(Client::builder(), prefer_http2))
+            .timeout(Duration::from_millis(timeout_ms))
+            .pool_max_idle_per_host(pool_max_idle_per_host)
+            .pool_idle_timeout(pool_idle_timeout)
+            .tcp_keepalive(tcp_keepalive);
+        builder = if prefer_http2 {
+            builder
+                .http2_keep_alive_interval(tcp_keepalive)
+                .http2_keep_alive_while_idle(true)
+        } else {
+            builder.http1_only()
+        };
+
+        if let Some(proxy) = proxy_url {
+            if let Ok(p) = reqwest::Proxy::all(proxy) {
+                builder = builder.proxy(p);
+            }
+        }
+
+        let rate = rate_limit.per_sec();
+        let rate_limiter = if share_rate_limiter {
+            RateLimiter::shared(&api_key, rate, &group_rate_limits)?
+        } else {
+            RateLimiter::new(rate, &group_rate_limits)?
+        };
+
+        Ok(Self {
+            // A default client would silently drop the TLS and header settings
+            client: builder.build()?,
+            api_key,
+            api_secret: Arc::new(api_secret),
+            venue: Arc::new(venue_profile),
+            rate_limiter,
+            circuit_breaker: CircuitBreaker::new(circuit_breaker_threshold, circuit_breaker_cooldown),
+            in_flight: max_in_flight.map(|max| Arc::new(Semaphore::new(max))),
+            max_in_flight,
+            symbols_cache: SymbolsCache::default(),
+            symbols_ttl,
+            strict_sizes,
+            tls,
+            headers,
+            stats: ClientStats::default(),
+            clock_offset_ms: Arc::new(AtomicI64::new(0)),
+            max_wait: None,
+            deadline: None,
+            request_timeout: None,
+            closed: CloseSignal::default(),
+            #[cfg(feature = "python")]
+            blocking: false,
+        })
+    }
+
+    /// Clone of this client whose requests must complete within `deadline_ms`
+    /// from now. Waiting for a rate limit token and the HTTP call both count;
+    /// past the deadline the request is abandoned with `TimeoutError` rather
+    /// than sent late. Note an order request abandoned mid-flight may still
+    /// have reached the venue.
+    pub fn with_deadline(&self, deadline_ms: Option<u64>) -> Self {
+        let mut client = self.clone();
+        client.deadline = deadline_ms
+            .map(|ms| tokio::time::Instant::now() + Duration::from_millis(ms));
+        client
+    }
+
+    /// Clone of this client whose HTTP requests time out after `timeout_ms`
+    /// instead of the client-wide `timeout_ms`, e.g. a tight budget for
+    /// order submission and a generous one for history downloads. Unlike
+    /// `with_deadline`, time spent waiting for a rate limit token does not count.
+    pub fn with_timeout(&self, timeout_ms: Option<u64>) -> Self {
+        let mut client = self.clone();
+        client.request_timeout = timeout_ms.map(Duration::from_millis);
+        client
+    }
+
+    /// Fail pending and future requests of this client and its clones with
+    /// `GmocoinError::Closed`.
+    pub fn close(&self) {
+        self.closed.close();
+    }
+
+    /// Whether `close` (or the module-wide `shutdown`) has been called.
+    pub fn is_closed(&self) -> bool {
+        self.closed.is_closed() || runtime::shutdown_signal().is_closed()
+    }
+
+    async fn closed(&self) {
+        tokio::select! {
+            _ = self.closed.closed() => {}
+            _ = runtime::shutdown_signal().closed() => {}
+        }
+    }
+
+    /// Change the rate limit of an endpoint group on the fly. Applies to
+    /// every clone of this client, and to all clients sharing its limiter.
+    pub fn set_rate_limit(&self, group: EndpointGroup, per_sec: f64) -> Result<(), GmocoinError> {
+        check_rate(per_sec)?;
+        self.rate_limiter.bucket(group).set_rate(per_sec);
+        info!("GMO: Rate limit for '{}' set to {}/s", group, per_sec);
+        Ok(())
+    }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl GmocoinRestClient {
     /// Create a new GmocoinRestClient.
     ///
-    /// `rate_limit_per_sec`: API rate limit (requests/sec). Default 20 (Tier 1).
-    ///   GMO Coin Tier 1: 20/s, Tier 2: 30/s.
+    /// `rate_limit`: API tier, `"tier1"` (20/s, default) or `"tier2"` (30/s),
+    ///   or a custom requests/sec number. Applies to every endpoint group.
+    /// `group_rate_limits`: per endpoint group overrides of `rate_limit`,
+    ///   keyed by `"public"`, `"account"` or `"order"`. Each group has its own bucket.
+    /// `share_rate_limiter`: share one limiter with every other client in this
+    ///   process using the same API key. Default false.
+    /// `circuit_breaker_threshold`: consecutive venue failures per endpoint group
+    ///   before failing fast. Default 5, 0 disables.
+    /// `circuit_breaker_cooldown_ms`: how long an open circuit fails fast. Default 30000.
+    /// `max_in_flight`: most requests outstanding at once; further requests
+    ///   wait for one to finish (shed like a rate limit wait by `max_wait_ms`
+    ///   and deadlines). Default unlimited.
+    /// `symbols_ttl_ms`: how long `get_symbols_py` answers from cache, and
+    ///   how long an order for a symbol missing from it is refused before
+    ///   the list is fetched again. Default 60000, 0 always fetches.
+    /// `strict_sizes`: raise `ValueError` for order sizes that are not a
+    ///   multiple of the symbol's `sizeStep` instead of rounding them down.
+    ///   Default false.
+    /// `tls_ca_file` / `tls_native_roots` / `tls_min_version`: see `TlsSettings`;
+    ///   also used for the Private WebSocket of an execution client.
+    /// `pool_max_idle_per_host`: idle connections kept per host. Default unlimited.
+    /// `pool_idle_timeout_ms`: how long an idle connection is kept. Default 90000.
+    /// `prefer_http2`: offer HTTP/2 via ALPN, falling back to HTTP/1.1. Default false.
+    /// `tcp_keepalive_ms`: TCP keepalive interval (also the HTTP/2 ping interval
+    ///   when `prefer_http2`). Default 15000.
+    /// `venue_profile`: `"production"` (default) or a dict of alternate base
+    ///   URLs (see `VenueProfile`); execution clients use it for the Private WS too.
+    /// `user_agent` / `extra_headers`: static headers sent on every request
+    ///   (and the Private WS handshake of an execution client).
+    /// `credentials` / `passphrase`: an API key pair sealed with
+    ///   `encrypt_credentials`, decrypted here instead of passing
+    ///   `api_key` / `api_secret` (which must then be empty).
     #[new]
-    pub fn new(
+    #[pyo3(signature = (api_key, api_secret, timeout_ms, proxy_url, rate_limit, circuit_breaker_threshold=None, circuit_breaker_cooldown_ms=None, group_rate_limits=None, share_rate_limiter=None, tls_ca_file=None, tls_native_roots=None, tls_min_version=None, pool_max_idle_per_host=None, pool_idle_timeout_ms=None, prefer_http2=None, tcp_keepalive_ms=None, venue_profile=None, user_agent=None, extra_headers=None, max_in_flight=None, symbols_ttl_ms=None, strict_sizes=None, credentials=None, passphrase=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(
         api_key: String,
         api_secret: String,
         timeout_ms: u64,
         proxy_url: Option<String>,
-        rate_limit_per_sec: Option<f64>,
-    ) -> Self {
-        let mut builder = Client::builder()
-            .timeout(std::time::Duration::from_millis(timeout_ms));
-
-        if let Some(proxy) = proxy_url {
-            if let Ok(p) = reqwest::Proxy::all(proxy) {
-                builder = builder.proxy(p);
-            }
+        rate_limit: Option<RateLimitTier>,
+        circuit_breaker_threshold: Option<u32>,
+        circuit_breaker_cooldown_ms: Option<u64>,
+        group_rate_limits: Option<HashMap<String, f64>>,
+        share_rate_limiter: Option<bool>,
+        tls_ca_file: Option<String>,
+        tls_native_roots: Option<bool>,
+        tls_min_version: Option<String>,
+        pool_max_idle_per_host: Option<usize>,
+        pool_idle_timeout_ms: Option<u64>,
+        prefer_http2: Option<bool>,
+        tcp_keepalive_ms: Option<u64>,
+        venue_profile: Option<VenueProfile>,
+        user_agent: Option<String>,
+        extra_headers: Option<HashMap<String, String>>,
+        max_in_flight: Option<usize>,
+        symbols_ttl_ms: Option<u64>,
+        strict_sizes: Option<bool>,
+        credentials: Option<String>,
+        passphrase: Option<String>,
+    ) -> PyResult<Self> {
+        let defaults = RestClientConfig::default();
+        let mut overrides = HashMap::new();
+        for (name, group_rate) in group_rate_limits.unwrap_or_default() {
+            overrides.insert(parse_group(&name)?, group_rate);
         }
-
-        let rate = rate_limit_per_sec.unwrap_or(20.0);
-
-        Self {
-            client: builder.build().unwrap_or_else(|_| Client::new()),
+        let Credentials { api_key, api_secret } =
+            Credentials::resolve(api_key, api_secret, credentials.as_deref(), passphrase.as_deref())?;
+        let config = RestClientConfig {
             api_key,
             api_secret,
-            base_url_public: "https://api.coin.z.com/public".to_string(),
-            base_url_private: "https://api.coin.z.com/private".to_string(),
-            rate_limit_get: TokenBucket::new(rate, rate),
-            rate_limit_post: TokenBucket::new(rate, rate),
-        }
+            timeout_ms,
+            proxy_url,
+            rate_limit: rate_limit.unwrap_or_default(),
+            group_rate_limits: overrides,
+            share_rate_limiter: share_rate_limiter.unwrap_or(false),
+            circuit_breaker_threshold: circuit_breaker_threshold
+                .unwrap_or(defaults.circuit_breaker_threshold),
+            circuit_breaker_cooldown: circuit_breaker_cooldown_ms
+                .map_or(defaults.circuit_breaker_cooldown, Duration::from_millis),
+            max_in_flight: max_in_flight.filter(|max| *max > 0),
+            symbols_ttl: symbols_ttl_ms.map_or(defaults.symbols_ttl, Duration::from_millis),
+            strict_sizes: strict_sizes.unwrap_or(false),
+            tls: TlsSettings::new(tls_ca_file.as_deref(), tls_native_roots, tls_min_version.as_deref())?,
+            pool_max_idle_per_host: pool_max_idle_per_host.unwrap_or(defaults.pool_max_idle_per_host),
+            pool_idle_timeout: pool_idle_timeout_ms
+                .map_or(defaults.pool_idle_timeout, Duration::from_millis),
+            prefer_http2: prefer_http2.unwrap_or(false),
+            tcp_keepalive: tcp_keepalive_ms.map_or(defaults.tcp_keepalive, Duration::from_millis),
+            venue_profile: venue_profile.unwrap_or_default(),
+            headers: StaticHeaders::new(user_agent, extra_headers)?,
+        };
+        Ok(Self::from_config(config)?)
+    }
+
+    /// See `with_deadline` (Rust).
+    #[pyo3(name = "with_deadline", signature = (deadline_ms=None))]
+    fn py_with_deadline(&self, deadline_ms: Option<u64>) -> Self {
+        self.with_deadline(deadline_ms)
+    }
+
+    /// See `with_timeout` (Rust).
+    #[pyo3(name = "with_timeout", signature = (timeout_ms=None))]
+    fn py_with_timeout(&self, timeout_ms: Option<u64>) -> Self {
+        self.with_timeout(timeout_ms)
+    }
+
+    /// Change the rate limit of an endpoint group (`"public"`, `"account"`
+    /// or `"order"`) on the fly. Applies to every clone of this client, and
+    /// to all clients sharing its limiter.
+    #[pyo3(name = "set_rate_limit")]
+    pub fn py_set_rate_limit(&self, group: &str, per_sec: f64) -> PyResult<()> {
+        Ok(self.set_rate_limit(parse_group(group)?, per_sec)?)
+    }
+
+    /// Fail pending and future requests of this client (and its clones)
+    /// with `RuntimeError("Client closed")`.
+    #[pyo3(name = "close")]
+    fn py_close(&self) {
+        self.close();
+    }
+
+    /// Snapshot of this client's counters (shared with its clones): requests
+    /// sent, errors by class, and for execution clients WS reconnects,
+    /// last message time per channel and token refreshes.
+    pub fn get_stats(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.stats.snapshot())
+    }
+
+    /// Health snapshot for monitoring probes: `{"status", "checked_at_ms",
+    /// "rest", "rate_limits"}` (see `GmocoinExecutionClient.health`).
+    #[pyo3(name = "health")]
+    fn py_health(&self) -> PyResult<PyJson> {
+        PyJson::from_serialize(&self.health())
     }
 
     // ========== Public API (Python) ==========
 
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_status_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let res: serde_json::Value = client.public_get("/v1/status", None).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    pub fn get_ticker_py<'py>(&self, py: Python<'py>, symbol: Option<String>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
+    /// `max_wait_ms`: shed the request with `TimeoutError` if no rate limit
+    /// token is available within this many ms (0 = only if free right now).
+    #[pyo3(signature = (symbol=None, max_wait_ms=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_ticker_py<'py>(&self, py: Python<'py>, symbol: Option<String>, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
         let future = async move {
             let query = symbol.as_ref().map(|s| vec![("symbol", s.as_str())]);
             let res: Vec<Ticker> = client.public_get("/v1/ticker", query.as_deref()).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
+    }
+
+    /// Tickers of `symbols` as `{symbol: Ticker}`, fetched with one request
+    /// for several symbols. Raises `ValueError` for symbols GMO has no
+    /// ticker for. `max_wait_ms`: see `get_ticker_py`.
+    #[pyo3(name = "get_tickers", signature = (symbols, max_wait_ms=None) -> "collections.abc.Awaitable[dict[str, Ticker]]")]
+    fn py_get_tickers<'py>(&self, py: Python<'py>, symbols: Vec<String>, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
+        let future = async move { client.get_tickers(&symbols).await.map_err(PyErr::from) };
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_orderbooks_py<'py>(&self, py: Python<'py>, symbol: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let query = vec![("symbol", symbol.as_str())];
             let res: Depth = client.public_get("/v1/orderbooks", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol, page, count) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_trades_py<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
@@ -101,41 +483,93 @@ impl GmocoinRestClient {
             if let Some(c) = count { query_owned.push(("count".to_string(), c.to_string())); }
             let query: Vec<(&str, &str)> = query_owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
             let res: serde_json::Value = client.public_get("/v1/trades", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    pub fn get_klines_py<'py>(&self, py: Python<'py>, symbol: String, interval: String, date: String) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
+    /// `max_wait_ms`: see `get_ticker_py`.
+    #[pyo3(signature = (symbol, interval, date, max_wait_ms=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_klines_py<'py>(&self, py: Python<'py>, symbol: String, interval: String, date: String, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
         let future = async move {
             let path = format!("/v1/klines?symbol={}&interval={}&date={}", symbol, interval, date);
             let res: serde_json::Value = client.public_get_raw(&path).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    pub fn get_symbols_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    /// Klines as an Arrow RecordBatch (open_time, open, high, low, close, volume).
+    #[pyo3(signature = (symbol, interval, date, max_wait_ms=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_klines_arrow<'py>(&self, py: Python<'py>, symbol: String, interval: String, date: String, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
+        let future = async move {
+            let klines = client.get_klines(&symbol, &interval, &date).await.map_err(PyErr::from)?;
+            Ok(ArrowRecordBatch::new(arrow::klines_batch(&klines).map_err(arrow::arrow_err)?))
+        };
+        self.run_py(py, future)
+    }
+
+    /// Klines of `symbol` opening between `start` and `end` (UNIX ns, end
+    /// exclusive), oldest first, requested per JST trading day or year as
+    /// `interval` needs (see `kline_date`).
+    #[pyo3(name = "get_klines_range", signature = (symbol, interval, start, end, max_wait_ms=None) -> "collections.abc.Awaitable[typing.Any]")]
+    fn py_get_klines_range<'py>(&self, py: Python<'py>, symbol: String, interval: String, start: u64, end: u64, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
+        let future = async move {
+            let klines = client.get_klines_range(&symbol, &interval, time::from_ns(start), time::from_ns(end)).await.map_err(PyErr::from)?;
+            PyJson::from_serialize(&klines)
+        };
+        self.run_py(py, future)
+    }
+
+    /// `get_klines_range` as an Arrow RecordBatch (columns as `get_klines_arrow`).
+    #[pyo3(signature = (symbol, interval, start, end, max_wait_ms=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_klines_range_arrow<'py>(&self, py: Python<'py>, symbol: String, interval: String, start: u64, end: u64, max_wait_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.with_max_wait(max_wait_ms);
+        let future = async move {
+            let klines = client.get_klines_range(&symbol, &interval, time::from_ns(start), time::from_ns(end)).await.map_err(PyErr::from)?;
+            Ok(ArrowRecordBatch::new(arrow::klines_batch(&klines).map_err(arrow::arrow_err)?))
+        };
+        self.run_py(py, future)
+    }
+
+    /// Trade history as an Arrow RecordBatch (timestamp, price, size, side).
+    #[pyo3(signature = (symbol, page=None, count=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_trades_arrow<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let res: Vec<SymbolInfo> = client.public_get("/v1/symbols", None).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let trades = client.get_trades(&symbol, page.unwrap_or(1), count.unwrap_or(100)).await.map_err(PyErr::from)?;
+            Ok(ArrowRecordBatch::new(arrow::trades_batch(&trades.list).map_err(arrow::arrow_err)?))
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
+    }
+
+    /// Served from cache for `symbols_ttl_ms`; `force_refresh` fetches anew.
+    #[pyo3(signature = (force_refresh=false) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_symbols_py<'py>(&self, py: Python<'py>, force_refresh: bool) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            let res = client.symbols(force_refresh).await.map_err(PyErr::from)?;
+            PyJson::from_serialize(&res)
+        };
+        self.run_py(py, future)
     }
 
     // ========== Private API (Python) ==========
 
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_assets_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let res: Vec<Asset> = client.private_get("/v1/account/assets", None).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol, page, count) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_active_orders_py<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
@@ -144,21 +578,23 @@ impl GmocoinRestClient {
             if let Some(c) = count { query_owned.push(("count".to_string(), c.to_string())); }
             let query: Vec<(&str, &str)> = query_owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
             let res: OrdersList = client.private_get("/v1/activeOrders", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (order_id) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_executions_py<'py>(&self, py: Python<'py>, order_id: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let query = vec![("orderId", order_id.as_str())];
             let res: ExecutionsList = client.private_get("/v1/executions", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol, page, count) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_latest_executions_py<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
@@ -167,14 +603,28 @@ impl GmocoinRestClient {
             if let Some(c) = count { query_owned.push(("count".to_string(), c.to_string())); }
             let query: Vec<(&str, &str)> = query_owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
             let res: ExecutionsList = client.private_get("/v1/latestExecutions", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
+    }
+
+    /// Latest executions as an Arrow RecordBatch; columns follow `Execution`.
+    #[pyo3(signature = (symbol, page=None, count=None) -> "collections.abc.Awaitable[ArrowRecordBatch]")]
+    pub fn get_latest_executions_arrow<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            let (page, count) = (page.unwrap_or(1).to_string(), count.unwrap_or(100).to_string());
+            let query = [("symbol", symbol.as_str()), ("page", page.as_str()), ("count", count.as_str())];
+            let res: ExecutionsList = client.private_get("/v1/latestExecutions", Some(&query)).await.map_err(PyErr::from)?;
+            Ok(ArrowRecordBatch::new(arrow::executions_batch(&res.list).map_err(arrow::arrow_err)?))
+        };
+        self.run_py(py, future)
     }
 
     // ========== Order API (Python) ==========
 
-    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None, cancel_before=None, losscut_price=None, settle_type=None))]
+    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None, cancel_before=None, losscut_price=None, settle_type=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn post_order_py<'py>(
         &self,
         py: Python<'py>,
@@ -190,58 +640,55 @@ impl GmocoinRestClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let mut body = serde_json::json!({
-                "symbol": symbol,
-                "side": side,
-                "executionType": execution_type,
-                "size": size,
-            });
-            if let Some(p) = price { body["price"] = serde_json::json!(p); }
-            if let Some(tif) = time_in_force { body["timeInForce"] = serde_json::json!(tif); }
-            if let Some(cb) = cancel_before { body["cancelBefore"] = serde_json::json!(cb); }
-            if let Some(lp) = losscut_price { body["losscutPrice"] = serde_json::json!(lp); }
-            if let Some(st) = settle_type { body["settleType"] = serde_json::json!(st); }
-
-            let body_str = body.to_string();
-            let res: serde_json::Value = client.private_post("/v1/order", &body_str).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let res = client
+                .submit_order(
+                    &symbol, &side, &execution_type, &size, price.as_deref(), time_in_force.as_deref(),
+                    cancel_before, losscut_price.as_deref(), settle_type.as_deref(),
+                )
+                .await?;
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    #[pyo3(signature = (order_id, price, losscut_price=None))]
+    /// `symbol` / `side`: the order's, to round the prices to its tick
+    /// (see `change_order`); without them they are sent as given.
+    #[pyo3(signature = (order_id, price, losscut_price=None, symbol=None, side=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn post_change_order_py<'py>(
         &self,
         py: Python<'py>,
         order_id: String,
         price: String,
         losscut_price: Option<String>,
+        symbol: Option<String>,
+        side: Option<String>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let mut body = serde_json::json!({
-                "orderId": order_id,
-                "price": price,
-            });
-            if let Some(lp) = losscut_price { body["losscutPrice"] = serde_json::json!(lp); }
-
-            let body_str = body.to_string();
-            let res: serde_json::Value = client.private_post("/v1/changeOrder", &body_str).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let order_id = order_id
+                .parse::<u64>()
+                .map_err(|e| GmocoinError::InvalidInput(format!("Invalid order_id: {}", e)))?;
+            let res = client
+                .change_order(order_id, symbol.as_deref(), side.as_deref(), &price, losscut_price.as_deref())
+                .await?;
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (order_id) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn post_cancel_order_py<'py>(&self, py: Python<'py>, order_id: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let body = serde_json::json!({"orderId": order_id}).to_string();
             let res: serde_json::Value = client.private_post("/v1/cancelOrder", &body).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbols, side) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn post_cancel_bulk_order_py<'py>(
         &self,
         py: Python<'py>,
@@ -255,61 +702,78 @@ impl GmocoinRestClient {
 
             let body_str = body.to_string();
             let res: serde_json::Value = client.private_post("/v1/cancelBulkOrder", &body_str).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
     // ========== WS Auth (Python) ==========
 
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Any]")]
     pub fn post_ws_auth_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let res: serde_json::Value = client.private_post("/v1/ws-auth", "").await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (order_ids) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn post_cancel_orders_py<'py>(&self, py: Python<'py>, order_ids: Vec<u64>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let res = client.cancel_orders(&order_ids).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (token) -> "collections.abc.Awaitable[str]")]
     pub fn delete_ws_auth_py<'py>(&self, py: Python<'py>, token: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             client.delete_ws_auth(&token).await.map_err(PyErr::from)?;
             Ok("ok".to_string())
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (token) -> "collections.abc.Awaitable[str]")]
     pub fn put_ws_auth_py<'py>(&self, py: Python<'py>, token: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             client.put_ws_auth(&token).await.map_err(PyErr::from)?;
             // PUT returns no data; return the same token for convenience
-            serde_json::to_string(&token).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&token)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
     // ========== Position API (Python) ==========
 
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_margin_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let res: Margin = client.private_get("/v1/account/margin", None).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
+        };
+        self.run_py(py, future)
+    }
+
+    /// Trading volume and the account's fee tier (for `FeeModel`).
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_trading_volume_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let future = async move {
+            let res = client.get_trading_volume().await.map_err(PyErr::from)?;
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol, page, count) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_open_positions_py<'py>(&self, py: Python<'py>, symbol: String, page: Option<i32>, count: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
@@ -318,11 +782,12 @@ impl GmocoinRestClient {
             if let Some(c) = count { query_owned.push(("count".to_string(), c.to_string())); }
             let query: Vec<(&str, &str)> = query_owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
             let res: PositionsList = client.private_get("/v1/openPositions", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (symbol) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_position_summary_py<'py>(&self, py: Python<'py>, symbol: Option<String>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
@@ -334,12 +799,13 @@ impl GmocoinRestClient {
             let query: Vec<(&str, &str)> = query_owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
             let q = if query.is_empty() { None } else { Some(query.as_slice()) };
             let res: PositionSummaryList = client.private_get("/v1/positionSummary", q).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    #[pyo3(signature = (symbol, side, execution_type, settle_position, price=None, time_in_force=None))]
+    #[pyo3(signature = (symbol, side, execution_type, settle_position, price=None, time_in_force=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn post_close_order_py<'py>(
         &self,
         py: Python<'py>,
@@ -352,26 +818,17 @@ impl GmocoinRestClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let positions: Vec<serde_json::Value> = settle_position.iter()
-                .map(|(pid, size)| serde_json::json!({"positionId": pid, "size": size}))
-                .collect();
-            let mut body = serde_json::json!({
-                "symbol": symbol,
-                "side": side,
-                "executionType": execution_type,
-                "settlePosition": positions,
-            });
-            if let Some(p) = price { body["price"] = serde_json::json!(p); }
-            if let Some(tif) = time_in_force { body["timeInForce"] = serde_json::json!(tif); }
-
-            let body_str = body.to_string();
-            let res: serde_json::Value = client.private_post("/v1/closeOrder", &body_str).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let settle_position: Vec<(u64, &str)> = settle_position.iter().map(|(pid, size)| (*pid, size.as_str())).collect();
+            let ack = client
+                .close_order(&symbol, &side, &execution_type, &settle_position, price.as_deref(), time_in_force.as_deref())
+                .await?;
+            PyJson::from_serialize(&ack)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None))]
+    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
     pub fn post_close_bulk_order_py<'py>(
         &self,
         py: Python<'py>,
@@ -384,49 +841,185 @@ impl GmocoinRestClient {
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let mut body = serde_json::json!({
-                "symbol": symbol,
-                "side": side,
-                "executionType": execution_type,
-                "size": size,
-            });
-            if let Some(p) = price { body["price"] = serde_json::json!(p); }
-            if let Some(tif) = time_in_force { body["timeInForce"] = serde_json::json!(tif); }
-
-            let body_str = body.to_string();
-            let res: serde_json::Value = client.private_post("/v1/closeBulkOrder", &body_str).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let ack = client
+                .close_bulk_order(&symbol, &side, &execution_type, &size, price.as_deref(), time_in_force.as_deref())
+                .await?;
+            PyJson::from_serialize(&ack)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
-    pub fn put_losscut_price_py<'py>(&self, py: Python<'py>, position_id: u64, losscut_price: String) -> PyResult<Bound<'py, PyAny>> {
+    /// `symbol`: the position's, to round `losscut_price` to its tick.
+    #[pyo3(signature = (position_id, losscut_price, symbol=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn put_losscut_price_py<'py>(&self, py: Python<'py>, position_id: u64, losscut_price: String, symbol: Option<String>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
-            let body = serde_json::json!({
-                "positionId": position_id,
-                "losscutPrice": losscut_price,
-            }).to_string();
-            let res: serde_json::Value = client.private_put("/v1/changeLosscutPrice", &body).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            let ack = client.change_losscut_price(position_id, symbol.as_deref(), &losscut_price).await?;
+            PyJson::from_serialize(&ack)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 
+    #[pyo3(signature = (order_id) -> "collections.abc.Awaitable[typing.Any]")]
     pub fn get_order_py<'py>(&self, py: Python<'py>, order_id: String) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         let future = async move {
             let query = vec![("orderId", order_id.as_str())];
             let res: OrdersList = client.private_get("/v1/orders", Some(&query)).await.map_err(PyErr::from)?;
-            serde_json::to_string(&res).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            PyJson::from_serialize(&res)
         };
-        pyo3_async_runtimes::tokio::future_into_py(py, future)
+        self.run_py(py, future)
     }
 }
 
+#[cfg(feature = "python")]
+impl GmocoinRestClient {
+    /// Hand a request future to Python: an awaitable, or for a
+    /// `SyncGmocoinRestClient` the result itself, run on the shared runtime
+    /// with the GIL released.
+    fn run_py<'py, F, T>(&self, py: Python<'py>, future: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: Future<Output = PyResult<T>> + Send + 'static,
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        if !self.blocking {
+            return pyo3_async_runtimes::tokio::future_into_py(py, future);
+        }
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                "SyncGmocoinRestClient cannot block inside a client callback; use GmocoinRestClient",
+            ));
+        }
+        let value = py.detach(|| runtime::get().block_on(future))?;
+        value.into_bound_py_any(py)
+    }
+}
+
+/// `GmocoinRestClient` whose methods block and return their results directly,
+/// for scripts, notebooks and the REPL. Takes the same arguments.
+#[cfg(feature = "python")]
+#[pyclass(extends = GmocoinRestClient)]
+pub struct SyncGmocoinRestClient;
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SyncGmocoinRestClient {
+    #[new]
+    #[pyo3(signature = (*args, **kwargs))]
+    fn py_new(
+        args: &Bound<'_, pyo3::types::PyTuple>,
+        kwargs: Option<&Bound<'_, pyo3::types::PyDict>>,
+    ) -> PyResult<(Self, GmocoinRestClient)> {
+        let mut client: GmocoinRestClient = args.py().get_type::<GmocoinRestClient>().call(args, kwargs)?.extract()?;
+        client.blocking = true;
+        Ok((Self, client))
+    }
+}
+
+/// A process-unique id for an outbound REST request, e.g. `3f9a1c2e-000001a4`.
+fn next_request_id() -> String {
+    static PREFIX: OnceLock<u32> = OnceLock::new();
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    // Distinguishes processes (and restarts) writing to the same log
+    let prefix = PREFIX.get_or_init(|| {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos();
+        nanos ^ std::process::id().rotate_left(16)
+    });
+    format!("{:08x}-{:08x}", prefix, SEQ.fetch_add(1, Ordering::Relaxed) + 1)
+}
+
+/// The ack of a close order endpoint, whose `data` is the order id as a
+/// string.
+fn close_order_ack(endpoint: &str, data: &str) -> Result<CloseOrderAck, GmocoinError> {
+    let order_id = data.parse().map_err(|_| GmocoinError::Unknown(format!("{} returned order id '{}'", endpoint, data)))?;
+    Ok(CloseOrderAck { order_id })
+}
+
+#[cfg(feature = "python")]
+pub(crate) fn parse_group(name: &str) -> Result<EndpointGroup, GmocoinError> {
+    EndpointGroup::parse(name).ok_or_else(|| {
+        GmocoinError::InvalidInput(format!(
+            "Unknown endpoint group '{}' (expected 'public', 'account' or 'order')",
+            name
+        ))
+    })
+}
+
 // ========== Internal (Rust-only) ==========
 
 impl GmocoinRestClient {
+    /// This client for another API key pair (a second account): the same
+    /// connection pool, venue, circuit breaker and `/v1/symbols` cache, but
+    /// its own rate limiter (`rate_limit`, per group unless overridden),
+    /// in-flight slots, counters, clock offset and close signal. Rates
+    /// must pass `check_rate`.
+    pub fn for_account(
+        &self,
+        api_key: String,
+        api_secret: Zeroizing<String>,
+        rate_limit: RateLimitTier,
+        group_rate_limits: &HashMap<EndpointGroup, f64>,
+    ) -> Result<Self, GmocoinError> {
+        Ok(Self {
+            api_key,
+            api_secret: Arc::new(api_secret),
+            rate_limiter: RateLimiter::new(rate_limit.per_sec(), group_rate_limits)?,
+            in_flight: self.max_in_flight.map(|max| Arc::new(Semaphore::new(max))),
+            stats: ClientStats::default(),
+            clock_offset_ms: Arc::new(AtomicI64::new(0)),
+            max_wait: None,
+            deadline: None,
+            request_timeout: None,
+            closed: CloseSignal::default(),
+            ..self.clone()
+        })
+    }
+
+    /// TLS connector for WebSocket connections made on behalf of this client.
+    pub fn ws_connector(&self) -> Option<tokio_tungstenite::Connector> {
+        self.tls.ws_connector()
+    }
+
+    /// WebSocket handshake request for `url` with the configured headers.
+    pub fn ws_request(&self, url: &str) -> Result<WsRequest, GmocoinError> {
+        Ok(self.headers.ws_request(url)?)
+    }
+
+    pub fn venue(&self) -> &VenueProfile {
+        &self.venue
+    }
+
+    pub fn stats(&self) -> &ClientStats {
+        &self.stats
+    }
+
+    /// Token bucket of an endpoint group, e.g. to check its headroom.
+    pub fn rate_bucket(&self, group: EndpointGroup) -> &TokenBucket {
+        self.rate_limiter.bucket(group)
+    }
+
+    /// Whether the venue is answering, and which circuits are open.
+    pub fn rest_health(&self) -> RestHealth {
+        let mut health = RestHealth::new(&self.stats, self.circuit_breaker.open_groups());
+        if let (Some(slots), Some(max)) = (&self.in_flight, self.max_in_flight) {
+            health.in_flight = Some(max - slots.available_permits());
+            health.max_in_flight = Some(max);
+        }
+        health
+    }
+
+    /// State of the rate limit bucket of each endpoint group.
+    pub fn rate_limit_health(&self) -> BTreeMap<&'static str, LimiterHealth> {
+        [EndpointGroup::Public, EndpointGroup::Account, EndpointGroup::Order]
+            .into_iter()
+            .map(|group| (group.as_str(), LimiterHealth::new(self.rate_bucket(group))))
+            .collect()
+    }
+
+    pub fn health(&self) -> Health {
+        Health::new(Some(self.rest_health()), None, None, self.rate_limit_health())
+    }
+
     fn generate_signature(&self, text: &str) -> String {
         let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
             .expect("HMAC can take key of any size");
@@ -434,32 +1027,217 @@ impl GmocoinRestClient {
         hex::encode(mac.finalize().into_bytes())
     }
 
-    fn timestamp_ms() -> String {
+    fn local_ms() -> i64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
-            .as_millis()
-            .to_string()
+            .as_millis() as i64
+    }
+
+    /// API-TIMESTAMP value: local clock corrected by the learned server offset.
+    fn timestamp_ms(&self) -> String {
+        (Self::local_ms() + self.clock_offset_ms.load(Ordering::Relaxed)).to_string()
+    }
+
+    /// Re-derive the server clock offset from a response's `responsetime`.
+    fn sync_clock(&self, val: &serde_json::Value) {
+        let server_ms = val
+            .get("responsetime")
+            .and_then(|v| v.as_str())
+            .and_then(|s| time::timestamp_ms(s).ok());
+        if let Some(server_ms) = server_ms {
+            let offset = server_ms - Self::local_ms();
+            let previous = self.clock_offset_ms.swap(offset, Ordering::Relaxed);
+            if previous != offset {
+                warn!("GMO: Clock offset adjusted to {}ms (was {}ms)", offset, previous);
+            }
+        }
+    }
+
+    /// Send a signed request, re-signing and retrying once if the venue
+    /// rejected the timestamp or signature (the offset has been re-derived
+    /// from that rejection by `parse_response`).
+    async fn with_skew_retry<T, F, Fut>(&self, endpoint: &str, mut send: F) -> Result<T, GmocoinError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, GmocoinError>>,
+    {
+        match send().await {
+            Err(e) if e.is_clock_skew() => {
+                warn!("GMO: {} rejected ({}), retrying with corrected timestamp", endpoint, e);
+                send().await
+            }
+            other => other,
+        }
+    }
+
+    /// Clone of this client whose requests give up waiting for a rate limit
+    /// token after `max_wait_ms`.
+    pub fn with_max_wait(&self, max_wait_ms: Option<u64>) -> Self {
+        let mut client = self.clone();
+        client.max_wait = max_wait_ms.map(Duration::from_millis);
+        client
+    }
+
+    /// Take a rate limit token for `group`, shedding the request with
+    /// `GmocoinError::Shed` if `max_wait` or the deadline elapses first.
+    async fn acquire(&self, group: EndpointGroup, priority: Priority) -> Result<(), GmocoinError> {
+        let bucket = self.rate_limiter.bucket(group);
+        let acquired = match self.wait_limit() {
+            None => {
+                bucket.acquire_with(priority).await;
+                true
+            }
+            Some(wait) if wait.is_zero() => bucket.try_acquire(),
+            Some(wait) => bucket.acquire_timeout(priority, wait).await,
+        };
+        if acquired {
+            Ok(())
+        } else {
+            Err(GmocoinError::Shed(format!(
+                "rate limiter busy for '{}' endpoints, request shed",
+                group
+            )))
+        }
+    }
+
+    /// How long a request may wait for a rate limit token or request slot:
+    /// the shorter of `max_wait` and the time left to the deadline.
+    fn wait_limit(&self) -> Option<Duration> {
+        let until_deadline = self
+            .deadline
+            .map(|d| d.saturating_duration_since(tokio::time::Instant::now()));
+        match (self.max_wait, until_deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Take one of the `max_in_flight` request slots, held until the
+    /// request finishes; shed like `acquire` if none frees up in time.
+    async fn in_flight_permit(&self) -> Result<Option<OwnedSemaphorePermit>, GmocoinError> {
+        let Some(slots) = &self.in_flight else {
+            return Ok(None);
+        };
+        let permit = match self.wait_limit() {
+            None => slots.clone().acquire_owned().await.ok(),
+            Some(wait) if wait.is_zero() => slots.clone().try_acquire_owned().ok(),
+            Some(wait) => tokio::time::timeout(wait, slots.clone().acquire_owned()).await.ok().and_then(Result::ok),
+        };
+        match permit {
+            Some(permit) => Ok(Some(permit)),
+            None => Err(GmocoinError::Shed("too many requests in flight, request shed".to_string())),
+        }
+    }
+
+    /// Bound an outgoing request by the per-call timeout and the remaining
+    /// time to the deadline, refusing to send it at all once the deadline
+    /// has passed.
+    fn bounded(&self, builder: RequestBuilder) -> Result<RequestBuilder, GmocoinError> {
+        let remaining = match self.deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+                if remaining.is_zero() {
+                    return Err(GmocoinError::Shed("deadline passed before sending".to_string()));
+                }
+                Some(remaining)
+            }
+            None => None,
+        };
+        self.stats.request_sent();
+        let timeout = match (self.request_timeout, remaining) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Ok(match timeout {
+            Some(timeout) => builder.timeout(timeout),
+            None => builder,
+        })
+    }
+
+    /// Run a request through the circuit breaker for its endpoint group,
+    /// throttling the group's bucket if the venue answers with ERR-5003.
+    ///
+    /// Each call gets a request id, recorded on a `request` tracing span
+    /// and attached to the returned error.
+    async fn guarded<T, F>(&self, group: EndpointGroup, endpoint: &str, request: F) -> Result<T, GmocoinError>
+    where
+        F: Future<Output = Result<T, GmocoinError>>,
+    {
+        let request_id = next_request_id();
+        self.stats.request_started(&request_id);
+        let span = tracing::debug_span!("request", id = %request_id, endpoint = %endpoint);
+
+        let result = async {
+            if self.is_closed() {
+                return Err(GmocoinError::Closed);
+            }
+            self.circuit_breaker.check(group)?;
+            debug!("GMO: Request started");
+            let result = tokio::select! {
+                biased;
+                _ = self.closed() => return Err(GmocoinError::Closed),
+                result = async {
+                    let _slot = self.in_flight_permit().await?;
+                    request.await
+                } => result,
+            };
+            let result = match result {
+                Err(GmocoinError::RequestError(e)) if e.is_timeout() && self.deadline.is_some() => {
+                    Err(GmocoinError::DeadlineExceeded(e))
+                }
+                other => other,
+            };
+            self.circuit_breaker.record(group, &result);
+            match &result {
+                Ok(_) => {
+                    debug!("GMO: Request completed");
+                    self.stats.rest_ok();
+                }
+                Err(e) => {
+                    debug!("GMO: Request failed: {}", e);
+                    if e.is_venue_failure() {
+                        self.stats.rest_failure();
+                    } else if matches!(e, GmocoinError::ExchangeError { .. }) {
+                        self.stats.rest_ok();
+                    }
+                    if e.is_rate_limited() {
+                        self.rate_limiter.bucket(group).throttle();
+                    }
+                }
+            }
+            result
+        }
+        .instrument(span)
+        .await;
+
+        result.map_err(|source| {
+            let err = GmocoinError::Request { request_id, source: Box::new(source) };
+            self.stats.error(&err);
+            err
+        })
     }
 
-    /// Public GET: base_url_public + endpoint
+    /// Public GET: venue rest_public + endpoint
     pub async fn public_get<T: DeserializeOwned>(
         &self,
         endpoint: &str,
         query: Option<&[(&str, &str)]>,
     ) -> Result<T, GmocoinError> {
-        self.rate_limit_get.acquire().await;
+        self.guarded(EndpointGroup::Public, endpoint, async {
+            self.acquire(EndpointGroup::Public, Priority::Normal).await?;
 
-        let url = format!("{}{}", self.base_url_public, endpoint);
-        let mut builder = self.client.get(&url);
-        if let Some(q) = query {
-            builder = builder.query(q);
-        }
+            let url = format!("{}{}", self.venue.rest_public, endpoint);
+            let mut builder = self.client.get(&url);
+            if let Some(q) = query {
+                builder = builder.query(q);
+            }
 
-        let response = builder.send().await?;
-        let text = response.text().await?;
+            let response = self.bounded(builder)?.send().await?;
+            let text = response.text().await?;
 
-        self.parse_response::<T>(&text)
+            self.parse_response::<T>(&text)
+        }).await
     }
 
     /// Public GET with raw path (already includes query string)
@@ -467,44 +1245,49 @@ impl GmocoinRestClient {
         &self,
         path_with_query: &str,
     ) -> Result<T, GmocoinError> {
-        self.rate_limit_get.acquire().await;
-
-        let url = format!("{}{}", self.base_url_public, path_with_query);
-        let response = self.client.get(&url).send().await?;
-        let text = response.text().await?;
-        self.parse_response::<T>(&text)
+        self.guarded(EndpointGroup::Public, path_with_query, async {
+            self.acquire(EndpointGroup::Public, Priority::Normal).await?;
+
+            let url = format!("{}{}", self.venue.rest_public, path_with_query);
+            let response = self.bounded(self.client.get(&url))?.send().await?;
+            let text = response.text().await?;
+            self.parse_response::<T>(&text)
+        }).await
     }
 
-    /// Private GET: base_url_private + endpoint with auth headers
+    /// Private GET: venue rest_private + endpoint with auth headers
     pub async fn private_get<T: DeserializeOwned>(
         &self,
         endpoint: &str,
         query: Option<&[(&str, &str)]>,
     ) -> Result<T, GmocoinError> {
-        self.rate_limit_get.acquire().await;
+        let group = EndpointGroup::for_private(endpoint);
+        self.guarded(group, endpoint, self.with_skew_retry(endpoint, || async {
+            self.acquire(group, EndpointGroup::priority(endpoint)).await?;
 
-        let timestamp = Self::timestamp_ms();
+            let timestamp = self.timestamp_ms();
 
-        // GMO Coin GET signature: timestamp + "GET" + path (NO query params in signature)
-        let text_to_sign = format!("{}GET{}", timestamp, endpoint);
-        let signature = self.generate_signature(&text_to_sign);
+            // GMO Coin GET signature: timestamp + "GET" + path (NO query params in signature)
+            let text_to_sign = format!("{}GET{}", timestamp, endpoint);
+            let signature = self.generate_signature(&text_to_sign);
 
-        let url = format!("{}{}", self.base_url_private, endpoint);
-        let mut builder = self.client.get(&url)
-            .header("API-KEY", &self.api_key)
-            .header("API-TIMESTAMP", &timestamp)
-            .header("API-SIGN", signature);
+            let url = format!("{}{}", self.venue.rest_private, endpoint);
+            let mut builder = self.client.get(&url)
+                .header("API-KEY", &self.api_key)
+                .header("API-TIMESTAMP", &timestamp)
+                .header("API-SIGN", signature);
 
-        if let Some(q) = query {
-            builder = builder.query(q);
-        }
+            if let Some(q) = query {
+                builder = builder.query(q);
+            }
 
-        let response = builder.send().await?;
-        let text = response.text().await?;
-        self.parse_response::<T>(&text)
+            let response = self.bounded(builder)?.send().await?;
+            let text = response.text().await?;
+            self.parse_response::<T>(&text)
+        })).await
     }
 
-    /// Private POST: base_url_private + endpoint with auth headers
+    /// Private POST: venue rest_private + endpoint with auth headers
     pub async fn private_post<T: DeserializeOwned>(
         &self,
         endpoint: &str,
@@ -513,7 +1296,7 @@ impl GmocoinRestClient {
         self.private_request::<T>(Method::POST, endpoint, body).await
     }
 
-    /// Private PUT: base_url_private + endpoint with auth headers
+    /// Private PUT: venue rest_private + endpoint with auth headers
     pub async fn private_put<T: DeserializeOwned>(
         &self,
         endpoint: &str,
@@ -528,33 +1311,36 @@ impl GmocoinRestClient {
         endpoint: &str,
         body: &str,
     ) -> Result<T, GmocoinError> {
-        self.rate_limit_post.acquire().await;
+        let group = EndpointGroup::for_private(endpoint);
+        self.guarded(group, endpoint, self.with_skew_retry(endpoint, || async {
+            self.acquire(group, EndpointGroup::priority(endpoint)).await?;
 
-        let timestamp = Self::timestamp_ms();
-        let method_str = method.as_str();
+            let timestamp = self.timestamp_ms();
+            let method_str = method.as_str();
 
-        // GMO Coin signature: POST includes body, PUT/DELETE do not
-        let text_to_sign = if method == Method::POST {
-            format!("{}{}{}{}", timestamp, method_str, endpoint, body)
-        } else {
-            format!("{}{}{}", timestamp, method_str, endpoint)
-        };
-        let signature = self.generate_signature(&text_to_sign);
+            // GMO Coin signature: POST includes body, PUT/DELETE do not
+            let text_to_sign = if method == Method::POST {
+                format!("{}{}{}{}", timestamp, method_str, endpoint, body)
+            } else {
+                format!("{}{}{}", timestamp, method_str, endpoint)
+            };
+            let signature = self.generate_signature(&text_to_sign);
 
-        let url = format!("{}{}", self.base_url_private, endpoint);
-        let mut builder = self.client.request(method, &url)
-            .header("API-KEY", &self.api_key)
-            .header("API-TIMESTAMP", &timestamp)
-            .header("API-SIGN", signature)
-            .header("Content-Type", "application/json");
+            let url = format!("{}{}", self.venue.rest_private, endpoint);
+            let mut builder = self.client.request(method.clone(), &url)
+                .header("API-KEY", &self.api_key)
+                .header("API-TIMESTAMP", &timestamp)
+                .header("API-SIGN", signature)
+                .header("Content-Type", "application/json");
 
-        if !body.is_empty() {
-            builder = builder.body(body.to_string());
-        }
+            if !body.is_empty() {
+                builder = builder.body(body.to_string());
+            }
 
-        let response = builder.send().await?;
-        let text = response.text().await?;
-        self.parse_response::<T>(&text)
+            let response = self.bounded(builder)?.send().await?;
+            let text = response.text().await?;
+            self.parse_response::<T>(&text)
+        })).await
     }
 
     /// Parse GMO Coin response: {"status": 0, "data": ..., "responsetime": "..."}
@@ -583,10 +1369,9 @@ impl GmocoinRestClient {
                 }
             }
         } else {
-            // Extract error messages
-            let messages = val
-                .get("messages")
-                .and_then(|m| m.as_array())
+            // Extract error messages and codes
+            let entries = val.get("messages").and_then(|m| m.as_array());
+            let messages = entries
                 .map(|arr| {
                     arr.iter()
                         .filter_map(|msg| msg.get("message_string").and_then(|s| s.as_str()))
@@ -594,11 +1379,249 @@ impl GmocoinRestClient {
                         .join("; ")
                 })
                 .unwrap_or_else(|| format!("Unknown error. Body: {}", text));
+            let codes = entries
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|msg| msg.get("message_code").and_then(|s| s.as_str()))
+                        .map(|s| s.to_string())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
 
-            Err(GmocoinError::ExchangeError { status, messages })
+            let err = GmocoinError::ExchangeError { status, messages, codes };
+            if err.is_clock_skew() {
+                self.sync_clock(&val);
+            }
+            Err(err)
         }
     }
 
+    // Public market data
+
+    pub async fn get_status(&self) -> Result<serde_json::Value, GmocoinError> {
+        self.public_get("/v1/status", None).await
+    }
+
+    /// All symbols when `symbol` is `None`.
+    pub async fn get_ticker(&self, symbol: Option<&str>) -> Result<Vec<Ticker>, GmocoinError> {
+        let query = symbol.map(|s| vec![("symbol", s)]);
+        self.public_get("/v1/ticker", query.as_deref()).await
+    }
+
+    /// Tickers of `symbols` by symbol. A single symbol is asked for alone;
+    /// several are taken from one all-symbols request, which costs one rate
+    /// limit token instead of one per symbol.
+    pub async fn get_tickers(&self, symbols: &[String]) -> Result<HashMap<String, Ticker>, GmocoinError> {
+        let tickers = match symbols {
+            [] => return Ok(HashMap::new()),
+            [symbol] => self.get_ticker(Some(symbol)).await?,
+            _ => self.get_ticker(None).await?,
+        };
+        let by_symbol: HashMap<String, Ticker> = tickers
+            .into_iter()
+            .filter(|t| symbols.contains(&t.symbol))
+            .map(|t| (t.symbol.clone(), t))
+            .collect();
+        let missing: Vec<&str> = symbols.iter().filter(|s| !by_symbol.contains_key(*s)).map(String::as_str).collect();
+        if !missing.is_empty() {
+            return Err(GmocoinError::InvalidInput(format!("No ticker for {}", missing.join(", "))));
+        }
+        Ok(by_symbol)
+    }
+
+    pub async fn get_orderbooks(&self, symbol: &str) -> Result<Depth, GmocoinError> {
+        self.public_get("/v1/orderbooks", Some(&[("symbol", symbol)])).await
+    }
+
+    pub async fn get_trades(&self, symbol: &str, page: i32, count: i32) -> Result<TradesList, GmocoinError> {
+        let (page, count) = (page.to_string(), count.to_string());
+        let query = [("symbol", symbol), ("page", page.as_str()), ("count", count.as_str())];
+        self.public_get("/v1/trades", Some(&query)).await
+    }
+
+    /// `date` is `YYYYMMDD` for minute/hour intervals, `YYYY` otherwise.
+    pub async fn get_klines(&self, symbol: &str, interval: &str, date: &str) -> Result<Vec<Kline>, GmocoinError> {
+        let path = format!("/v1/klines?symbol={}&interval={}&date={}", symbol, interval, date);
+        self.public_get_raw(&path).await
+    }
+
+    /// Klines opening in `[start, end)`, oldest first; see `klines`.
+    pub async fn get_klines_range(
+        &self,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>, GmocoinError> {
+        klines::klines_between(self, symbol, interval, start, end).await
+    }
+
+    /// Served from cache while younger than `symbols_ttl`.
+    pub async fn get_symbols(&self) -> Result<Vec<SymbolInfo>, GmocoinError> {
+        self.symbols(false).await
+    }
+
+    /// `/v1/symbols` fetched anew, refreshing the cache.
+    pub async fn refresh_symbols(&self) -> Result<Vec<SymbolInfo>, GmocoinError> {
+        self.symbols(true).await
+    }
+
+    /// `price` as sent for `symbol`: see `round_to_tick`. Passed through
+    /// as given when the symbol has no known `tickSize`.
+    pub async fn format_price(&self, symbol: &str, side: Option<&str>, price: &str) -> Result<String, GmocoinError> {
+        let Some(tick) = self.symbol_param(symbol, "tickSize").await else {
+            return Ok(price.to_string());
+        };
+        Ok(round_to_tick(decimal("price", price)?, tick, side).to_string())
+    }
+
+    /// `format_price` for `symbol`, or `price` as given without one.
+    async fn format_price_for(&self, symbol: Option<&str>, side: Option<&str>, price: &str) -> Result<String, GmocoinError> {
+        match symbol {
+            Some(symbol) => self.format_price(symbol, side, price).await,
+            None => Ok(price.to_string()),
+        }
+    }
+
+    /// `size` as sent for `symbol`: see `to_size_step`, or an error for a
+    /// size off the `sizeStep` with `strict_sizes`. Passed through as given
+    /// when the symbol has no known `sizeStep`.
+    pub async fn format_size(&self, symbol: &str, size: &str) -> Result<String, GmocoinError> {
+        let Some(step) = self.symbol_param(symbol, "sizeStep").await else {
+            return Ok(size.to_string());
+        };
+        let parsed = decimal("size", size)?;
+        let rounded = to_size_step(parsed, step);
+        if self.strict_sizes && rounded != parsed {
+            return Err(GmocoinError::InvalidInput(format!(
+                "Size {} of {} is not a multiple of sizeStep {}",
+                size, symbol, step
+            )));
+        }
+        if rounded <= Decimal::ZERO {
+            return Err(GmocoinError::InvalidInput(format!("Size {} of {} is below sizeStep {}", size, symbol, step)));
+        }
+        Ok(rounded.to_string())
+    }
+
+    /// `OrderTooLarge` when `size` is over the `maxOrderSize` of `symbol`.
+    pub async fn check_order_size(&self, symbol: &str, size: &str) -> Result<(), GmocoinError> {
+        match self.symbol_param(symbol, "maxOrderSize").await {
+            Some(max) if decimal("size", size)? > max => Err(GmocoinError::OrderTooLarge {
+                symbol: symbol.to_string(),
+                size: size.to_string(),
+                max_order_size: max.normalize().to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// An error when a closing order of `size` is below the
+    /// `minCloseOrderSize` of `symbol` (not the same as `minOrderSize`).
+    pub async fn check_close_size(&self, symbol: &str, size: &str) -> Result<(), GmocoinError> {
+        match self.symbol_param(symbol, "minCloseOrderSize").await {
+            Some(min) if decimal("size", size)? < min => Err(GmocoinError::InvalidInput(format!(
+                "Close size {} of {} is below minCloseOrderSize {}",
+                size, symbol, min.normalize()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// `size` as orders of at most the `maxOrderSize` of `symbol`: full
+    /// ones then the rest. A rest below `minOrderSize` is topped up from the
+    /// last full order, or the split fails before anything is sent. One
+    /// order when the limit is unknown.
+    pub async fn split_order_size(&self, symbol: &str, size: &str) -> Result<Vec<String>, GmocoinError> {
+        let total = decimal("size", size)?;
+        let Some(max) = self.symbol_param(symbol, "maxOrderSize").await.filter(|max| total > *max) else {
+            return Ok(vec![size.to_string()]);
+        };
+        let full = (total / max).floor();
+        let rest = total - full * max;
+        let mut sizes = vec![max; usize::try_from(full).unwrap_or_default()];
+        match self.symbol_param(symbol, "minOrderSize").await {
+            _ if rest.is_zero() => {}
+            Some(min) if rest < min => {
+                // The last full order gives up what the rest lacks
+                let topped_up = max + rest - min;
+                if topped_up < min {
+                    return Err(GmocoinError::InvalidInput(format!(
+                        "Order size {} of {} cannot be split into orders between minOrderSize {} and maxOrderSize {}",
+                        size, symbol, min.normalize(), max.normalize()
+                    )));
+                }
+                sizes.pop();
+                sizes.extend([topped_up, min]);
+            }
+            _ => sizes.push(rest),
+        }
+        Ok(sizes.into_iter().map(|s| s.normalize().to_string()).collect())
+    }
+
+    /// An error when `/v1/symbols` does not list `symbol`, so a typo fails
+    /// here rather than as a missing feed or an exchange error. A symbol
+    /// missing from the cache is looked up again once the cache is older
+    /// than `symbols_ttl`, so new listings are accepted. Passes when the
+    /// list cannot be fetched.
+    pub async fn check_symbol(&self, symbol: &str) -> Result<(), GmocoinError> {
+        let listed = |symbols: &[SymbolInfo]| symbols.iter().any(|info| info.symbol == symbol);
+        if self.with_symbols(listed).await != Some(false) {
+            return Ok(());
+        }
+        match self.get_symbols().await {
+            Ok(symbols) if !listed(&symbols) => Err(GmocoinError::InvalidInput(format!("Unknown symbol '{}'", symbol))),
+            _ => Ok(()),
+        }
+    }
+
+    /// `tickSize`, `sizeStep`, `minOrderSize`, `maxOrderSize` or
+    /// `minCloseOrderSize` of `symbol`.
+    async fn symbol_param(&self, symbol: &str, field: &str) -> Option<Decimal> {
+        self.with_symbols(|symbols| {
+            let info = symbols.iter().find(|info| info.symbol == symbol)?;
+            let value = match field {
+                "sizeStep" => &info.size_step,
+                "minOrderSize" => &info.min_order_size,
+                "maxOrderSize" => &info.max_order_size,
+                "minCloseOrderSize" => &info.min_close_order_size,
+                _ => &info.tick_size,
+            };
+            value.as_deref().and_then(|v| decimal(field, v).ok()).filter(|v| *v > Decimal::ZERO)
+        })
+        .await
+        .flatten()
+    }
+
+    /// `f` of the cached symbols however old (they rarely change), so
+    /// orders do not wait on `/v1/symbols` once it has been fetched; `None`
+    /// when it cannot be.
+    async fn with_symbols<T>(&self, f: impl FnOnce(&[SymbolInfo]) -> T) -> Option<T> {
+        if let Some((_, symbols)) = self.symbols_cache.lock().await.as_ref() {
+            return Some(f(symbols));
+        }
+        let symbols = self
+            .get_symbols()
+            .await
+            .map_err(|e| warn!("GMO: Symbols unavailable, order parameters not checked: {}", e))
+            .ok()?;
+        Some(f(&symbols))
+    }
+
+    /// Concurrent callers wait for one fetch rather than each sending one;
+    /// a failed fetch leaves the cache as it was.
+    async fn symbols(&self, force_refresh: bool) -> Result<Vec<SymbolInfo>, GmocoinError> {
+        let mut cache = self.symbols_cache.lock().await;
+        if let Some((fetched, symbols)) = cache.as_ref() {
+            if !force_refresh && fetched.elapsed() < self.symbols_ttl {
+                return Ok(symbols.clone());
+            }
+        }
+        let symbols: Vec<SymbolInfo> = self.public_get("/v1/symbols", None).await?;
+        *cache = Some((tokio::time::Instant::now(), symbols.clone()));
+        Ok(symbols)
+    }
+
     // Internal Rust methods for use by execution_client
 
     pub async fn post_ws_auth(&self) -> Result<String, GmocoinError> {
@@ -618,6 +1641,7 @@ impl GmocoinRestClient {
         self.private_get("/v1/account/assets", None).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn submit_order(
         &self,
         symbol: &str,
@@ -630,6 +1654,9 @@ impl GmocoinRestClient {
         losscut_price: Option<&str>,
         settle_type: Option<&str>,
     ) -> Result<serde_json::Value, GmocoinError> {
+        self.check_symbol(symbol).await?;
+        let size = self.format_size(symbol, size).await?;
+        self.check_order_size(symbol, &size).await?;
         let mut body = serde_json::json!({
             "symbol": symbol,
             "side": side,
@@ -637,7 +1664,7 @@ impl GmocoinRestClient {
             "size": size,
         });
         if let Some(p) = price {
-            body["price"] = serde_json::json!(p);
+            body["price"] = serde_json::json!(self.format_price(symbol, Some(side), p).await?);
         }
         if let Some(tif) = time_in_force {
             body["timeInForce"] = serde_json::json!(tif);
@@ -646,7 +1673,7 @@ impl GmocoinRestClient {
             body["cancelBefore"] = serde_json::json!(cb);
         }
         if let Some(lp) = losscut_price {
-            body["losscutPrice"] = serde_json::json!(lp);
+            body["losscutPrice"] = serde_json::json!(self.format_price(symbol, None, lp).await?);
         }
         if let Some(st) = settle_type {
             body["settleType"] = serde_json::json!(st);
@@ -656,18 +1683,23 @@ impl GmocoinRestClient {
         self.private_post("/v1/order", &body_str).await
     }
 
+    /// `changeOrder` of `order_id`. With the order's `symbol` (and `side`),
+    /// `price` and `losscut_price` are formatted for it (see
+    /// `format_price`); without, they are sent as given.
     pub async fn change_order(
         &self,
         order_id: u64,
+        symbol: Option<&str>,
+        side: Option<&str>,
         price: &str,
         losscut_price: Option<&str>,
     ) -> Result<serde_json::Value, GmocoinError> {
         let mut body = serde_json::json!({
             "orderId": order_id,
-            "price": price,
+            "price": self.format_price_for(symbol, side, price).await?,
         });
         if let Some(lp) = losscut_price {
-            body["losscutPrice"] = serde_json::json!(lp);
+            body["losscutPrice"] = serde_json::json!(self.format_price_for(symbol, None, lp).await?);
         }
         let body_str = body.to_string();
         self.private_post("/v1/changeOrder", &body_str).await
@@ -683,12 +1715,25 @@ impl GmocoinRestClient {
         self.private_post("/v1/cancelOrders", &body).await
     }
 
+    /// Cancel every open order of `symbols`.
+    pub async fn cancel_bulk_order(&self, symbols: &[String]) -> Result<serde_json::Value, GmocoinError> {
+        let body = serde_json::json!({"symbols": symbols}).to_string();
+        self.private_post("/v1/cancelBulkOrder", &body).await
+    }
+
     pub async fn get_order(&self, order_id: u64) -> Result<OrdersList, GmocoinError> {
         let oid_str = order_id.to_string();
         let query = vec![("orderId", oid_str.as_str())];
         self.private_get("/v1/orders", Some(&query)).await
     }
 
+    /// Up to 10 orders per call (`orderId` is comma-separated).
+    pub async fn get_orders(&self, order_ids: &[u64]) -> Result<OrdersList, GmocoinError> {
+        let ids = order_ids.iter().map(u64::to_string).collect::<Vec<_>>().join(",");
+        let query = vec![("orderId", ids.as_str())];
+        self.private_get("/v1/orders", Some(&query)).await
+    }
+
     pub async fn get_active_orders(&self, symbol: &str, page: i32, count: i32) -> Result<serde_json::Value, GmocoinError> {
         let page_str = page.to_string();
         let count_str = count.to_string();
@@ -746,20 +1791,26 @@ impl GmocoinRestClient {
         settle_position: &[(u64, &str)],
         price: Option<&str>,
         time_in_force: Option<&str>,
-    ) -> Result<serde_json::Value, GmocoinError> {
-        let positions: Vec<serde_json::Value> = settle_position.iter()
-            .map(|(pid, size)| serde_json::json!({"positionId": pid, "size": size}))
-            .collect();
+    ) -> Result<CloseOrderAck, GmocoinError> {
+        let mut positions = Vec::with_capacity(settle_position.len());
+        let mut total = Decimal::ZERO;
+        for (pid, size) in settle_position {
+            let size = self.format_size(symbol, size).await?;
+            total += decimal("size", &size)?;
+            positions.push(serde_json::json!({"positionId": pid, "size": size}));
+        }
+        self.check_close_size(symbol, &total.to_string()).await?;
         let mut body = serde_json::json!({
             "symbol": symbol,
             "side": side,
             "executionType": execution_type,
             "settlePosition": positions,
         });
-        if let Some(p) = price { body["price"] = serde_json::json!(p); }
+        if let Some(p) = price { body["price"] = serde_json::json!(self.format_price(symbol, Some(side), p).await?); }
         if let Some(tif) = time_in_force { body["timeInForce"] = serde_json::json!(tif); }
         let body_str = body.to_string();
-        self.private_post("/v1/closeOrder", &body_str).await
+        let data: String = self.private_post("/v1/closeOrder", &body_str).await?;
+        close_order_ack("/v1/closeOrder", &data)
     }
 
     pub async fn close_bulk_order(
@@ -770,31 +1821,44 @@ impl GmocoinRestClient {
         size: &str,
         price: Option<&str>,
         time_in_force: Option<&str>,
-    ) -> Result<serde_json::Value, GmocoinError> {
+    ) -> Result<CloseOrderAck, GmocoinError> {
+        let size = self.format_size(symbol, size).await?;
+        self.check_order_size(symbol, &size).await?;
+        self.check_close_size(symbol, &size).await?;
         let mut body = serde_json::json!({
             "symbol": symbol,
             "side": side,
             "executionType": execution_type,
             "size": size,
         });
-        if let Some(p) = price { body["price"] = serde_json::json!(p); }
+        if let Some(p) = price { body["price"] = serde_json::json!(self.format_price(symbol, Some(side), p).await?); }
         if let Some(tif) = time_in_force { body["timeInForce"] = serde_json::json!(tif); }
         let body_str = body.to_string();
-        self.private_post("/v1/closeBulkOrder", &body_str).await
+        let data: String = self.private_post("/v1/closeBulkOrder", &body_str).await?;
+        close_order_ack("/v1/closeBulkOrder", &data)
     }
 
-    pub async fn change_losscut_price(&self, position_id: u64, losscut_price: &str) -> Result<serde_json::Value, GmocoinError> {
+    /// `changeLosscutPrice`, with `losscut_price` formatted for the
+    /// position's `symbol` when given (see `format_price`), else sent as
+    /// given.
+    pub async fn change_losscut_price(&self, position_id: u64, symbol: Option<&str>, losscut_price: &str) -> Result<LosscutPriceAck, GmocoinError> {
+        let losscut_price = self.format_price_for(symbol, None, losscut_price).await?;
         let body = serde_json::json!({
             "positionId": position_id,
             "losscutPrice": losscut_price,
         }).to_string();
-        self.private_put("/v1/changeLosscutPrice", &body).await
+        let _: serde_json::Value = self.private_put("/v1/changeLosscutPrice", &body).await?;
+        Ok(LosscutPriceAck { position_id, losscut_price })
     }
 
     pub async fn get_margin(&self) -> Result<Margin, GmocoinError> {
         self.private_get("/v1/account/margin", None).await
     }
 
+    pub async fn get_trading_volume(&self) -> Result<TradingVolume, GmocoinError> {
+        self.private_get("/v1/account/tradingVolume", None).await
+    }
+
     pub async fn delete_ws_auth(&self, token: &str) -> Result<(), GmocoinError> {
         let body = serde_json::json!({"token": token}).to_string();
         let _: serde_json::Value = self.private_request(reqwest::Method::DELETE, "/v1/ws-auth", &body).await?;
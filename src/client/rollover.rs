@@ -0,0 +1,209 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+use crate::client::calendar::TradingCalendar;
+use crate::client::rest::GmocoinRestClient;
+use crate::error::GmocoinError;
+use crate::model::account::Margin;
+use crate::model::order::PositionSummary;
+use crate::model::report::decimal;
+#[cfg(feature = "python")]
+use crate::model::report::from_json;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+
+/// GMO's leverage fee: 0.04% of the position value per rollover.
+pub const DEFAULT_LEVERAGE_FEE_RATE: Decimal = Decimal::from_parts(4, 0, 0, false, 4);
+
+/// When the rollover projection and reconciliation run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RolloverConfig {
+    /// Leverage fee per rollover, as a fraction of the position value.
+    pub fee_rate: Decimal,
+    /// How long before the rollover the cost is projected.
+    pub lead: Duration,
+    /// How long after the rollover the fees are reconciled.
+    pub settle: Duration,
+}
+
+impl Default for RolloverConfig {
+    fn default() -> Self {
+        Self {
+            fee_rate: DEFAULT_LEVERAGE_FEE_RATE,
+            lead: Duration::from_secs(300),
+            settle: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RolloverConfig {
+    /// Defaults with any of the given settings replaced.
+    pub fn new(lead_ms: Option<u64>, fee_rate: Option<f64>) -> Result<Self, GmocoinError> {
+        let defaults = Self::default();
+        let fee_rate = match fee_rate {
+            Some(rate) => Decimal::try_from(rate)
+                .ok()
+                .filter(|rate| !rate.is_sign_negative())
+                .ok_or_else(|| GmocoinError::InvalidInput(format!("Invalid leverage fee rate {}", rate)))?,
+            None => defaults.fee_rate,
+        };
+        Ok(Self {
+            fee_rate,
+            lead: lead_ms.map(Duration::from_millis).unwrap_or(defaults.lead),
+            ..defaults
+        })
+    }
+}
+
+/// The leverage fee one position summary is expected to pay.
+#[derive(Clone, Debug, Serialize)]
+pub struct RolloverCost {
+    pub symbol: String,
+    pub side: String,
+    pub size: Decimal,
+    /// Average open price.
+    pub price: Decimal,
+    pub notional: Decimal,
+    /// `notional` × fee rate, rounded down to the yen.
+    pub fee: Decimal,
+}
+
+/// Leverage fees expected at the next rollover.
+#[derive(Clone, Debug, Serialize)]
+pub struct RolloverProjection {
+    /// ISO 8601 (UTC)
+    pub rollover_at: String,
+    pub fee_rate: Decimal,
+    pub positions: Vec<RolloverCost>,
+    pub total: Decimal,
+}
+
+/// Projected against charged fees after a rollover.
+#[derive(Clone, Debug, Serialize)]
+pub struct RolloverReconciliation {
+    pub rollover_at: String,
+    pub projected: Decimal,
+    /// Drop in the margin account's cash (valuation less unrealized P&L)
+    /// across the rollover; trades or transfers in between distort it.
+    pub actual: Decimal,
+    /// `actual` - `projected`
+    pub difference: Decimal,
+}
+
+/// Leverage fees of open positions at `fee_rate`, and their total.
+pub fn project(summaries: &[PositionSummary], fee_rate: Decimal) -> Result<(Vec<RolloverCost>, Decimal), GmocoinError> {
+    let positions = summaries
+        .iter()
+        .map(|summary| {
+            let size = decimal("sumPositionQuantity", &summary.sum_position_quantity)?;
+            let price = decimal("averagePositionRate", &summary.average_position_rate)?;
+            let notional = size * price;
+            Ok(RolloverCost {
+                symbol: summary.symbol.clone(),
+                side: summary.side.clone(),
+                size,
+                price,
+                notional,
+                fee: (notional * fee_rate).floor(),
+            })
+        })
+        .collect::<Result<Vec<_>, GmocoinError>>()?;
+    let total = positions.iter().map(|p| p.fee).sum();
+    Ok((positions, total))
+}
+
+/// Valuation less unrealized P&L, when GMO reports both.
+fn cash(margin: &Margin) -> Option<Decimal> {
+    let valuation = decimal("actualProfitLoss", margin.actual_profit_loss.as_deref()?).ok()?;
+    let unrealized = decimal("profitLoss", margin.profit_loss.as_deref()?).ok()?;
+    Some(valuation - unrealized)
+}
+
+fn iso(ts: DateTime<Utc>) -> String {
+    ts.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+async fn sleep_until(at: DateTime<Utc>) {
+    sleep((at - Utc::now()).to_std().unwrap_or_default()).await;
+}
+
+async fn margin_cash(client: &GmocoinRestClient) -> Option<Decimal> {
+    match client.get_margin().await {
+        Ok(margin) => cash(&margin),
+        Err(e) => {
+            warn!("GMO: Rollover margin query failed: {}", e);
+            None
+        }
+    }
+}
+
+/// `config.lead` before each rollover of `calendar`, emit a
+/// `"RolloverProjection"` for the open positions; `config.settle` after it,
+/// a `"RolloverReconciliation"` when the margin account was readable both
+/// times. Rollovers without positions emit nothing.
+pub(crate) async fn scheduler(
+    client: GmocoinRestClient,
+    calendar: TradingCalendar,
+    config: RolloverConfig,
+    emit: impl Fn(&'static str, serde_json::Value),
+) {
+    let lead = chrono::TimeDelta::from_std(config.lead).unwrap_or_default();
+    let settle = chrono::TimeDelta::from_std(config.settle).unwrap_or_default();
+    loop {
+        let rollover = calendar.next_rollover(Utc::now());
+        sleep_until(rollover - lead).await;
+
+        let projected = match client.get_position_summary(None).await.and_then(|s| project(&s.list, config.fee_rate)) {
+            Ok((positions, _)) if positions.is_empty() => None,
+            Ok((positions, total)) => {
+                info!("GMO: Projected leverage fees at {}: {} JPY", iso(rollover), total);
+                emit("RolloverProjection", serde_json::to_value(RolloverProjection {
+                    rollover_at: iso(rollover),
+                    fee_rate: config.fee_rate,
+                    positions,
+                    total,
+                }).expect("projection serializes"));
+                Some(total)
+            }
+            Err(e) => {
+                warn!("GMO: Rollover projection failed: {}", e);
+                None
+            }
+        };
+        let before = match projected {
+            Some(_) => margin_cash(&client).await,
+            None => None,
+        };
+
+        sleep_until(rollover + settle).await;
+        let (Some(projected), Some(before)) = (projected, before) else { continue };
+        let Some(after) = margin_cash(&client).await else { continue };
+        let actual = before - after;
+        if actual != projected {
+            warn!("GMO: Leverage fees at {} were {} JPY, projected {}", iso(rollover), actual, projected);
+        }
+        emit("RolloverReconciliation", serde_json::to_value(RolloverReconciliation {
+            rollover_at: iso(rollover),
+            projected,
+            actual,
+            difference: actual - projected,
+        }).expect("reconciliation serializes"));
+    }
+}
+
+/// `{"positions": [...], "total": ...}`: leverage fees the given
+/// `/v1/positionSummary` entries pay at a rollover (`fee_rate` defaults to
+/// 0.0004).
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "project_rollover_cost", signature = (summaries, fee_rate=None))]
+pub fn py_project_rollover_cost(summaries: PyJson, fee_rate: Option<f64>) -> PyResult<PyJson> {
+    let summaries: Vec<PositionSummary> = from_json("position summary", summaries)?;
+    let (positions, total) = project(&summaries, RolloverConfig::new(None, fee_rate)?.fee_rate)?;
+    PyJson::from_serialize(&serde_json::json!({"positions": positions, "total": total}))
+}
@@ -0,0 +1,317 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+use crate::error::GmocoinError;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+use crate::time::now_ms;
+
+/// Entries covered by each `"checksum"` line.
+const CHUNK_LINES: usize = 1000;
+
+/// `meta.format` of a recorded session.
+const FORMAT: &str = "gmocoin-session";
+const VERSION: u32 = 1;
+
+/// One line of a recorded Private WS session fixture (JSONL).
+#[derive(Serialize, Deserialize)]
+pub struct SessionEntry<'a> {
+    /// Epoch ms
+    pub ts: i64,
+    /// `"auth"` (token obtained, value redacted), `"send"` or `"recv"`;
+    /// `"meta"`, `"checksum"` and `"end"` lines carry the integrity data
+    pub dir: Cow<'a, str>,
+    /// Frame text exactly as sent / received (JSON for the integrity lines)
+    pub text: Cow<'a, str>,
+}
+
+/// First line of a recording.
+#[derive(Serialize, Deserialize)]
+struct Meta {
+    format: String,
+    version: u32,
+    started_ms: i64,
+    chunk_lines: usize,
+    crate_version: String,
+}
+
+/// SHA-256 of the `lines` lines (bytes and newlines) since the previous
+/// checksum; `chunk` counts from 0.
+#[derive(Serialize, Deserialize)]
+struct Checksum {
+    chunk: usize,
+    lines: usize,
+    sha256: String,
+}
+
+/// Last line of a recording stopped cleanly.
+#[derive(Serialize, Deserialize)]
+struct End {
+    lines: usize,
+    chunks: usize,
+}
+
+struct Recording {
+    writer: BufWriter<File>,
+    hasher: Sha256,
+    chunk: usize,
+    chunk_lines: usize,
+    lines: usize,
+}
+
+impl Recording {
+    fn create(path: &str) -> std::io::Result<Self> {
+        let mut recording = Self {
+            writer: BufWriter::new(File::create(path)?),
+            hasher: Sha256::new(),
+            chunk: 0,
+            chunk_lines: 0,
+            lines: 0,
+        };
+        let meta = Meta {
+            format: FORMAT.to_string(),
+            version: VERSION,
+            started_ms: now_ms(),
+            chunk_lines: CHUNK_LINES,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        };
+        recording.write_entry("meta", &serde_json::to_string(&meta).expect("meta serializes"))?;
+        Ok(recording)
+    }
+
+    fn write_line(&mut self, dir: &str, text: &str) -> std::io::Result<String> {
+        let entry = SessionEntry { ts: now_ms(), dir: Cow::Borrowed(dir), text: Cow::Borrowed(text) };
+        let line = serde_json::to_string(&entry).expect("session entry serializes") + "\n";
+        self.writer.write_all(line.as_bytes())?;
+        Ok(line)
+    }
+
+    /// A checksummed entry, closing the chunk when it is full.
+    fn write_entry(&mut self, dir: &str, text: &str) -> std::io::Result<()> {
+        let line = self.write_line(dir, text)?;
+        self.hasher.update(line.as_bytes());
+        self.chunk_lines += 1;
+        self.lines += 1;
+        if self.chunk_lines == CHUNK_LINES {
+            self.write_checksum()?;
+        }
+        // Flush per line so a crash still leaves a usable fixture
+        self.writer.flush()
+    }
+
+    fn write_checksum(&mut self) -> std::io::Result<()> {
+        let checksum = Checksum {
+            chunk: self.chunk,
+            lines: self.chunk_lines,
+            sha256: hex::encode(std::mem::take(&mut self.hasher).finalize()),
+        };
+        self.write_line("checksum", &serde_json::to_string(&checksum).expect("checksum serializes"))?;
+        self.chunk += 1;
+        self.chunk_lines = 0;
+        Ok(())
+    }
+
+    /// Close the last chunk and mark the recording complete.
+    fn finish(mut self) -> std::io::Result<()> {
+        if self.chunk_lines > 0 {
+            self.write_checksum()?;
+        }
+        let end = End { lines: self.lines, chunks: self.chunk };
+        self.write_line("end", &serde_json::to_string(&end).expect("end serializes"))?;
+        self.writer.flush()
+    }
+}
+
+/// Appends Private WS traffic to a JSONL fixture while recording is on.
+/// Shared by all clones; a no-op when no file is set.
+///
+/// The file starts with a `"meta"` line, has a `"checksum"` line after
+/// every `CHUNK_LINES` entries and, once recording is stopped, a final
+/// checksum and an `"end"` line; `verify_session` checks them.
+#[derive(Clone, Default)]
+pub struct SessionRecorder {
+    file: Arc<Mutex<Option<Recording>>>,
+}
+
+impl SessionRecorder {
+    /// Start recording to `path` (truncated), or stop with `None`; either
+    /// completes the previous recording.
+    pub fn set_path(&self, path: Option<&str>) -> Result<(), GmocoinError> {
+        let recording = path.map(Recording::create).transpose()?;
+        let mut file = self.file.lock().unwrap();
+        if let Some(previous) = file.take() {
+            if let Err(e) = previous.finish() {
+                warn!("GMO: Failed to complete Private WS session recording: {}", e);
+            }
+        }
+        *file = recording;
+        Ok(())
+    }
+
+    pub fn record(&self, dir: &str, text: &str) {
+        let mut file = self.file.lock().unwrap();
+        let Some(recording) = file.as_mut() else { return };
+        if let Err(e) = recording.write_entry(dir, text) {
+            warn!("GMO: Failed to record Private WS session, recording stopped: {}", e);
+            *file = None;
+        }
+    }
+}
+
+/// A problem `verify_session` found, at a 1-based line.
+#[derive(Clone, Debug, Serialize)]
+pub struct SessionProblem {
+    pub line: usize,
+    /// `"invalid_entry"`, `"checksum_mismatch"`, `"chunk_out_of_order"`,
+    /// `"truncated_line"`, `"data_after_end"`, `"missing_end"` or
+    /// `"no_checksums"`
+    pub kind: &'static str,
+    pub detail: String,
+}
+
+/// What `verify_session` found.
+#[derive(Clone, Debug, Serialize)]
+pub struct SessionVerification {
+    /// No problems: every chunk matched and the recording was completed.
+    pub ok: bool,
+    /// The `"meta"` line, if any.
+    pub meta: Option<serde_json::Value>,
+    /// Entries (all lines but checksums and the end marker).
+    pub lines: usize,
+    /// Chunks whose checksum matched.
+    pub verified_chunks: usize,
+    /// Entries after the last checksum, which nothing vouches for.
+    pub unverified_lines: usize,
+    /// Whether the `"end"` line is there.
+    pub complete: bool,
+    pub problems: Vec<SessionProblem>,
+}
+
+impl SessionVerification {
+    fn problem(&mut self, line: usize, kind: &'static str, detail: String) {
+        self.problems.push(SessionProblem { line, kind, detail });
+    }
+}
+
+/// Check a recorded session against its checksums, reporting corrupted
+/// chunks, truncation (a cut-off last line, entries past the last
+/// checksum, no end marker) and unreadable lines. Only fails if the file
+/// cannot be read.
+pub fn verify_session(path: &str) -> Result<SessionVerification, GmocoinError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut report = SessionVerification {
+        ok: false,
+        meta: None,
+        lines: 0,
+        verified_chunks: 0,
+        unverified_lines: 0,
+        complete: false,
+        problems: Vec::new(),
+    };
+    let mut hasher = Sha256::new();
+    let (mut chunk, mut chunk_lines, mut checksums) = (0, 0, 0);
+    let mut buf = Vec::new();
+    let mut number = 0;
+    loop {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+        number += 1;
+        if !buf.ends_with(b"\n") {
+            report.problem(number, "truncated_line", "last line has no newline".to_string());
+            break;
+        }
+        let entry: SessionEntry = match serde_json::from_slice(&buf) {
+            Ok(entry) => entry,
+            Err(e) => {
+                report.problem(number, "invalid_entry", e.to_string());
+                hasher.update(&buf);
+                chunk_lines += 1;
+                report.lines += 1;
+                continue;
+            }
+        };
+        if report.complete {
+            report.problem(number, "data_after_end", format!("{} line after the end marker", entry.dir));
+        }
+        match entry.dir.as_ref() {
+            "checksum" => {
+                checksums += 1;
+                let digest = hex::encode(std::mem::take(&mut hasher).finalize());
+                match serde_json::from_str::<Checksum>(&entry.text) {
+                    Ok(expected) if expected.chunk != chunk => report.problem(
+                        number, "chunk_out_of_order", format!("expected chunk {}, found {}", chunk, expected.chunk),
+                    ),
+                    Ok(expected) if expected.sha256 != digest || expected.lines != chunk_lines => report.problem(
+                        number, "checksum_mismatch",
+                        format!("chunk {} ({} lines) does not match its checksum", chunk, chunk_lines),
+                    ),
+                    Ok(_) => report.verified_chunks += 1,
+                    Err(e) => report.problem(number, "invalid_entry", format!("checksum: {}", e)),
+                }
+                chunk += 1;
+                chunk_lines = 0;
+            }
+            "end" => report.complete = true,
+            dir => {
+                if dir == "meta" && report.meta.is_none() {
+                    report.meta = serde_json::from_str(&entry.text).ok();
+                }
+                hasher.update(&buf);
+                chunk_lines += 1;
+                report.lines += 1;
+            }
+        }
+    }
+    report.unverified_lines = chunk_lines;
+    if checksums == 0 {
+        report.problem(number, "no_checksums", "recorded without checksums".to_string());
+    } else if !report.complete {
+        report.problem(number, "missing_end", format!("recording not completed, {} entries unverified", chunk_lines));
+    }
+    report.ok = report.problems.is_empty();
+    Ok(report)
+}
+
+/// `{"ok", "meta", "lines", "verified_chunks", "unverified_lines",
+/// "complete", "problems": [{"line", "kind", "detail"}]}` of a recorded
+/// session (see `record_session`).
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "verify_session")]
+pub fn py_verify_session(path: &str) -> PyResult<PyJson> {
+    PyJson::from_serialize(&verify_session(path)?)
+}
+
+/// Received frames of a recorded session, in order.
+pub fn read_received_frames(path: &str) -> Result<Vec<String>, GmocoinError> {
+    Ok(read_received_entries(path)?.into_iter().map(|(_, frame)| frame).collect())
+}
+
+/// Received frames of a recorded session with their recorded times (epoch
+/// ms), in order.
+pub fn read_received_entries(path: &str) -> Result<Vec<(i64, String)>, GmocoinError> {
+    let file = File::open(path)?;
+    let mut frames = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: SessionEntry = serde_json::from_str(&line).map_err(|e| {
+            GmocoinError::InvalidInput(format!("{}:{}: invalid session entry: {}", path, index + 1, e))
+        })?;
+        if entry.dir == "recv" {
+            frames.push((entry.ts, entry.text.into_owned()));
+        }
+    }
+    Ok(frames)
+}
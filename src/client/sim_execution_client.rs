@@ -0,0 +1,628 @@
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use pyo3::prelude::*;
+use rust_decimal::Decimal;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+use crate::callback::{CallbackPayload, CallbackSlot};
+use crate::client::data_client::{GmocoinDataClient, MarketEvent};
+use crate::client::endpoint::EndpointGroup;
+use crate::client::execution_client::DEFAULT_ACCOUNT_LABEL;
+use crate::error::GmocoinError;
+use crate::model::order::{ExecutionsList, OrdersList};
+use crate::py_json::PyJson;
+use crate::runtime;
+use crate::sim::{FeeModel, LatencyModel, SimEngine, SimEvent};
+use crate::stats::ClientStats;
+
+/// Paper-trading counterpart of `GmocoinExecutionClient`.
+///
+/// Accepts the same calls and emits the same `(event_type, data)` order
+/// callbacks, but orders never leave the process: they are matched against
+/// the books and trades received by a `GmocoinDataClient` (see `SimEngine`
+/// for the fill rules). The data client must be connected; the symbols of
+/// submitted orders are subscribed to `orderbooks` and `trades` on it.
+#[pyclass]
+pub struct GmocoinSimExecutionClient {
+    data_client: GmocoinDataClient,
+    engine: Arc<Mutex<SimEngine>>,
+    latency: Arc<LatencyModel>,
+    order_callback: CallbackSlot,
+    client_oid_map: Arc<Mutex<HashMap<String, u64>>>,
+    stats: ClientStats,
+    feed_task: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+#[pymethods]
+impl GmocoinSimExecutionClient {
+    /// `latency_ms` / `latency_jitter_ms`: delay before an order, cancel or
+    ///   change reaches the simulated venue (`latency_ms` + uniform jitter).
+    /// `maker_fee` / `taker_fee`: fee rates (default GMO Coin spot: -0.0001 / 0.0005).
+    /// `initial_balances`: e.g. `{"JPY": "1000000"}`. When given, balances are
+    ///   tracked and orders exceeding the available amount are rejected
+    ///   (ERR-208); otherwise funds are unlimited and `get_assets_py` is empty.
+    /// `callback_payload`: `"object"` (default) or `"msgpack"`, as for
+    ///   `GmocoinExecutionClient`.
+    #[new]
+    #[pyo3(signature = (data_client, latency_ms=None, latency_jitter_ms=None, maker_fee=None, taker_fee=None, initial_balances=None, callback_payload=None))]
+    pub fn new(
+        data_client: GmocoinDataClient,
+        latency_ms: Option<u64>,
+        latency_jitter_ms: Option<u64>,
+        maker_fee: Option<f64>,
+        taker_fee: Option<f64>,
+        initial_balances: Option<HashMap<String, String>>,
+        callback_payload: Option<CallbackPayload>,
+    ) -> PyResult<Self> {
+        let invalid = |msg: String| PyErr::new::<pyo3::exceptions::PyValueError, _>(msg);
+        let rate = |name: &str, value: Option<f64>, default: Decimal| match value {
+            Some(v) => Decimal::try_from(v).map_err(|e| invalid(format!("Invalid {}: {}", name, e))),
+            None => Ok(default),
+        };
+        let defaults = FeeModel::default();
+        let fees = FeeModel {
+            maker: rate("maker_fee", maker_fee, defaults.maker)?,
+            taker: rate("taker_fee", taker_fee, defaults.taker)?,
+        };
+        let balances = initial_balances
+            .map(|balances| {
+                balances
+                    .into_iter()
+                    .map(|(asset, amount)| {
+                        let amount = Decimal::from_str(&amount)
+                            .map_err(|e| invalid(format!("Invalid balance for {}: {}", asset, e)))?;
+                        Ok((asset.to_uppercase(), amount))
+                    })
+                    .collect::<PyResult<BTreeMap<_, _>>>()
+            })
+            .transpose()?;
+
+        let stats = ClientStats::default();
+        Ok(Self {
+            data_client,
+            engine: Arc::new(Mutex::new(SimEngine::new(fees, balances))),
+            latency: Arc::new(LatencyModel::new(latency_ms.unwrap_or(0), latency_jitter_ms.unwrap_or(0))),
+            order_callback: CallbackSlot::new(callback_payload.unwrap_or_default(), stats.clone()),
+            client_oid_map: Arc::new(Mutex::new(HashMap::new())),
+            stats,
+            feed_task: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Accepted for API compatibility; the simulator does not throttle.
+    pub fn set_rate_limit(&self, group: &str, _per_sec: f64) -> PyResult<()> {
+        EndpointGroup::parse(group).map(|_| ()).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Unknown endpoint group '{}'", group))
+        })
+    }
+
+    /// Simulated request / error counters plus the number of orders held.
+    pub fn get_stats(&self) -> PyResult<PyJson> {
+        let mut snapshot = self.stats.snapshot();
+        snapshot.orders_cached = Some(self.engine.lock().unwrap().order_count());
+        PyJson::from_serialize(&snapshot)
+    }
+
+    /// `callback(event_type, data)`; `async def` callbacks are scheduled on
+    /// the event loop running at this call.
+    pub fn set_order_callback(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<()> {
+        self.order_callback.set(py, callback)
+    }
+
+    /// `handler(event_type, exception)` for exceptions raised by the callback,
+    /// which are always logged and counted in `get_stats()`. `None` clears it.
+    #[pyo3(signature = (handler=None))]
+    pub fn set_callback_error_handler(&self, handler: Option<Py<PyAny>>) {
+        self.order_callback.set_error_handler(handler);
+    }
+
+    /// Start matching against the data client's market events
+    #[pyo3(signature = () -> "collections.abc.Awaitable[str]")]
+    pub fn connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let start = self.start_feed();
+        let future = async move {
+            start.await;
+            Ok("Connected")
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Stop matching; open orders are kept
+    #[pyo3(signature = () -> "collections.abc.Awaitable[str]")]
+    pub fn disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let stop = self.stop_feed();
+        let future = async move {
+            stop.await;
+            Ok("Disconnected")
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Stop matching and wait up to `timeout_ms` (default 5000) for the task
+    /// to exit. Resolves to whether it did.
+    #[pyo3(signature = (timeout_ms=None) -> "collections.abc.Awaitable[bool]")]
+    pub fn close<'py>(&self, py: Python<'py>, timeout_ms: Option<u64>) -> PyResult<Bound<'py, PyAny>> {
+        let stop = self.stop_feed();
+        let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(5000));
+        let future = async move { Ok(tokio::time::timeout(timeout, stop).await.is_ok()) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// `async with GmocoinSimExecutionClient(...) as client:` connects on entry.
+    #[pyo3(signature = () -> "collections.abc.Awaitable[typing.Self]")]
+    fn __aenter__<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        let start = slf.borrow().start_feed();
+        let this = slf.clone().unbind();
+        let future = async move {
+            start.await;
+            Ok(this)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(slf.py(), future)
+    }
+
+    /// Disconnects and waits for the matching task to stop, even on error.
+    #[pyo3(signature = (_exc_type, _exc_value, _traceback) -> "collections.abc.Awaitable[bool]")]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let stop = self.stop_feed();
+        let future = async move {
+            stop.await;
+            Ok(false)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    // ========== Order Operations (Python) ==========
+
+    /// Same signature as `GmocoinExecutionClient.submit_order`;
+    /// `cancel_before`, `losscut_price`, `settle_type`, `deadline_ms`,
+    /// `timeout_ms`, `arrival_bid`, `arrival_ask` and `split` are accepted
+    /// and ignored (the simulator has no `maxOrderSize`).
+    ///
+    /// `account`, here and on the other order and query methods, must be
+    /// `None` or `"main"`: the simulator holds a single account, the
+    /// `"account"` of its events, and any other name raises `ValueError` as
+    /// an unknown account does on the live client.
+    #[pyo3(signature = (symbol, amount, side, execution_type, client_order_id, price=None, time_in_force=None, cancel_before=None, losscut_price=None, settle_type=None, deadline_ms=None, timeout_ms=None, arrival_bid=None, arrival_ask=None, split=false, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_order<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        amount: String,
+        side: String,
+        execution_type: String,
+        client_order_id: String,
+        price: Option<String>,
+        time_in_force: Option<String>,
+        cancel_before: Option<bool>,
+        losscut_price: Option<String>,
+        settle_type: Option<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        arrival_bid: Option<String>,
+        arrival_ask: Option<String>,
+        split: bool,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (cancel_before, losscut_price, settle_type, deadline_ms, timeout_ms, arrival_bid, arrival_ask, split);
+        let data_client = self.data_client.clone();
+        let engine = self.engine.clone();
+        let latency = self.latency.clone();
+        let order_cb = self.order_callback.clone();
+        let client_oid_map = self.client_oid_map.clone();
+        let stats = self.stats.clone();
+
+        let future = async move {
+            stats.request_sent();
+            data_client.ensure_subscribed("orderbooks", &symbol);
+            data_client.ensure_subscribed("trades", &symbol);
+            sleep(latency.sample()).await;
+
+            let book = data_client.book(&symbol);
+            let result = engine.lock().unwrap().submit(
+                &symbol, &side, &execution_type, &amount, price.as_deref(), time_in_force.as_deref(), book.as_ref(),
+            );
+            let (order_id, events) = Self::checked(&stats, result)?;
+            client_oid_map.lock().unwrap().insert(client_order_id, order_id);
+            Self::emit(&order_cb, events);
+
+            Ok(PyJson(serde_json::json!({"order_id": order_id})))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (_symbol, order_id, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn cancel_order<'py>(
+        &self,
+        py: Python<'py>,
+        _symbol: String,
+        order_id: String,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (deadline_ms, timeout_ms);
+        let oid = Self::parse_order_id(&order_id)?;
+        let engine = self.engine.clone();
+        let latency = self.latency.clone();
+        let order_cb = self.order_callback.clone();
+        let stats = self.stats.clone();
+
+        let future = async move {
+            stats.request_sent();
+            sleep(latency.sample()).await;
+            let result = engine.lock().unwrap().cancel(oid);
+            Self::emit(&order_cb, Self::checked(&stats, result)?);
+            Ok(PyJson(serde_json::Value::Null))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (order_id, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_order<'py>(&self, py: Python<'py>, order_id: String, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let oid = Self::parse_order_id(&order_id)?;
+        let engine = self.engine.clone();
+        let future = async move {
+            let list = engine.lock().unwrap().order(oid);
+            PyJson::from_serialize(&OrdersList { list })
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (order_id, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_executions<'py>(&self, py: Python<'py>, order_id: String, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let oid = Self::parse_order_id(&order_id)?;
+        let engine = self.engine.clone();
+        let future = async move {
+            let list = engine.lock().unwrap().executions_for(oid);
+            PyJson::from_serialize(&ExecutionsList { list })
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (order_id, price, losscut_price=None, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn change_order<'py>(
+        &self,
+        py: Python<'py>,
+        order_id: String,
+        price: String,
+        losscut_price: Option<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (losscut_price, deadline_ms, timeout_ms);
+        let oid = Self::parse_order_id(&order_id)?;
+        let data_client = self.data_client.clone();
+        let engine = self.engine.clone();
+        let latency = self.latency.clone();
+        let order_cb = self.order_callback.clone();
+        let stats = self.stats.clone();
+
+        let future = async move {
+            stats.request_sent();
+            sleep(latency.sample()).await;
+            let mut engine = engine.lock().unwrap();
+            let book = engine.symbol_of(oid).and_then(|symbol| data_client.book(&symbol));
+            let result = engine.change(oid, &price, book.as_ref());
+            drop(engine);
+            Self::emit(&order_cb, Self::checked(&stats, result)?);
+            Ok(PyJson(serde_json::Value::Null))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (order_ids, deadline_ms=None, timeout_ms=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn cancel_orders<'py>(
+        &self,
+        py: Python<'py>,
+        order_ids: Vec<String>,
+        deadline_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (deadline_ms, timeout_ms);
+        let oids = order_ids
+            .iter()
+            .map(|s| Self::parse_order_id(s))
+            .collect::<PyResult<Vec<_>>>()?;
+        let engine = self.engine.clone();
+        let latency = self.latency.clone();
+        let order_cb = self.order_callback.clone();
+        let stats = self.stats.clone();
+
+        let future = async move {
+            stats.request_sent();
+            sleep(latency.sample()).await;
+            // Same shape as /v1/cancelOrders: per-order success / failure
+            let mut success = Vec::new();
+            let mut failed = Vec::new();
+            let mut events = Vec::new();
+            {
+                let mut engine = engine.lock().unwrap();
+                for oid in oids {
+                    match engine.cancel(oid) {
+                        Ok(cancel_events) => {
+                            success.push(oid);
+                            events.extend(cancel_events);
+                        }
+                        Err(GmocoinError::ExchangeError { messages, codes, .. }) => failed.push(serde_json::json!({
+                            "message_code": codes.first(),
+                            "message_string": messages,
+                            "orderId": oid,
+                        })),
+                        Err(e) => return Err(PyErr::from(e)),
+                    }
+                }
+            }
+            Self::emit(&order_cb, events);
+            Ok(PyJson(serde_json::json!({"success": success, "failed": failed})))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_active_orders<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        page: Option<i32>,
+        count: Option<i32>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let engine = self.engine.clone();
+        let future = async move {
+            let list = engine.lock().unwrap().active_orders(&symbol);
+            Ok(Self::paginated(list, page, count))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_latest_executions<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        page: Option<i32>,
+        count: Option<i32>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let engine = self.engine.clone();
+        let future = async move {
+            let list = engine.lock().unwrap().latest_executions(&symbol);
+            Ok(Self::paginated(list, page, count))
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    /// Simulated balances (empty unless `initial_balances` was given).
+    #[pyo3(signature = (account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_assets_py<'py>(&self, py: Python<'py>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let engine = self.engine.clone();
+        let future = async move {
+            let assets = engine.lock().unwrap().assets();
+            PyJson::from_serialize(&assets)
+        };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    // ========== Position Operations (Python) ==========
+    // The simulator trades spot only: queries report no positions and
+    // position mutations are refused.
+
+    #[pyo3(signature = (account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_margin_py<'py>(&self, _py: Python<'py>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        Err(Self::unsupported("get_margin_py"))
+    }
+
+    #[pyo3(signature = (symbol, page=None, count=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_open_positions<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        page: Option<i32>,
+        count: Option<i32>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = symbol;
+        let future = async move { Ok(Self::paginated(Vec::<()>::new(), page, count)) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (symbol, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn get_position_summary<'py>(&self, py: Python<'py>, symbol: Option<String>, account: Option<String>) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = symbol;
+        let future = async move { Ok(PyJson(serde_json::json!({"list": []}))) };
+        pyo3_async_runtimes::tokio::future_into_py(py, future)
+    }
+
+    #[pyo3(signature = (symbol, side, execution_type, settle_position, price=None, time_in_force=None, client_order_id=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_order<'py>(
+        &self,
+        _py: Python<'py>,
+        symbol: String,
+        side: String,
+        execution_type: String,
+        settle_position: Vec<(u64, String)>,
+        price: Option<String>,
+        time_in_force: Option<String>,
+        client_order_id: Option<String>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (symbol, side, execution_type, settle_position, price, time_in_force, client_order_id);
+        Err(Self::unsupported("close_order"))
+    }
+
+    #[pyo3(signature = (symbol, side, execution_type, size, price=None, time_in_force=None, client_order_id=None, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_bulk_order<'py>(
+        &self,
+        _py: Python<'py>,
+        symbol: String,
+        side: String,
+        execution_type: String,
+        size: String,
+        price: Option<String>,
+        time_in_force: Option<String>,
+        client_order_id: Option<String>,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (symbol, side, execution_type, size, price, time_in_force, client_order_id);
+        Err(Self::unsupported("close_bulk_order"))
+    }
+
+    #[pyo3(signature = (position_id, losscut_price, account=None) -> "collections.abc.Awaitable[typing.Any]")]
+    pub fn change_losscut_price<'py>(
+        &self,
+        _py: Python<'py>,
+        position_id: u64,
+        losscut_price: String,
+        account: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Self::check_account(account.as_deref())?;
+        let _ = (position_id, losscut_price);
+        Err(Self::unsupported("change_losscut_price"))
+    }
+}
+
+impl GmocoinSimExecutionClient {
+    /// Spawns the matching task (replacing a running one) when awaited.
+    fn start_feed(&self) -> impl Future<Output = ()> + Send + 'static {
+        let events = self.data_client.market_events();
+        let data_client = self.data_client.clone();
+        let engine = self.engine.clone();
+        let order_cb = self.order_callback.clone();
+        let stats = self.stats.clone();
+        let task_arc = self.feed_task.clone();
+        async move {
+            let handle = runtime::spawn(Self::feed_loop(events, data_client, engine, order_cb, stats));
+            if let Some(previous) = task_arc.lock().unwrap().replace(handle) {
+                previous.abort();
+            }
+            info!("GMO: Simulated execution client started");
+        }
+    }
+
+    /// Aborts the matching task and waits for it to exit.
+    fn stop_feed(&self) -> impl Future<Output = ()> + Send + 'static {
+        let task_arc = self.feed_task.clone();
+        async move {
+            let handle = task_arc.lock().unwrap().take();
+            if let Some(handle) = handle {
+                handle.abort();
+                let _ = handle.await;
+            }
+        }
+    }
+
+    async fn feed_loop(
+        mut events: tokio::sync::broadcast::Receiver<MarketEvent>,
+        data_client: GmocoinDataClient,
+        engine: Arc<Mutex<SimEngine>>,
+        order_cb: CallbackSlot,
+        stats: ClientStats,
+    ) {
+        loop {
+            let sim_events = match events.recv().await {
+                Ok(MarketEvent::Book(book)) => {
+                    stats.message("orderbooks");
+                    engine.lock().unwrap().on_book(&book)
+                }
+                Ok(MarketEvent::Ticker(_) | MarketEvent::Maintenance(_) | MarketEvent::Reconnect(_) | MarketEvent::ParseError(_) | MarketEvent::Instrument(_) | MarketEvent::LatencyAlarm(_) | MarketEvent::TradeGap(_)) => continue,
+                Ok(MarketEvent::Trade(trade)) => {
+                    stats.message("trades");
+                    let book = trade.symbol.as_deref().and_then(|symbol| data_client.book(symbol));
+                    engine.lock().unwrap().on_trade(&trade, book.as_ref())
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("GMO: Simulator fell behind the market feed, skipped {} updates", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+            Self::emit(&order_cb, sim_events);
+        }
+    }
+
+    fn checked<T>(stats: &ClientStats, result: Result<T, GmocoinError>) -> PyResult<T> {
+        result.map_err(|e| {
+            stats.error(&e);
+            PyErr::from(e)
+        })
+    }
+
+    fn parse_order_id(order_id: &str) -> PyResult<u64> {
+        order_id.parse::<u64>().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid order_id: {}", e))
+        })
+    }
+
+    fn paginated<T: serde::Serialize>(list: Vec<T>, page: Option<i32>, count: Option<i32>) -> PyJson {
+        let page = page.unwrap_or(1).max(1);
+        let count = count.unwrap_or(100).max(1);
+        let items: Vec<_> = list
+            .into_iter()
+            .skip(((page - 1) * count) as usize)
+            .take(count as usize)
+            .collect();
+        PyJson(serde_json::json!({
+            "pagination": {"currentPage": page, "count": count},
+            "list": items,
+        }))
+    }
+
+    /// The simulator has no accounts besides its own.
+    fn check_account(account: Option<&str>) -> PyResult<()> {
+        match account {
+            None | Some(DEFAULT_ACCOUNT_LABEL) => Ok(()),
+            Some(name) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Unknown account '{}'", name))),
+        }
+    }
+
+    fn unsupported(method: &str) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+            format!("{} is not supported by the simulated execution client (spot only)", method),
+        )
+    }
+
+    /// Deliver events to the order callback, with `"account"` as the live
+    /// client's; the GIL is only held for the calls.
+    fn emit(order_cb: &CallbackSlot, mut events: Vec<SimEvent>) {
+        if events.is_empty() {
+            return;
+        }
+        for (_, data) in &mut events {
+            if let Some(fields) = data.as_object_mut() {
+                fields.insert("account".to_string(), DEFAULT_ACCOUNT_LABEL.into());
+            }
+        }
+        order_cb.call_events(events);
+    }
+}
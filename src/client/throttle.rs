@@ -0,0 +1,114 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+use tokio::time::{sleep, Duration};
+
+use crate::error::GmocoinError;
+use crate::rate_limit::TokenBucket;
+
+/// Order-bucket tokens kept free for other mutations by default: re-quotes
+/// are queued once fewer are left.
+pub const DEFAULT_REQUOTE_RESERVE: f64 = 2.0;
+
+/// How often a queued re-quote checks the bucket for headroom.
+const POLL: Duration = Duration::from_millis(20);
+
+/// What became of a re-quote.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RequoteOutcome<T> {
+    /// Sent to GMO, immediately or once tokens freed up.
+    Sent { amendment: T },
+    /// Replaced by a later re-quote of the same order before it was sent.
+    Superseded,
+}
+
+type Reply<T> = oneshot::Sender<Result<RequoteOutcome<T>, GmocoinError>>;
+
+struct Pending<T> {
+    order_ids: VecDeque<u64>,
+    /// Latest price per queued order, with the caller waiting on it.
+    latest: HashMap<u64, (String, Reply<T>)>,
+    draining: bool,
+}
+
+/// Low-priority order mutations (re-quotes), sent straight away while the
+/// order bucket has more than `reserve` tokens and queued otherwise. A
+/// queued re-quote is superseded by a newer one for the same order, so only
+/// the latest price is sent when tokens free up; orders are served in the
+/// order they were first queued.
+pub struct RequoteQueue<T> {
+    pending: Arc<Mutex<Pending<T>>>,
+    reserve: f64,
+}
+
+impl<T> Clone for RequoteQueue<T> {
+    fn clone(&self) -> Self {
+        Self { pending: self.pending.clone(), reserve: self.reserve }
+    }
+}
+
+impl<T> RequoteQueue<T> {
+    pub fn new(reserve: f64) -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(Pending { order_ids: VecDeque::new(), latest: HashMap::new(), draining: false })),
+            reserve,
+        }
+    }
+
+    pub fn reserve(&self) -> f64 {
+        self.reserve
+    }
+
+    /// Whether a re-quote of `order_id` may go out now: the bucket has
+    /// headroom and no earlier re-quote of the order is waiting.
+    pub fn can_send(&self, order_id: u64, bucket: &TokenBucket) -> bool {
+        !self.pending.lock().unwrap().latest.contains_key(&order_id) && bucket.available() > self.reserve
+    }
+
+    /// Queue `price` for `order_id`, superseding what was queued for it.
+    /// Returns the outcome receiver and whether a drainer must be started.
+    pub fn enqueue(&self, order_id: u64, price: String) -> (oneshot::Receiver<Result<RequoteOutcome<T>, GmocoinError>>, bool) {
+        let (reply, outcome) = oneshot::channel();
+        let mut pending = self.pending.lock().unwrap();
+        match pending.latest.insert(order_id, (price, reply)) {
+            Some((_, superseded)) => {
+                let _ = superseded.send(Ok(RequoteOutcome::Superseded));
+            }
+            None => pending.order_ids.push_back(order_id),
+        }
+        let start = !pending.draining;
+        pending.draining = true;
+        (outcome, start)
+    }
+
+    /// Wait for headroom in `bucket`, then take the oldest queued order with
+    /// its latest price; `None` once the queue is empty (the drainer exits).
+    pub async fn next(&self, bucket: &TokenBucket) -> Option<(u64, String, Reply<T>)> {
+        loop {
+            {
+                let mut pending = self.pending.lock().unwrap();
+                if pending.order_ids.is_empty() {
+                    pending.draining = false;
+                    return None;
+                }
+                if bucket.available() > self.reserve {
+                    let order_id = pending.order_ids.pop_front().expect("queue not empty");
+                    let (price, reply) = pending.latest.remove(&order_id).expect("queued order has a price");
+                    return Some((order_id, price, reply));
+                }
+            }
+            sleep(POLL).await;
+        }
+    }
+
+    /// Re-quotes waiting for tokens.
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap().order_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
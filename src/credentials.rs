@@ -0,0 +1,110 @@
+//! API keys sealed with a passphrase, so they need not sit in plaintext in
+//! strategy configs: Argon2id derives an AES-256-GCM key from the
+//! passphrase, and the clients decrypt the blob in memory when built. The
+//! secret is held in a `Zeroizing` buffer, wiped when the last client
+//! using it is dropped.
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::error::GmocoinError;
+
+/// Prefix of sealed credentials; the rest is hex of salt, nonce and
+/// ciphertext.
+const PREFIX: &str = "gmocoin1:";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// An API key pair. `Debug` leaves the secret out.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Credentials {
+    pub api_key: String,
+    pub api_secret: Zeroizing<String>,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials").field("api_key", &self.api_key).finish_non_exhaustive()
+    }
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> Result<Aes256Gcm, GmocoinError> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| GmocoinError::InvalidInput(format!("Cannot derive credentials key: {}", e)))?;
+    Ok(Aes256Gcm::new(key.as_ref().into()))
+}
+
+impl Credentials {
+    pub fn new(api_key: impl Into<String>, api_secret: impl Into<String>) -> Self {
+        Self { api_key: api_key.into(), api_secret: Zeroizing::new(api_secret.into()) }
+    }
+
+    /// Seal the pair with `passphrase` (a fresh salt and nonce each time).
+    pub fn encrypt(&self, passphrase: &str) -> Result<String, GmocoinError> {
+        if passphrase.is_empty() {
+            return Err(GmocoinError::InvalidInput("Credentials passphrase must not be empty".to_string()));
+        }
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let plaintext = Zeroizing::new(serde_json::to_vec(self)?);
+        let ciphertext = cipher(passphrase, &salt)?
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| GmocoinError::InvalidInput("Cannot encrypt credentials".to_string()))?;
+        Ok(format!("{}{}{}{}", PREFIX, hex::encode(salt), hex::encode(nonce), hex::encode(ciphertext)))
+    }
+
+    /// Open a blob from `encrypt`. A wrong passphrase and a damaged blob
+    /// fail alike.
+    pub fn decrypt(sealed: &str, passphrase: &str) -> Result<Self, GmocoinError> {
+        let invalid = || GmocoinError::AuthError("Cannot decrypt credentials: wrong passphrase or damaged blob".to_string());
+        let bytes = sealed
+            .trim()
+            .strip_prefix(PREFIX)
+            .and_then(|hex| hex::decode(hex).ok())
+            .filter(|bytes| bytes.len() > SALT_LEN + NONCE_LEN)
+            .ok_or_else(invalid)?;
+        let (salt, rest) = bytes.split_at(SALT_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let plaintext = Zeroizing::new(
+            cipher(passphrase, salt)?.decrypt(Nonce::from_slice(nonce), ciphertext).map_err(|_| invalid())?,
+        );
+        serde_json::from_slice(&plaintext).map_err(|_| invalid())
+    }
+
+    /// `api_key` / `api_secret`, or the pair sealed in `sealed` when given
+    /// (the plain arguments must then be empty). A `passphrase` without
+    /// `sealed` is refused rather than ignored.
+    pub fn resolve(
+        api_key: String,
+        api_secret: String,
+        sealed: Option<&str>,
+        passphrase: Option<&str>,
+    ) -> Result<Self, GmocoinError> {
+        match (sealed, passphrase) {
+            (None, None) => Ok(Self::new(api_key, api_secret)),
+            (None, Some(_)) => Err(GmocoinError::InvalidInput(
+                "A passphrase is only used with encrypted credentials".to_string(),
+            )),
+            (Some(_), _) if !api_key.is_empty() || !api_secret.is_empty() => Err(GmocoinError::InvalidInput(
+                "Pass either api_key / api_secret or encrypted credentials, not both".to_string(),
+            )),
+            (Some(_), None) => Err(GmocoinError::InvalidInput("Encrypted credentials need a passphrase".to_string())),
+            (Some(sealed), Some(passphrase)) => Self::decrypt(sealed, passphrase),
+        }
+    }
+}
+
+/// Seal an API key pair with `passphrase` for the `credentials` argument of
+/// the clients, which open it with their `passphrase` argument.
+#[cfg(feature = "python")]
+#[pyo3::pyfunction(name = "encrypt_credentials")]
+pub fn py_encrypt_credentials(api_key: String, api_secret: String, passphrase: &str) -> pyo3::PyResult<String> {
+    Ok(Credentials::new(api_key, api_secret).encrypt(passphrase)?)
+}
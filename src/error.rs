@@ -1,6 +1,12 @@
 use thiserror::Error;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "python")]
+pyo3::create_exception!(_nautilus_gmocoin, CircuitOpenError, pyo3::exceptions::PyConnectionError);
+#[cfg(feature = "python")]
+pyo3::create_exception!(_nautilus_gmocoin, OrderTooLargeError, pyo3::exceptions::PyValueError);
+
 #[derive(Error, Debug)]
 pub enum GmocoinError {
     #[error("API Request Error: {0}")]
@@ -19,24 +25,182 @@ pub enum GmocoinError {
     ExchangeError {
         status: i32,
         messages: String,
+        /// `message_code` values (e.g. `ERR-5008`) from the response.
+        codes: Vec<String>,
+    },
+
+    #[error("Circuit open for '{group}' endpoints, retry after {retry_after_ms}ms")]
+    CircuitOpen {
+        group: &'static str,
+        retry_after_ms: u64,
+    },
+
+    /// Shed before it was sent: no rate limit token or request slot in
+    /// time, or the deadline passed first.
+    #[error("Timeout: {0}")]
+    Shed(String),
+
+    /// Sent, but no response came before the deadline.
+    #[error("Timeout: deadline exceeded: {0}")]
+    DeadlineExceeded(reqwest::Error),
+
+    /// A successful order response without an order id: the order may be
+    /// live but cannot be tracked.
+    #[error("No order id in submit response: {0}")]
+    MissingOrderId(serde_json::Value),
+
+    /// An order over the symbol's `maxOrderSize`, rejected before sending.
+    #[error("Order size {size} of {symbol} exceeds maxOrderSize {max_order_size}")]
+    OrderTooLarge {
+        symbol: String,
+        size: String,
+        max_order_size: String,
     },
 
+    /// Invalid settings or arguments, rejected before anything is sent.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("IO Error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The client was closed (or the module shut down) while the request was pending.
+    #[error("Client closed")]
+    Closed,
+
     #[error("Unknown Error: {0}")]
     Unknown(String),
+
+    /// Any of the above, tagged with the id of the REST request that failed.
+    #[error("{source} [request_id={request_id}]")]
+    Request {
+        request_id: String,
+        source: Box<GmocoinError>,
+    },
 }
 
+impl GmocoinError {
+    /// The underlying error, without any request id tag.
+    pub fn root(&self) -> &GmocoinError {
+        match self {
+            GmocoinError::Request { source, .. } => source.root(),
+            other => other,
+        }
+    }
+
+    /// Id of the REST request this error came from, if tagged.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            GmocoinError::Request { request_id, .. } => Some(request_id),
+            _ => None,
+        }
+    }
+
+    /// Whether this error indicates the venue itself is unavailable
+    /// (network failure, non-JSON error page, system error or maintenance),
+    /// as opposed to a business-level rejection of the request.
+    pub fn is_venue_failure(&self) -> bool {
+        match self.root() {
+            GmocoinError::RequestError(_) | GmocoinError::ParseError(_) => true,
+            // status 1: system error, status 5: maintenance
+            GmocoinError::ExchangeError { status, .. } => matches!(status, 1 | 5),
+            _ => false,
+        }
+    }
+
+    /// Whether the request may have been executed even though it failed
+    /// (sent, but the response was lost), so a blind retry could act twice.
+    pub fn is_ambiguous(&self) -> bool {
+        match self.root() {
+            GmocoinError::RequestError(e) => !e.is_connect() && !e.is_builder(),
+            GmocoinError::ParseError(_) | GmocoinError::MissingOrderId(_) | GmocoinError::Closed => true,
+            GmocoinError::DeadlineExceeded(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Short error category used for statistics.
+    pub fn class(&self) -> &'static str {
+        match self.root() {
+            GmocoinError::RequestError(_) => "request",
+            GmocoinError::WebSocketError(_) => "websocket",
+            GmocoinError::ParseError(_) | GmocoinError::MissingOrderId(_) => "parse",
+            GmocoinError::AuthError(_) => "auth",
+            GmocoinError::ExchangeError { .. } if self.is_rate_limited() => "rate_limited",
+            GmocoinError::ExchangeError { .. } => "exchange",
+            GmocoinError::CircuitOpen { .. } => "circuit_open",
+            GmocoinError::Shed(_) | GmocoinError::DeadlineExceeded(_) => "timeout",
+            GmocoinError::OrderTooLarge { .. } | GmocoinError::InvalidInput(_) => "invalid_input",
+            GmocoinError::Io(_) => "io",
+            GmocoinError::Closed => "closed",
+            GmocoinError::Unknown(_) => "unknown",
+            GmocoinError::Request { .. } => unreachable!("root() strips request tags"),
+        }
+    }
+
+    /// Whether the venue answered that it is in maintenance (status 5).
+    pub fn is_maintenance(&self) -> bool {
+        matches!(self.root(), GmocoinError::ExchangeError { status: 5, .. })
+    }
+
+    /// Whether the venue rejected the request for exceeding the rate limit (ERR-5003).
+    pub fn is_rate_limited(&self) -> bool {
+        match self.root() {
+            GmocoinError::ExchangeError { codes, .. } => codes.iter().any(|c| c == "ERR-5003"),
+            _ => false,
+        }
+    }
+
+    /// Whether the venue rejected the request's API-TIMESTAMP or signature
+    /// (ERR-5008 / ERR-5009 / ERR-5010), which a corrected clock can fix.
+    pub fn is_clock_skew(&self) -> bool {
+        match self.root() {
+            GmocoinError::ExchangeError { codes, .. } => codes
+                .iter()
+                .any(|c| matches!(c.as_str(), "ERR-5008" | "ERR-5009" | "ERR-5010")),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
 impl From<GmocoinError> for PyErr {
     fn from(err: GmocoinError) -> Self {
+        // Keep the request id in the Python message so it can be matched
+        // against the `request{id=...}` span in the Rust logs
+        let (err, suffix) = match err {
+            GmocoinError::Request { request_id, source } => {
+                (*source, format!(" [request_id={}]", request_id))
+            }
+            err => (err, String::new()),
+        };
         match err {
             GmocoinError::AuthError(e) => {
-                pyo3::exceptions::PyPermissionError::new_err(e)
+                pyo3::exceptions::PyPermissionError::new_err(format!("{}{}", e, suffix))
             }
-            GmocoinError::ExchangeError { status, messages } => {
+            GmocoinError::ExchangeError { status, messages, .. } => {
                 pyo3::exceptions::PyRuntimeError::new_err(
-                    format!("GMO Coin Error (status={}): {}", status, messages),
+                    format!("GMO Coin Error (status={}): {}{}", status, messages, suffix),
                 )
             }
-            _ => pyo3::exceptions::PyRuntimeError::new_err(err.to_string()),
+            GmocoinError::CircuitOpen { .. } => CircuitOpenError::new_err(format!("{}{}", err, suffix)),
+            GmocoinError::Shed(e) => pyo3::exceptions::PyTimeoutError::new_err(format!("{}{}", e, suffix)),
+            GmocoinError::DeadlineExceeded(e) => {
+                pyo3::exceptions::PyTimeoutError::new_err(format!("deadline exceeded: {}{}", e, suffix))
+            }
+            // The limit is also attached as attributes for callers to act on
+            GmocoinError::OrderTooLarge { ref symbol, ref size, ref max_order_size } => Python::attach(|py| {
+                let py_err = OrderTooLargeError::new_err(format!("{}{}", err, suffix));
+                let value = py_err.value(py);
+                let attached = value
+                    .setattr("symbol", symbol)
+                    .and_then(|_| value.setattr("size", size))
+                    .and_then(|_| value.setattr("max_order_size", max_order_size));
+                attached.err().unwrap_or(py_err)
+            }),
+            GmocoinError::InvalidInput(e) => pyo3::exceptions::PyValueError::new_err(format!("{}{}", e, suffix)),
+            GmocoinError::Io(e) => pyo3::exceptions::PyOSError::new_err(format!("{}{}", e, suffix)),
+            _ => pyo3::exceptions::PyRuntimeError::new_err(format!("{}{}", err, suffix)),
         }
     }
 }
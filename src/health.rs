@@ -0,0 +1,141 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::rate_limit::TokenBucket;
+use crate::stats::ClientStats;
+
+/// Share of a rate limit bucket in use at which the client counts as
+/// degraded.
+pub const SATURATED: f64 = 0.9;
+
+/// Overall verdict of a `Health` snapshot: `"down"` while REST is
+/// unreachable, `"degraded"` while a WS is disconnected, a circuit is open
+/// or a rate limit is saturated or throttled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Degraded,
+    Down,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RestHealth {
+    /// Whether the venue answered since the last transport or
+    /// maintenance / system failure; `None` before the first request.
+    pub reachable: Option<bool>,
+    pub last_ok_ms: Option<i64>,
+    pub last_failure_ms: Option<i64>,
+    /// Endpoint groups whose circuit breaker is failing fast.
+    pub open_circuits: Vec<&'static str>,
+    /// Requests outstanding against `max_in_flight`; only when capped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_flight: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_in_flight: Option<usize>,
+}
+
+impl RestHealth {
+    pub fn new(stats: &ClientStats, open_circuits: Vec<&'static str>) -> Self {
+        let liveness = stats.liveness();
+        let reachable = match (liveness.rest_ok_ms, liveness.rest_failure_ms) {
+            (None, None) => None,
+            (Some(ok), Some(failure)) => Some(ok >= failure),
+            (ok, _) => Some(ok.is_some()),
+        };
+        Self {
+            reachable,
+            last_ok_ms: liveness.rest_ok_ms,
+            last_failure_ms: liveness.rest_failure_ms,
+            open_circuits,
+            in_flight: None,
+            max_in_flight: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct WsHealth {
+    pub connected: bool,
+    /// When the connection last came up or went down (epoch ms).
+    pub since_ms: Option<i64>,
+    /// Last message on any channel (epoch ms).
+    pub last_message_ms: Option<i64>,
+    /// Age of the Private WS access token; Private WS only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_age_ms: Option<i64>,
+}
+
+impl WsHealth {
+    /// From the WS counters of `stats`; `with_token` adds the token age.
+    pub fn new(stats: &ClientStats, with_token: bool) -> Self {
+        let liveness = stats.liveness();
+        let now = chrono::Utc::now().timestamp_millis();
+        Self {
+            connected: liveness.ws_connected == Some(true),
+            since_ms: liveness.ws_changed_ms,
+            last_message_ms: stats.last_message_ms(),
+            token_age_ms: liveness.token_ms.filter(|_| with_token).map(|issued| now - issued),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LimiterHealth {
+    /// Tokens left after serving the queued requests (negative while
+    /// requests wait).
+    pub available: f64,
+    pub capacity: f64,
+    /// Share of the capacity in use or waited for, 0.0 to 1.0.
+    pub saturation: f64,
+    pub throttled: bool,
+}
+
+impl LimiterHealth {
+    pub fn new(bucket: &TokenBucket) -> Self {
+        let available = bucket.available();
+        let capacity = bucket.capacity();
+        let saturation = if capacity > 0.0 { (1.0 - available / capacity).clamp(0.0, 1.0) } else { 1.0 };
+        Self { available, capacity, saturation, throttled: bucket.is_throttled() }
+    }
+}
+
+/// Point-in-time health of a client for liveness / readiness probes,
+/// returned to Python as a dict. Parts a client does not have are `None`.
+#[derive(Clone, Debug, Serialize)]
+pub struct Health {
+    pub status: HealthStatus,
+    pub checked_at_ms: i64,
+    pub rest: Option<RestHealth>,
+    pub public_ws: Option<WsHealth>,
+    pub private_ws: Option<WsHealth>,
+    /// By endpoint group.
+    pub rate_limits: BTreeMap<&'static str, LimiterHealth>,
+}
+
+impl Health {
+    pub fn new(
+        rest: Option<RestHealth>,
+        public_ws: Option<WsHealth>,
+        private_ws: Option<WsHealth>,
+        rate_limits: BTreeMap<&'static str, LimiterHealth>,
+    ) -> Self {
+        let down = rest.as_ref().is_some_and(|r| r.reachable == Some(false));
+        let degraded = [&public_ws, &private_ws].into_iter().flatten().any(|ws| !ws.connected)
+            || rest.as_ref().is_some_and(|r| !r.open_circuits.is_empty())
+            || rate_limits.values().any(|l| l.throttled || l.saturation >= SATURATED);
+        let status = match (down, degraded) {
+            (true, _) => HealthStatus::Down,
+            (false, true) => HealthStatus::Degraded,
+            (false, false) => HealthStatus::Ok,
+        };
+        Self {
+            status,
+            checked_at_ms: chrono::Utc::now().timestamp_millis(),
+            rest,
+            public_ws,
+            private_ws,
+            rate_limits,
+        }
+    }
+}
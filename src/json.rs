@@ -0,0 +1,91 @@
+//! JSON decoding for hot WebSocket paths.
+//!
+//! With the `simd-json` feature, frames are decoded with simd-json, which is
+//! markedly faster on large `orderbooks` snapshots. Without it, serde_json.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The routing fields of a public WS frame. Decoding only these skips over
+/// the (possibly large) payload, and borrows from the frame instead of
+/// allocating.
+#[derive(Deserialize)]
+pub struct Envelope<'a> {
+    #[serde(borrow)]
+    pub channel: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub error: Option<Cow<'a, str>>,
+}
+
+impl<'a> Envelope<'a> {
+    pub fn parse(text: &'a str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// A WS frame that could not be decoded, delivered instead of being
+/// dropped so schema changes show up immediately.
+#[derive(Clone, Debug, Serialize)]
+pub struct ParseErrorEvent {
+    /// The frame's channel, if it got that far.
+    pub channel: Option<String>,
+    pub error: String,
+    /// The frame as received.
+    pub raw: String,
+}
+
+impl ParseErrorEvent {
+    pub fn new(channel: Option<&str>, error: String, raw: &str) -> Self {
+        Self { channel: channel.map(str::to_string), error, raw: raw.to_string() }
+    }
+}
+
+/// Per-connection decode state, reused across frames.
+///
+/// simd-json parses in place and needs a mutable copy of each frame plus
+/// parser scratch space; both are kept here and grow to the largest frame
+/// seen, so steady-state decoding does not allocate for them.
+#[derive(Default)]
+pub struct FrameDecoder {
+    #[cfg(feature = "simd-json")]
+    input: Vec<u8>,
+    #[cfg(feature = "simd-json")]
+    buffers: simd_json::Buffers,
+}
+
+impl FrameDecoder {
+    #[cfg(feature = "simd-json")]
+    pub fn decode<T: DeserializeOwned>(&mut self, text: &str) -> Result<T, String> {
+        self.input.clear();
+        self.input.extend_from_slice(text.as_bytes());
+        simd_json::serde::from_slice_with_buffers(&mut self.input, &mut self.buffers)
+            .map_err(|e| e.to_string())
+    }
+
+    #[cfg(not(feature = "simd-json"))]
+    pub fn decode<T: DeserializeOwned>(&mut self, text: &str) -> Result<T, String> {
+        serde_json::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// A WS `subscribe` / `unsubscribe` command, serialized straight from
+/// borrowed fields into its final buffer.
+#[derive(Serialize)]
+pub struct WsCommand<'a> {
+    pub command: &'a str,
+    pub channel: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option: Option<&'a str>,
+}
+
+impl WsCommand<'_> {
+    pub fn to_text(&self) -> String {
+        // The body moves into the outgoing frame, so size it once up front
+        let mut buf = Vec::with_capacity(96);
+        serde_json::to_writer(&mut buf, self).expect("WS command serialization cannot fail");
+        String::from_utf8(buf).expect("serde_json emits UTF-8")
+    }
+}
@@ -1,36 +1,107 @@
 #![allow(non_local_definitions)]
 
+//! GMO Coin REST / WebSocket clients.
+//!
+//! With the default `python` feature this crate builds the
+//! `_nautilus_gmocoin` extension module. Without it, the clients and models
+//! are a plain Rust API:
+//!
+//! ```no_run
+//! use _nautilus_gmocoin::{GmocoinRestClient, RestClientConfig};
+//!
+//! # async fn run() -> Result<(), _nautilus_gmocoin::GmocoinError> {
+//! let client = GmocoinRestClient::from_config(RestClientConfig::default())?;
+//! let tickers = client.get_ticker(Some("BTC")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
-mod client;
-mod error;
-mod model;
-mod rate_limit;
+#[cfg(feature = "python")]
+mod arrow;
+#[cfg(feature = "python")]
+mod callback;
+mod circuit_breaker;
+pub mod client;
+pub mod credentials;
+pub mod error;
+pub mod health;
+mod json;
+#[cfg(feature = "python")]
+mod logging;
+pub mod model;
+#[cfg(feature = "python")]
+mod py_json;
+pub mod rate_limit;
+mod runtime;
+pub mod sim;
+pub mod stats;
+pub mod time;
+pub mod tls;
+
+pub use client::data_client::{DataClientConfig, GmocoinDataClient, MarketEvent};
+pub use client::execution_client::GmocoinExecutionClient;
+pub use client::profile::VenueProfile;
+pub use client::rest::{GmocoinRestClient, RestClientConfig};
+#[cfg(feature = "python")]
+pub use client::sim_execution_client::GmocoinSimExecutionClient;
+pub use credentials::Credentials;
+pub use error::GmocoinError;
+pub use runtime::shutdown;
 
-#[pymodule]
+// Shared state is behind Rust locks or atomics and callback delivery is
+// serialized per client, so the module is safe without the GIL.
+#[cfg(feature = "python")]
+#[pymodule(gil_used = false)]
 fn _nautilus_gmocoin(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    // Initialize tracing subscriber (stderr) for Rust log visibility
+    // Initialize tracing subscriber (stderr or GMOCOIN_LOG_FILE) for Rust log visibility
     use std::sync::Once;
     static INIT: Once = Once::new();
     INIT.call_once(|| {
-        tracing_subscriber::fmt()
-            .with_target(false)
-            .with_env_filter(
-                tracing_subscriber::EnvFilter::try_from_default_env()
-                    .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"))
-            )
-            .try_init().ok();
+        logging::init();
+        runtime::init();
     });
 
     m.add_class::<client::rest::GmocoinRestClient>()?;
+    m.add_class::<client::rest::SyncGmocoinRestClient>()?;
     m.add_class::<client::data_client::GmocoinDataClient>()?;
     m.add_class::<client::execution_client::GmocoinExecutionClient>()?;
+    m.add_class::<client::sim_execution_client::GmocoinSimExecutionClient>()?;
+    m.add_class::<client::calendar::TradingCalendar>()?;
+    m.add_class::<client::positions::PositionSnapshot>()?;
+    m.add_class::<model::fees::FeeModel>()?;
+
+    m.add_function(wrap_pyfunction!(logging::configure_logging, m)?)?;
+    m.add_function(wrap_pyfunction!(runtime::py_shutdown, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_order_status, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_order_status_report, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_fill_report, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_position_status_report, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_position_summary_report, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_account_balance, m)?)?;
+    m.add_function(wrap_pyfunction!(model::report::py_margin_balance, m)?)?;
+    m.add_function(wrap_pyfunction!(client::rollover::py_project_rollover_cost, m)?)?;
+    m.add_function(wrap_pyfunction!(model::tax::py_tax_report, m)?)?;
+    m.add_function(wrap_pyfunction!(client::klines::py_kline_date, m)?)?;
+    m.add_function(wrap_pyfunction!(client::session::py_verify_session, m)?)?;
+    m.add_function(wrap_pyfunction!(credentials::py_encrypt_credentials, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_timestamp_ns, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_format_timestamp, m)?)?;
+
+    m.add("CircuitOpenError", m.py().get_type::<error::CircuitOpenError>())?;
+    m.add("OrderTooLargeError", m.py().get_type::<error::OrderTooLargeError>())?;
 
     // Models
     m.add_class::<model::market_data::Ticker>()?;
+    m.add_class::<model::market_data::DepthEntry>()?;
     m.add_class::<model::market_data::Depth>()?;
     m.add_class::<model::market_data::Trade>()?;
     m.add_class::<model::market_data::SymbolInfo>()?;
     m.add_class::<model::orderbook::OrderBook>()?;
+    m.add_class::<model::orderbook::BookDelta>()?;
+    m.add_class::<model::orderbook::BestBidOffer>()?;
+    m.add_class::<arrow::ArrowRecordBatch>()?;
     Ok(())
 }
@@ -0,0 +1,154 @@
+//! The embedded tracing subscriber.
+//!
+//! Configured from the environment when the extension is first imported:
+//!
+//! - `RUST_LOG`: filter directives (default `info`)
+//! - `GMOCOIN_LOG_FORMAT`: `text` (default) or `json` (one object per line)
+//! - `GMOCOIN_LOG_FILE`: write to this file instead of stderr
+//! - `GMOCOIN_LOG_ROTATION`: `never` (default), `daily` or `hourly`; rotated
+//!   files get a date suffix (`adapter.log.2024-01-01`)
+//!
+//! The filter and the output are installed behind reload handles, so
+//! `configure_logging` can change them at any time afterwards.
+
+use pyo3::prelude::*;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::layer::Layered;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, reload, EnvFilter, Layer, Registry};
+
+type Base = Layered<reload::Layer<EnvFilter, Registry>, Registry>;
+type Output = Box<dyn Layer<Base> + Send + Sync>;
+
+#[derive(Clone, Default)]
+struct Settings {
+    json: bool,
+    /// `None` writes to stderr.
+    file: Option<String>,
+    rotation: Option<String>,
+}
+
+struct Handles {
+    filter: reload::Handle<EnvFilter, Registry>,
+    output: reload::Handle<Output, Base>,
+    settings: Mutex<Settings>,
+}
+
+static HANDLES: OnceLock<Handles> = OnceLock::new();
+
+pub fn init() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let mut settings = Settings {
+        json: std::env::var("GMOCOIN_LOG_FORMAT").is_ok_and(|f| f.eq_ignore_ascii_case("json")),
+        file: std::env::var("GMOCOIN_LOG_FILE").ok().filter(|p| !p.is_empty()),
+        rotation: std::env::var("GMOCOIN_LOG_ROTATION").ok(),
+    };
+    let output = output_layer(&settings).unwrap_or_else(|e| {
+        eprintln!("GMO: Cannot open GMOCOIN_LOG_FILE ({}), logging to stderr", e);
+        settings.file = None;
+        output_layer(&settings).expect("stderr output cannot fail")
+    });
+
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    let (output, output_handle) = reload::Layer::new(output);
+    if tracing_subscriber::registry().with(filter).with(output).try_init().is_ok() {
+        let _ = HANDLES.set(Handles {
+            filter: filter_handle,
+            output: output_handle,
+            settings: Mutex::new(settings),
+        });
+    }
+}
+
+/// Change the Rust log settings at runtime. Arguments left as `None` keep
+/// their current value.
+///
+/// `level`: filter directives, e.g. `"debug"` or `"info,_nautilus_gmocoin=trace"`.
+/// `format`: `"text"` or `"json"`.
+/// `target`: `"stderr"` or a file path.
+/// `rotation`: `"never"`, `"daily"` or `"hourly"` (file targets only).
+#[pyfunction]
+#[pyo3(signature = (level=None, format=None, target=None, rotation=None))]
+pub fn configure_logging(
+    level: Option<&str>,
+    format: Option<&str>,
+    target: Option<&str>,
+    rotation: Option<&str>,
+) -> PyResult<()> {
+    let handles = HANDLES.get().ok_or_else(|| {
+        pyo3::exceptions::PyRuntimeError::new_err(
+            "Rust logging is managed by another tracing subscriber",
+        )
+    })?;
+    let invalid = |e: String| pyo3::exceptions::PyValueError::new_err(e);
+
+    let filter = level
+        .map(|l| EnvFilter::try_new(l).map_err(|e| invalid(format!("Invalid log level '{}': {}", l, e))))
+        .transpose()?;
+
+    let mut settings = handles.settings.lock().unwrap();
+    let output = if format.is_some() || target.is_some() || rotation.is_some() {
+        let mut next = settings.clone();
+        if let Some(f) = format {
+            next.json = match f.to_ascii_lowercase().as_str() {
+                "json" => true,
+                "text" => false,
+                _ => return Err(invalid(format!("Unknown log format '{}': expected 'text' or 'json'", f))),
+            };
+        }
+        if let Some(t) = target {
+            next.file = (t != "stderr").then(|| t.to_string());
+        }
+        if let Some(r) = rotation {
+            next.rotation = Some(r.to_string());
+        }
+        let layer = output_layer(&next).map_err(invalid)?;
+        Some((layer, next))
+    } else {
+        None
+    };
+
+    let reload_failed = |e: reload::Error| pyo3::exceptions::PyRuntimeError::new_err(e.to_string());
+    if let Some(filter) = filter {
+        handles.filter.reload(filter).map_err(reload_failed)?;
+    }
+    if let Some((layer, next)) = output {
+        handles.output.reload(layer).map_err(reload_failed)?;
+        *settings = next;
+    }
+    Ok(())
+}
+
+fn output_layer(settings: &Settings) -> Result<Output, String> {
+    let (writer, ansi) = match &settings.file {
+        Some(path) => (file_writer(path, settings.rotation.as_deref())?, false),
+        None => (BoxMakeWriter::new(std::io::stderr), true),
+    };
+    let layer = fmt::layer().with_target(false).with_writer(writer);
+    Ok(if settings.json {
+        layer.json().boxed()
+    } else {
+        layer.with_ansi(ansi).boxed()
+    })
+}
+
+fn file_writer(path: &str, rotation: Option<&str>) -> Result<BoxMakeWriter, String> {
+    let rotation = match rotation {
+        Some("daily") => Rotation::DAILY,
+        Some("hourly") => Rotation::HOURLY,
+        Some("never") | None => Rotation::NEVER,
+        Some(other) => return Err(format!("Unknown log rotation '{}'", other)),
+    };
+    let path = Path::new(path);
+    let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let name = path.file_name().ok_or_else(|| format!("'{}' is not a file path", path.display()))?;
+    let appender = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(name.to_string_lossy())
+        .build(dir)
+        .map_err(|e| e.to_string())?;
+    Ok(BoxMakeWriter::new(appender))
+}
@@ -27,3 +27,30 @@ pub struct Margin {
     #[serde(rename = "marginRate")]
     pub margin_rate: Option<String>,
 }
+
+/// Trading volume and fee tier, from GET /v1/account/tradingVolume
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TradingVolume {
+    #[serde(rename = "jpyVolume")]
+    pub jpy_volume: Option<String>,
+    #[serde(rename = "tierLevel")]
+    pub tier_level: Option<u32>,
+    #[serde(default)]
+    pub limit: Vec<SymbolLimit>,
+}
+
+/// Per-symbol limits and fee rates of the account's tier
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SymbolLimit {
+    pub symbol: String,
+    #[serde(rename = "todayLimitOpenSize")]
+    pub today_limit_open_size: Option<String>,
+    #[serde(rename = "todayLimitBuySize")]
+    pub today_limit_buy_size: Option<String>,
+    #[serde(rename = "todayLimitSellSize")]
+    pub today_limit_sell_size: Option<String>,
+    #[serde(rename = "takerFee")]
+    pub taker_fee: Option<String>,
+    #[serde(rename = "makerFee")]
+    pub maker_fee: Option<String>,
+}
@@ -0,0 +1,193 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::error::GmocoinError;
+use crate::model::account::TradingVolume;
+use crate::model::market_data::SymbolInfo;
+use crate::model::order::Execution;
+use crate::model::report::{decimal, liquidity_side};
+#[cfg(feature = "python")]
+use crate::model::report::from_json;
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+
+/// Fee rates of a symbol, as fractions of the notional. A negative maker
+/// rate is a rebate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct FeeRates {
+    pub maker: Decimal,
+    pub taker: Decimal,
+}
+
+/// The fee of one fill. `fee` is negative for a rebate.
+#[derive(Clone, Debug, Serialize)]
+pub struct FeeQuote {
+    pub symbol: String,
+    /// `"MAKER"` or `"TAKER"`
+    pub liquidity_side: &'static str,
+    pub rate: Decimal,
+    pub notional: Decimal,
+    pub fee: Decimal,
+    pub rebate: bool,
+    /// What GMO charged, for realized fills.
+    pub charged: Option<Decimal>,
+}
+
+/// Per-symbol fee rates: the `/v1/symbols` rates, overridden by the
+/// account's tier rates from `/v1/account/tradingVolume` where given.
+#[cfg_attr(feature = "python", pyclass(from_py_object))]
+#[derive(Clone, Debug, Default)]
+pub struct FeeModel {
+    rates: HashMap<String, FeeRates>,
+    tier_level: Option<u32>,
+}
+
+/// `"btc_jpy"` and `"BTC/JPY"` both as `"BTC_JPY"`.
+fn key(symbol: &str) -> String {
+    symbol.to_uppercase().replace('/', "_")
+}
+
+fn rate(name: &str, value: Option<&str>) -> Result<Option<Decimal>, GmocoinError> {
+    value.map(|v| decimal(name, v)).transpose()
+}
+
+impl FeeModel {
+    /// Rates of every symbol; missing fees count as zero.
+    pub fn from_symbols(symbols: &[SymbolInfo]) -> Result<Self, GmocoinError> {
+        let rates = symbols
+            .iter()
+            .map(|info| {
+                let rates = FeeRates {
+                    maker: rate("makerFee", info.maker_fee.as_deref())?.unwrap_or_default(),
+                    taker: rate("takerFee", info.taker_fee.as_deref())?.unwrap_or_default(),
+                };
+                Ok((key(&info.symbol), rates))
+            })
+            .collect::<Result<_, GmocoinError>>()?;
+        Ok(Self { rates, tier_level: None })
+    }
+
+    /// Apply the account's tier: its per-symbol rates replace the listed
+    /// ones.
+    pub fn with_tier(mut self, volume: &TradingVolume) -> Result<Self, GmocoinError> {
+        for limit in &volume.limit {
+            let rates = self.rates.entry(key(&limit.symbol)).or_default();
+            if let Some(maker) = rate("makerFee", limit.maker_fee.as_deref())? {
+                rates.maker = maker;
+            }
+            if let Some(taker) = rate("takerFee", limit.taker_fee.as_deref())? {
+                rates.taker = taker;
+            }
+        }
+        self.tier_level = volume.tier_level;
+        Ok(self)
+    }
+
+    pub fn tier_level(&self) -> Option<u32> {
+        self.tier_level
+    }
+
+    pub fn rates(&self, symbol: &str) -> Option<FeeRates> {
+        self.rates.get(&key(symbol)).copied()
+    }
+
+    /// The fee of a hypothetical fill; `liquidity_side` is `"MAKER"` or
+    /// `"TAKER"`.
+    pub fn quote(&self, symbol: &str, liquidity_side: &str, price: Decimal, size: Decimal) -> Result<FeeQuote, GmocoinError> {
+        let rates = self
+            .rates(symbol)
+            .ok_or_else(|| GmocoinError::InvalidInput(format!("No fee rates for symbol '{}'", symbol)))?;
+        let (liquidity_side, rate) = match liquidity_side.to_uppercase().as_str() {
+            "MAKER" => ("MAKER", rates.maker),
+            "TAKER" => ("TAKER", rates.taker),
+            other => {
+                return Err(GmocoinError::InvalidInput(format!(
+                    "Invalid liquidity side '{}' (expected MAKER or TAKER)",
+                    other
+                )))
+            }
+        };
+        let notional = (price * size).normalize();
+        let fee = (notional * rate).normalize();
+        Ok(FeeQuote {
+            symbol: symbol.to_string(),
+            liquidity_side,
+            rate,
+            notional,
+            fee,
+            rebate: fee.is_sign_negative() && !fee.is_zero(),
+            charged: None,
+        })
+    }
+
+    /// The modelled fee of a realized fill next to what GMO charged.
+    /// Fills whose side cannot be inferred are priced as taker.
+    pub fn fill(&self, execution: &Execution) -> Result<FeeQuote, GmocoinError> {
+        let charged = decimal("fee", &execution.fee)?;
+        let side = match liquidity_side(execution.execution_type.as_deref(), execution.time_in_force.as_deref(), charged) {
+            "MAKER" => "MAKER",
+            _ => "TAKER",
+        };
+        let price = decimal("price", &execution.price)?;
+        let size = decimal("size", &execution.size)?;
+        let quote = self.quote(&execution.symbol, side, price, size)?;
+        Ok(FeeQuote { charged: Some(charged), ..quote })
+    }
+}
+
+/// A decimal argument given as `str`, `Decimal`, `int` or `float`.
+#[cfg(feature = "python")]
+fn decimal_arg(name: &str, value: PyJson) -> Result<Decimal, GmocoinError> {
+    match value.0 {
+        serde_json::Value::String(s) => decimal(name, &s),
+        serde_json::Value::Number(n) => decimal(name, &n.to_string()),
+        other => Err(GmocoinError::InvalidInput(format!("Invalid {} {}", name, other))),
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl FeeModel {
+    /// `symbols`: the `/v1/symbols` list; `trading_volume`: the
+    /// `/v1/account/tradingVolume` response, whose tier rates take
+    /// precedence.
+    #[new]
+    #[pyo3(signature = (symbols, trading_volume=None))]
+    fn py_new(symbols: PyJson, trading_volume: Option<PyJson>) -> PyResult<Self> {
+        let symbols: Vec<SymbolInfo> = from_json("symbols", symbols)?;
+        let model = Self::from_symbols(&symbols)?;
+        let Some(volume) = trading_volume else { return Ok(model) };
+        let volume: TradingVolume = from_json("trading volume", volume)?;
+        Ok(model.with_tier(&volume)?)
+    }
+
+    #[getter(tier_level)]
+    fn py_tier_level(&self) -> Option<u32> {
+        self.tier_level
+    }
+
+    /// `{"maker", "taker"}`, or `None` for an unknown symbol.
+    #[pyo3(name = "rates")]
+    fn py_rates(&self, symbol: &str) -> PyResult<Option<PyJson>> {
+        self.rates(symbol).map(|r| PyJson::from_serialize(&r)).transpose()
+    }
+
+    /// Fee of a hypothetical fill; `price` / `size` as `str`, `Decimal` or
+    /// numbers.
+    #[pyo3(name = "quote")]
+    fn py_quote(&self, symbol: &str, liquidity_side: &str, price: PyJson, size: PyJson) -> PyResult<PyJson> {
+        let quote = self.quote(symbol, liquidity_side, decimal_arg("price", price)?, decimal_arg("size", size)?)?;
+        PyJson::from_serialize(&quote)
+    }
+
+    /// Modelled fee of a REST execution or `executionEvents` dict, with
+    /// the fee GMO charged as `charged`.
+    #[pyo3(name = "fill")]
+    fn py_fill(&self, execution: PyJson) -> PyResult<PyJson> {
+        let execution: Execution = from_json("execution", execution)?;
+        PyJson::from_serialize(&self.fill(&execution)?)
+    }
+}
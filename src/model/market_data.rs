@@ -1,31 +1,72 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
-#[pyclass(from_py_object)]
+use crate::time::timestamp_ns;
+
+/// `ts_event` of a GMO timestamp; `None` when missing or malformed.
+fn ts_event(timestamp: &str) -> Option<u64> {
+    timestamp_ns(timestamp).ok()
+}
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(from = "TickerFrame")]
 pub struct Ticker {
-    #[pyo3(get)]
     pub ask: String,
-    #[pyo3(get)]
     pub bid: String,
-    #[pyo3(get)]
     pub high: String,
-    #[pyo3(get)]
     pub low: String,
-    #[pyo3(get)]
     pub last: String,
-    #[pyo3(get)]
     pub symbol: String,
-    #[pyo3(get)]
     pub timestamp: String,
-    #[pyo3(get)]
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
     pub volume: String,
 }
 
+/// `Ticker` as sent by GMO, before `ts_event` is parsed.
+#[derive(Deserialize)]
+struct TickerFrame {
+    ask: String,
+    bid: String,
+    high: String,
+    low: String,
+    last: String,
+    symbol: String,
+    timestamp: String,
+    volume: String,
+}
+
+impl From<TickerFrame> for Ticker {
+    fn from(t: TickerFrame) -> Self {
+        Self::new(t.ask, t.bid, t.high, t.low, t.last, t.symbol, t.timestamp, t.volume)
+    }
+}
+
+impl Ticker {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ask: String,
+        bid: String,
+        high: String,
+        low: String,
+        last: String,
+        symbol: String,
+        timestamp: String,
+        volume: String,
+    ) -> Self {
+        let ts_event = ts_event(&timestamp);
+        Self { ask, bid, high, low, last, symbol, timestamp, ts_event, volume }
+    }
+}
+
+#[cfg(feature = "python")]
 #[pymethods]
 impl Ticker {
     #[new]
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
         ask: String,
         bid: String,
         high: String,
@@ -35,96 +76,150 @@ impl Ticker {
         timestamp: String,
         volume: String,
     ) -> Self {
-        Self { ask, bid, high, low, last, symbol, timestamp, volume }
+        Self::new(ask, bid, high, low, last, symbol, timestamp, volume)
     }
 }
 
-#[pyclass(from_py_object)]
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct DepthEntry {
-    #[pyo3(get)]
     pub price: String,
-    #[pyo3(get)]
     pub size: String,
 }
 
-#[pyclass(from_py_object)]
+impl DepthEntry {
+    pub fn new(price: String, size: String) -> Self {
+        Self { price, size }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl DepthEntry {
+    #[new]
+    fn py_new(price: String, size: String) -> Self {
+        Self::new(price, size)
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(from = "DepthFrame")]
 pub struct Depth {
-    #[pyo3(get)]
     pub asks: Vec<DepthEntry>,
-    #[pyo3(get)]
     pub bids: Vec<DepthEntry>,
-    #[pyo3(get)]
     pub symbol: String,
-    #[pyo3(get)]
-    #[serde(default)]
     pub timestamp: String,
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
 }
 
+#[derive(Deserialize)]
+struct DepthFrame {
+    asks: Vec<DepthEntry>,
+    bids: Vec<DepthEntry>,
+    symbol: String,
+    #[serde(default)]
+    timestamp: String,
+}
+
+impl From<DepthFrame> for Depth {
+    fn from(d: DepthFrame) -> Self {
+        Self::new(d.asks, d.bids, d.symbol, d.timestamp)
+    }
+}
+
+impl Depth {
+    pub fn new(asks: Vec<DepthEntry>, bids: Vec<DepthEntry>, symbol: String, timestamp: String) -> Self {
+        let ts_event = ts_event(&timestamp);
+        Self { asks, bids, symbol, timestamp, ts_event }
+    }
+}
+
+#[cfg(feature = "python")]
 #[pymethods]
 impl Depth {
     #[new]
-    pub fn new(asks: Vec<DepthEntry>, bids: Vec<DepthEntry>, symbol: String, timestamp: String) -> Self {
-        Self { asks, bids, symbol, timestamp }
+    fn py_new(asks: Vec<DepthEntry>, bids: Vec<DepthEntry>, symbol: String, timestamp: String) -> Self {
+        Self::new(asks, bids, symbol, timestamp)
     }
 }
 
-#[pyclass(from_py_object)]
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(from = "TradeFrame")]
 pub struct Trade {
-    #[pyo3(get)]
     pub price: String,
-    #[pyo3(get)]
     pub side: String,
-    #[pyo3(get)]
     pub size: String,
-    #[pyo3(get)]
     pub timestamp: String,
-    #[pyo3(get)]
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
     pub symbol: Option<String>,
+    /// Backfilled from REST `/v1/trades` rather than received live.
+    pub historical: bool,
+}
+
+#[derive(Deserialize)]
+struct TradeFrame {
+    price: String,
+    side: String,
+    size: String,
+    timestamp: String,
+    symbol: Option<String>,
+}
+
+impl From<TradeFrame> for Trade {
+    fn from(t: TradeFrame) -> Self {
+        Self::new(t.price, t.side, t.size, t.timestamp, t.symbol)
+    }
 }
 
+impl Trade {
+    pub fn new(price: String, side: String, size: String, timestamp: String, symbol: Option<String>) -> Self {
+        let ts_event = ts_event(&timestamp);
+        Self { price, side, size, timestamp, ts_event, symbol, historical: false }
+    }
+}
+
+#[cfg(feature = "python")]
 #[pymethods]
 impl Trade {
     #[new]
-    pub fn new(price: String, side: String, size: String, timestamp: String, symbol: Option<String>) -> Self {
-        Self { price, side, size, timestamp, symbol }
+    fn py_new(price: String, side: String, size: String, timestamp: String, symbol: Option<String>) -> Self {
+        Self::new(price, side, size, timestamp, symbol)
     }
 }
 
+/// Container for GET /v1/trades (`pagination` is dropped)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TradesList {
+    #[serde(default)]
+    pub list: Vec<Trade>,
+}
+
 /// Symbol info from GET /v1/symbols
-#[pyclass(from_py_object)]
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SymbolInfo {
-    #[pyo3(get)]
     pub symbol: String,
-    #[pyo3(get)]
     #[serde(rename = "minCloseOrderSize")]
     pub min_close_order_size: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "maxOrderSize")]
     pub max_order_size: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "sizeStep")]
     pub size_step: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "tickSize")]
     pub tick_size: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "minOrderSize")]
     pub min_order_size: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "takerFee")]
     pub taker_fee: Option<String>,
-    #[pyo3(get)]
     #[serde(rename = "makerFee")]
     pub maker_fee: Option<String>,
 }
 
-#[pymethods]
 impl SymbolInfo {
-    #[new]
     pub fn new(symbol: String) -> Self {
         Self {
             symbol,
@@ -139,9 +234,17 @@ impl SymbolInfo {
     }
 }
 
+#[cfg(feature = "python")]
+#[pymethods]
+impl SymbolInfo {
+    #[new]
+    fn py_new(symbol: String) -> Self {
+        Self::new(symbol)
+    }
+}
+
 /// Kline data from GET /v1/klines
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[allow(dead_code)]
 pub struct Kline {
     #[serde(rename = "openTime")]
     pub open_time: String,
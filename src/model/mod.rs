@@ -1,7 +1,10 @@
 pub mod market_data;
 pub mod order;
 pub mod account;
+pub mod fees;
 pub mod orderbook;
+pub mod report;
+pub mod tax;
 
 use serde::Deserialize;
 
@@ -1,29 +1,121 @@
 use serde::{Deserialize, Serialize};
 
+/// Order from the REST API; also accepts the `order*` field names used by
+/// Private WS `orderEvents`.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Order {
     #[serde(rename = "orderId")]
     pub order_id: u64,
     #[serde(rename = "rootOrderId")]
     pub root_order_id: Option<u64>,
+    #[serde(rename = "clientOrderId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
     pub symbol: String,
     pub side: String,
     #[serde(rename = "executionType")]
     pub execution_type: String,
     #[serde(rename = "settleType")]
     pub settle_type: Option<String>,
+    #[serde(alias = "orderSize")]
     pub size: String,
-    #[serde(rename = "executedSize")]
+    #[serde(rename = "executedSize", alias = "orderExecutedSize")]
     pub executed_size: String,
+    #[serde(alias = "orderPrice")]
     pub price: Option<String>,
     #[serde(rename = "losscutPrice")]
     pub losscut_price: Option<String>,
+    #[serde(alias = "orderStatus")]
     pub status: String,
     #[serde(rename = "timeInForce")]
     pub time_in_force: Option<String>,
+    #[serde(alias = "orderTimestamp")]
     pub timestamp: String,
+    /// `orderEvents` only; see `OrderEventKind`.
+    #[serde(rename = "msgType", skip_serializing_if = "Option::is_none")]
+    pub msg_type: Option<String>,
+    /// `orderEvents` only: why GMO cancelled the order (empty otherwise);
+    /// see `CancelReason`.
+    #[serde(rename = "cancelType", skip_serializing_if = "Option::is_none")]
+    pub cancel_type: Option<String>,
 }
 
+impl Order {
+    /// What this `orderEvents` message reports; `None` for REST orders.
+    pub fn event_kind(&self) -> Option<OrderEventKind> {
+        self.msg_type.as_deref().map(OrderEventKind::from_msg_type)
+    }
+
+    /// Why this `orderEvents` message cancelled the order, if it did.
+    pub fn cancel_reason(&self) -> Option<CancelReason> {
+        self.cancel_type.as_deref().filter(|t| !t.is_empty()).map(CancelReason::from_cancel_type)
+    }
+}
+
+/// What an `orderEvents` message reports, from its `msgType`: `NOR` (new
+/// order), `ROR` (price changed), `COR` (cancelled) or `ER` (expired or
+/// rejected by GMO).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderEventKind {
+    New,
+    Amended,
+    Canceled,
+    Expired,
+    Unknown,
+}
+
+impl OrderEventKind {
+    pub fn from_msg_type(msg_type: &str) -> Self {
+        match msg_type {
+            "NOR" => Self::New,
+            "ROR" => Self::Amended,
+            "COR" => Self::Canceled,
+            "ER" => Self::Expired,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Why an order was cancelled or expired, normalized from the `cancelType`
+/// of `orderEvents`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReason {
+    /// `USER`: cancelled through the API or the web UI.
+    UserCanceled,
+    /// `POSITION_LOSSCUT`, `ACCOUNT_LOSSCUT`, `MARGIN_CALL_LOSSCUT`
+    Losscut,
+    /// `INSUFFICIENT_BALANCE`, `INSUFFICIENT_MARGIN`, `MARGIN_CALL`
+    InsufficientMargin,
+    /// `EXPIRED_FAK`, `EXPIRED_FOK`, `EXPIRED_SOK`: the unfilled rest of a
+    /// FAK / FOK / SOK order.
+    Expired,
+    /// `SOK_TAKER`: a post-only order that would have taken.
+    PostOnlyRejected,
+    /// `PRICE_LIMIT`: outside the price limit.
+    PriceLimit,
+    /// `CLOSED_ORDER`: the positions it would close are gone.
+    PositionClosed,
+    Other,
+}
+
+impl CancelReason {
+    pub fn from_cancel_type(cancel_type: &str) -> Self {
+        match cancel_type {
+            "USER" => Self::UserCanceled,
+            "POSITION_LOSSCUT" | "ACCOUNT_LOSSCUT" | "MARGIN_CALL_LOSSCUT" => Self::Losscut,
+            "INSUFFICIENT_BALANCE" | "INSUFFICIENT_MARGIN" | "MARGIN_CALL" => Self::InsufficientMargin,
+            t if t.starts_with("EXPIRED") => Self::Expired,
+            "SOK_TAKER" => Self::PostOnlyRejected,
+            "PRICE_LIMIT" => Self::PriceLimit,
+            "CLOSED_ORDER" => Self::PositionClosed,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Execution from the REST API; also accepts the `execution*` field names
+/// used by Private WS `executionEvents`.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Execution {
     #[serde(rename = "executionId")]
@@ -34,12 +126,21 @@ pub struct Execution {
     pub side: String,
     #[serde(rename = "settleType")]
     pub settle_type: Option<String>,
+    #[serde(alias = "executionSize")]
     pub size: String,
+    #[serde(alias = "executionPrice")]
     pub price: String,
     #[serde(rename = "lossGain")]
     pub loss_gain: Option<String>,
     pub fee: String,
+    #[serde(alias = "executionTimestamp")]
     pub timestamp: String,
+    /// Only on `executionEvents`.
+    #[serde(rename = "executionType", skip_serializing_if = "Option::is_none")]
+    pub execution_type: Option<String>,
+    /// Only on `executionEvents`.
+    #[serde(rename = "timeInForce", skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<String>,
 }
 
 /// Container for orders list response
@@ -56,7 +157,8 @@ pub struct ExecutionsList {
     pub list: Vec<Execution>,
 }
 
-/// Open position (leverage)
+/// Open position (leverage), from the REST API or Private WS
+/// `positionEvents`.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Position {
     #[serde(rename = "positionId")]
@@ -73,6 +175,25 @@ pub struct Position {
     #[serde(rename = "losscutPrice")]
     pub losscut_price: Option<String>,
     pub timestamp: String,
+    /// `positionEvents` only: `OPR` (opened), `UPR` (updated), `ULO`
+    /// (losscut price changed) or `CPR` (closed).
+    #[serde(rename = "msgType", skip_serializing_if = "Option::is_none")]
+    pub msg_type: Option<String>,
+}
+
+/// Acknowledgment of `/v1/changeLosscutPrice`, which returns no data: the
+/// change GMO accepted.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LosscutPriceAck {
+    pub position_id: u64,
+    pub losscut_price: String,
+}
+
+/// Acknowledgment of `/v1/closeOrder` and `/v1/closeBulkOrder`, whose
+/// `data` is the id of the closing order.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct CloseOrderAck {
+    pub order_id: u64,
 }
 
 /// Container for positions list response
@@ -82,7 +203,8 @@ pub struct PositionsList {
     pub list: Vec<Position>,
 }
 
-/// Position summary
+/// Position summary, from the REST API or Private WS
+/// `positionSummaryEvents`.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PositionSummary {
     pub symbol: String,
@@ -1,62 +1,228 @@
-use std::collections::BTreeMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use crate::model::market_data::Depth;
+use serde::Serialize;
+use serde::ser::{SerializeStruct, Serializer};
+use crate::model::market_data::{Depth, DepthEntry};
 
-#[pyclass(from_py_object)]
+/// Latest `orderbooks` snapshot for a symbol.
+///
+/// Levels are kept in venue order (asks ascending, bids descending, best
+/// first) as received, so a snapshot is applied by moving the decoded
+/// vectors in rather than re-inserting every level. They sit behind an
+/// `Arc`, making the clone handed to each Python callback a pointer copy.
+#[cfg_attr(feature = "python", pyclass(from_py_object))]
 #[derive(Clone)]
 pub struct OrderBook {
-    #[pyo3(get)]
     pub symbol: String,
-    pub asks: BTreeMap<String, String>,
-    pub bids: BTreeMap<String, String>,
-    #[pyo3(get)]
+    levels: Arc<Levels>,
     pub timestamp: String,
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
+}
+
+/// Changes to a book since the last one emitted for the subscription, on
+/// the `"book_deltas"` channel: levels added or resized, and removed ones
+/// with size `"0"`. With `snapshot`, the levels are the whole book and
+/// replace whatever the receiver holds.
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
+#[derive(Clone, Debug, Serialize)]
+pub struct BookDelta {
+    pub symbol: String,
+    pub snapshot: bool,
+    pub asks: Vec<DepthEntry>,
+    pub bids: Vec<DepthEntry>,
+    pub timestamp: String,
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
+}
+
+/// Best ask and bid of a book, on the `"bbo"` channel. A side is `None`
+/// while it is empty.
+#[cfg_attr(feature = "python", pyclass(from_py_object, get_all))]
+#[derive(Clone, Debug, Serialize)]
+pub struct BestBidOffer {
+    pub symbol: String,
+    pub ask_price: Option<String>,
+    pub ask_size: Option<String>,
+    pub bid_price: Option<String>,
+    pub bid_size: Option<String>,
+    pub timestamp: String,
+    /// `timestamp` in UNIX ns.
+    pub ts_event: Option<u64>,
+}
+
+/// `(price, size)` as returned to Python.
+#[cfg(feature = "python")]
+type Level = (String, String);
+
+#[derive(Clone, Default)]
+struct Levels {
+    asks: Vec<DepthEntry>,
+    bids: Vec<DepthEntry>,
 }
 
-#[pymethods]
 impl OrderBook {
-    #[new]
     pub fn new(symbol: String) -> Self {
         Self {
             symbol,
-            asks: BTreeMap::new(),
-            bids: BTreeMap::new(),
+            levels: Arc::new(Levels::default()),
             timestamp: String::new(),
+            ts_event: None,
         }
     }
 
     pub fn apply_snapshot(&mut self, depth: Depth) {
-        self.asks.clear();
-        for entry in &depth.asks {
-            self.asks.insert(entry.price.clone(), entry.size.clone());
+        let Depth { asks, bids, timestamp, ts_event, .. } = depth;
+        match Arc::get_mut(&mut self.levels) {
+            // Nobody else holds the previous snapshot: update in place
+            Some(levels) => {
+                levels.asks = asks;
+                levels.bids = bids;
+            }
+            // A callback still holds the previous snapshot: leave it intact
+            None => self.levels = Arc::new(Levels { asks, bids }),
         }
-        self.bids.clear();
-        for entry in &depth.bids {
-            self.bids.insert(entry.price.clone(), entry.size.clone());
+        self.timestamp = timestamp;
+        self.ts_event = ts_event;
+    }
+
+    /// How `depth` differs from the current snapshot: whether the best ask
+    /// or bid (price or size) moved, and how many levels were added, removed
+    /// or resized on both sides.
+    pub fn diff(&self, depth: &Depth) -> (bool, usize) {
+        let touch = |old: &[DepthEntry], new: &[DepthEntry]| match (old.first(), new.first()) {
+            (Some(old), Some(new)) => old.price != new.price || old.size != new.size,
+            (old, new) => old.is_some() != new.is_some(),
+        };
+        let touch_changed = touch(self.asks(), &depth.asks) || touch(self.bids(), &depth.bids);
+        let levels_changed = Self::levels_changed(self.asks(), &depth.asks) + Self::levels_changed(self.bids(), &depth.bids);
+        (touch_changed, levels_changed)
+    }
+
+    /// Levels of one side added or resized in `new`, plus those removed.
+    fn levels_changed(old: &[DepthEntry], new: &[DepthEntry]) -> usize {
+        let old_sizes: HashMap<&str, &str> = old.iter().map(|e| (e.price.as_str(), e.size.as_str())).collect();
+        let new_prices: HashSet<&str> = new.iter().map(|e| e.price.as_str()).collect();
+        let updated = new.iter().filter(|e| old_sizes.get(e.price.as_str()) != Some(&e.size.as_str())).count();
+        let removed = old.iter().filter(|e| !new_prices.contains(e.price.as_str())).count();
+        updated + removed
+    }
+
+    /// The levels that changed from `base` to this book (see `BookDelta`),
+    /// or all of them when there is no `base`.
+    pub fn delta(&self, base: Option<&OrderBook>) -> BookDelta {
+        let side = |old: Option<&[DepthEntry]>, new: &[DepthEntry]| match old {
+            Some(old) => Self::side_delta(old, new),
+            None => new.to_vec(),
+        };
+        BookDelta {
+            symbol: self.symbol.clone(),
+            snapshot: base.is_none(),
+            asks: side(base.map(OrderBook::asks), self.asks()),
+            bids: side(base.map(OrderBook::bids), self.bids()),
+            timestamp: self.timestamp.clone(),
+            ts_event: self.ts_event,
         }
-        self.timestamp = depth.timestamp.clone();
     }
 
-    pub fn get_asks(&self) -> Vec<Vec<String>> {
-        self.asks.iter().map(|(p, a)| vec![p.clone(), a.clone()]).collect()
+    /// Levels of one side added or resized in `new`, in book order, then
+    /// those removed with size `"0"`.
+    fn side_delta(old: &[DepthEntry], new: &[DepthEntry]) -> Vec<DepthEntry> {
+        let old_sizes: HashMap<&str, &str> = old.iter().map(|e| (e.price.as_str(), e.size.as_str())).collect();
+        let new_prices: HashSet<&str> = new.iter().map(|e| e.price.as_str()).collect();
+        let updated = new.iter().filter(|e| old_sizes.get(e.price.as_str()) != Some(&e.size.as_str())).cloned();
+        let removed = old
+            .iter()
+            .filter(|e| !new_prices.contains(e.price.as_str()))
+            .map(|e| DepthEntry::new(e.price.clone(), "0".to_string()));
+        updated.chain(removed).collect()
     }
 
-    pub fn get_bids(&self) -> Vec<Vec<String>> {
-        self.bids.iter().rev().map(|(p, a)| vec![p.clone(), a.clone()]).collect()
+    pub fn bbo(&self) -> BestBidOffer {
+        let (ask, bid) = (self.asks().first(), self.bids().first());
+        BestBidOffer {
+            symbol: self.symbol.clone(),
+            ask_price: ask.map(|e| e.price.clone()),
+            ask_size: ask.map(|e| e.size.clone()),
+            bid_price: bid.map(|e| e.price.clone()),
+            bid_size: bid.map(|e| e.size.clone()),
+            timestamp: self.timestamp.clone(),
+            ts_event: self.ts_event,
+        }
     }
 
-    pub fn get_top_n(&self, n: usize) -> (Vec<Vec<String>>, Vec<Vec<String>>) {
-        let top_asks: Vec<Vec<String>> = self.asks.iter()
-            .take(n)
-            .map(|(p, a)| vec![p.clone(), a.clone()])
-            .collect();
+    /// Ask levels, best first.
+    pub fn asks(&self) -> &[DepthEntry] {
+        &self.levels.asks
+    }
+
+    /// Bid levels, best first.
+    pub fn bids(&self) -> &[DepthEntry] {
+        &self.levels.bids
+    }
 
-        let top_bids: Vec<Vec<String>> = self.bids.iter()
-            .rev()
+    #[cfg(feature = "python")]
+    fn pairs(levels: &[DepthEntry], n: usize) -> Vec<Level> {
+        levels
+            .iter()
             .take(n)
-            .map(|(p, a)| vec![p.clone(), a.clone()])
-            .collect();
+            .map(|e| (e.price.clone(), e.size.clone()))
+            .collect()
+    }
+}
+
+/// Same shape as an `orderbooks` message (`Depth`).
+impl Serialize for OrderBook {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut book = serializer.serialize_struct("OrderBook", 5)?;
+        book.serialize_field("asks", &self.levels.asks)?;
+        book.serialize_field("bids", &self.levels.bids)?;
+        book.serialize_field("symbol", &self.symbol)?;
+        book.serialize_field("timestamp", &self.timestamp)?;
+        book.serialize_field("ts_event", &self.ts_event)?;
+        book.end()
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrderBook {
+    #[new]
+    fn py_new(symbol: String) -> Self {
+        Self::new(symbol)
+    }
+
+    #[getter]
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    #[getter]
+    fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    #[getter]
+    fn ts_event(&self) -> Option<u64> {
+        self.ts_event
+    }
+
+    #[pyo3(name = "apply_snapshot")]
+    fn py_apply_snapshot(&mut self, depth: Depth) {
+        self.apply_snapshot(depth)
+    }
+
+    pub fn get_asks(&self) -> Vec<Level> {
+        Self::pairs(&self.levels.asks, usize::MAX)
+    }
+
+    pub fn get_bids(&self) -> Vec<Level> {
+        Self::pairs(&self.levels.bids, usize::MAX)
+    }
 
-        (top_asks, top_bids)
+    pub fn get_top_n(&self, n: usize) -> (Vec<Level>, Vec<Level>) {
+        (Self::pairs(&self.levels.asks, n), Self::pairs(&self.levels.bids, n))
     }
 }
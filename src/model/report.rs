@@ -0,0 +1,403 @@
+//! GMO payloads mapped onto the fields of NautilusTrader execution reports.
+//!
+//! Enum-valued fields carry the Nautilus enum member name (`"BUY"`,
+//! `"STOP_MARKET"`, ...), quantities and prices are decimals (strings on the
+//! Python side) and timestamps are UNIX nanoseconds, so the adapter only has
+//! to construct the report objects.
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::str::FromStr;
+use tracing::warn;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::error::GmocoinError;
+use crate::model::account::{Asset, Margin};
+use crate::model::order::{Execution, Order, Position, PositionSummary};
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+use crate::time::timestamp_ns;
+#[cfg(feature = "python")]
+use crate::time::now_ns;
+
+pub(crate) fn decimal(name: &str, value: &str) -> Result<Decimal, GmocoinError> {
+    Decimal::from_str(value)
+        .map(|d| d.normalize())
+        .map_err(|e| GmocoinError::InvalidInput(format!("Invalid {} '{}': {}", name, value, e)))
+}
+
+/// `OrderSide` for a GMO `side`.
+pub fn order_side(side: &str) -> &'static str {
+    if side == "BUY" { "BUY" } else { "SELL" }
+}
+
+/// `OrderType` for a GMO `executionType`.
+pub fn order_type(execution_type: &str) -> &'static str {
+    match execution_type {
+        "MARKET" => "MARKET",
+        "STOP" => "STOP_MARKET",
+        _ => "LIMIT",
+    }
+}
+
+/// `TimeInForce` for a GMO `timeInForce` (FAS and SOK rest on the book).
+pub fn time_in_force(time_in_force: &str) -> &'static str {
+    match time_in_force {
+        "FAK" => "IOC",
+        "FOK" => "FOK",
+        _ => "GTC",
+    }
+}
+
+/// `OrderStatus` for a GMO order status. The adapter maps `orderEvents`
+/// through this too, so reports and live events agree.
+pub fn order_status(status: &str) -> &'static str {
+    match status {
+        // WAITING is an untriggered stop order, MODIFYING a price change
+        "WAITING" | "ORDERED" | "MODIFYING" => "ACCEPTED",
+        "CANCELLING" => "PENDING_CANCEL",
+        "CANCELED" => "CANCELED",
+        "EXECUTED" => "FILLED",
+        "EXPIRED" => "EXPIRED",
+        _ => {
+            warn!("GMO: Unknown order status '{}', treating it as accepted", status);
+            "ACCEPTED"
+        }
+    }
+}
+
+/// The fields of an `OrderStatusReport`.
+#[derive(Clone, Debug, Serialize)]
+pub struct OrderStatusReportFields {
+    pub venue_order_id: String,
+    pub client_order_id: Option<String>,
+    pub symbol: String,
+    pub order_side: &'static str,
+    pub order_type: &'static str,
+    pub time_in_force: &'static str,
+    pub order_status: &'static str,
+    pub quantity: Decimal,
+    pub filled_qty: Decimal,
+    pub price: Option<Decimal>,
+    /// The limit price once anything is filled. Left unset for orders
+    /// without a positive price (market orders), so Nautilus infers it from
+    /// fill reports rather than filling at zero.
+    pub avg_px: Option<Decimal>,
+    pub ts_accepted: u64,
+    pub ts_last: u64,
+    pub ts_init: u64,
+}
+
+/// Map a REST order or Private WS `orderEvents` payload.
+pub fn order_status_report(order: &Order, ts_init: u64) -> Result<OrderStatusReportFields, GmocoinError> {
+    let quantity = decimal("size", &order.size)?;
+    let filled_qty = decimal("executedSize", &order.executed_size)?;
+    let price = order.price.as_deref().map(|p| decimal("price", p)).transpose()?;
+    let avg_px = match price {
+        Some(px) if filled_qty > Decimal::ZERO && px > Decimal::ZERO => Some(px),
+        Some(px) if filled_qty > Decimal::ZERO => {
+            warn!("GMO: Order {} has non-positive price {}; not setting avg_px", order.order_id, px);
+            None
+        }
+        _ => None,
+    };
+    Ok(OrderStatusReportFields {
+        venue_order_id: order.order_id.to_string(),
+        client_order_id: order.client_order_id.clone(),
+        symbol: order.symbol.clone(),
+        order_side: order_side(&order.side),
+        order_type: order_type(&order.execution_type),
+        time_in_force: time_in_force(order.time_in_force.as_deref().unwrap_or("FAS")),
+        order_status: order_status(&order.status),
+        quantity,
+        filled_qty,
+        price,
+        avg_px,
+        ts_accepted: timestamp_ns(&order.timestamp)?,
+        ts_last: ts_init,
+        ts_init,
+    })
+}
+
+/// `LiquiditySide` of an execution. GMO does not report it, so it is
+/// inferred: market and stop orders take, post-only (SOK) orders make, a fee
+/// rebate means maker and a positive fee taker; remaining limit orders are
+/// assumed to have rested. `NO_LIQUIDITY_SIDE` when nothing tells.
+pub fn liquidity_side(execution_type: Option<&str>, time_in_force: Option<&str>, fee: Decimal) -> &'static str {
+    match (execution_type, time_in_force) {
+        (Some("MARKET" | "STOP"), _) => "TAKER",
+        (_, Some("SOK")) => "MAKER",
+        _ if fee < Decimal::ZERO => "MAKER",
+        _ if fee > Decimal::ZERO => "TAKER",
+        (Some("LIMIT"), _) => "MAKER",
+        _ => "NO_LIQUIDITY_SIDE",
+    }
+}
+
+/// Currency fees are charged in: the quote of a pair (`BTC_JPY`), JPY for
+/// spot symbols (`BTC`).
+pub fn fee_currency(symbol: &str) -> &str {
+    symbol.split_once('_').map(|(_, quote)| quote).unwrap_or("JPY")
+}
+
+/// The fields of a `FillReport`.
+#[derive(Clone, Debug, Serialize)]
+pub struct FillReportFields {
+    pub venue_order_id: String,
+    pub trade_id: String,
+    pub symbol: String,
+    pub order_side: &'static str,
+    pub last_qty: Decimal,
+    pub last_px: Decimal,
+    pub commission: Decimal,
+    pub commission_currency: String,
+    pub liquidity_side: &'static str,
+    pub ts_event: u64,
+    pub ts_init: u64,
+}
+
+/// Map a REST execution or Private WS `executionEvents` payload. Fails on a
+/// non-positive price or size rather than report a corrupt fill.
+pub fn fill_report(execution: &Execution, ts_init: u64) -> Result<FillReportFields, GmocoinError> {
+    let last_qty = decimal("size", &execution.size)?;
+    let last_px = decimal("price", &execution.price)?;
+    if last_qty <= Decimal::ZERO || last_px <= Decimal::ZERO {
+        return Err(GmocoinError::InvalidInput(format!(
+            "Execution {} has non-positive size {} or price {}",
+            execution.execution_id, last_qty, last_px
+        )));
+    }
+    let commission = decimal("fee", &execution.fee)?;
+    Ok(FillReportFields {
+        venue_order_id: execution.order_id.to_string(),
+        trade_id: execution.execution_id.to_string(),
+        symbol: execution.symbol.clone(),
+        order_side: order_side(&execution.side),
+        last_qty,
+        last_px,
+        commission,
+        commission_currency: fee_currency(&execution.symbol).to_string(),
+        liquidity_side: liquidity_side(
+            execution.execution_type.as_deref(),
+            execution.time_in_force.as_deref(),
+            commission,
+        ),
+        ts_event: timestamp_ns(&execution.timestamp)?,
+        ts_init,
+    })
+}
+
+/// The fields of a `PositionStatusReport`. GMO keeps long and short
+/// leverage positions separately; each maps to a signed quantity, positive
+/// for long and negative for short, so WS and REST positions net the same way.
+#[derive(Clone, Debug, Serialize)]
+pub struct PositionStatusReportFields {
+    pub symbol: String,
+    /// `positionId` of an individual position; `None` for a summary.
+    pub venue_position_id: Option<String>,
+    /// `"LONG"`, `"SHORT"` or `"FLAT"`.
+    pub position_side: &'static str,
+    /// Absolute size.
+    pub quantity: Decimal,
+    pub signed_decimal_qty: Decimal,
+    /// Average entry price; `None` when flat.
+    pub avg_px_open: Option<Decimal>,
+    pub ts_last: u64,
+    pub ts_init: u64,
+}
+
+fn position_fields(
+    symbol: &str,
+    venue_position_id: Option<String>,
+    side: &str,
+    size: Decimal,
+    avg_px: Decimal,
+    ts_init: u64,
+) -> PositionStatusReportFields {
+    let signed = if side == "BUY" { size } else { -size };
+    let position_side = match signed.cmp(&Decimal::ZERO) {
+        std::cmp::Ordering::Greater => "LONG",
+        std::cmp::Ordering::Less => "SHORT",
+        std::cmp::Ordering::Equal => "FLAT",
+    };
+    PositionStatusReportFields {
+        symbol: symbol.to_string(),
+        venue_position_id,
+        position_side,
+        quantity: size,
+        signed_decimal_qty: signed,
+        avg_px_open: (size > Decimal::ZERO).then_some(avg_px),
+        ts_last: ts_init,
+        ts_init,
+    }
+}
+
+/// Map a REST open position or Private WS `positionEvents` payload; a `CPR`
+/// (closed) event maps to a flat position.
+pub fn position_status_report(position: &Position, ts_init: u64) -> Result<PositionStatusReportFields, GmocoinError> {
+    let size = match position.msg_type.as_deref() {
+        Some("CPR") => Decimal::ZERO,
+        _ => decimal("size", &position.size)?,
+    };
+    let avg_px = decimal("price", &position.price)?;
+    Ok(position_fields(
+        &position.symbol,
+        Some(position.position_id.to_string()),
+        &position.side,
+        size,
+        avg_px,
+        ts_init,
+    ))
+}
+
+/// Map a REST position summary or Private WS `positionSummaryEvents` payload
+/// (one side of one symbol).
+pub fn position_summary_report(summary: &PositionSummary, ts_init: u64) -> Result<PositionStatusReportFields, GmocoinError> {
+    let size = decimal("sumPositionQuantity", &summary.sum_position_quantity)?;
+    let avg_px = decimal("averagePositionRate", &summary.average_position_rate)?;
+    Ok(position_fields(&summary.symbol, None, &summary.side, size, avg_px, ts_init))
+}
+
+/// The fields of an `AccountBalance`; `total` = `locked` + `free`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AccountBalanceFields {
+    pub currency: String,
+    pub total: Decimal,
+    pub locked: Decimal,
+    pub free: Decimal,
+}
+
+/// GMO losscuts leverage positions at a 75% margin ratio.
+const LOSSCUT_MARGIN_RATIO: Decimal = Decimal::from_parts(75, 0, 0, false, 2);
+
+/// The fields of a `MarginBalance` (JPY).
+#[derive(Clone, Debug, Serialize)]
+pub struct MarginBalanceFields {
+    pub currency: &'static str,
+    /// Margin held by open positions.
+    pub initial: Decimal,
+    /// Valuation below which GMO losscuts: 75% of `initial`.
+    pub maintenance: Decimal,
+}
+
+/// A leverage account: its JPY balance and margin.
+#[derive(Clone, Debug, Serialize)]
+pub struct MarginAccountFields {
+    pub balance: AccountBalanceFields,
+    pub margin: MarginBalanceFields,
+}
+
+/// Map a REST asset or Private WS asset update: `amount` is the total,
+/// `available` the free part, the rest locked in orders.
+pub fn account_balance(asset: &Asset) -> Result<AccountBalanceFields, GmocoinError> {
+    let total = decimal("amount", &asset.amount)?;
+    let free = decimal("available", &asset.available)?;
+    Ok(AccountBalanceFields {
+        currency: asset.symbol.to_uppercase(),
+        total,
+        locked: total - free,
+        free,
+    })
+}
+
+/// Map a REST `/v1/account/margin` response: valuation (`actualProfitLoss`)
+/// as the total, `availableAmount` free, the rest locked as margin.
+pub fn margin_balance(margin: &Margin) -> Result<MarginAccountFields, GmocoinError> {
+    let free = decimal("availableAmount", &margin.available_amount)?;
+    let total = margin
+        .actual_profit_loss
+        .as_deref()
+        .map(|v| decimal("actualProfitLoss", v))
+        .transpose()?
+        .unwrap_or(free);
+    let initial = margin
+        .margin
+        .as_deref()
+        .map(|v| decimal("margin", v))
+        .transpose()?
+        .unwrap_or_default();
+    Ok(MarginAccountFields {
+        balance: AccountBalanceFields { currency: "JPY".to_string(), total, locked: total - free, free },
+        margin: MarginBalanceFields {
+            currency: "JPY",
+            initial,
+            maintenance: (initial * LOSSCUT_MARGIN_RATIO).normalize(),
+        },
+    })
+}
+
+#[cfg(feature = "python")]
+pub(crate) fn from_json<T: serde::de::DeserializeOwned>(kind: &str, payload: PyJson) -> PyResult<T> {
+    serde_json::from_value(payload.0)
+        .map_err(|e| GmocoinError::InvalidInput(format!("Invalid {} payload: {}", kind, e)).into())
+}
+
+/// The `OrderStatus` member name for a GMO order status (`orderStatus` /
+/// `status`).
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "order_status")]
+pub fn py_order_status(status: &str) -> &'static str {
+    order_status(status)
+}
+
+/// `OrderStatusReport` fields for a REST order or `orderEvents` dict.
+/// `ts_init` (UNIX ns) defaults to now and is also used as `ts_last`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "order_status_report", signature = (order, ts_init=None))]
+pub fn py_order_status_report(order: PyJson, ts_init: Option<u64>) -> PyResult<PyJson> {
+    let order: Order = from_json("order", order)?;
+    PyJson::from_serialize(&order_status_report(&order, ts_init.unwrap_or_else(now_ns))?)
+}
+
+/// `FillReport` fields for a REST execution or `executionEvents` dict.
+/// `ts_init` (UNIX ns) defaults to now.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "fill_report", signature = (execution, ts_init=None))]
+pub fn py_fill_report(execution: PyJson, ts_init: Option<u64>) -> PyResult<PyJson> {
+    let execution: Execution = from_json("execution", execution)?;
+    PyJson::from_serialize(&fill_report(&execution, ts_init.unwrap_or_else(now_ns))?)
+}
+
+/// `PositionStatusReport` fields for a REST open position or
+/// `positionEvents` dict. `ts_init` (UNIX ns) defaults to now.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "position_status_report", signature = (position, ts_init=None))]
+pub fn py_position_status_report(position: PyJson, ts_init: Option<u64>) -> PyResult<PyJson> {
+    let position: Position = from_json("position", position)?;
+    PyJson::from_serialize(&position_status_report(&position, ts_init.unwrap_or_else(now_ns))?)
+}
+
+/// `PositionStatusReport` fields for a REST position summary or
+/// `positionSummaryEvents` dict. `ts_init` (UNIX ns) defaults to now.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "position_summary_report", signature = (summary, ts_init=None))]
+pub fn py_position_summary_report(summary: PyJson, ts_init: Option<u64>) -> PyResult<PyJson> {
+    let summary: PositionSummary = from_json("position summary", summary)?;
+    PyJson::from_serialize(&position_summary_report(&summary, ts_init.unwrap_or_else(now_ns))?)
+}
+
+/// `AccountBalance` fields for a REST asset or WS asset update dict.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "account_balance")]
+pub fn py_account_balance(asset: PyJson) -> PyResult<PyJson> {
+    let asset: Asset = from_json("asset", asset)?;
+    PyJson::from_serialize(&account_balance(&asset)?)
+}
+
+/// `{"balance": AccountBalance fields, "margin": MarginBalance fields}` for
+/// a REST margin dict.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "margin_balance")]
+pub fn py_margin_balance(margin: PyJson) -> PyResult<PyJson> {
+    let margin: Margin = from_json("margin", margin)?;
+    PyJson::from_serialize(&margin_balance(&margin)?)
+}
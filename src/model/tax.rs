@@ -0,0 +1,212 @@
+//! Yearly realized P&L in JPY for tax filing.
+//!
+//! Spot symbols (`BTC`, ...) use moving-average cost (移動平均法): each buy
+//! re-averages the holding's cost, fees included, and each sell realizes
+//! its proceeds less fee against the current average. Leverage symbols
+//! (`BTC_JPY`, ...) realize GMO's `lossGain` on closing fills, less fees
+//! and leverage fees. Years are calendar years in JST.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use chrono::Datelike;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::GmocoinError;
+use crate::model::order::Execution;
+use crate::model::report::decimal;
+#[cfg(feature = "python")]
+use crate::model::report::from_json;
+use crate::time::{from_ns, jst, timestamp_ns};
+#[cfg(feature = "python")]
+use crate::py_json::PyJson;
+
+/// A leverage fee charged at a rollover.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LeverageCost {
+    pub symbol: String,
+    /// JPY paid.
+    pub amount: String,
+    /// ISO 8601
+    pub timestamp: String,
+}
+
+/// A spot holding carried in from before the executions given.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpeningHolding {
+    pub size: String,
+    /// Average acquisition cost per unit.
+    pub avg_cost: String,
+}
+
+/// One symbol's year.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SymbolPnl {
+    pub symbol: String,
+    /// `"spot"` or `"leverage"`
+    pub kind: &'static str,
+    pub buy_size: Decimal,
+    pub sell_size: Decimal,
+    /// Spot: sell notional. Leverage: sum of `lossGain`.
+    pub proceeds: Decimal,
+    /// Spot: average cost of what was sold (buy fees included).
+    pub cost: Decimal,
+    /// Fees of the year's fills; for spot, buy fees are realized through
+    /// `cost` only once sold.
+    pub fees: Decimal,
+    pub leverage_fees: Decimal,
+    pub realized_pnl: Decimal,
+    /// Spot holding and its average cost at the end of the year.
+    pub closing_size: Decimal,
+    pub closing_avg_cost: Option<Decimal>,
+    /// Spot size sold beyond the known holding, realized at zero cost.
+    pub unmatched_size: Decimal,
+}
+
+/// One calendar year (JST).
+#[derive(Clone, Debug, Serialize)]
+pub struct YearReport {
+    pub year: i32,
+    pub symbols: Vec<SymbolPnl>,
+    pub fees: Decimal,
+    pub leverage_fees: Decimal,
+    pub realized_pnl: Decimal,
+}
+
+#[derive(Default)]
+struct Holding {
+    size: Decimal,
+    cost: Decimal,
+}
+
+fn is_leverage(symbol: &str) -> bool {
+    symbol.ends_with("_JPY")
+}
+
+fn year_of(timestamp: &str) -> Result<i32, GmocoinError> {
+    Ok(from_ns(timestamp_ns(timestamp)?).with_timezone(&jst()).year())
+}
+
+fn round(value: Decimal) -> Decimal {
+    value.round_dp(8).normalize()
+}
+
+type Years = BTreeMap<i32, BTreeMap<String, SymbolPnl>>;
+
+fn symbol_pnl<'a>(years: &'a mut Years, year: i32, symbol: &str) -> &'a mut SymbolPnl {
+    years.entry(year).or_default().entry(symbol.to_string()).or_insert_with(|| SymbolPnl {
+        symbol: symbol.to_string(),
+        kind: if is_leverage(symbol) { "leverage" } else { "spot" },
+        ..Default::default()
+    })
+}
+
+/// Realized P&L per year and symbol over `executions` (any order) and
+/// `leverage_costs`, with spot holdings starting from `opening`.
+pub fn tax_report(
+    executions: &[Execution],
+    leverage_costs: &[LeverageCost],
+    opening: &HashMap<String, OpeningHolding>,
+) -> Result<Vec<YearReport>, GmocoinError> {
+    let mut holdings: HashMap<String, Holding> = HashMap::new();
+    for (symbol, holding) in opening {
+        let size = decimal("size", &holding.size)?;
+        holdings.insert(symbol.clone(), Holding { size, cost: size * decimal("avg_cost", &holding.avg_cost)? });
+    }
+
+    let mut ordered = executions.iter().map(|e| Ok((timestamp_ns(&e.timestamp)?, e))).collect::<Result<Vec<_>, GmocoinError>>()?;
+    ordered.sort_by_key(|(ts, e)| (*ts, e.execution_id));
+
+    let mut years = Years::new();
+    for (_, execution) in ordered {
+        let pnl = symbol_pnl(&mut years, year_of(&execution.timestamp)?, &execution.symbol);
+        let size = decimal("size", &execution.size)?;
+        let price = decimal("price", &execution.price)?;
+        let fee = decimal("fee", &execution.fee)?;
+        let buy = execution.side == "BUY";
+        if buy {
+            pnl.buy_size += size;
+        } else {
+            pnl.sell_size += size;
+        }
+        pnl.fees += fee;
+        if is_leverage(&execution.symbol) {
+            let loss_gain = execution.loss_gain.as_deref().map(|v| decimal("lossGain", v)).transpose()?.unwrap_or_default();
+            pnl.proceeds += loss_gain;
+            pnl.realized_pnl += loss_gain - fee;
+            continue;
+        }
+        let holding = holdings.entry(execution.symbol.clone()).or_default();
+        if buy {
+            holding.size += size;
+            holding.cost += price * size + fee;
+        } else {
+            let matched = size.min(holding.size);
+            let cost = if holding.size > Decimal::ZERO { holding.cost * matched / holding.size } else { Decimal::ZERO };
+            holding.size -= matched;
+            holding.cost -= cost;
+            pnl.unmatched_size += size - matched;
+            pnl.proceeds += price * size;
+            pnl.cost += cost;
+            pnl.realized_pnl += price * size - fee - cost;
+        }
+        // The year's last fill leaves the year-end holding
+        pnl.closing_size = holding.size;
+        pnl.closing_avg_cost = (holding.size > Decimal::ZERO).then(|| round(holding.cost / holding.size));
+    }
+    for cost in leverage_costs {
+        let amount = decimal("amount", &cost.amount)?;
+        let pnl = symbol_pnl(&mut years, year_of(&cost.timestamp)?, &cost.symbol);
+        pnl.leverage_fees += amount;
+        pnl.realized_pnl -= amount;
+    }
+
+    Ok(years
+        .into_iter()
+        .map(|(year, symbols)| {
+            let symbols: Vec<SymbolPnl> = symbols
+                .into_values()
+                .map(|mut pnl| {
+                    for value in [
+                        &mut pnl.buy_size,
+                        &mut pnl.sell_size,
+                        &mut pnl.proceeds,
+                        &mut pnl.cost,
+                        &mut pnl.fees,
+                        &mut pnl.leverage_fees,
+                        &mut pnl.realized_pnl,
+                        &mut pnl.closing_size,
+                        &mut pnl.unmatched_size,
+                    ] {
+                        *value = round(*value);
+                    }
+                    pnl
+                })
+                .collect();
+            YearReport {
+                year,
+                fees: symbols.iter().map(|s| s.fees).sum::<Decimal>().normalize(),
+                leverage_fees: symbols.iter().map(|s| s.leverage_fees).sum::<Decimal>().normalize(),
+                realized_pnl: symbols.iter().map(|s| s.realized_pnl).sum::<Decimal>().normalize(),
+                symbols,
+            }
+        })
+        .collect())
+}
+
+/// Realized JPY P&L per calendar year (JST) and symbol: `executions` are
+/// REST execution dicts (any order, all history needed for spot cost),
+/// `leverage_costs` `[{"symbol", "amount", "timestamp"}]` and `opening`
+/// `{symbol: {"size", "avg_cost"}}` spot holdings from before them.
+/// Returns `[{"year", "symbols": [...], "fees", "leverage_fees",
+/// "realized_pnl"}]`.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "tax_report", signature = (executions, leverage_costs=None, opening=None))]
+pub fn py_tax_report(executions: PyJson, leverage_costs: Option<PyJson>, opening: Option<PyJson>) -> PyResult<PyJson> {
+    let executions: Vec<Execution> = from_json("executions", executions)?;
+    let leverage_costs: Vec<LeverageCost> = leverage_costs.map(|c| from_json("leverage costs", c)).transpose()?.unwrap_or_default();
+    let opening: HashMap<String, OpeningHolding> = opening.map(|o| from_json("opening holdings", o)).transpose()?.unwrap_or_default();
+    PyJson::from_serialize(&tax_report(&executions, &leverage_costs, &opening)?)
+}
@@ -0,0 +1,100 @@
+use pyo3::inspect::PyStaticExpr;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A JSON value handed to Python as native objects (`dict`, `list`, `str`,
+/// `int`, `float`, `bool`, `None`) instead of a JSON string that Python has
+/// to parse again; also taken from Python the same way.
+pub struct PyJson(pub Value);
+
+impl PyJson {
+    /// Convert any serializable response into a `PyJson`.
+    pub fn from_serialize<T: Serialize>(value: &T) -> PyResult<Self> {
+        serde_json::to_value(value)
+            .map(PyJson)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for PyJson {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    const OUTPUT_TYPE: PyStaticExpr = pyo3::type_hint_identifier!("typing", "Any");
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        value_to_py(py, &self.0)
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for PyJson {
+    type Error = PyErr;
+
+    const INPUT_TYPE: PyStaticExpr = pyo3::type_hint_identifier!("typing", "Any");
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        py_to_value(&obj).map(PyJson)
+    }
+}
+
+fn py_to_value(obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    Ok(if obj.is_none() {
+        Value::Null
+    } else if let Ok(b) = obj.cast::<PyBool>() {
+        Value::Bool(b.is_true())
+    } else if obj.is_instance_of::<PyInt>() {
+        match obj.extract::<i64>() {
+            Ok(i) => Value::from(i),
+            Err(_) => Value::from(obj.extract::<u64>()?),
+        }
+    } else if let Ok(f) = obj.cast::<PyFloat>() {
+        Value::from(f.value())
+    } else if let Ok(s) = obj.cast::<PyString>() {
+        Value::String(s.to_str()?.to_string())
+    } else if let Ok(dict) = obj.cast::<PyDict>() {
+        let mut map = serde_json::Map::with_capacity(dict.len());
+        for (k, v) in dict.iter() {
+            map.insert(k.str()?.to_str()?.to_string(), py_to_value(&v)?);
+        }
+        Value::Object(map)
+    } else if obj.is_instance_of::<PyList>() || obj.is_instance_of::<PyTuple>() {
+        Value::Array(obj.try_iter()?.map(|item| py_to_value(&item?)).collect::<PyResult<_>>()?)
+    } else {
+        // Decimal and the like: as their string form, which is how GMO sends numbers
+        Value::String(obj.str()?.to_str()?.to_string())
+    })
+}
+
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => b.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into_pyobject(py)?.into_any()
+            } else if let Some(u) = n.as_u64() {
+                u.into_pyobject(py)?.into_any()
+            } else {
+                n.as_f64().unwrap_or(f64::NAN).into_pyobject(py)?.into_any()
+            }
+        }
+        Value::String(s) => s.into_pyobject(py)?.into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(value_to_py(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(k, value_to_py(py, v)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
@@ -1,6 +1,100 @@
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use tokio::sync::Notify;
 use tokio::time::{sleep, Duration, Instant};
+use tracing::{info, warn};
+
+use crate::client::endpoint::EndpointGroup;
+use crate::error::GmocoinError;
+#[cfg(feature = "python")]
+use pyo3::{type_hint_identifier, type_hint_union};
+
+/// How long a throttled bucket stays at its reduced rate.
+const THROTTLE_HOLD: Duration = Duration::from_secs(10);
+/// Time taken to ramp linearly back to the configured rate after the hold.
+const THROTTLE_RECOVERY: Duration = Duration::from_secs(30);
+/// Each rate-limit rejection halves the current rate...
+const THROTTLE_FACTOR: f64 = 0.5;
+/// ...but never below this fraction of the configured rate.
+const THROTTLE_FLOOR: f64 = 0.1;
+
+/// Reject a requests/sec rate that is not finite and positive.
+pub fn check_rate(per_sec: f64) -> Result<(), GmocoinError> {
+    if per_sec.is_finite() && per_sec > 0.0 {
+        Ok(())
+    } else {
+        Err(GmocoinError::InvalidInput(format!("Rate limit must be finite and positive, got {}", per_sec)))
+    }
+}
+
+/// GMO Coin API usage tier, which sets the REST limit for every endpoint
+/// group. The tier is decided by weekly trading volume.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RateLimitTier {
+    /// < 1B JPY weekly: 20 requests/sec
+    #[default]
+    Tier1,
+    /// >= 1B JPY weekly: 30 requests/sec
+    Tier2,
+    /// Explicit requests/sec
+    Custom(f64),
+}
+
+impl RateLimitTier {
+    pub fn per_sec(&self) -> f64 {
+        match self {
+            RateLimitTier::Tier1 => 20.0,
+            RateLimitTier::Tier2 => 30.0,
+            RateLimitTier::Custom(rate) => *rate,
+        }
+    }
+}
+
+/// Accepts `"tier1"` / `"tier2"` (case-insensitive) or a number for `Custom`.
+#[cfg(feature = "python")]
+impl<'a, 'py> pyo3::FromPyObject<'a, 'py> for RateLimitTier {
+    type Error = pyo3::PyErr;
+
+    const INPUT_TYPE: pyo3::inspect::PyStaticExpr = type_hint_union!(
+        type_hint_identifier!("builtins", "str"),
+        type_hint_identifier!("builtins", "float")
+    );
+
+    fn extract(obj: pyo3::Borrowed<'a, 'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(name) = obj.extract::<String>() {
+            return match name.to_ascii_lowercase().as_str() {
+                "tier1" => Ok(RateLimitTier::Tier1),
+                "tier2" => Ok(RateLimitTier::Tier2),
+                _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown rate limit tier '{}' (expected 'tier1', 'tier2' or a number)",
+                    name
+                ))),
+            };
+        }
+        let rate = obj.extract::<f64>()?;
+        if !rate.is_finite() || rate <= 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("rate limit must be positive"));
+        }
+        Ok(RateLimitTier::Custom(rate))
+    }
+}
+
+/// Acquisition lane. Waiters in the `High` lane are served before any
+/// `Normal` waiter so latency-sensitive order traffic goes first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    High,
+    Normal,
+}
+
+impl Priority {
+    fn lane(self) -> usize {
+        match self {
+            Priority::High => 0,
+            Priority::Normal => 1,
+        }
+    }
+}
 
 /// Token Bucket rate limiter.
 ///
@@ -8,16 +102,35 @@ use tokio::time::{sleep, Duration, Instant};
 /// - Tier 1 (<1B JPY weekly): GET 20/s, POST 20/s
 /// - Tier 2 (>=1B JPY weekly): GET 30/s, POST 30/s
 /// - WebSocket subscriptions: ~1/s
+///
+/// REST buckets are sized from a `RateLimitTier`.
+///
+/// Waiters are queued and served strictly in arrival order within each
+/// priority lane, so no task can be starved under contention.
+///
+/// When the venue still answers ERR-5003, `throttle` lowers the refill rate
+/// for a cooldown window and then restores it gradually.
 #[derive(Clone)]
 pub struct TokenBucket {
     inner: Arc<Mutex<TokenBucketInner>>,
+    /// Signalled whenever the head of the queue changes.
+    turn: Arc<Notify>,
 }
 
 struct TokenBucketInner {
     tokens: f64,
     capacity: f64,
-    refill_rate: f64, // tokens per second
+    refill_rate: f64, // configured tokens per second
     last_refill: Instant,
+    throttle: Option<Throttle>,
+    /// Waiting tickets per lane, in arrival order.
+    queues: [VecDeque<u64>; 2],
+    next_ticket: u64,
+}
+
+struct Throttle {
+    rate: f64,
+    since: Instant,
 }
 
 impl TokenBucket {
@@ -32,37 +145,258 @@ impl TokenBucket {
                 capacity,
                 refill_rate,
                 last_refill: Instant::now(),
+                throttle: None,
+                queues: [VecDeque::new(), VecDeque::new()],
+                next_ticket: 0,
             })),
+            turn: Arc::new(Notify::new()),
         }
     }
 
-    /// Acquire a token, waiting if necessary.
+    /// Acquire a token in the normal lane, waiting if necessary.
     pub async fn acquire(&self) {
+        self.acquire_with(Priority::Normal).await
+    }
+
+    /// Acquire a token in the given lane, waiting (in FIFO order) if necessary.
+    pub async fn acquire_with(&self, priority: Priority) {
+        let lane = priority.lane();
+        let mut ticket = {
+            let mut inner = self.inner.lock().unwrap();
+            let id = inner.next_ticket;
+            inner.next_ticket += 1;
+            inner.queues[lane].push_back(id);
+            Ticket { bucket: self, lane, id, served: false }
+        };
+
         loop {
+            // Register for wake-ups before inspecting the queue so a change
+            // of head between the check and the await is not missed.
+            let notified = self.turn.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
             let wait_time = {
-                let mut inner = self.inner.lock().await;
+                let mut inner = self.inner.lock().unwrap();
                 inner.refill();
 
-                if inner.tokens >= 1.0 {
-                    inner.tokens -= 1.0;
-                    return;
+                if inner.head() == Some((lane, ticket.id)) {
+                    if inner.tokens >= 1.0 {
+                        inner.tokens -= 1.0;
+                        inner.queues[lane].pop_front();
+                        ticket.served = true;
+                        drop(inner);
+                        self.turn.notify_waiters();
+                        return;
+                    }
+                    // Calculate time to wait for 1 token; a rate that yields
+                    // no usable wait (zero, say) waits for a rate change
+                    let deficit = 1.0 - inner.tokens;
+                    let wait = Duration::try_from_secs_f64(deficit / inner.current_rate(Instant::now()));
+                    Some(wait.unwrap_or(Duration::MAX))
+                } else {
+                    None
                 }
-
-                // Calculate time to wait for 1 token
-                let deficit = 1.0 - inner.tokens;
-                Duration::from_secs_f64(deficit / inner.refill_rate)
             };
 
-            sleep(wait_time).await;
+            match wait_time {
+                // At the head: wait for the next token (or a rate change)
+                Some(d) => {
+                    tokio::select! {
+                        _ = sleep(d) => {}
+                        _ = notified => {}
+                    }
+                }
+                // Behind someone else: wait for the queue to move
+                None => notified.await,
+            }
+        }
+    }
+
+    /// Take a token only if one is available right now and nobody is queued
+    /// ahead (normal lane).
+    pub fn try_acquire(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        inner.refill();
+        if inner.tokens >= 1.0 && inner.head().is_none() {
+            inner.tokens -= 1.0;
+            true
+        } else {
+            false
         }
     }
+
+    /// Tokens left right now after serving everyone already queued;
+    /// negative while waiters outnumber the tokens.
+    pub fn available(&self) -> f64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.refill();
+        inner.tokens - inner.queues.iter().map(VecDeque::len).sum::<usize>() as f64
+    }
+
+    /// Burst size: the most tokens the bucket holds.
+    pub fn capacity(&self) -> f64 {
+        self.inner.lock().unwrap().capacity
+    }
+
+    /// Whether an ERR-5003 throttle is still holding the rate down.
+    pub fn is_throttled(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        inner.refill();
+        inner.throttle.is_some()
+    }
+
+    /// Acquire a token in the given lane, giving up after `timeout`.
+    /// Returns whether a token was taken.
+    pub async fn acquire_timeout(&self, priority: Priority, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, self.acquire_with(priority)).await.is_ok()
+    }
+
+    /// Change the configured rate (and burst size) of a live bucket.
+    /// Queued waiters are re-timed against the new rate immediately.
+    pub fn set_rate(&self, rate: f64) {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.refill();
+            inner.refill_rate = rate;
+            inner.capacity = rate.max(1.0);
+            inner.tokens = inner.tokens.min(inner.capacity);
+        }
+        self.turn.notify_waiters();
+    }
+
+    /// Back off after a rate-limit rejection (ERR-5003).
+    ///
+    /// Halves the current rate (down to 10% of the configured rate), holds it
+    /// for 10s, then ramps linearly back to the configured rate over 30s.
+    /// Pending tokens are dropped so queued callers slow down immediately.
+    pub fn throttle(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.refill();
+        let now = Instant::now();
+        let rate = (inner.current_rate(now) * THROTTLE_FACTOR)
+            .max(inner.refill_rate * THROTTLE_FLOOR);
+        inner.throttle = Some(Throttle { rate, since: now });
+        inner.tokens = inner.tokens.min(0.0);
+        warn!(
+            "GMO: Rate limited by venue, throttling to {:.2}/s (configured {:.2}/s)",
+            rate, inner.refill_rate
+        );
+        drop(inner);
+        self.turn.notify_waiters();
+    }
+}
+
+/// A waiter's place in the queue. Dropping it unserved (e.g. the acquire was
+/// cancelled by a timeout) gives up the place and lets the next waiter move up.
+struct Ticket<'a> {
+    bucket: &'a TokenBucket,
+    lane: usize,
+    id: u64,
+    served: bool,
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        if self.served {
+            return;
+        }
+        // May run while unwinding: a poisoned lock must not turn into an abort
+        let mut inner = self.bucket.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        inner.queues[self.lane].retain(|&t| t != self.id);
+        drop(inner);
+        self.bucket.turn.notify_waiters();
+    }
 }
 
 impl TokenBucketInner {
+    /// The ticket to be served next: oldest high-lane waiter, else oldest normal one.
+    fn head(&self) -> Option<(usize, u64)> {
+        self.queues
+            .iter()
+            .enumerate()
+            .find_map(|(lane, q)| q.front().map(|&id| (lane, id)))
+    }
+
+    /// Effective refill rate at `now`, accounting for an active throttle.
+    fn current_rate(&self, now: Instant) -> f64 {
+        match &self.throttle {
+            None => self.refill_rate,
+            Some(t) => {
+                let elapsed = now.duration_since(t.since);
+                if elapsed <= THROTTLE_HOLD {
+                    return t.rate;
+                }
+                let progress = ((elapsed - THROTTLE_HOLD).as_secs_f64()
+                    / THROTTLE_RECOVERY.as_secs_f64())
+                    .min(1.0);
+                t.rate + (self.refill_rate - t.rate) * progress
+            }
+        }
+    }
+
     fn refill(&mut self) {
         let now = Instant::now();
         let elapsed = now.duration_since(self.last_refill).as_secs_f64();
-        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        let rate = self.current_rate(now);
+        self.tokens = (self.tokens + elapsed * rate).min(self.capacity);
         self.last_refill = now;
+
+        if let Some(t) = &self.throttle {
+            if now.duration_since(t.since) >= THROTTLE_HOLD + THROTTLE_RECOVERY {
+                self.throttle = None;
+                info!("GMO: Rate limit restored to {:.2}/s", self.refill_rate);
+            }
+        }
+    }
+}
+
+/// One token bucket per REST endpoint group, so a burst of order operations
+/// is never starved by account or market data polling (and vice versa).
+#[derive(Clone)]
+pub struct RateLimiter {
+    buckets: HashMap<EndpointGroup, TokenBucket>,
+}
+
+impl RateLimiter {
+    /// `default_rate` applies to every group not listed in `overrides`.
+    /// Every rate must pass `check_rate`.
+    pub fn new(default_rate: f64, overrides: &HashMap<EndpointGroup, f64>) -> Result<Self, GmocoinError> {
+        check_rate(default_rate)?;
+        for rate in overrides.values() {
+            check_rate(*rate)?;
+        }
+        let buckets = [EndpointGroup::Public, EndpointGroup::Account, EndpointGroup::Order]
+            .into_iter()
+            .map(|group| {
+                let rate = overrides.get(&group).copied().unwrap_or(default_rate);
+                // A burst below one token could never serve a request
+                (group, TokenBucket::new(rate.max(1.0), rate))
+            })
+            .collect();
+        Ok(Self { buckets })
+    }
+
+    /// Process-wide limiter for `api_key`, created on first use.
+    ///
+    /// Every client built with the same key shares the same buckets, so
+    /// several clients in one process stay within the account's tier
+    /// together. The rates given by the first client win.
+    pub fn shared(api_key: &str, default_rate: f64, overrides: &HashMap<EndpointGroup, f64>) -> Result<Self, GmocoinError> {
+        static REGISTRY: OnceLock<Mutex<HashMap<String, RateLimiter>>> = OnceLock::new();
+        let mut registry = REGISTRY
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap();
+        if let Some(limiter) = registry.get(api_key) {
+            return Ok(limiter.clone());
+        }
+        let limiter = Self::new(default_rate, overrides)?;
+        registry.insert(api_key.to_string(), limiter.clone());
+        Ok(limiter)
+    }
+
+    pub fn bucket(&self, group: EndpointGroup) -> &TokenBucket {
+        &self.buckets[&group]
     }
 }
@@ -0,0 +1,112 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+use tokio::sync::watch;
+use tokio::task::{AbortHandle, JoinHandle};
+
+/// The crate's single multi-threaded Tokio runtime.
+///
+/// REST futures (via `pyo3_async_runtimes`) and the WebSocket loops of every
+/// client run here, instead of one runtime per connection thread, so they
+/// can share limiters and be shut down as ordinary tasks.
+pub fn get() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .thread_name("gmocoin-worker")
+            .enable_all()
+            .build()
+            .expect("Failed to build tokio runtime")
+    })
+}
+
+/// Make `pyo3_async_runtimes` drive Python awaitables on the shared runtime.
+/// Must run before the first `future_into_py`.
+///
+/// `pyo3_async_runtimes::tokio` is the only Python/async bridge in the crate:
+/// every client method returns its awaitable through `future_into_py`, bound
+/// to the caller's running asyncio loop, so all of them share the same
+/// cancellation and error semantics.
+#[cfg(feature = "python")]
+pub fn init() {
+    let _ = pyo3_async_runtimes::tokio::init_with_runtime(get());
+}
+
+/// Background tasks started by `spawn`, for `shutdown`.
+static TASKS: Mutex<Vec<AbortHandle>> = Mutex::new(Vec::new());
+
+/// Spawn a background task (e.g. a WebSocket loop) on the shared runtime.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let handle = get().spawn(future);
+    let mut tasks = TASKS.lock().unwrap();
+    tasks.retain(|task| !task.is_finished());
+    tasks.push(handle.abort_handle());
+    handle
+}
+
+/// A one-way "closed" flag that pending work can wait on.
+#[derive(Clone)]
+pub struct CloseSignal(Arc<watch::Sender<bool>>);
+
+impl Default for CloseSignal {
+    fn default() -> Self {
+        Self(Arc::new(watch::channel(false).0))
+    }
+}
+
+impl CloseSignal {
+    pub fn close(&self) {
+        self.0.send_replace(true);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Resolves once `close` has been called.
+    pub async fn closed(&self) {
+        let _ = self.0.subscribe().wait_for(|closed| *closed).await;
+    }
+}
+
+/// Closed by `shutdown`: every client's pending and future REST requests fail.
+pub fn shutdown_signal() -> &'static CloseSignal {
+    static SIGNAL: OnceLock<CloseSignal> = OnceLock::new();
+    SIGNAL.get_or_init(CloseSignal::default)
+}
+
+/// Cancel all REST requests, abort every background task and wait up to
+/// `timeout` for them to exit. Returns whether they all did.
+pub async fn shutdown(timeout: Duration) -> bool {
+    shutdown_signal().close();
+    let tasks = std::mem::take(&mut *TASKS.lock().unwrap());
+    for task in &tasks {
+        task.abort();
+    }
+    let wait = async {
+        while tasks.iter().any(|task| !task.is_finished()) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    };
+    tokio::time::timeout(timeout, wait).await.is_ok()
+}
+
+/// Stop every client in the process before the interpreter exits: cancels
+/// pending REST requests and stops the WebSocket loops, waiting up to
+/// `timeout_ms` (default 5000). Registered with `atexit` by the package;
+/// the clients are unusable afterwards. Returns whether all tasks stopped.
+#[cfg(feature = "python")]
+#[pyo3::pyfunction]
+#[pyo3(name = "shutdown", signature = (timeout_ms=None))]
+pub fn py_shutdown(py: pyo3::Python<'_>, timeout_ms: Option<u64>) -> pyo3::PyResult<bool> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err("shutdown cannot be called from a client callback"));
+    }
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(5000));
+    Ok(py.detach(|| get().block_on(shutdown(timeout))))
+}
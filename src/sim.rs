@@ -0,0 +1,618 @@
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::error::GmocoinError;
+use crate::model::account::Asset;
+use crate::model::market_data::{DepthEntry, Trade};
+use crate::model::order::{CancelReason, Execution, Order, OrderEventKind};
+use crate::model::orderbook::OrderBook;
+
+/// Quote currency of every GMO Coin symbol.
+const QUOTE: &str = "JPY";
+
+/// Order callback event: `(event_type, payload)` shaped like the Private WS.
+pub type SimEvent = (&'static str, serde_json::Value);
+
+/// Maker / taker fee rates applied to simulated fills (negative = rebate).
+#[derive(Clone, Copy, Debug)]
+pub struct FeeModel {
+    pub maker: Decimal,
+    pub taker: Decimal,
+}
+
+impl Default for FeeModel {
+    /// GMO Coin spot exchange rates: maker -0.01%, taker 0.05%.
+    fn default() -> Self {
+        Self {
+            maker: Decimal::new(-1, 4),
+            taker: Decimal::new(5, 4),
+        }
+    }
+}
+
+impl FeeModel {
+    fn fee(&self, price: Decimal, size: Decimal, maker: bool) -> Decimal {
+        let rate = if maker { self.maker } else { self.taker };
+        (price * size * rate).normalize()
+    }
+}
+
+/// Delay between an order request and its arrival at the simulated venue:
+/// `base_ms` plus a uniform `0..=jitter_ms`.
+pub struct LatencyModel {
+    base_ms: u64,
+    jitter_ms: u64,
+    seed: AtomicU64,
+}
+
+impl LatencyModel {
+    pub fn new(base_ms: u64, jitter_ms: u64) -> Self {
+        let seed = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(1) as u64 | 1;
+        Self { base_ms, jitter_ms, seed: AtomicU64::new(seed) }
+    }
+
+    pub fn sample(&self) -> Duration {
+        if self.jitter_ms == 0 {
+            return Duration::from_millis(self.base_ms);
+        }
+        // xorshift64: good enough to spread arrivals, no extra dependency
+        let mut x = self.seed.load(Ordering::Relaxed);
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.seed.store(x, Ordering::Relaxed);
+        Duration::from_millis(self.base_ms + x % (self.jitter_ms + 1))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Market,
+    Limit,
+    Stop,
+}
+
+struct SimOrder {
+    order: Order,
+    side: Side,
+    kind: Kind,
+    price: Option<Decimal>,
+    size: Decimal,
+    executed: Decimal,
+    tif: &'static str,
+    /// Not yet matched as a taker (no book at arrival, just triggered or re-priced)
+    fresh: bool,
+}
+
+impl SimOrder {
+    fn remaining(&self) -> Decimal {
+        self.size - self.executed
+    }
+
+    fn is_active(&self) -> bool {
+        matches!(self.order.status.as_str(), "WAITING" | "ORDERED")
+    }
+
+    /// Whether `level` is at or better than this order's limit.
+    fn accepts(&self, level: Decimal) -> bool {
+        match (self.kind, self.price) {
+            (Kind::Limit, Some(limit)) => match self.side {
+                Side::Buy => level <= limit,
+                Side::Sell => level >= limit,
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Book side as `(price, size)`, depleted as orders take liquidity within
+/// one matching pass so two orders cannot fill against the same size.
+type Levels = Vec<(Decimal, Decimal)>;
+
+fn parse_levels(levels: &[DepthEntry]) -> Levels {
+    levels
+        .iter()
+        .filter_map(|l| Some((Decimal::from_str(&l.price).ok()?, Decimal::from_str(&l.size).ok()?)))
+        .collect()
+}
+
+fn parse_decimal(name: &str, value: &str) -> Result<Decimal, GmocoinError> {
+    Decimal::from_str(value).map_err(|e| GmocoinError::Unknown(format!("Invalid {} '{}': {}", name, value, e)))
+}
+
+fn rejected(code: &str, message: &str) -> GmocoinError {
+    GmocoinError::ExchangeError {
+        status: 1,
+        messages: format!("{}: {}", code, message),
+        codes: vec![code.to_string()],
+    }
+}
+
+fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+/// Base asset of a symbol (`BTC` for both `BTC` and `BTC_JPY`).
+fn base_asset(symbol: &str) -> &str {
+    symbol.split('_').next().unwrap_or(symbol)
+}
+
+/// Paper-trading venue: order state, matching against live books / trades,
+/// fills and (optionally) balances.
+///
+/// Orders arrive after the latency model's delay. Marketable orders take
+/// liquidity from the latest book at the book's prices (taker); resting
+/// limit orders fill at their own price (maker) once a trade prints through
+/// it or the opposite side of the book crosses it. Stop orders trigger on
+/// trades and then execute as market orders. Everything is spot / cash:
+/// settle types and positions are not modelled.
+pub struct SimEngine {
+    fees: FeeModel,
+    next_order_id: u64,
+    next_execution_id: u64,
+    orders: BTreeMap<u64, SimOrder>,
+    executions: Vec<Execution>,
+    /// `None`: balances not tracked, orders are never rejected for funds
+    balances: Option<BTreeMap<String, Decimal>>,
+}
+
+impl SimEngine {
+    pub fn new(fees: FeeModel, balances: Option<BTreeMap<String, Decimal>>) -> Self {
+        Self {
+            fees,
+            next_order_id: 1,
+            next_execution_id: 1,
+            orders: BTreeMap::new(),
+            executions: Vec::new(),
+            balances,
+        }
+    }
+
+    pub fn order_count(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn symbol_of(&self, order_id: u64) -> Option<String> {
+        self.orders.get(&order_id).map(|o| o.order.symbol.clone())
+    }
+
+    // ========== Order entry ==========
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit(
+        &mut self,
+        symbol: &str,
+        side: &str,
+        execution_type: &str,
+        size: &str,
+        price: Option<&str>,
+        time_in_force: Option<&str>,
+        book: Option<&OrderBook>,
+    ) -> Result<(u64, Vec<SimEvent>), GmocoinError> {
+        let side_enum = match side {
+            "BUY" => Side::Buy,
+            "SELL" => Side::Sell,
+            _ => return Err(rejected("ERR-5106", &format!("Invalid side '{}'", side))),
+        };
+        let kind = match execution_type {
+            "MARKET" => Kind::Market,
+            "LIMIT" => Kind::Limit,
+            "STOP" => Kind::Stop,
+            _ => return Err(rejected("ERR-5106", &format!("Invalid executionType '{}'", execution_type))),
+        };
+        let size_dec = parse_decimal("size", size)?;
+        if size_dec <= Decimal::ZERO {
+            return Err(rejected("ERR-5106", "size must be positive"));
+        }
+        let price_dec = match (kind, price) {
+            (Kind::Market, _) => None,
+            (_, Some(p)) => Some(parse_decimal("price", p)?),
+            (_, None) => return Err(rejected("ERR-5106", "price is required for LIMIT / STOP orders")),
+        };
+        let tif = match (kind, time_in_force) {
+            (_, Some("FAK")) => "FAK",
+            (_, Some("FOK")) => "FOK",
+            (Kind::Limit, Some("SOK")) => "SOK",
+            (Kind::Market, None) => "FAK",
+            (Kind::Limit | Kind::Stop, None | Some("FAS")) => "FAS",
+            (_, Some(other)) => {
+                return Err(rejected("ERR-5106", &format!("Invalid timeInForce '{}' for {}", other, execution_type)))
+            }
+        };
+
+        self.check_funds(symbol, side_enum, size_dec, price_dec.or_else(|| Self::best(book, side_enum)))?;
+
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+        let order = Order {
+            order_id,
+            root_order_id: Some(order_id),
+            client_order_id: None,
+            symbol: symbol.to_string(),
+            side: side.to_string(),
+            execution_type: execution_type.to_string(),
+            settle_type: None,
+            size: size_dec.normalize().to_string(),
+            executed_size: "0".to_string(),
+            price: price_dec.map(|p| p.normalize().to_string()),
+            losscut_price: None,
+            status: if kind == Kind::Stop { "WAITING" } else { "ORDERED" }.to_string(),
+            time_in_force: Some(tif.to_string()),
+            msg_type: None,
+            cancel_type: None,
+            timestamp: now(),
+        };
+        self.orders.insert(order_id, SimOrder {
+            order,
+            side: side_enum,
+            kind,
+            price: price_dec,
+            size: size_dec,
+            executed: Decimal::ZERO,
+            tif,
+            fresh: kind != Kind::Stop,
+        });
+
+        let mut events = vec![self.order_event(order_id, "NOR", "")];
+        if let Some(book) = book {
+            events.extend(self.on_book(book));
+        }
+        Ok((order_id, events))
+    }
+
+    pub fn cancel(&mut self, order_id: u64) -> Result<Vec<SimEvent>, GmocoinError> {
+        let order = self.active_order(order_id)?;
+        order.order.status = "CANCELED".to_string();
+        Ok(vec![self.order_event(order_id, "COR", "USER")])
+    }
+
+    pub fn change(&mut self, order_id: u64, price: &str, book: Option<&OrderBook>) -> Result<Vec<SimEvent>, GmocoinError> {
+        let price_dec = parse_decimal("price", price)?;
+        let order = self.active_order(order_id)?;
+        if order.kind == Kind::Market {
+            return Err(rejected("ERR-5122", "MARKET orders cannot be changed"));
+        }
+        order.price = Some(price_dec);
+        order.order.price = Some(price_dec.normalize().to_string());
+        // A re-priced limit order may now be marketable
+        order.fresh = order.kind == Kind::Limit;
+        let mut events = vec![self.order_event(order_id, "ROR", "")];
+        if let Some(book) = book {
+            events.extend(self.on_book(book));
+        }
+        Ok(events)
+    }
+
+    fn active_order(&mut self, order_id: u64) -> Result<&mut SimOrder, GmocoinError> {
+        match self.orders.get_mut(&order_id) {
+            Some(order) if order.is_active() => Ok(order),
+            Some(_) => Err(rejected("ERR-5122", "The order is already executed or canceled")),
+            None => Err(rejected("ERR-5122", &format!("Unknown orderId {}", order_id))),
+        }
+    }
+
+    // ========== Market data ==========
+
+    /// Match fresh and resting orders against a new book snapshot.
+    pub fn on_book(&mut self, book: &OrderBook) -> Vec<SimEvent> {
+        let mut asks = parse_levels(book.asks());
+        let mut bids = parse_levels(book.bids());
+        let mut events = Vec::new();
+
+        let ids: Vec<u64> = self
+            .orders
+            .iter()
+            .filter(|(_, o)| o.order.symbol == book.symbol && o.order.status == "ORDERED")
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in ids {
+            let order = &self.orders[&id];
+            let levels = match order.side {
+                Side::Buy => &mut asks,
+                Side::Sell => &mut bids,
+            };
+
+            if order.fresh {
+                // Taker pass: walk the book at its own prices
+                let (kind, tif) = (order.kind, order.tif);
+                if tif == "SOK" {
+                    // Post-only: never takes, canceled if it would
+                    let marketable = levels.first().is_some_and(|(price, _)| order.accepts(*price));
+                    let order = self.orders.get_mut(&id).expect("order exists");
+                    order.fresh = false;
+                    if marketable {
+                        order.order.status = "CANCELED".to_string();
+                        events.push(self.order_event(id, "COR", "SOK_TAKER"));
+                    }
+                    continue;
+                }
+                for (price, size) in Self::take(order, levels, tif == "FOK") {
+                    events.push(self.fill(id, price, size, false));
+                }
+                let order = self.orders.get_mut(&id).expect("order exists");
+                // Triggered stops (FAS) keep taking on later books until done
+                order.fresh = kind != Kind::Limit && tif == "FAS" && order.is_active();
+                let expiry = match tif {
+                    "FAK" => Some("EXPIRED_FAK"),
+                    "FOK" => Some("EXPIRED_FOK"),
+                    _ => None,
+                };
+                if let (Some(cancel_type), true) = (expiry, order.is_active()) {
+                    order.order.status = "CANCELED".to_string();
+                    events.push(self.order_event(id, "COR", cancel_type));
+                }
+            } else if order.kind == Kind::Limit {
+                // Resting: the book crossed our price, fill at our price (maker)
+                let limit = order.price.expect("limit order has a price");
+                let fills = Self::take(order, levels, false);
+                let size: Decimal = fills.iter().map(|(_, s)| *s).sum();
+                if size > Decimal::ZERO {
+                    events.push(self.fill(id, limit, size, true));
+                }
+            }
+        }
+        events
+    }
+
+    /// Fill resting limits a trade printed through and trigger stops.
+    pub fn on_trade(&mut self, trade: &Trade, book: Option<&OrderBook>) -> Vec<SimEvent> {
+        let Some(symbol) = trade.symbol.as_deref() else { return Vec::new() };
+        let (Ok(price), Ok(mut size)) = (Decimal::from_str(&trade.price), Decimal::from_str(&trade.size)) else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        let mut triggered = false;
+
+        let ids: Vec<u64> = self
+            .orders
+            .iter()
+            .filter(|(_, o)| o.order.symbol == symbol && o.is_active() && !o.fresh)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in ids {
+            let order = self.orders.get_mut(&id).expect("order exists");
+            let trigger = order.price.expect("limit / stop order has a price");
+            match order.kind {
+                Kind::Stop => {
+                    let hit = match order.side {
+                        Side::Buy => price >= trigger,
+                        Side::Sell => price <= trigger,
+                    };
+                    if hit {
+                        order.order.status = "ORDERED".to_string();
+                        order.fresh = true;
+                        triggered = true;
+                    }
+                }
+                Kind::Limit if size > Decimal::ZERO => {
+                    let through = match order.side {
+                        Side::Buy => price < trigger,
+                        Side::Sell => price > trigger,
+                    };
+                    if through {
+                        let fill = order.remaining().min(size);
+                        size -= fill;
+                        events.push(self.fill(id, trigger, fill, true));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if triggered {
+            if let Some(book) = book {
+                events.extend(self.on_book(book));
+            }
+        }
+        events
+    }
+
+    /// Liquidity `order` can take from `levels` within its limit, depleting
+    /// them. With `all_or_none`, nothing is taken unless it fills completely.
+    fn take(order: &SimOrder, levels: &mut Levels, all_or_none: bool) -> Vec<(Decimal, Decimal)> {
+        if all_or_none {
+            let available: Decimal = levels
+                .iter()
+                .take_while(|(price, _)| order.accepts(*price))
+                .map(|(_, size)| *size)
+                .sum();
+            if available < order.remaining() {
+                return Vec::new();
+            }
+        }
+        let mut remaining = order.remaining();
+        let mut fills = Vec::new();
+        for (price, size) in levels.iter_mut() {
+            if remaining <= Decimal::ZERO || !order.accepts(*price) {
+                break;
+            }
+            let fill = remaining.min(*size);
+            if fill > Decimal::ZERO {
+                *size -= fill;
+                remaining -= fill;
+                fills.push((*price, fill));
+            }
+        }
+        fills
+    }
+
+    fn fill(&mut self, order_id: u64, price: Decimal, size: Decimal, maker: bool) -> SimEvent {
+        let fee = self.fees.fee(price, size, maker);
+        let order = self.orders.get_mut(&order_id).expect("order exists");
+        order.executed += size;
+        order.order.executed_size = order.executed.normalize().to_string();
+        if order.remaining() <= Decimal::ZERO {
+            order.order.status = "EXECUTED".to_string();
+        }
+        let (symbol, side) = (order.order.symbol.clone(), order.side);
+
+        let execution_id = self.next_execution_id;
+        self.next_execution_id += 1;
+        let timestamp = now();
+        self.executions.push(Execution {
+            execution_id,
+            order_id,
+            symbol: symbol.clone(),
+            side: order.order.side.clone(),
+            settle_type: None,
+            size: size.normalize().to_string(),
+            price: price.normalize().to_string(),
+            loss_gain: Some("0".to_string()),
+            fee: fee.to_string(),
+            timestamp: timestamp.clone(),
+            execution_type: None,
+            time_in_force: None,
+        });
+
+        if let Some(balances) = self.balances.as_mut() {
+            let notional = price * size;
+            let (base_delta, quote_delta) = match side {
+                Side::Buy => (size, -notional - fee),
+                Side::Sell => (-size, notional - fee),
+            };
+            *balances.entry(base_asset(&symbol).to_string()).or_default() += base_delta;
+            *balances.entry(QUOTE.to_string()).or_default() += quote_delta;
+        }
+
+        let order = &self.orders[&order_id].order;
+        ("ExecutionUpdate", json!({
+            "channel": "executionEvents",
+            "orderId": order_id,
+            "executionId": execution_id,
+            "symbol": symbol,
+            "settleType": "OPEN",
+            "executionType": order.execution_type,
+            "side": order.side,
+            "executionPrice": price.normalize().to_string(),
+            "executionSize": size.normalize().to_string(),
+            "positionId": 0,
+            "orderTimestamp": order.timestamp,
+            "executionTimestamp": timestamp,
+            "lossGain": "0",
+            "fee": fee.to_string(),
+            "orderPrice": order.price.clone().unwrap_or_else(|| "0".to_string()),
+            "orderSize": order.size,
+            "orderExecutedSize": order.executed_size,
+            "timeInForce": order.time_in_force,
+            "msgType": "ER",
+        }))
+    }
+
+    fn order_event(&self, order_id: u64, msg_type: &str, cancel_type: &str) -> SimEvent {
+        let order = &self.orders[&order_id].order;
+        let mut event = json!({
+            "channel": "orderEvents",
+            "orderId": order_id,
+            "symbol": order.symbol,
+            "settleType": "OPEN",
+            "executionType": order.execution_type,
+            "side": order.side,
+            "orderStatus": order.status,
+            "cancelType": cancel_type,
+            "orderTimestamp": order.timestamp,
+            "orderPrice": order.price.clone().unwrap_or_else(|| "0".to_string()),
+            "orderSize": order.size,
+            "orderExecutedSize": order.executed_size,
+            "losscutPrice": "0",
+            "timeInForce": order.time_in_force,
+            "msgType": msg_type,
+            "orderEvent": OrderEventKind::from_msg_type(msg_type),
+        });
+        if !cancel_type.is_empty() {
+            event["cancelReason"] = json!(CancelReason::from_cancel_type(cancel_type));
+        }
+        ("OrderUpdate", event)
+    }
+
+    // ========== Balances ==========
+
+    fn best(book: Option<&OrderBook>, side: Side) -> Option<Decimal> {
+        let book = book?;
+        let levels = match side {
+            Side::Buy => book.asks(),
+            Side::Sell => book.bids(),
+        };
+        Decimal::from_str(&levels.first()?.price).ok()
+    }
+
+    /// Amount of each asset held by open orders.
+    fn reserved(&self) -> BTreeMap<String, Decimal> {
+        let mut reserved: BTreeMap<String, Decimal> = BTreeMap::new();
+        for order in self.orders.values().filter(|o| o.is_active()) {
+            match (order.side, order.price) {
+                (Side::Buy, Some(price)) => {
+                    *reserved.entry(QUOTE.to_string()).or_default() += price * order.remaining();
+                }
+                (Side::Sell, _) => {
+                    *reserved.entry(base_asset(&order.order.symbol).to_string()).or_default() += order.remaining();
+                }
+                // Market buys are priced at fill time
+                (Side::Buy, None) => {}
+            }
+        }
+        reserved
+    }
+
+    fn check_funds(&self, symbol: &str, side: Side, size: Decimal, price: Option<Decimal>) -> Result<(), GmocoinError> {
+        let Some(balances) = self.balances.as_ref() else { return Ok(()) };
+        let (asset, needed) = match side {
+            Side::Buy => (QUOTE, price.map_or(Decimal::ZERO, |p| p * size)),
+            Side::Sell => (base_asset(symbol), size),
+        };
+        let held = balances.get(asset).copied().unwrap_or_default();
+        let reserved = self.reserved().get(asset).copied().unwrap_or_default();
+        if held - reserved < needed {
+            return Err(rejected("ERR-208", &format!("Insufficient {} balance", asset)));
+        }
+        Ok(())
+    }
+
+    // ========== Queries ==========
+
+    pub fn order(&self, order_id: u64) -> Vec<Order> {
+        self.orders.get(&order_id).map(|o| o.order.clone()).into_iter().collect()
+    }
+
+    pub fn executions_for(&self, order_id: u64) -> Vec<Execution> {
+        self.executions.iter().filter(|e| e.order_id == order_id).cloned().collect()
+    }
+
+    pub fn active_orders(&self, symbol: &str) -> Vec<Order> {
+        self.orders
+            .values()
+            .rev()
+            .filter(|o| o.order.symbol == symbol && o.is_active())
+            .map(|o| o.order.clone())
+            .collect()
+    }
+
+    pub fn latest_executions(&self, symbol: &str) -> Vec<Execution> {
+        self.executions.iter().rev().filter(|e| e.symbol == symbol).cloned().collect()
+    }
+
+    pub fn assets(&self) -> Vec<Asset> {
+        let Some(balances) = self.balances.as_ref() else { return Vec::new() };
+        let reserved = self.reserved();
+        balances
+            .iter()
+            .map(|(symbol, amount)| Asset {
+                amount: amount.normalize().to_string(),
+                available: (amount - reserved.get(symbol).copied().unwrap_or_default()).normalize().to_string(),
+                conversion_rate: (symbol == QUOTE).then(|| "1".to_string()),
+                symbol: symbol.clone(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,230 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::client::reconnect::DisconnectReason;
+use crate::error::GmocoinError;
+
+/// Runtime counters for one client, shared by all of its clones and tasks.
+#[derive(Clone, Default)]
+pub struct ClientStats {
+    inner: Arc<StatsInner>,
+}
+
+#[derive(Default)]
+struct StatsInner {
+    requests_sent: AtomicU64,
+    reconnects: AtomicU64,
+    reconnect_reasons: Mutex<BTreeMap<&'static str, u64>>,
+    token_refreshes: AtomicU64,
+    errors: Mutex<BTreeMap<&'static str, u64>>,
+    last_message_ms: Mutex<BTreeMap<String, i64>>,
+    last_request_id: Mutex<Option<String>>,
+    last_error: Mutex<Option<LastError>>,
+    liveness: Mutex<Liveness>,
+    book_churn: Mutex<BTreeMap<String, ChurnCounter>>,
+}
+
+/// Running totals behind `BookChurn`.
+struct ChurnCounter {
+    first: Instant,
+    last: Instant,
+    updates: u64,
+    touch_changes: u64,
+    levels_changed: u64,
+}
+
+/// Order book update churn of one symbol, for tuning conflation and depth.
+#[derive(Clone, Debug, Serialize)]
+pub struct BookChurn {
+    pub updates: u64,
+    /// Between the first and the last update (0 until there are two).
+    pub updates_per_sec: f64,
+    /// Fraction of updates that moved the best ask or bid (price or size).
+    pub touch_change_ratio: f64,
+    /// Levels added, removed or resized per update, both sides together.
+    pub avg_levels_changed: f64,
+}
+
+impl From<&ChurnCounter> for BookChurn {
+    fn from(counter: &ChurnCounter) -> Self {
+        let updates = counter.updates as f64;
+        let span = counter.last.duration_since(counter.first).as_secs_f64();
+        Self {
+            updates: counter.updates,
+            updates_per_sec: if span > 0.0 { (updates - 1.0) / span } else { 0.0 },
+            touch_change_ratio: counter.touch_changes as f64 / updates,
+            avg_levels_changed: counter.levels_changed as f64 / updates,
+        }
+    }
+}
+
+/// When the venue last answered and the WS connection last changed, for
+/// `health()`. Times are epoch ms.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Liveness {
+    pub rest_ok_ms: Option<i64>,
+    /// Last transport error or maintenance / system error response.
+    pub rest_failure_ms: Option<i64>,
+    /// `None` until the WS loop first tries to connect.
+    pub ws_connected: Option<bool>,
+    pub ws_changed_ms: Option<i64>,
+    /// Last Private WS token issue or extension.
+    pub token_ms: Option<i64>,
+}
+
+/// The most recent error, with the request id to look up in the logs.
+#[derive(Clone, Serialize)]
+pub struct LastError {
+    pub request_id: Option<String>,
+    pub class: &'static str,
+    pub message: String,
+    pub time_ms: i64,
+}
+
+/// Point-in-time copy of `ClientStats`, returned to Python as a dict.
+#[derive(Serialize)]
+pub struct StatsSnapshot {
+    pub requests_sent: u64,
+    /// Error count by `GmocoinError::class`.
+    pub errors: BTreeMap<&'static str, u64>,
+    pub reconnects: u64,
+    /// Reconnect count by `DisconnectReason`.
+    pub reconnect_reasons: BTreeMap<&'static str, u64>,
+    /// Last message time per WS channel (epoch ms).
+    pub last_message_ms: BTreeMap<String, i64>,
+    pub token_refreshes: u64,
+    /// Id of the most recently started REST request.
+    pub last_request_id: Option<String>,
+    pub last_error: Option<LastError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orders_cached: Option<usize>,
+    /// Per-symbol `orderbooks` churn (data clients with book updates only).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub book_churn: BTreeMap<String, BookChurn>,
+}
+
+impl ClientStats {
+    pub fn request_started(&self, request_id: &str) {
+        *self.inner.last_request_id.lock().unwrap() = Some(request_id.to_string());
+    }
+
+    pub fn request_sent(&self) {
+        self.inner.requests_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reconnect(&self, reason: DisconnectReason) {
+        self.inner.reconnects.fetch_add(1, Ordering::Relaxed);
+        *self.inner.reconnect_reasons.lock().unwrap().entry(reason.as_str()).or_default() += 1;
+    }
+
+    pub fn token_refresh(&self) {
+        self.inner.token_refreshes.fetch_add(1, Ordering::Relaxed);
+        self.token_issued();
+    }
+
+    pub fn token_issued(&self) {
+        self.inner.liveness.lock().unwrap().token_ms = Some(chrono::Utc::now().timestamp_millis());
+    }
+
+    /// The venue answered a REST request (successfully or with a rejection
+    /// of the request itself).
+    pub fn rest_ok(&self) {
+        self.inner.liveness.lock().unwrap().rest_ok_ms = Some(chrono::Utc::now().timestamp_millis());
+    }
+
+    pub fn rest_failure(&self) {
+        self.inner.liveness.lock().unwrap().rest_failure_ms = Some(chrono::Utc::now().timestamp_millis());
+    }
+
+    /// The WS connection came up or went down; repeats keep the first time.
+    pub fn ws_connected(&self, connected: bool) {
+        let mut liveness = self.inner.liveness.lock().unwrap();
+        if liveness.ws_connected != Some(connected) {
+            liveness.ws_connected = Some(connected);
+            liveness.ws_changed_ms = Some(chrono::Utc::now().timestamp_millis());
+        }
+    }
+
+    /// Last message time on any WS channel (epoch ms).
+    pub fn last_message_ms(&self) -> Option<i64> {
+        self.inner.last_message_ms.lock().unwrap().values().max().copied()
+    }
+
+    pub fn liveness(&self) -> Liveness {
+        *self.inner.liveness.lock().unwrap()
+    }
+
+    pub fn error(&self, err: &GmocoinError) {
+        self.error_class(err.class());
+        *self.inner.last_error.lock().unwrap() = Some(LastError {
+            request_id: err.request_id().map(str::to_string),
+            class: err.class(),
+            message: err.to_string(),
+            time_ms: chrono::Utc::now().timestamp_millis(),
+        });
+    }
+
+    /// A Python callback raised; counted under the `"callback"` class.
+    pub fn callback_error(&self, message: String) {
+        self.error_class("callback");
+        *self.inner.last_error.lock().unwrap() = Some(LastError {
+            request_id: None,
+            class: "callback",
+            message,
+            time_ms: chrono::Utc::now().timestamp_millis(),
+        });
+    }
+
+    /// For failures that never became a `GmocoinError` (e.g. WS error frames).
+    pub fn error_class(&self, class: &'static str) {
+        *self.inner.errors.lock().unwrap().entry(class).or_default() += 1;
+    }
+
+    pub fn message(&self, channel: &str) {
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut last = self.inner.last_message_ms.lock().unwrap();
+        match last.get_mut(channel) {
+            Some(ts) => *ts = now,
+            None => {
+                last.insert(channel.to_string(), now);
+            }
+        }
+    }
+
+    /// An `orderbooks` update of `symbol`, with what it changed (see
+    /// `OrderBook::diff`).
+    pub fn book_update(&self, symbol: &str, touch_changed: bool, levels_changed: usize) {
+        let now = Instant::now();
+        let mut churn = self.inner.book_churn.lock().unwrap();
+        if !churn.contains_key(symbol) {
+            churn.insert(symbol.to_string(), ChurnCounter {
+                first: now, last: now, updates: 0, touch_changes: 0, levels_changed: 0,
+            });
+        }
+        let counter = churn.get_mut(symbol).expect("inserted above");
+        counter.last = now;
+        counter.updates += 1;
+        counter.touch_changes += u64::from(touch_changed);
+        counter.levels_changed += levels_changed as u64;
+    }
+
+    pub fn snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            requests_sent: self.inner.requests_sent.load(Ordering::Relaxed),
+            errors: self.inner.errors.lock().unwrap().clone(),
+            reconnects: self.inner.reconnects.load(Ordering::Relaxed),
+            reconnect_reasons: self.inner.reconnect_reasons.lock().unwrap().clone(),
+            last_message_ms: self.inner.last_message_ms.lock().unwrap().clone(),
+            token_refreshes: self.inner.token_refreshes.load(Ordering::Relaxed),
+            last_request_id: self.inner.last_request_id.lock().unwrap().clone(),
+            last_error: self.inner.last_error.lock().unwrap().clone(),
+            orders_cached: None,
+            book_churn: self.inner.book_churn.lock().unwrap().iter()
+                .map(|(symbol, counter)| (symbol.clone(), counter.into()))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Conversions between GMO timestamps and UNIX nanoseconds.
+//!
+//! GMO sends ISO-8601 UTC strings (`"2019-03-19T02:15:06.059Z"`), usually
+//! with milliseconds but not always; maintenance schedules and statements
+//! are in JST, sometimes written without an offset.
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::error::GmocoinError;
+
+/// Formats of offset-less timestamps, read as JST.
+const NAIVE_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+/// GMO Coin's local time (UTC+9, no DST).
+pub fn jst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("JST offset is valid")
+}
+
+/// JST wall-clock time to UTC.
+pub fn from_jst(local: NaiveDateTime) -> DateTime<Utc> {
+    jst().from_local_datetime(&local).single().expect("JST has no DST").with_timezone(&Utc)
+}
+
+/// An ISO-8601 timestamp with any offset and fractional seconds (or none);
+/// without an offset it is JST.
+pub fn parse(timestamp: &str) -> Result<DateTime<Utc>, GmocoinError> {
+    let timestamp = timestamp.trim();
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NAIVE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(timestamp, format).ok())
+                .map(from_jst)
+        })
+        .ok_or_else(|| GmocoinError::InvalidInput(format!("Invalid timestamp '{}'", timestamp)))
+}
+
+/// GMO timestamp (`"2019-03-19T02:15:06.059Z"`) in UNIX nanoseconds.
+pub fn timestamp_ns(timestamp: &str) -> Result<u64, GmocoinError> {
+    let parsed = parse(timestamp)?;
+    parsed
+        .timestamp_nanos_opt()
+        .and_then(|ns| u64::try_from(ns).ok())
+        .ok_or_else(|| GmocoinError::InvalidInput(format!("Timestamp '{}' is out of range", timestamp)))
+}
+
+/// GMO timestamp in UNIX milliseconds.
+pub fn timestamp_ms(timestamp: &str) -> Result<i64, GmocoinError> {
+    parse(timestamp).map(|t| t.timestamp_millis())
+}
+
+/// UNIX ns, 0 before 1970 and past 2262.
+pub fn to_ns(ts: DateTime<Utc>) -> u64 {
+    ts.timestamp_nanos_opt().and_then(|ns| u64::try_from(ns).ok()).unwrap_or_default()
+}
+
+pub fn from_ns(ns: u64) -> DateTime<Utc> {
+    DateTime::from_timestamp_nanos(ns as i64)
+}
+
+/// UNIX ns in GMO's format: UTC, milliseconds, `Z`.
+pub fn format_ns(ns: u64) -> String {
+    from_ns(ns).to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+pub fn now_ns() -> u64 {
+    to_ns(Utc::now())
+}
+
+pub fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+/// A GMO timestamp (UTC ISO-8601 with or without milliseconds, or JST
+/// without an offset) in UNIX ns.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "timestamp_ns")]
+pub fn py_timestamp_ns(timestamp: &str) -> PyResult<u64> {
+    Ok(timestamp_ns(timestamp)?)
+}
+
+/// UNIX ns as a GMO timestamp (`"2019-03-19T02:15:06.059Z"`).
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "format_timestamp")]
+pub fn py_format_timestamp(ns: u64) -> String {
+    format_ns(ns)
+}
@@ -0,0 +1,113 @@
+//! TLS configuration shared by the REST client (reqwest) and the WebSocket
+//! connections (tokio-tungstenite).
+//!
+//! Both already use rustls only (no OpenSSL / native-tls is linked), which
+//! suits musl-based images. By default reqwest verifies against the platform
+//! verifier and tungstenite against the native root store; these settings
+//! let hardened deployments pin their own roots and a minimum TLS version.
+//! With the `webpki-roots` feature the bundled Mozilla roots are trusted as
+//! well, for images that ship no CA bundle at all.
+
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::CertificateDer;
+use rustls::{ClientConfig, RootCertStore};
+use std::sync::Arc;
+use tokio_tungstenite::Connector;
+use tracing::warn;
+
+use crate::error::GmocoinError;
+
+#[derive(Clone, Default)]
+pub struct TlsSettings {
+    /// `None` keeps each library's default TLS setup.
+    config: Option<Arc<ClientConfig>>,
+}
+
+impl TlsSettings {
+    /// `ca_file`: PEM bundle of extra trusted root certificates.
+    /// `native_roots`: also trust the OS root store (default true).
+    /// `min_version`: `"1.2"` (default) or `"1.3"`.
+    pub fn new(
+        ca_file: Option<&str>,
+        native_roots: Option<bool>,
+        min_version: Option<&str>,
+    ) -> Result<Self, GmocoinError> {
+        if ca_file.is_none()
+            && native_roots.is_none()
+            && min_version.is_none()
+            && !cfg!(feature = "webpki-roots")
+        {
+            return Ok(Self::default());
+        }
+
+        let mut roots = RootCertStore::empty();
+        if native_roots.unwrap_or(true) {
+            let native = rustls_native_certs::load_native_certs();
+            for e in &native.errors {
+                warn!("GMO: Failed to load a native root certificate: {}", e);
+            }
+            roots.add_parsable_certificates(native.certs);
+        }
+        #[cfg(feature = "webpki-roots")]
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        if let Some(path) = ca_file {
+            let certs = CertificateDer::pem_file_iter(path)
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| GmocoinError::InvalidInput(
+                    format!("Failed to read tls_ca_file '{}': {}", path, e)
+                ))?;
+            for cert in certs {
+                roots.add(cert).map_err(|e| GmocoinError::InvalidInput(
+                    format!("Invalid certificate in tls_ca_file '{}': {}", path, e)
+                ))?;
+            }
+        }
+        if roots.is_empty() {
+            return Err(GmocoinError::InvalidInput(
+                "No trusted root certificates: set tls_ca_file or enable tls_native_roots".to_string(),
+            ));
+        }
+
+        let versions: &[&rustls::SupportedProtocolVersion] = match min_version.unwrap_or("1.2") {
+            "1.2" => rustls::ALL_VERSIONS,
+            "1.3" => &[&rustls::version::TLS13],
+            other => {
+                return Err(GmocoinError::InvalidInput(format!(
+                    "Unsupported tls_min_version '{}': expected '1.2' or '1.3'",
+                    other
+                )))
+            }
+        };
+
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let config = ClientConfig::builder_with_provider(provider)
+            .with_protocol_versions(versions)
+            .map_err(|e| GmocoinError::InvalidInput(e.to_string()))?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        Ok(Self { config: Some(Arc::new(config)) })
+    }
+
+    /// Apply to a reqwest client builder. A preconfigured rustls config
+    /// gets no ALPN from reqwest, so offer the protocols here.
+    pub fn apply(&self, builder: reqwest::ClientBuilder, http2: bool) -> reqwest::ClientBuilder {
+        match &self.config {
+            Some(config) => {
+                let mut config = ClientConfig::clone(config);
+                config.alpn_protocols = if http2 {
+                    vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+                } else {
+                    vec![b"http/1.1".to_vec()]
+                };
+                builder.tls_backend_preconfigured(config)
+            }
+            None => builder,
+        }
+    }
+
+    /// Connector for `connect_async_tls_with_config`; `None` uses tungstenite's default.
+    pub fn ws_connector(&self) -> Option<Connector> {
+        self.config.clone().map(Connector::Rustls)
+    }
+}
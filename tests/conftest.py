@@ -77,8 +77,7 @@ def vcr(request):
 
     Usage in tests:
         def test_something(self, vcr):
-            result = vcr(_live(lambda c: c.some_api_py()))
-            data = json.loads(result)
+            data = vcr(_live(lambda c: c.some_api_py()))
             assert ...
 
     Record cassettes:  pytest -m integration --record-cassettes
@@ -98,20 +97,70 @@ def vcr(request):
     def play_or_record(live_fn):
         if cassette_path.exists() and not record:
             # Replay from cassette
-            data = json.loads(cassette_path.read_text())
-            return json.dumps(data, ensure_ascii=False)
+            return json.loads(cassette_path.read_text())
         if not record:
             pytest.skip(
                 f"Cassette not found: {cassette_name}.json "
                 "(run with --record-cassettes to record)"
             )
         # Record: execute live, save cassette
-        result = live_fn()
-        data = json.loads(result)
+        data = live_fn()
         cassette_path.parent.mkdir(parents=True, exist_ok=True)
         cassette_path.write_text(
             json.dumps(data, indent=2, ensure_ascii=False) + "\n"
         )
-        return result
+        return data
+
+    return play_or_record
+
+
+@pytest.fixture
+def ws_session(request):
+    """Private WS session cassette: path of a recorded JSONL session.
+
+    Usage in tests:
+        def test_something(self, ws_session):
+            path = ws_session(seconds=30)
+            await client.replay_session(path)
+
+    Record sessions:   pytest -m integration --record-cassettes
+                       (connects with the API keys and captures `seconds` of traffic;
+                       place / cancel orders meanwhile to capture order events)
+    Replay (default):  pytest  (uses committed cassette files)
+    """
+    record = request.config.getoption("--record-cassettes")
+
+    node = request.node
+    parent = node.parent
+    if parent and parent.name and not parent.name.endswith(".py"):
+        cassette_name = f"{parent.name}.{node.name}"
+    else:
+        cassette_name = node.name
+    cassette_path = CASSETTE_DIR / f"{cassette_name}.jsonl"
+
+    def play_or_record(seconds: float = 30.0) -> str:
+        if cassette_path.exists() and not record:
+            return str(cassette_path)
+        if not record or not has_api_keys():
+            pytest.skip(
+                f"Session cassette not found: {cassette_name}.jsonl "
+                "(run with --record-cassettes and API keys to record)"
+            )
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        api_key, api_secret = load_api_keys()
+        client = gmocoin.GmocoinExecutionClient(api_key, api_secret, 10000, None, None)
+        cassette_path.parent.mkdir(parents=True, exist_ok=True)
+        client.record_session(str(cassette_path))
+
+        async def capture():
+            await client.connect()
+            await asyncio.sleep(seconds)
+            await client.disconnect()
+
+        asyncio.run(capture())
+        client.record_session(None)
+        return str(cassette_path)
 
     return play_or_record
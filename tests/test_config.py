@@ -1,6 +1,11 @@
 """Tests for nautilus_gmocoin.config."""
 import pytest
-from nautilus_gmocoin.config import GmocoinDataClientConfig, GmocoinExecClientConfig
+from nautilus_gmocoin.config import (
+    GmocoinDataClientConfig,
+    GmocoinExecClientConfig,
+    credentials_passphrase,
+    rate_limit_setting,
+)
 
 
 class TestGmocoinDataClientConfig:
@@ -14,8 +19,25 @@ class TestGmocoinDataClientConfig:
         assert config.timeout_ms == 10000
         assert config.proxy_url is None
         assert config.order_book_depth == 20
+        assert config.rate_limit_tier is None
         assert config.rate_limit_per_sec is None
+        assert config.group_rate_limits is None
+        assert config.share_rate_limiter is False
+        assert config.circuit_breaker_threshold is None
+        assert config.circuit_breaker_cooldown_ms is None
         assert config.ws_rate_limit_per_sec is None
+        assert config.tls_ca_file is None
+        assert config.tls_native_roots is None
+        assert config.tls_min_version is None
+        assert config.pool_max_idle_per_host is None
+        assert config.pool_idle_timeout_ms is None
+        assert config.prefer_http2 is False
+        assert config.tcp_keepalive_ms is None
+        assert config.venue_profile is None
+        assert config.user_agent is None
+        assert config.extra_headers is None
+        assert config.callback_payload is None
+        assert config.callback_payload is None
         assert config.trades_taker_only is False
 
     def test_custom_values(self):
@@ -45,7 +67,25 @@ class TestGmocoinExecClientConfig:
         config = GmocoinExecClientConfig(api_key="key", api_secret="secret")
         assert config.timeout_ms == 10000
         assert config.proxy_url is None
+        assert config.rate_limit_tier is None
         assert config.rate_limit_per_sec is None
+        assert config.group_rate_limits is None
+        assert config.share_rate_limiter is False
+        assert config.circuit_breaker_threshold is None
+        assert config.circuit_breaker_cooldown_ms is None
+        assert config.order_deadline_ms is None
+        assert config.order_timeout_ms is None
+        assert config.tls_ca_file is None
+        assert config.tls_native_roots is None
+        assert config.tls_min_version is None
+        assert config.pool_max_idle_per_host is None
+        assert config.pool_idle_timeout_ms is None
+        assert config.prefer_http2 is False
+        assert config.tcp_keepalive_ms is None
+        assert config.venue_profile is None
+        assert config.user_agent is None
+        assert config.extra_headers is None
+        assert config.callback_payload is None
 
     def test_custom_rate_limit(self):
         config = GmocoinExecClientConfig(
@@ -54,3 +94,92 @@ class TestGmocoinExecClientConfig:
             rate_limit_per_sec=30.0,
         )
         assert config.rate_limit_per_sec == 30.0
+
+    def test_rate_limit_tier(self):
+        config = GmocoinExecClientConfig(
+            api_key="key",
+            api_secret="secret",
+            rate_limit_tier="tier2",
+        )
+        assert config.rate_limit_tier == "tier2"
+        assert rate_limit_setting(config) == "tier2"
+
+    def test_custom_rate_overrides_tier(self):
+        config = GmocoinExecClientConfig(
+            api_key="key",
+            api_secret="secret",
+            rate_limit_tier="tier2",
+            rate_limit_per_sec=25.0,
+        )
+        assert rate_limit_setting(config) == 25.0
+
+    def test_unknown_rate_limit_tier_raises(self):
+        with pytest.raises(ValueError, match="rate_limit_tier"):
+            GmocoinExecClientConfig(
+                api_key="key",
+                api_secret="secret",
+                rate_limit_tier="tier3",
+            )
+
+    def test_custom_group_rate_limits(self):
+        config = GmocoinExecClientConfig(
+            api_key="key",
+            api_secret="secret",
+            group_rate_limits={"order": 10.0},
+        )
+        assert config.group_rate_limits == {"order": 10.0}
+
+    def test_tls_min_version(self):
+        config = GmocoinExecClientConfig(
+            api_key="key",
+            api_secret="secret",
+            tls_min_version="1.3",
+        )
+        assert config.tls_min_version == "1.3"
+        with pytest.raises(ValueError, match="tls_min_version"):
+            GmocoinExecClientConfig(api_key="key", api_secret="secret", tls_min_version="1.1")
+
+    def test_callback_payload(self):
+        config = GmocoinExecClientConfig(api_key="key", api_secret="secret", callback_payload="msgpack")
+        assert config.callback_payload == "msgpack"
+        with pytest.raises(ValueError, match="callback_payload"):
+            GmocoinDataClientConfig(api_key="key", api_secret="secret", callback_payload="json")
+
+    def test_custom_circuit_breaker(self):
+        config = GmocoinExecClientConfig(
+            api_key="key",
+            api_secret="secret",
+            circuit_breaker_threshold=3,
+            circuit_breaker_cooldown_ms=10000,
+        )
+        assert config.circuit_breaker_threshold == 3
+        assert config.circuit_breaker_cooldown_ms == 10000
+
+    def test_encrypted_credentials(self, monkeypatch):
+        config = GmocoinExecClientConfig(encrypted_credentials="gmocoin1:00")
+        assert config.api_key is None
+        with pytest.raises(ValueError, match="not both"):
+            GmocoinExecClientConfig(api_key="key", api_secret="secret", encrypted_credentials="gmocoin1:00")
+        with pytest.raises(ValueError, match="encrypted_credentials"):
+            GmocoinDataClientConfig(api_key="key")
+
+        monkeypatch.delenv("GMOCOIN_CREDENTIALS_PASSPHRASE", raising=False)
+        with pytest.raises(ValueError, match="GMOCOIN_CREDENTIALS_PASSPHRASE"):
+            credentials_passphrase(config, config.encrypted_credentials)
+        monkeypatch.setenv("GMOCOIN_CREDENTIALS_PASSPHRASE", "passphrase")
+        assert credentials_passphrase(config, config.encrypted_credentials) == "passphrase"
+        assert credentials_passphrase(config, None) is None
+
+    def test_account_credentials(self):
+        config = GmocoinExecClientConfig(
+            api_key="key", api_secret="secret",
+            accounts={"hedge": {"credentials": "gmocoin1:00"}, "other": {"api_key": "k", "api_secret": "s"}},
+        )
+        assert set(config.accounts) == {"hedge", "other"}
+        with pytest.raises(ValueError, match="'hedge' takes either"):
+            GmocoinExecClientConfig(
+                api_key="key", api_secret="secret",
+                accounts={"hedge": {"credentials": "gmocoin1:00", "api_key": "k"}},
+            )
+        with pytest.raises(ValueError, match="'hedge' requires both"):
+            GmocoinExecClientConfig(api_key="key", api_secret="secret", accounts={"hedge": {"api_key": "k"}})
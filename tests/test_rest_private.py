@@ -9,7 +9,6 @@ Replay (default):
     pytest tests/test_rest_private.py -v
 """
 import asyncio
-import json
 from tests.conftest import requires_rust_extension, integration, load_api_keys
 
 
@@ -35,32 +34,27 @@ class TestPrivateRestApi:
     """Tests that call the real GMO Coin private API."""
 
     def test_get_assets(self, vcr):
-        result = vcr(_live(lambda c: c.get_assets_py()))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_assets_py()))
         assert isinstance(data, list)
         symbols = [a.get("symbol", "") for a in data]
         assert "JPY" in symbols
 
     def test_get_margin(self, vcr):
-        result = vcr(_live(lambda c: c.get_margin_py()))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_margin_py()))
         assert isinstance(data, dict)
         assert "availableAmount" in data or "available_amount" in data
 
     def test_get_active_orders(self, vcr):
-        result = vcr(_live(lambda c: c.get_active_orders_py("BTC", None, None)))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_active_orders_py("BTC", None, None)))
         assert isinstance(data, dict)
         assert "list" in data
 
     def test_get_position_summary(self, vcr):
-        result = vcr(_live(lambda c: c.get_position_summary_py(None)))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_position_summary_py(None)))
         assert isinstance(data, dict)
         assert "list" in data
 
     def test_ws_auth(self, vcr):
-        result = vcr(_live(lambda c: c.post_ws_auth_py()))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.post_ws_auth_py()))
         assert isinstance(data, str)
         assert len(data) > 0
@@ -7,7 +7,6 @@ Record cassettes:  pytest tests/test_rest_public.py --record-cassettes -v
 Replay (default):  pytest tests/test_rest_public.py -v
 """
 import asyncio
-import json
 from tests.conftest import requires_rust_extension, integration
 
 
@@ -32,14 +31,12 @@ class TestPublicRestApi:
     """Tests that call the real GMO Coin public API."""
 
     def test_get_status(self, vcr):
-        result = vcr(_live(lambda c: c.get_status_py()))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_status_py()))
         assert isinstance(data, dict)
         assert "status" in data
 
     def test_get_ticker(self, vcr):
-        result = vcr(_live(lambda c: c.get_ticker_py("BTC")))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_ticker_py("BTC")))
         assert isinstance(data, list)
         if len(data) > 0:
             ticker = data[0]
@@ -48,16 +45,14 @@ class TestPublicRestApi:
             assert "symbol" in ticker
 
     def test_get_orderbooks(self, vcr):
-        result = vcr(_live(lambda c: c.get_orderbooks_py("BTC")))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_orderbooks_py("BTC")))
         assert "asks" in data
         assert "bids" in data
         assert isinstance(data["asks"], list)
         assert isinstance(data["bids"], list)
 
     def test_get_symbols(self, vcr):
-        result = vcr(_live(lambda c: c.get_symbols_py()))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_symbols_py()))
         assert isinstance(data, list)
         assert len(data) > 0
 
@@ -68,13 +63,11 @@ class TestPublicRestApi:
         assert "tickSize" in btc
 
     def test_get_trades(self, vcr):
-        result = vcr(_live(lambda c: c.get_trades_py("BTC", None, None)))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_trades_py("BTC", None, None)))
         assert isinstance(data, (list, dict))
 
     def test_get_klines(self, vcr):
-        result = vcr(_live(lambda c: c.get_klines_py("BTC", "1hour", "20250101")))
-        data = json.loads(result)
+        data = vcr(_live(lambda c: c.get_klines_py("BTC", "1hour", "20250101")))
         assert isinstance(data, list)
         if len(data) > 0:
             kline = data[0]
@@ -2,6 +2,122 @@
 import pytest
 from tests.conftest import requires_rust_extension
 
+# Nothing listens here; the WS loops just retry until disconnected
+UNREACHABLE_PROFILE = {
+    "rest_public": "http://127.0.0.1:9",
+    "rest_private": "http://127.0.0.1:9",
+    "ws_public": "ws://127.0.0.1:9",
+    "ws_private": "ws://127.0.0.1:9",
+}
+
+
+def _start_ws_server(frames=(), received=None):
+    """A local WS server that sends `frames` (text) to each connection after
+    the handshake, then stays silent, appending the text frames it receives
+    to `received` if given."""
+    import base64
+    import hashlib
+    import socketserver
+    import struct
+    import threading
+
+    class Handler(socketserver.BaseRequestHandler):
+        def handle(self):
+            request = b""
+            while b"\r\n\r\n" not in request:
+                request += self.request.recv(1024)
+            key = next(line.split(b":", 1)[1].strip() for line in request.split(b"\r\n")
+                       if line.lower().startswith(b"sec-websocket-key"))
+            accept = base64.b64encode(hashlib.sha1(key + b"258EAFA5-E914-47DA-95CA-C5AB0DC85B11").digest())
+            self.request.sendall(
+                b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n"
+                b"Sec-WebSocket-Accept: " + accept + b"\r\n\r\n"
+            )
+            for frame in frames:
+                payload = frame.encode()
+                header = bytes([0x81, len(payload)]) if len(payload) < 126 else b"\x81\x7e" + struct.pack(">H", len(payload))
+                self.request.sendall(header + payload)
+            buffer = b""
+            while chunk := self.request.recv(1024):
+                buffer += chunk
+                # Client frames are masked: 2 byte header, length, 4 byte mask
+                while len(buffer) >= 2:
+                    length, offset = buffer[1] & 0x7F, 2
+                    if length == 126:
+                        length, offset = struct.unpack(">H", buffer[2:4])[0], 4
+                    if len(buffer) < offset + 4 + length:
+                        break
+                    mask = buffer[offset:offset + 4]
+                    payload = bytes(b ^ mask[i % 4] for i, b in enumerate(buffer[offset + 4:offset + 4 + length]))
+                    if received is not None and buffer[0] & 0x0F == 1:
+                        received.append(payload.decode())
+                    buffer = buffer[offset + 4 + length:]
+
+    server = socketserver.ThreadingTCPServer(("127.0.0.1", 0), Handler)
+    server.daemon_threads = True
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    return server
+
+
+class _Reply:
+    """A raw REST reply: `payload` as the whole JSON body (no body if None)
+    with HTTP `status`, for anything other than a success envelope."""
+
+    def __init__(self, payload=None, status=200):
+        self.payload = payload
+        self.status = status
+
+
+def _start_http_server(routes, received=None):
+    """A local REST server answering from `routes`, keyed by "METHOD /path"
+    or by "METHOD" alone for any path (the query is ignored). A route is the
+    `data` of a success envelope, a `_Reply`, or a callable taking the request
+    (method, path, query, body, headers) and returning either; anything
+    unrouted gets a 404. Requests are appended to `received` if given, and
+    the base URL is `server.url`."""
+    import json
+    import threading
+    import types
+    from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+    from urllib.parse import parse_qs, urlparse
+
+    missing = object()
+
+    class Handler(BaseHTTPRequestHandler):
+        def handle_request(self):
+            url = urlparse(self.path)
+            length = int(self.headers.get("Content-Length") or 0)
+            raw = self.rfile.read(length) if length else b""
+            request = types.SimpleNamespace(
+                method=self.command, path=url.path, query={k: v[0] for k, v in parse_qs(url.query).items()},
+                body=json.loads(raw) if raw else None, headers=self.headers,
+            )
+            if received is not None:
+                received.append(request)
+            route = routes.get(f"{self.command} {url.path}", routes.get(self.command, missing))
+            if route is missing:
+                reply = _Reply(status=404)
+            else:
+                reply = route(request) if callable(route) else route
+                if not isinstance(reply, _Reply):
+                    reply = _Reply({"status": 0, "data": reply, "responsetime": ""})
+            body = b"" if reply.payload is None else json.dumps(reply.payload).encode()
+            self.send_response(reply.status)
+            self.send_header("Content-Length", str(len(body)))
+            self.end_headers()
+            self.wfile.write(body)
+
+        do_GET = do_POST = do_PUT = do_DELETE = handle_request
+
+        def log_message(self, *args):
+            pass
+
+    server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    server.daemon_threads = True
+    server.url = f"http://127.0.0.1:{server.server_port}"
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    return server
+
 
 @requires_rust_extension
 class TestTicker:
@@ -24,6 +140,7 @@ class TestTicker:
         assert ticker.last == "5000050"
         assert ticker.symbol == "BTC"
         assert ticker.volume == "123.456"
+        assert ticker.ts_event == 1_704_067_200_000_000_000
 
 
 @requires_rust_extension
@@ -41,6 +158,7 @@ class TestTrade:
         assert trade.side == "BUY"
         assert trade.size == "0.01"
         assert trade.symbol == "BTC"
+        assert trade.ts_event == 1_704_067_200_000_000_000
 
     def test_create_without_symbol(self):
         from nautilus_gmocoin import gmocoin
@@ -53,6 +171,12 @@ class TestTrade:
         )
         assert trade.symbol is None
 
+    def test_malformed_timestamp_has_no_ts_event(self):
+        from nautilus_gmocoin import gmocoin
+        trade = gmocoin.Trade(price="100", side="SELL", size="1.0", timestamp="yesterday", symbol=None)
+        assert trade.timestamp == "yesterday"
+        assert trade.ts_event is None
+
 
 @requires_rust_extension
 class TestSymbolInfo:
@@ -79,6 +203,32 @@ class TestOrderBook:
         assert asks == []
         assert bids == []
 
+    def test_apply_snapshot_keeps_venue_order(self):
+        from nautilus_gmocoin import gmocoin
+        book = gmocoin.OrderBook(symbol="BTC")
+        depth = gmocoin.Depth(
+            asks=[gmocoin.DepthEntry("999", "0.1"), gmocoin.DepthEntry("1000", "0.2")],
+            bids=[gmocoin.DepthEntry("998", "0.3"), gmocoin.DepthEntry("99", "0.4")],
+            symbol="BTC",
+            timestamp="2025-01-01T00:00:00.000Z",
+        )
+        book.apply_snapshot(depth)
+        asks, bids = book.get_top_n(1)
+        assert asks == [("999", "0.1")]
+        assert bids == [("998", "0.3")]
+        assert book.get_bids() == [("998", "0.3"), ("99", "0.4")]
+        assert book.timestamp == "2025-01-01T00:00:00.000Z"
+        assert book.ts_event == depth.ts_event == 1_735_689_600_000_000_000
+
+    def test_snapshot_held_by_caller_is_unchanged(self):
+        from nautilus_gmocoin import gmocoin
+        book = gmocoin.OrderBook(symbol="BTC")
+        book.apply_snapshot(gmocoin.Depth([gmocoin.DepthEntry("1", "1")], [], "BTC", "t1"))
+        held = book.get_asks()
+        book.apply_snapshot(gmocoin.Depth([gmocoin.DepthEntry("2", "2")], [], "BTC", "t2"))
+        assert held == [("1", "1")]
+        assert book.get_asks() == [("2", "2")]
+
 
 @requires_rust_extension
 class TestRestClientCreate:
@@ -96,6 +246,608 @@ class TestRestClientCreate:
         )
         assert client is not None
 
+    def test_create_with_circuit_breaker(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            circuit_breaker_threshold=3,
+            circuit_breaker_cooldown_ms=1000,
+        )
+        assert client is not None
+
+    def test_create_with_group_rate_limits(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, 20.0,
+            group_rate_limits={"order": 10.0, "public": 5.0},
+        )
+        assert client is not None
+
+    def test_create_with_rate_limit_tier(self):
+        from nautilus_gmocoin import gmocoin
+        for setting in ("tier1", "Tier2", 25.0, None):
+            client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, setting)
+            assert client is not None
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, "tier3")
+
+    def test_create_with_shared_rate_limiter(self):
+        from nautilus_gmocoin import gmocoin
+        a = gmocoin.GmocoinRestClient(
+            "shared_key", "test_secret", 5000, None, None, share_rate_limiter=True,
+        )
+        b = gmocoin.GmocoinExecutionClient(
+            "shared_key", "test_secret", 5000, None, None, share_rate_limiter=True,
+        )
+        assert a is not None and b is not None
+
+    def test_create_with_unknown_group_raises(self):
+        from nautilus_gmocoin import gmocoin
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None,
+                group_rate_limits={"private": 10.0},
+            )
+
+    def test_create_with_invalid_group_rate_raises(self):
+        from nautilus_gmocoin import gmocoin
+        for rate in (0, -1.0, float("nan")):
+            with pytest.raises(ValueError, match="finite and positive"):
+                gmocoin.GmocoinRestClient(
+                    "test_key", "test_secret", 1000, None, None,
+                    group_rate_limits={"public": rate},
+                )
+
+    def test_rate_below_one_per_sec_serves_requests(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, 0.5,
+            group_rate_limits={"order": 0.25}, venue_profile=UNREACHABLE_PROFILE,
+        )
+
+        async def run():
+            # Refused by the unreachable venue, not stuck waiting for a token
+            with pytest.raises(RuntimeError, match="API Request Error"):
+                await asyncio.wait_for(client.get_ticker_py("BTC"), 5)
+            with pytest.raises(RuntimeError, match="API Request Error"):
+                await asyncio.wait_for(client.post_cancel_order_py("1"), 5)
+
+        asyncio.run(run())
+
+    def test_shed_when_no_token_available(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            group_rate_limits={"public": 0.001}, venue_profile=UNREACHABLE_PROFILE,
+        )
+
+        async def run():
+            # The burst of one token goes to the first request; the next
+            # token is 1000s away
+            with pytest.raises(RuntimeError, match="API Request Error"):
+                await client.get_ticker_py("BTC", max_wait_ms=0)
+            with pytest.raises(TimeoutError):
+                await client.get_ticker_py("BTC", max_wait_ms=0)
+            with pytest.raises(TimeoutError):
+                await client.get_ticker_py("BTC", max_wait_ms=10)
+
+        asyncio.run(run())
+
+    def test_max_in_flight(self):
+        import asyncio
+        import threading
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        ticker = {"ask": "1", "bid": "1", "high": "1", "low": "1", "last": "1", "symbol": "BTC",
+                  "timestamp": "2024-01-01T00:00:00.000Z", "volume": "1"}
+        lock = threading.Lock()
+        counts = {"now": 0, "peak": 0}
+
+        def slow_ticker(request):
+            with lock:
+                counts["now"] += 1
+                counts["peak"] = max(counts["peak"], counts["now"])
+            time.sleep(0.3)
+            with lock:
+                counts["now"] -= 1
+            return [ticker]
+
+        server = _start_http_server({"GET": slow_ticker})
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url, rest_private=server.url)
+        capped = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None,
+                                           venue_profile=profile, max_in_flight=2)
+        single = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None,
+                                           venue_profile=profile, max_in_flight=1)
+
+        async def run():
+            await asyncio.gather(*(capped.get_ticker_py("BTC") for _ in range(4)))
+            assert capped.health()["rest"]["in_flight"] == 0
+            assert capped.health()["rest"]["max_in_flight"] == 2
+            outstanding = asyncio.ensure_future(single.get_ticker_py("BTC"))
+            await asyncio.sleep(0.1)
+            with pytest.raises(TimeoutError, match="in flight"):
+                await single.get_ticker_py("BTC", max_wait_ms=0)
+            await outstanding
+
+        asyncio.run(run())
+        server.shutdown()
+        assert counts["peak"] == 2
+
+    def test_get_tickers(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        def ticker(symbol, last):
+            return {"ask": last, "bid": last, "high": last, "low": last, "last": last, "symbol": symbol,
+                    "timestamp": "2024-01-01T00:00:00.000Z", "volume": "1"}
+
+        tickers = [ticker("BTC", "5000000"), ticker("ETH", "300000"), ticker("XRP", "80")]
+        received = []
+        server = _start_http_server({
+            "GET /v1/ticker": lambda r: [t for t in tickers if t["symbol"] == r.query.get("symbol")] or tickers,
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            both = await client.get_tickers(["BTC", "ETH"])
+            single = await client.get_tickers(["XRP"])
+            with pytest.raises(ValueError, match="DOGE"):
+                await client.get_tickers(["BTC", "DOGE"])
+            return both, single
+
+        both, single = asyncio.run(run())
+        server.shutdown()
+        assert sorted(both) == ["BTC", "ETH"]
+        assert isinstance(both["BTC"], gmocoin.Ticker)
+        assert both["ETH"].last == "300000"
+        assert single["XRP"].last == "80"
+        assert [r.query for r in received] == [{}, {"symbol": "XRP"}, {}]
+
+    def test_symbols_cache(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        requests = []
+        server = _start_http_server({"GET": lambda r: [{"symbol": "BTC", "tickSize": str(len(requests))}]}, requests)
+        url = server.url
+        profile = dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url)
+        cached = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None,
+                                           venue_profile=profile, symbols_ttl_ms=200)
+        uncached = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None,
+                                             venue_profile=profile, symbols_ttl_ms=0)
+
+        async def run():
+            ticks = [s[0]["tickSize"] for s in await asyncio.gather(*(cached.get_symbols_py() for _ in range(3)))]
+            ticks.append((await cached.with_timeout(500).get_symbols_py())[0]["tickSize"])
+            ticks.append((await cached.get_symbols_py(force_refresh=True))[0]["tickSize"])
+            await asyncio.sleep(0.3)
+            ticks.append((await cached.get_symbols_py())[0]["tickSize"])
+            await uncached.get_symbols_py()
+            await uncached.get_symbols_py()
+            return ticks
+
+        ticks = asyncio.run(run())
+        server.shutdown()
+        assert ticks == ["1", "1", "1", "1", "2", "3"]
+        assert len(requests) == 5
+
+    def test_order_price_formatting(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        orders = []
+
+        def post(request):
+            orders.append(request.body)
+            return str(len(orders))
+
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC", "tickSize": "1"}, {"symbol": "XRP", "tickSize": "0.001"},
+                                {"symbol": "BTC_JPY", "tickSize": "1"}, {"symbol": "XRP_JPY", "tickSize": "0.005"}],
+            "POST": post,
+        })
+        url = server.url
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            await client.post_order_py("BTC", "BUY", "LIMIT", "0.01", "5000000.4")
+            await client.post_order_py("BTC", "SELL", "LIMIT", "0.01", "5000000.4")
+            await client.post_order_py("XRP", "BUY", "LIMIT", "10", "50.1")
+            await client.post_order_py("BTC_JPY", "BUY", "LIMIT", "0.01", "5000000", losscut_price="4500000.6")
+            # Rounded to a multiple of the tick, not only to its decimals
+            await client.post_order_py("XRP_JPY", "BUY", "LIMIT", "10", "50.1234")
+            await client.post_change_order_py("7", "50.1234", losscut_price="60.1234", symbol="XRP_JPY", side="SELL")
+            # Without the order's symbol the prices are sent as given
+            await client.post_change_order_py("7", "50.1234")
+            with pytest.raises(ValueError, match="Unknown symbol 'DOGE'"):
+                await client.post_order_py("DOGE", "BUY", "LIMIT", "10", "20.12345")
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [o["price"] for o in orders] == ["5000000", "5000001", "50.100", "5000000", "50.120", "50.125", "50.1234"]
+        assert orders[3]["losscutPrice"] == "4500001"
+        assert orders[5] == {"orderId": 7, "price": "50.125", "losscutPrice": "60.125"}
+        assert orders[6] == {"orderId": 7, "price": "50.1234"}
+
+    def test_new_symbol_accepted_after_ttl(self):
+        import asyncio
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        symbols = [{"symbol": "BTC"}]
+        received = []
+        server = _start_http_server({"GET": symbols, "POST": "1"}, received)
+        url = server.url
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None, symbols_ttl_ms=200,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            await client.post_order_py("BTC", "BUY", "LIMIT", "0.01", "5000000")
+            # Listed after the cache was filled: refused until the cache expires
+            symbols.append({"symbol": "SOL"})
+            with pytest.raises(ValueError, match="Unknown symbol 'SOL'"):
+                await client.post_order_py("SOL", "BUY", "LIMIT", "1", "20000")
+            time.sleep(0.3)
+            await client.post_order_py("SOL", "BUY", "LIMIT", "1", "20000")
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [r.body["symbol"] for r in received if r.method == "POST"] == ["BTC", "SOL"]
+        assert len([r for r in received if r.method == "GET"]) == 2
+
+    def test_order_size_formatting(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({
+            "GET": [{"symbol": "BTC", "sizeStep": "0.0001"}, {"symbol": "XRP", "sizeStep": "1"}],
+            "POST": "1",
+        }, received)
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url, rest_private=server.url)
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None, venue_profile=profile)
+        strict = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None,
+                                           venue_profile=profile, strict_sizes=True)
+
+        async def run():
+            await client.post_order_py("BTC", "BUY", "MARKET", "0.01239")
+            await client.post_order_py("XRP", "SELL", "MARKET", "10.9")
+            await client.post_close_bulk_order_py("BTC", "SELL", "MARKET", "0.00015")
+            with pytest.raises(ValueError, match="below sizeStep"):
+                await client.post_order_py("BTC", "BUY", "MARKET", "0.00009")
+            with pytest.raises(ValueError, match="not a multiple of sizeStep"):
+                await strict.post_order_py("BTC", "BUY", "MARKET", "0.01239")
+            await strict.post_order_py("BTC", "BUY", "MARKET", "0.0123")
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [r.body["size"] for r in received if r.method == "POST"] == ["0.0123", "10", "0.0001", "0.0123"]
+
+    def test_get_klines_range(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        dates = []
+
+        def klines(request):
+            date = request.query["date"]
+            dates.append(date)
+            # Hourly klines of the trading day from 06:00 JST
+            day_start = _jst_ns(int(date[:4]), int(date[4:6]), int(date[6:]), 6, 0) // 1_000_000
+            return [{"openTime": str(day_start + h * 3_600_000), "open": "1", "high": "1", "low": "1",
+                     "close": "1", "volume": "0"} for h in range(24)]
+
+        server = _start_http_server({"GET": klines})
+        url = server.url
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        start, end = _jst_ns(2024, 6, 1, 5, 0), _jst_ns(2024, 6, 2, 7, 0)
+
+        async def run():
+            klines = await client.get_klines_range("BTC", "1hour", start, end)
+            batch = await client.get_klines_range_arrow("BTC", "1hour", start, start + 3_600_000_000_000)
+            with pytest.raises(ValueError, match="interval"):
+                await client.get_klines_range("BTC", "2hour", start, end)
+            return klines, batch
+
+        klines, batch = asyncio.run(run())
+        server.shutdown()
+        assert dates == ["20240531", "20240601", "20240602", "20240531"]
+        open_times = [int(k["openTime"]) * 1_000_000 for k in klines]
+        assert open_times == list(range(start, end, 3_600_000_000_000))
+        assert batch.num_rows == 1
+
+    def test_expired_deadline_is_not_sent(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+
+        async def run():
+            with pytest.raises(TimeoutError):
+                await client.with_deadline(0).get_status_py()
+
+        asyncio.run(run())
+
+    def test_with_timeout(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+        assert client.with_timeout(500) is not client
+        assert client.with_timeout(None) is not None
+
+    def test_close(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+        client.with_timeout(500).close()
+
+        async def run():
+            await client.get_status_py()
+
+        # Closing any clone closes them all; nothing is sent
+        with pytest.raises(RuntimeError, match="Client closed"):
+            asyncio.run(run())
+
+    def test_set_rate_limit(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+        client.set_rate_limit("order", 30.0)
+        with pytest.raises(ValueError):
+            client.set_rate_limit("private", 30.0)
+        with pytest.raises(ValueError):
+            client.set_rate_limit("order", 0.0)
+
+    def test_create_with_tls_settings(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None, tls_min_version="1.3",
+        )
+        assert client is not None
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None, tls_min_version="1.1",
+            )
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None,
+                tls_ca_file="/nonexistent/ca.pem",
+            )
+
+    def test_create_with_pool_settings(self):
+        from nautilus_gmocoin import gmocoin
+        for prefer_http2 in (False, True):
+            client = gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None,
+                pool_max_idle_per_host=4,
+                pool_idle_timeout_ms=300000,
+                prefer_http2=prefer_http2,
+                tcp_keepalive_ms=30000,
+            )
+            assert client is not None
+
+    def test_create_with_venue_profile(self):
+        from nautilus_gmocoin import gmocoin
+        urls = {
+            "rest_public": "https://sandbox.example/public",
+            "rest_private": "https://sandbox.example/private",
+            "ws_public": "wss://sandbox.example/ws/public/v1",
+            "ws_private": "wss://sandbox.example/ws/private/v1",
+        }
+        for profile in ("production", urls):
+            client = gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None, venue_profile=profile,
+            )
+            assert client is not None
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None, venue_profile="sandbox",
+            )
+        partial = {k: v for k, v in urls.items() if k != "ws_private"}
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinExecutionClient(
+                "test_key", "test_secret", 5000, None, None, venue_profile=partial,
+            )
+
+    def test_create_with_headers(self):
+        from nautilus_gmocoin import gmocoin
+        headers = {"X-Gateway-Id": "desk-1"}
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            user_agent="my-bot/1.0", extra_headers=headers,
+        )
+        assert client is not None
+        data_client = gmocoin.GmocoinDataClient(1, user_agent="my-bot/1.0", extra_headers=headers)
+        assert data_client is not None
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinRestClient(
+                "test_key", "test_secret", 5000, None, None, extra_headers={"API-KEY": "x"},
+            )
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinExecutionClient(
+                "test_key", "test_secret", 5000, None, None, user_agent="bad\nagent",
+            )
+
+    def test_get_stats(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+        stats = client.get_stats()
+        assert stats["requests_sent"] == 0
+        assert stats["errors"] == {}
+
+        async def run():
+            with pytest.raises(TimeoutError):
+                await client.with_deadline(0).get_status_py()
+
+        asyncio.run(run())
+        stats = client.get_stats()
+        assert stats["requests_sent"] == 0
+        assert stats["errors"] == {"timeout": 1}
+        assert stats["last_error"]["request_id"] == stats["last_request_id"]
+
+    def test_error_carries_request_id(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None)
+
+        async def run():
+            with pytest.raises(TimeoutError) as exc_info:
+                await client.with_deadline(0).get_status_py()
+            return str(exc_info.value)
+
+        message = asyncio.run(run())
+        assert f"[request_id={client.get_stats()['last_request_id']}]" in message
+
+    def test_circuit_open_error_is_connection_error(self):
+        from nautilus_gmocoin import gmocoin
+        assert issubclass(gmocoin.CircuitOpenError, ConnectionError)
+
+    def test_circuit_breaker_lifecycle(self):
+        import asyncio
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        state = {"failing": True, "requests": 0}
+
+        def status(request):
+            state["requests"] += 1
+            if state["failing"]:
+                return _Reply({"status": 5, "messages": [{"message_code": "ERR-5201", "message_string": "MAINTENANCE"}]})
+            time.sleep(0.3)
+            return {"status": "OPEN"}
+
+        server = _start_http_server({"GET": status})
+        url = server.url
+        client = gmocoin.GmocoinRestClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            circuit_breaker_threshold=2, circuit_breaker_cooldown_ms=300,
+        )
+
+        async def run():
+            # Opens after two consecutive failures
+            for _ in range(2):
+                with pytest.raises(RuntimeError, match="status=5"):
+                    await client.get_status_py()
+            with pytest.raises(gmocoin.CircuitOpenError, match=r"retry after \d+ms"):
+                await client.get_status_py()
+            assert state["requests"] == 2
+            assert client.health()["rest"]["open_circuits"] == ["public"]
+
+            # Half-open: one trial goes through, the others keep failing fast
+            await asyncio.sleep(0.35)
+            state["failing"] = False
+            trial = asyncio.ensure_future(client.get_status_py())
+            await asyncio.sleep(0.1)
+            with pytest.raises(gmocoin.CircuitOpenError):
+                await client.get_status_py()
+            await trial
+            assert state["requests"] == 3
+
+            # Closed by the trial's success
+            await client.get_status_py()
+            assert state["requests"] == 4
+            assert client.health()["rest"]["open_circuits"] == []
+
+        asyncio.run(run())
+        server.shutdown()
+
+    def test_encrypted_credentials(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        sealed = gmocoin.encrypt_credentials("sealed_key", "sealed_secret", "passphrase")
+        assert sealed.startswith("gmocoin1:")
+        assert "sealed_secret" not in sealed
+        # A fresh salt and nonce each time
+        assert gmocoin.encrypt_credentials("sealed_key", "sealed_secret", "passphrase") != sealed
+        with pytest.raises(ValueError, match="empty"):
+            gmocoin.encrypt_credentials("sealed_key", "sealed_secret", "")
+
+        received = []
+        server = _start_http_server({"GET": []}, received)
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url, rest_private=server.url)
+        client = gmocoin.GmocoinRestClient(
+            "", "", 5000, None, None, venue_profile=profile, credentials=sealed, passphrase="passphrase",
+        )
+        exec_client = gmocoin.GmocoinExecutionClient(
+            "", "", 5000, None, None, venue_profile=profile, credentials=sealed, passphrase="passphrase",
+        )
+        exec_client.add_account("other", "", "", credentials=sealed, passphrase="passphrase")
+
+        async def run():
+            await client.get_assets_py()
+            await exec_client.get_assets_py(account="other")
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [r.headers["API-KEY"] for r in received] == ["sealed_key", "sealed_key"]
+
+        with pytest.raises(PermissionError, match="wrong passphrase"):
+            gmocoin.GmocoinRestClient("", "", 5000, None, None, credentials=sealed, passphrase="wrong")
+        with pytest.raises(PermissionError, match="damaged"):
+            gmocoin.GmocoinRestClient("", "", 5000, None, None, credentials=sealed[:-2], passphrase="passphrase")
+        with pytest.raises(ValueError, match="passphrase"):
+            gmocoin.GmocoinRestClient("", "", 5000, None, None, credentials=sealed)
+        with pytest.raises(ValueError, match="not both"):
+            gmocoin.GmocoinRestClient("key", "secret", 5000, None, None, credentials=sealed, passphrase="passphrase")
+        # A passphrase without encrypted credentials is a mistake, not ignored
+        with pytest.raises(ValueError, match="only used with encrypted credentials"):
+            gmocoin.GmocoinRestClient("key", "secret", 5000, None, None, passphrase="passphrase")
+
+
+@requires_rust_extension
+class TestConfigureLogging:
+    def test_change_level(self):
+        from nautilus_gmocoin import gmocoin
+        gmocoin.configure_logging(level="debug")
+        gmocoin.configure_logging(level="info")
+
+    def test_file_target(self, tmp_path):
+        from nautilus_gmocoin import gmocoin
+        path = tmp_path / "adapter.log"
+        gmocoin.configure_logging(format="json", target=str(path))
+        gmocoin.GmocoinRestClient("test_key", "test_secret", 5000, None, None).set_rate_limit("order", 5.0)
+        gmocoin.configure_logging(format="text", target="stderr")
+        assert '"level":"INFO"' in path.read_text()
+
+    def test_invalid_settings_raise(self):
+        from nautilus_gmocoin import gmocoin
+        with pytest.raises(ValueError):
+            gmocoin.configure_logging(format="xml")
+        with pytest.raises(ValueError):
+            gmocoin.configure_logging(level="[bad")
+
+
+@requires_rust_extension
+class TestSyncRestClient:
+    def test_create(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.SyncGmocoinRestClient("test_key", "test_secret", 10000, None, None, circuit_breaker_threshold=2)
+        assert isinstance(client, gmocoin.GmocoinRestClient)
+        with pytest.raises(ValueError):
+            gmocoin.SyncGmocoinRestClient("test_key", "test_secret", 10000, None, "tier9")
+
+    def test_returns_result_directly(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.SyncGmocoinRestClient("", "", 5000, None, None, venue_profile=UNREACHABLE_PROFILE)
+        # Raised by the call itself, not by awaiting a future
+        with pytest.raises(RuntimeError, match="request_id="):
+            client.get_status_py()
+
 
 @requires_rust_extension
 class TestDataClientCreate:
@@ -109,12 +861,2588 @@ class TestDataClientCreate:
         client = gmocoin.GmocoinDataClient(1.0)
         assert client is not None
 
+    def test_create_with_tls_settings(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinDataClient(None, tls_native_roots=True, tls_min_version="1.2")
+        assert client is not None
+        with pytest.raises(ValueError):
+            gmocoin.GmocoinDataClient(None, tls_native_roots=False)
 
-@requires_rust_extension
-class TestExecutionClientCreate:
-    def test_create(self):
+    def test_close(self):
+        import asyncio
         from nautilus_gmocoin import gmocoin
-        client = gmocoin.GmocoinExecutionClient(
-            "test_key", "test_secret", 10000, None, None
+        client = gmocoin.GmocoinDataClient(None, venue_profile=UNREACHABLE_PROFILE)
+
+        async def run():
+            await client.connect()
+            return await client.close(timeout_ms=1000)
+
+        assert asyncio.run(run()) is True
+
+    def test_shutdown_at_exit(self):
+        import subprocess
+        import sys
+        script = (
+            "import asyncio\n"
+            "from nautilus_gmocoin import gmocoin\n"
+            f"client = gmocoin.GmocoinDataClient(None, venue_profile={UNREACHABLE_PROFILE!r})\n"
+            "async def run():\n"
+            "    await client.connect()\n"
+            "asyncio.run(run())\n"
+            "print(gmocoin.shutdown(1000))\n"
+        )
+        result = subprocess.run([sys.executable, "-c", script], capture_output=True, text=True, timeout=30)
+        assert result.returncode == 0, result.stderr
+        assert result.stdout.strip() == "True"
+
+    def test_create_with_callback_payload(self):
+        from nautilus_gmocoin import gmocoin
+        assert gmocoin.GmocoinDataClient(None, callback_payload="msgpack") is not None
+        with pytest.raises(ValueError, match="callback_payload"):
+            gmocoin.GmocoinDataClient(None, callback_payload="json")
+
+    def test_create_with_maintenance_windows(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinDataClient(
+            None, maintenance_windows=["23:30-00:30"], maintenance_status_check=False
+        )
+        assert client is not None
+        assert client.calendar == gmocoin.TradingCalendar(["23:30-00:30"])
+        with pytest.raises(ValueError, match="maintenance window"):
+            gmocoin.GmocoinDataClient(None, maintenance_windows=["15:00"])
+
+    def test_create_with_reconnect_backoff(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinDataClient(
+            None, reconnect_initial_ms=200, reconnect_max_ms=5000, reconnect_multiplier=1.5, reconnect_jitter=0.2
         )
         assert client is not None
+        with pytest.raises(ValueError, match="initial <= max"):
+            gmocoin.GmocoinDataClient(None, reconnect_initial_ms=10000, reconnect_max_ms=5000)
+        with pytest.raises(ValueError, match="multiplier"):
+            gmocoin.GmocoinDataClient(None, reconnect_multiplier=0.5)
+        with pytest.raises(ValueError, match="jitter"):
+            gmocoin.GmocoinDataClient(None, reconnect_jitter=1.5)
+
+    def test_stale_connection_reconnects_with_reason(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_ws_server()
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(
+            None, venue_profile=profile, stale_timeout_ms=200, reconnect_initial_ms=50,
+            maintenance_status_check=False,
+        )
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data)))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if events:
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        channel, event = events[0]
+        assert channel == "reconnect"
+        assert event["reason"] == "stale"
+        assert event["downtime_ms"] >= 0
+        assert event["attempts"] == 1
+        assert client.get_stats()["reconnect_reasons"] == {"stale": 1}
+
+    def test_event_gap_alarm(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_ws_server()
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, event_gap_alarm_ms=200, maintenance_status_check=False)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data)))
+
+        async def run():
+            await client.connect()
+            await asyncio.sleep(1.0)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        # One alarm per silence, however long it lasts
+        assert [channel for channel, _ in events] == ["latency_alarm"]
+        assert events[0][1]["kind"] == "event_gap"
+        assert events[0][1]["threshold_ms"] == 200
+        assert events[0][1]["latency_ms"] > 200
+
+    def test_undecodable_frames_reported(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        bad_ticker = '{"channel":"ticker","symbol":"BTC","ask":[]}'
+        server = _start_ws_server(["not json", bad_ticker])
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data)))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if len(events) >= 2:
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [channel for channel, _ in events] == ["parse_error", "parse_error"]
+        assert events[0][1]["channel"] is None
+        assert events[0][1]["raw"] == "not json"
+        assert events[1][1]["channel"] == "ticker"
+        assert events[1][1]["raw"] == bad_ticker
+        assert events[1][1]["error"]
+        assert client.get_stats()["errors"]["parse"] == 2
+
+    def test_instrument_refresh_reports_changes(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        btc = {"symbol": "BTC", "tickSize": "1", "sizeStep": "0.0001", "minOrderSize": "0.0001", "takerFee": "0.0005", "makerFee": "-0.0001"}
+        eth = {"symbol": "ETH", "tickSize": "1", "sizeStep": "0.001", "minOrderSize": "0.01"}
+        polls = [[btc, eth], [], [dict(btc, tickSize="1.0", sizeStep="0.001"), {"symbol": "XRP", "tickSize": "0.001"}]]
+
+        server = _start_http_server({"GET": lambda r: polls.pop(0) if len(polls) > 1 else polls[0]})
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url)
+        client = gmocoin.GmocoinDataClient(
+            None, venue_profile=profile, instrument_refresh_ms=50, maintenance_status_check=False,
+        )
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data)))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if len(events) >= 3:
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [channel for channel, _ in events] == ["instrument"] * 3
+        by_symbol = {event["symbol"]: event for _, event in events}
+        assert by_symbol["BTC"]["action"] == "changed"
+        assert by_symbol["BTC"]["changes"] == {"sizeStep": {"old": "0.0001", "new": "0.001"}}
+        assert by_symbol["BTC"]["info"]["sizeStep"] == "0.001"
+        assert by_symbol["XRP"]["action"] == "added"
+        assert by_symbol["XRP"]["info"]["tickSize"] == "0.001"
+        assert by_symbol["ETH"]["action"] == "removed"
+        assert by_symbol["ETH"]["info"] is None
+
+    def test_create_with_stale_timeout(self):
+        from nautilus_gmocoin import gmocoin
+        assert gmocoin.GmocoinDataClient(None, stale_timeout_ms=30000) is not None
+        assert gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None, stale_timeout_ms=30000
+        ) is not None
+
+    def test_async_context_manager(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinDataClient(None, venue_profile=UNREACHABLE_PROFILE)
+
+        async def run():
+            async with client as entered:
+                assert entered is client
+            with pytest.raises(KeyError):
+                async with client:
+                    raise KeyError("propagates")
+
+        asyncio.run(run())
+
+    def test_subscribe_all(self):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        ws = _start_ws_server(received=received)
+        server = _start_http_server({"GET": [{"symbol": "BTC"}, {"symbol": "ETH"}, {"symbol": "BTC_JPY"}]})
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url,
+                       ws_public=f"ws://127.0.0.1:{ws.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(100.0, venue_profile=profile, maintenance_status_check=False)
+        offline = gmocoin.GmocoinDataClient(None, venue_profile=UNREACHABLE_PROFILE)
+
+        async def run():
+            await client.connect()
+            symbols = await client.subscribe_all("trades", "TAKER_ONLY")
+            for _ in range(50):
+                if len(received) >= 3:
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+            with pytest.raises(RuntimeError):
+                await offline.subscribe_all("ticker")
+            return symbols
+
+        symbols = asyncio.run(run())
+        server.shutdown()
+        ws.shutdown()
+        assert symbols == ["BTC", "ETH", "BTC_JPY"]
+        commands = [json.loads(text) for text in received]
+        assert sorted(c["symbol"] for c in commands) == ["BTC", "BTC_JPY", "ETH"]
+        assert {(c["command"], c["channel"], c.get("option")) for c in commands} == {("subscribe", "trades", "TAKER_ONLY")}
+
+    def test_set_subscriptions(self):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        ws = _start_ws_server(received=received)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{ws.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(100.0, venue_profile=profile, maintenance_status_check=False)
+
+        async def wait_for(count):
+            for _ in range(50):
+                if len(received) >= count:
+                    return
+                await asyncio.sleep(0.1)
+
+        async def run():
+            await client.connect()
+            first = await client.set_subscriptions([("ticker", "BTC"), ("ticker", "ETH"), ("trades", "BTC", "TAKER_ONLY")])
+            await wait_for(3)
+            # Rotate ETH out for XRP and drop the trades filter
+            second = await client.set_subscriptions([("ticker", "BTC"), ("ticker", "XRP"), ("trades", "BTC")])
+            await wait_for(7)
+            unchanged = await client.set_subscriptions([("ticker", "BTC"), ("ticker", "XRP"), ("trades", "BTC")])
+            await asyncio.sleep(0.2)
+            await client.close()
+            return first, second, unchanged
+
+        first, second, unchanged = asyncio.run(run())
+        ws.shutdown()
+        assert first == {"subscribed": [["ticker", "BTC", None], ["ticker", "ETH", None], ["trades", "BTC", "TAKER_ONLY"]],
+                         "unsubscribed": []}
+        assert second == {"subscribed": [["ticker", "XRP", None], ["trades", "BTC", None]],
+                          "unsubscribed": [["ticker", "ETH", None], ["trades", "BTC", "TAKER_ONLY"]]}
+        assert unchanged == {"subscribed": [], "unsubscribed": []}
+        commands = [json.loads(text) for text in received]
+        assert len(commands) == 7
+        # Unsubscribes go out before the subscribes that replace them
+        assert [(c["command"], c["channel"], c["symbol"]) for c in commands[3:]] == [
+            ("unsubscribe", "ticker", "ETH"), ("unsubscribe", "trades", "BTC"),
+            ("subscribe", "ticker", "XRP"), ("subscribe", "trades", "BTC"),
+        ]
+
+    def test_callback_filter(self):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def ticker(symbol):
+            return json.dumps({"channel": "ticker", "ask": "1", "bid": "1", "high": "1", "low": "1", "last": "1",
+                               "symbol": symbol, "timestamp": "2024-01-01T00:00:00.000Z", "volume": "1"})
+
+        def trade(symbol):
+            return json.dumps({"channel": "trades", "price": "1", "side": "BUY", "size": "1",
+                               "timestamp": "2024-01-01T00:00:00.000Z", "symbol": symbol})
+
+        frames = [ticker("BTC"), ticker("ETH"), trade("BTC"), trade("XRP"), ticker("XRP"), trade("ETH")]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False,
+                                           callback_symbols=["BTC", "XRP"], callback_channels=["ticker"])
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data.symbol)))
+        unfiltered = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False,
+                                               callback_channels=["ticker"])
+        unfiltered.set_callback_filter()
+        all_events = []
+        unfiltered.set_data_callback(lambda channel, data: all_events.append((channel, data.symbol)))
+
+        async def run():
+            await client.connect()
+            await unfiltered.connect()
+            for _ in range(50):
+                if len(all_events) >= len(frames):
+                    break
+                await asyncio.sleep(0.1)
+            await asyncio.sleep(0.2)
+            await client.close()
+            await unfiltered.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        assert events == [("ticker", "BTC"), ("ticker", "XRP")]
+        assert len(all_events) == len(frames)
+
+    def test_ticker_conflation(self):
+        import asyncio
+        import json
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def ticker(symbol, last):
+            return json.dumps({"channel": "ticker", "ask": last, "bid": last, "high": last, "low": last, "last": last,
+                               "symbol": symbol, "timestamp": "2024-01-01T00:00:00.000Z", "volume": "1"})
+
+        frames = [ticker("BTC", str(last)) for last in range(1, 6)] + [ticker("ETH", "1")]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False,
+                                           ticker_max_rate=2.0)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((data.symbol, data.last, time.monotonic())))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if ("BTC", "5") in [(symbol, last) for symbol, last, _ in events]:
+                    break
+                await asyncio.sleep(0.1)
+            await asyncio.sleep(0.2)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        # The first update of each symbol goes out at once, the latest held one
+        # after the interval; 2-4 are dropped
+        assert [(symbol, last) for symbol, last, _ in events] == [("BTC", "1"), ("ETH", "1"), ("BTC", "5")]
+        assert events[2][2] - events[0][2] >= 0.4
+
+    def test_book_interval(self):
+        import asyncio
+        import json
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def book(symbol, second):
+            return json.dumps({"channel": "orderbooks", "asks": [{"price": "2", "size": "1"}],
+                               "bids": [{"price": "1", "size": "1"}], "symbol": symbol,
+                               "timestamp": f"2024-01-01T00:00:0{second}.000Z"})
+
+        frames = [book("BTC", 1), book("BTC", 2), book("ETH", 1), book("BTC", 3)]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False,
+                                           book_interval_ms=300)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((data.symbol, data.timestamp[17:19], time.monotonic())))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if len(events) >= 3:
+                    break
+                await asyncio.sleep(0.1)
+            await asyncio.sleep(0.2)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        # The first book goes out at once; the rest are sampled together,
+        # each symbol once in its latest state
+        assert [(symbol, second) for symbol, second, _ in events] == [("BTC", "01"), ("BTC", "03"), ("ETH", "01")]
+        assert events[1][2] - events[0][2] >= 0.25
+
+    def test_book_modes(self):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def book(symbol, asks):
+            return json.dumps({"channel": "orderbooks", "asks": [{"price": p, "size": s} for p, s in asks],
+                               "bids": [{"price": "1", "size": "1"}], "symbol": symbol,
+                               "timestamp": "2024-01-01T00:00:00.000Z"})
+
+        # Unchanged, a level below the touch resized, the best ask resized and a level removed
+        steps = [[("2", "1"), ("3", "1")], [("2", "1"), ("3", "1")], [("2", "1"), ("3", "2")], [("2", "5")]]
+        frames = [book(symbol, asks) for asks in steps for symbol in ("BTC", "ETH", "XRP")]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((channel, data)))
+
+        async def run():
+            with pytest.raises(ValueError):
+                await client.subscribe("ticker", "BTC", book_mode="delta")
+            with pytest.raises(ValueError):
+                await client.subscribe("orderbooks", "BTC", book_mode="full")
+            await client.subscribe("orderbooks", "BTC", book_mode="delta")
+            await client.subscribe("orderbooks", "ETH", book_mode="bbo")
+            await client.subscribe("orderbooks", "XRP")
+            await client.connect()
+            for _ in range(50):
+                if client.get_stats().get("book_churn", {}).get("XRP", {}).get("updates") == len(steps):
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+
+        def levels(entries):
+            return [(e.price, e.size) for e in entries]
+
+        deltas = [(d.snapshot, levels(d.asks), levels(d.bids)) for channel, d in events if channel == "book_deltas"]
+        assert deltas == [
+            (True, [("2", "1"), ("3", "1")], [("1", "1")]),
+            (False, [("3", "2")], []),
+            (False, [("2", "5"), ("3", "0")], []),
+        ]
+        bbos = [(b.ask_price, b.ask_size, b.bid_price, b.bid_size) for channel, b in events if channel == "bbo"]
+        assert bbos == [("2", "1", "1", "1"), ("2", "5", "1", "1")]
+        assert [data.symbol for channel, data in events if channel == "orderbooks"] == ["XRP"] * len(steps)
+        # Every update is still applied whatever the mode
+        assert {s: c["updates"] for s, c in client.get_stats()["book_churn"].items()} == {"BTC": 4, "ETH": 4, "XRP": 4}
+
+    def test_book_churn_stats(self):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def book(asks):
+            return json.dumps({"channel": "orderbooks", "asks": [{"price": p, "size": s} for p, s in asks],
+                               "bids": [{"price": "1", "size": "1"}], "symbol": "BTC",
+                               "timestamp": "2024-01-01T00:00:00.000Z"})
+
+        # New book (touch, 2 levels), unchanged, best ask resized plus a level added
+        frames = [book([("2", "1")]), book([("2", "1")]), book([("2", "3"), ("3", "1")])]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False)
+        assert "book_churn" not in client.get_stats()
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if client.get_stats().get("book_churn", {}).get("BTC", {}).get("updates") == len(frames):
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        churn = client.get_stats()["book_churn"]["BTC"]
+        assert churn["updates"] == 3
+        assert abs(churn["touch_change_ratio"] - 2 / 3) < 1e-9
+        assert abs(churn["avg_levels_changed"] - 4 / 3) < 1e-9
+        assert churn["updates_per_sec"] >= 0
+
+    def test_trades_backfill(self):
+        import asyncio
+        import json
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def trade(second, price):
+            return {"price": price, "side": "BUY", "size": "0.01", "timestamp": f"2024-01-01T00:00:0{second}.000Z"}
+
+        def trades(request):
+            # Slow enough for the live trades to arrive meanwhile
+            time.sleep(0.5)
+            return {
+                "pagination": {"currentPage": 1, "count": 3},
+                "list": [trade(3, "103"), trade(2, "102"), trade(1, "101")],
+            }
+
+        http = _start_http_server({"GET /v1/trades": trades})
+        # The newest backfilled trade again, then a new one
+        frames = [json.dumps(dict(trade(3, "103"), channel="trades", symbol="BTC")),
+                  json.dumps(dict(trade(4, "104"), channel="trades", symbol="BTC"))]
+        server = _start_ws_server(frames)
+        profile = dict(UNREACHABLE_PROFILE, rest_public=http.url, rest_private=http.url,
+                       ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False)
+        events = []
+        client.set_data_callback(lambda channel, data: events.append((data.price, data.historical)))
+
+        async def run():
+            with pytest.raises(ValueError, match="only supported for trades"):
+                await client.subscribe("ticker", "BTC", backfill=2)
+            # The server sends its frames on connect: connect while the
+            # backfill is being fetched, as live trades would arrive then
+            subscribing = asyncio.ensure_future(client.subscribe("trades", "BTC", backfill=2))
+            await asyncio.sleep(0.1)
+            await client.connect()
+            await subscribing
+            for _ in range(30):
+                if len(events) >= 3:
+                    break
+                await asyncio.sleep(0.1)
+            await asyncio.sleep(0.2)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        http.shutdown()
+        assert events == [("102", True), ("103", True), ("104", False)]
+
+    def test_trades_backfill_seam(self):
+        import asyncio
+        import json
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def trade(second, price):
+            return {"price": price, "side": "BUY", "size": "0.01", "timestamp": f"2024-01-01T00:00:{second:02}.000Z"}
+
+        backfill = [trade(3, "103"), trade(2, "102"), trade(1, "101")]
+
+        def backfill_with(refetch, live):
+            """Events of a 2-trade backfill whose seam is re-checked with
+            `refetch` (newest first) while `live` trades arrive."""
+            responses = [backfill, refetch]
+
+            def trades(request):
+                time.sleep(0.3)
+                return {"list": responses.pop(0)}
+
+            http = _start_http_server({"GET /v1/trades": trades})
+            server = _start_ws_server([json.dumps(dict(t, channel="trades", symbol="BTC")) for t in live])
+            profile = dict(UNREACHABLE_PROFILE, rest_public=http.url, rest_private=http.url,
+                           ws_public=f"ws://127.0.0.1:{server.server_address[1]}")
+            client = gmocoin.GmocoinDataClient(None, venue_profile=profile, maintenance_status_check=False)
+            events = []
+            client.set_data_callback(lambda channel, data: events.append(
+                ("gap", data["after"], data["before"]) if channel == "trade_gap" else (data.price, data.historical)
+            ))
+
+            async def run():
+                subscribing = asyncio.ensure_future(client.subscribe("trades", "BTC", backfill=2))
+                await asyncio.sleep(0.1)
+                await client.connect()
+                await subscribing
+                await asyncio.sleep(0.3)
+                await client.close()
+
+            asyncio.run(run())
+            server.shutdown()
+            http.shutdown()
+            assert responses == []
+            return events
+
+        # Live starts after the backfill; REST still reaches back to it, so
+        # the trade in between is filled in and the live copy of 105 dropped
+        events = backfill_with([trade(5, "105"), trade(4, "104")] + backfill, [trade(5, "105"), trade(6, "106")])
+        assert events == [("102", True), ("103", True), ("104", True), ("105", True), ("106", False)]
+
+        # A full page that no longer reaches the backfill: a gap
+        page = [trade(10, str(200 + i)) for i in range(100)]
+        events = backfill_with(page, [trade(20, "300")])
+        assert events[:3] == [("102", True), ("103", True), ("gap", "2024-01-01T00:00:03.000Z", "2024-01-01T00:00:10.000Z")]
+        assert len(events) == 3 + 100 + 1
+        assert all(historical for _, historical in events[3:-1])
+        assert events[-1] == ("300", False)
+
+    def test_subscribe_unknown_symbol(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({"GET": [{"symbol": "BTC"}]})
+        profile = dict(UNREACHABLE_PROFILE, rest_public=server.url)
+        client = gmocoin.GmocoinDataClient(None, venue_profile=profile)
+        offline = gmocoin.GmocoinDataClient(None, venue_profile=UNREACHABLE_PROFILE)
+
+        async def run():
+            await client.subscribe("ticker", "BTC")
+            with pytest.raises(ValueError, match="Unknown symbol 'BTCC'"):
+                await client.subscribe("ticker", "BTCC")
+            # Not checked when /v1/symbols cannot be fetched
+            await offline.subscribe("ticker", "BTCC")
+
+        asyncio.run(run())
+        server.shutdown()
+
+
+@requires_rust_extension
+class TestExecutionClientCreate:
+    def test_create(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None
+        )
+        assert client is not None
+
+    def test_get_stats(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None
+        )
+        stats = client.get_stats()
+        assert stats["orders_cached"] == 0
+        assert stats["reconnects"] == 0
+        assert stats["reconnect_reasons"] == {}
+        assert stats["token_refreshes"] == 0
+
+    def test_get_stats_from_callback(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps({
+            "channel": "orderEvents", "orderId": 5, "symbol": "BTC", "side": "SELL", "executionType": "LIMIT",
+            "settleType": "OPEN", "orderSize": "0.02", "orderExecutedSize": "0", "orderPrice": "6000000",
+            "losscutPrice": "0", "orderStatus": "ORDERED", "timeInForce": "FAS",
+            "orderTimestamp": "2024-01-01T00:00:00.000Z", "msgType": "NOR",
+        })}) + "\n")
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None, venue_profile=UNREACHABLE_PROFILE)
+        seen = []
+        # The callback runs on a runtime worker thread
+        client.set_order_callback(lambda event_type, data: seen.append(client.get_stats()["orders_cached"]))
+
+        async def run():
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        assert seen == [1]
+
+    def test_create_with_maintenance_windows(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None, maintenance_windows=["15:00-16:00"]
+        )
+        assert client is not None
+        with pytest.raises(ValueError, match="maintenance window"):
+            gmocoin.GmocoinExecutionClient(
+                "test_key", "test_secret", 10000, None, None, maintenance_windows=["16:00-16:00"]
+            )
+
+    def test_create_with_reconnect_backoff(self):
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None, reconnect_initial_ms=500, reconnect_jitter=0.5
+        )
+        assert client is not None
+        with pytest.raises(ValueError, match="initial <= max"):
+            gmocoin.GmocoinExecutionClient("test_key", "test_secret", 10000, None, None, reconnect_initial_ms=0)
+
+    def test_async_context_manager(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None, venue_profile=UNREACHABLE_PROFILE
+        )
+
+        async def run():
+            async with client as entered:
+                assert entered is client
+
+        asyncio.run(run())
+
+    SUMMARIES = [
+        {"symbol": "BTC_JPY", "side": "BUY", "sumPositionQuantity": "0.5", "averagePositionRate": "10000001", "positionLossGain": "0"},
+        {"symbol": "ETH_JPY", "side": "SELL", "sumPositionQuantity": "2", "averagePositionRate": "500000", "positionLossGain": "0"},
+    ]
+
+    def test_project_rollover_cost(self):
+        from nautilus_gmocoin import gmocoin
+        projection = gmocoin.project_rollover_cost(self.SUMMARIES)
+        assert [p["fee"] for p in projection["positions"]] == ["2000", "400"]
+        assert projection["positions"][0]["notional"] == "5000000.5"
+        assert projection["total"] == "2400"
+        assert gmocoin.project_rollover_cost(self.SUMMARIES, fee_rate=0.001)["total"] == "6000"
+        with pytest.raises(ValueError, match="fee rate"):
+            gmocoin.project_rollover_cost(self.SUMMARIES, fee_rate=-0.1)
+
+    def test_rollover_projection_event(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({
+            "GET /v1/positionSummary": {"list": self.SUMMARIES},
+            "GET /v1/account/margin": {"actualProfitLoss": "1000000", "availableAmount": "900000", "profitLoss": "0"},
+            "POST": _Reply(status=503),
+        })
+        url = server.url
+        # A lead longer than a day projects the next rollover right away
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            maintenance_status_check=False, rollover_lead_ms=86_400_001,
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        async def run():
+            await client.connect()
+            for _ in range(50):
+                if any(event_type == "RolloverProjection" for event_type, _ in events):
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        projection = next(data for event_type, data in events if event_type == "RolloverProjection")
+        assert projection["total"] == "2400"
+        assert projection["fee_rate"] == "0.0004"
+        assert projection["rollover_at"].endswith("T21:00:00.000Z")  # 06:00 JST
+        assert [p["symbol"] for p in projection["positions"]] == ["BTC_JPY", "ETH_JPY"]
+
+    def test_execution_quality(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({"POST": "123"})
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        def fill(execution_id, price):
+            return {
+                "channel": "executionEvents", "executionId": execution_id, "orderId": 123,
+                "symbol": "BTC", "side": "BUY", "executionSize": "0.005", "executionPrice": price,
+                "fee": "0", "executionTimestamp": "2024-01-01T00:00:00.000Z",
+            }
+
+        fixture = tmp_path / "fills.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(fill(i, price))}) + "\n"
+            for i, price in [(1, "10002000"), (2, "10004000")]
+        ))
+
+        async def run():
+            await client.submit_order(
+                "BTC", "0.01", "BUY", "MARKET", "c-1",
+                arrival_bid="9999000", arrival_ask="10001000",
+            )
+            order = client.get_execution_quality(123)
+            assert order["filled_size"] == "0" and order["time_to_fill_ms"] is None
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        server.shutdown()
+        order = client.get_execution_quality(123)
+        assert order["arrival"]["mid"] == "10000000"
+        assert order["avg_fill_price"] == "10003000"
+        assert order["arrival_slippage_bps"] == "3"
+        assert order["filled_size"] == "0.01"
+        assert order["time_to_fill_ms"] is not None
+        report = client.get_execution_quality()
+        assert report["summary"]["orders"] == 1
+        assert report["summary"]["filled_orders"] == 1
+        assert report["summary"]["mean_arrival_slippage_bps"] == "3"
+        assert client.get_execution_quality(999) is None
+        client.clear_execution_quality()
+        assert client.get_execution_quality()["orders"] == []
+
+    def test_twap(self, tmp_path):
+        import asyncio
+        import itertools
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        order_ids = itertools.count(1)
+        received = []
+        server = _start_http_server({"POST": lambda r: str(next(order_ids))}, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        with pytest.raises(ValueError, match="slices"):
+            client.start_twap("BTC", "BUY", "0.03", 300, 0)
+        with pytest.raises(ValueError, match="data client"):
+            client.start_twap("BTC", "BUY", "0.03", 300, 3, participation_cap="0.1")
+
+        fixture = tmp_path / "fills.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps({
+                "channel": "executionEvents", "executionId": order_id, "orderId": order_id,
+                "symbol": "BTC", "side": "BUY", "executionSize": "0.01", "executionPrice": price,
+                "fee": "0", "executionTimestamp": "2024-01-01T00:00:00.000Z",
+            })}) + "\n"
+            for order_id, price in [(1, "100"), (2, "101"), (3, "102")]
+        ))
+
+        async def run():
+            algo_id = client.start_twap("BTC", "buy", "0.03", 300, 3, size_step="0.001")
+            await asyncio.sleep(0.6)
+            assert client.get_algo(algo_id)["slices_done"] == 3
+            await client.replay_session(str(fixture))
+            for _ in range(50):
+                if client.get_algo(algo_id)["state"] != "running":
+                    break
+                await asyncio.sleep(0.1)
+            return algo_id
+
+        algo_id = asyncio.run(run())
+        server.shutdown()
+        submitted = [r.body for r in received if r.method == "POST"]
+        assert [(o["size"], o["executionType"]) for o in submitted] == [("0.01", "MARKET")] * 3
+        progress = client.get_algo(algo_id)
+        assert progress["state"] == "completed"
+        assert progress["filled"] == "0.03"
+        assert progress["remaining"] == "0"
+        assert progress["avg_price"] == "101"
+        assert progress["children"] == [1, 2, 3]
+        assert any(event_type == "AlgoProgress" for event_type, _ in events)
+        assert client.get_algo(999) is None
+        assert not client.cancel_algo(algo_id)
+
+    def test_amend_order(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        order = {
+            "orderId": 123, "rootOrderId": 123, "symbol": "BTC", "side": "BUY", "executionType": "LIMIT",
+            "settleType": "OPEN", "size": "0.03", "executedSize": "0.01", "price": "100",
+            "losscutPrice": "0", "status": "ORDERED", "timeInForce": "FAS",
+            "timestamp": "2024-01-01T00:00:00.000Z",
+        }
+        received = []
+        replacements = iter(["456", None])
+        server = _start_http_server({
+            "GET": {"list": [order]},
+            "POST": None,
+            "POST /v1/order": lambda r: next(replacements),
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            in_place = await client.amend_order("123", price="101")
+            replaced = await client.amend_order("123", size="0.05")
+            with pytest.raises(ValueError, match="price or a size"):
+                await client.amend_order("123")
+            with pytest.raises(ValueError, match="already executed"):
+                await client.amend_order("123", size="0.01")
+            # The replacement may be live without an id to track it by
+            with pytest.raises(RuntimeError, match="No order id"):
+                await client.amend_order("123", size="0.05")
+            return in_place, replaced
+
+        in_place, replaced = asyncio.run(run())
+        server.shutdown()
+        posted = [(r.path, r.body) for r in received if r.method == "POST"]
+        assert in_place == {"order_id": 123, "previous_order_id": 123, "replaced": False, "price": "101", "size": None}
+        assert replaced == {"order_id": 456, "previous_order_id": 123, "replaced": True, "price": "100", "size": "0.04"}
+        assert [path for path, _ in posted] == [
+            "/v1/changeOrder", "/v1/cancelOrder", "/v1/order", "/v1/cancelOrder", "/v1/order",
+        ]
+        assert posted[2][1] == {
+            "symbol": "BTC", "side": "BUY", "executionType": "LIMIT", "size": "0.04", "price": "100",
+            "timeInForce": "FAS", "losscutPrice": "0", "settleType": "OPEN",
+        }
+
+    def test_amend_price_clamps_to_tick(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        orders = {
+            1: {"side": "BUY", "price": "100"},
+            2: {"side": "SELL", "price": "100"},
+        }
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC", "tickSize": "0.5"}],
+            "GET /v1/orders": lambda r: {"list": [dict(
+                orders[int(r.query["orderId"])], orderId=int(r.query["orderId"]), symbol="BTC",
+                executionType="LIMIT", size="0.01", executedSize="0", status="ORDERED",
+                timestamp="2024-01-01T00:00:00.000Z",
+            )]},
+            "POST": None,
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            buy = await client.amend_price("1", "101.7")
+            sell = await client.amend_price("2", "101.2")
+            with pytest.raises(ValueError, match="already at price 100"):
+                await client.amend_price("1", "100.3")
+            return buy, sell
+
+        buy, sell = asyncio.run(run())
+        server.shutdown()
+        assert buy["price"] == "101.5"
+        assert sell["price"] == "101.5"
+        posted = [r.body for r in received if r.method == "POST"]
+        assert posted == [{"orderId": 1, "price": "101.5"}, {"orderId": 2, "price": "101.5"}]
+
+    def test_requote_coalesces_when_throttled(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC", "tickSize": "1"}],
+            "GET /v1/orders": lambda r: {"list": [{
+                "orderId": int(r.query["orderId"]), "symbol": "BTC", "side": "BUY", "executionType": "LIMIT",
+                "size": "0.01", "executedSize": "0", "price": "100", "status": "ORDERED",
+                "timestamp": "2024-01-01T00:00:00.000Z",
+            }]},
+            "POST": None,
+        }, received)
+        url = server.url
+        # 5 order tokens; once one is spent, re-quotes wait for the bucket to refill past 4.8
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None, group_rate_limits={"order": 5.0},
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            requote_reserve=4.8,
+        )
+
+        async def run():
+            first = await client.requote("1", "101")
+            pending = []
+            for price in ["102", "103", "104"]:
+                pending.append(client.requote("2", price))
+                await asyncio.sleep(0.01)
+            return first, await asyncio.gather(*pending)
+
+        first, queued = asyncio.run(run())
+        server.shutdown()
+        assert first["status"] == "sent"
+        assert first["amendment"]["price"] == "101"
+        assert [q["status"] for q in queued] == ["superseded", "superseded", "sent"]
+        assert queued[2]["amendment"]["price"] == "104"
+        posted = [r.body for r in received if r.method == "POST"]
+        assert posted == [{"orderId": 1, "price": "101"}, {"orderId": 2, "price": "104"}]
+
+    def test_duplicate_submissions(self):
+        import asyncio
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def order(request):
+            size = request.body["size"]
+            if size == "0.03":
+                time.sleep(0.5)  # response lost to the client timeout
+            if size == "0.02":
+                return _Reply({"status": 1, "messages": [{"message_code": "ERR-201", "message_string": "Insufficient funds"}]})
+            return "" if size == "0.04" else "123"
+
+        received = []
+        server = _start_http_server({"POST": order}, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            first = await client.submit_order("BTC", "0.01", "BUY", "MARKET", "c-1")
+            retry = await client.submit_order("BTC", "0.01", "BUY", "MARKET", "c-1")
+            assert first == {"order_id": 123, "duplicate": False, "recovered": False}
+            assert retry == {"order_id": 123, "duplicate": True}
+            # A rejection releases the id
+            for _ in range(2):
+                with pytest.raises(RuntimeError, match="Insufficient funds"):
+                    await client.submit_order("BTC", "0.02", "BUY", "MARKET", "c-2")
+            # A lost response does not
+            with pytest.raises(Exception):
+                await client.submit_order("BTC", "0.03", "BUY", "MARKET", "c-3", timeout_ms=100)
+            with pytest.raises(ValueError, match="ambiguously"):
+                await client.submit_order("BTC", "0.03", "BUY", "MARKET", "c-3")
+            client.forget_submission("c-3")
+            assert (await client.submit_order("BTC", "0.03", "BUY", "MARKET", "c-3"))["duplicate"] is False
+            # Accepted without an order id: placed, but untrackable
+            with pytest.raises(RuntimeError, match="No order id"):
+                await client.submit_order("BTC", "0.04", "BUY", "MARKET", "c-4")
+            with pytest.raises(ValueError, match="ambiguously"):
+                await client.submit_order("BTC", "0.04", "BUY", "MARKET", "c-4")
+
+        asyncio.run(run())
+        server.shutdown()
+        assert [r.body["size"] for r in received if r.method == "POST"] == ["0.01", "0.02", "0.02", "0.03", "0.03", "0.04"]
+        assert client.venue_order_id("c-1") == 123
+        assert client.venue_order_id("c-2") is None
+        assert client.venue_order_id("c-4") is None
+
+    def test_order_history(self):
+        import asyncio
+        from datetime import datetime, timezone
+        from nautilus_gmocoin import gmocoin
+
+        def fill(execution_id, order_id, size, price, ts, fee="0", loss_gain="0"):
+            return {
+                "executionId": execution_id, "orderId": order_id, "symbol": "BTC", "side": "BUY",
+                "settleType": "OPEN", "size": size, "price": price, "lossGain": loss_gain, "fee": fee,
+                "timestamp": ts,
+            }
+
+        def order(order_id, status, size, executed):
+            return {
+                "orderId": order_id, "rootOrderId": order_id, "symbol": "BTC", "side": "BUY",
+                "executionType": "LIMIT", "settleType": "OPEN", "size": size, "executedSize": executed,
+                "price": "5000000", "losscutPrice": "0", "status": status, "timeInForce": "FAS",
+                "timestamp": "2025-12-31T23:50:00.000Z",
+            }
+
+        inside, before = "2026-01-01T00:05:00.000Z", "2025-12-31T23:55:00.000Z"
+        pages = {
+            "1": [fill(i, 10, "0.001", "5000000" if i % 2 else "5000100", inside, fee="1") for i in range(100)],
+            "2": [
+                fill(200, 11, "0.01", "4900000", inside, loss_gain="-50"),
+                fill(201, 12, "0.01", "5000000", inside),
+                fill(202, 13, "0.01", "5000000", inside),
+                fill(203, 14, "0.01", "5000000", before),
+            ],
+        }
+        orders = {
+            10: order(10, "EXECUTED", "0.1", "0.1"),
+            11: order(11, "CANCELED", "0.05", "0.01"),
+            12: order(12, "ORDERED", "0.05", "0.01"),
+            13: order(13, "EXECUTED", "0.02", "0.02"),
+            14: order(14, "EXECUTED", "0.01", "0.01"),
+        }
+        received = []
+        server = _start_http_server({
+            "GET /v1/latestExecutions": lambda r: {"list": pages.get(r.query["page"], [])},
+            "GET /v1/orders": lambda r: {"list": [orders[int(i)] for i in r.query["orderId"].split(",")]},
+            "GET /v1/executions": {"list": [fill(202, 13, "0.01", "5000000", inside), fill(199, 13, "0.01", "5100000", before)]},
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        start = int(datetime(2026, 1, 1, tzinfo=timezone.utc).timestamp()) * 1_000_000_000
+
+        async def run():
+            return await client.get_order_history("BTC", start=start)
+
+        history = asyncio.run(run())
+        server.shutdown()
+
+        by_id = {o["order_id"]: o for o in history}
+        # Still working (12) and filled only before start (14) are left out
+        assert sorted(by_id) == [10, 11, 13]
+        assert by_id[10]["filled"] == "0.1"
+        assert by_id[10]["avg_price"] == "5000050"
+        assert by_id[10]["fee"] == "100"
+        assert by_id[10]["executions"] == 100
+        assert by_id[11]["status"] == "CANCELED"
+        assert by_id[11]["loss_gain"] == "-50"
+        # The fill before start is fetched for the order
+        assert by_id[13]["filled"] == "0.02"
+        assert by_id[13]["avg_price"] == "5050000"
+        assert by_id[13]["last_fill_at"] == start + 300 * 1_000_000_000
+        requested = [(r.path, r.query) for r in received]
+        assert ("/v1/executions", {"orderId": "13"}) in requested
+        assert [q["orderId"] for p, q in requested if p == "/v1/orders"] == ["10,11,12,13"]
+
+    def test_position_cache(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def summary(symbol, side, size, channel=None):
+            data = {
+                "symbol": symbol, "side": side, "sumPositionQuantity": size, "sumOrderQuantity": "0",
+                "averagePositionRate": "5000000", "positionLossGain": "100",
+            }
+            if channel:
+                data.update(channel=channel, timestamp="2024-01-01T00:00:00.000Z", msgType="UPDATE")
+            return data
+
+        rest_list = [summary("BTC_JPY", "BUY", "0.5"), summary("ETH_JPY", "SELL", "1")]
+        server = _start_http_server({"GET": {"list": rest_list}})
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            position_refresh_ms=0,
+        )
+        fixture = tmp_path / "positions.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(summary(*args, channel="positionSummaryEvents"))}) + "\n"
+            for args in [("BTC_JPY", "BUY", "0.7"), ("ETH_JPY", "SELL", "0"), ("BTC_JPY", "SELL", "0.1")]
+        ))
+
+        async def run():
+            assert client.get_position("BTC_JPY") is None
+            await client.refresh_positions()
+            position = client.get_position("BTC_JPY")
+            assert isinstance(position, gmocoin.PositionSnapshot)
+            assert (position.side, position.size, position.source) == ("BUY", "0.5", "rest")
+            assert client.get_position("ETH_JPY").side == "SELL"
+
+            await client.replay_session(str(fixture))
+            with pytest.raises(ValueError, match="both sides"):
+                client.get_position("BTC_JPY")
+            buy = client.get_position("BTC_JPY", "BUY")
+            assert (buy.size, buy.source, buy.avg_price, buy.loss_gain) == ("0.7", "ws", "5000000", "100")
+            # Flat sides are dropped
+            assert client.get_position("ETH_JPY") is None
+            assert [(p.symbol, p.side) for p in client.get_positions()] == [("BTC_JPY", "BUY"), ("BTC_JPY", "SELL")]
+
+            # A later REST snapshot replaces what it covers
+            await client.refresh_positions()
+            assert client.get_position("BTC_JPY", "sell") is None
+            assert client.get_position("BTC_JPY").size == "0.5"
+
+        asyncio.run(run())
+        server.shutdown()
+
+    def test_losscut_price_change(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC_JPY", "tickSize": "1"}],
+            "PUT": None,
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            position_refresh_ms=0,
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        def position(position_id, losscut, msg_type):
+            return {
+                "channel": "positionEvents", "positionId": position_id, "symbol": "BTC_JPY", "side": "BUY",
+                "size": "0.01", "orderdSize": "0", "price": "5000000", "lossGain": "0", "leverage": "2",
+                "losscutPrice": losscut, "timestamp": "2024-01-01T00:00:00.000Z", "msgType": msg_type,
+            }
+
+        fixture = tmp_path / "positions.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(message)}) + "\n"
+            for message in [position(1, "4000000", "UPR"), position(1, "4500000.0", "ULO"), position(2, "3000000", "ULO")]
+        ))
+
+        async def run():
+            # Formatted for the tick of the position's symbol
+            ack = await client.change_losscut_price(1, "4500000.4", symbol="BTC_JPY")
+            assert ack == {"position_id": 1, "losscut_price": "4500000"}
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        server.shutdown()
+        requested = [(r.path, r.body) for r in received if r.path != "/v1/symbols"]
+        assert requested == [("/v1/changeLosscutPrice", {"positionId": 1, "losscutPrice": "4500000"})]
+        updates = [data for event_type, data in events if event_type == "LosscutUpdated"]
+        assert updates == [
+            {"position_id": 1, "symbol": "BTC_JPY", "losscut_price": "4500000.0", "requested": "4500000", "confirmed": True, "account": "main"},
+            # Changed elsewhere (another session or the web UI)
+            {"position_id": 2, "symbol": "BTC_JPY", "losscut_price": "3000000", "requested": None, "confirmed": False, "account": "main"},
+        ]
+
+    def test_close_orders_are_tracked(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({"POST /v1/closeOrder": "701", "POST /v1/closeBulkOrder": "702"}, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            ack = await client.close_order("BTC_JPY", "SELL", "LIMIT", [(1, "0.01"), (2, "0.02")], price="5100000", client_order_id="C-1")
+            assert ack == {"order_id": 701, "duplicate": False}
+            ack = await client.close_bulk_order("BTC_JPY", "BUY", "MARKET", "0.05")
+            assert ack == {"order_id": 702, "duplicate": False}
+            # A retried client order id resolves to the order already placed
+            assert await client.close_order("BTC_JPY", "SELL", "LIMIT", [(1, "0.01")], client_order_id="C-1") == {"order_id": 701, "duplicate": True}
+            return await client.get_open_order_exposure("BTC_JPY")
+
+        exposure = asyncio.run(run())
+        server.shutdown()
+        assert [r.path for r in received if r.method == "POST"] == ["/v1/closeOrder", "/v1/closeBulkOrder"]
+        assert client.venue_order_id("C-1") == 701
+        assert exposure == [{
+            "symbol": "BTC_JPY", "open_orders": 2, "buy_orders": 1, "sell_orders": 1, "buy_size": "0.05",
+            "sell_size": "0.03", "best_buy_price": None, "best_sell_price": "5100000",
+        }]
+        assert client.get_execution_quality(701)["side"] == "SELL"
+        assert client.get_execution_quality(702) is not None
+
+    def test_state_checkpoint(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({"POST": "701"})
+        url = server.url
+
+        def client():
+            return gmocoin.GmocoinExecutionClient(
+                "test_key", "test_secret", 5000, None, None,
+                venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            )
+
+        old = client()
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps({
+            "channel": "orderEvents", "orderId": 5, "symbol": "BTC", "side": "SELL", "executionType": "LIMIT",
+            "settleType": "OPEN", "orderSize": "0.02", "orderExecutedSize": "0", "orderPrice": "6000000",
+            "losscutPrice": "0", "orderStatus": "ORDERED", "timeInForce": "FAS",
+            "orderTimestamp": "2024-01-01T00:00:00.000Z", "msgType": "NOR",
+        })}) + "\n")
+
+        async def run():
+            await old.submit_order("BTC", "0.01", "BUY", "LIMIT", "c-1", price="5000000")
+            await old.replay_session(str(fixture))
+
+        asyncio.run(run())
+        server.shutdown()
+        state = json.loads(json.dumps(old.export_state()))
+        assert state["version"] == 1
+        assert [o["orderId"] for o in state["orders"]] == [5]
+        assert state["submissions"] == [{"client_order_id": "c-1", "order_id": 701}]
+
+        new = client()
+        new.import_state(dict(state, submissions=state["submissions"] + [{"client_order_id": "c-2", "order_id": None}]))
+        assert new.venue_order_id("c-1") == 701
+
+        async def resubmit():
+            # Resubmitting an imported id does not place it again
+            assert (await new.submit_order("BTC", "0.01", "BUY", "LIMIT", "c-1", price="5000000"))["duplicate"]
+            with pytest.raises(ValueError, match="ambiguously"):
+                await new.submit_order("BTC", "0.01", "BUY", "LIMIT", "c-2", price="5000000")
+            return await new.get_open_order_exposure("BTC")
+
+        assert asyncio.run(resubmit())[0]["sell_size"] == "0.02"
+        assert new.get_stats()["orders_cached"] == 1
+        # A second import adds nothing
+        assert new.import_state(state) == {"orders": 0, "submissions": 0}
+        with pytest.raises(ValueError, match="version"):
+            new.import_state(dict(state, version=99))
+
+    def test_state_checkpoint_from_callback(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps({
+            "channel": "orderEvents", "orderId": 5, "symbol": "BTC", "side": "SELL", "executionType": "LIMIT",
+            "settleType": "OPEN", "orderSize": "0.02", "orderExecutedSize": "0", "orderPrice": "6000000",
+            "losscutPrice": "0", "orderStatus": "ORDERED", "timeInForce": "FAS",
+            "orderTimestamp": "2024-01-01T00:00:00.000Z", "msgType": "NOR",
+        })}) + "\n")
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None, venue_profile=UNREACHABLE_PROFILE)
+        other = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None, venue_profile=UNREACHABLE_PROFILE)
+        imported = []
+        # The callback runs on a runtime worker thread
+        client.set_order_callback(lambda event_type, data: imported.append(other.import_state(client.export_state())))
+
+        async def run():
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        assert imported == [{"orders": 1, "submissions": 0}]
+
+    def test_polling_fallback(self, tmp_path):
+        import asyncio
+        import datetime
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def now():
+            return datetime.datetime.now(datetime.timezone.utc).strftime("%Y-%m-%dT%H:%M:%S.%f")[:-3] + "Z"
+
+        def execution(execution_id, timestamp):
+            return {
+                "executionId": execution_id, "orderId": 5, "positionId": 1, "symbol": "BTC", "side": "SELL",
+                "settleType": "OPEN", "size": "0.01", "price": "6000000", "lossGain": "0", "fee": "0",
+                "timestamp": timestamp,
+            }
+
+        order = {
+            "orderId": 5, "rootOrderId": 5, "symbol": "BTC", "side": "SELL", "executionType": "LIMIT",
+            "settleType": "OPEN", "size": "0.02", "executedSize": "0.02", "price": "6000000", "losscutPrice": "0",
+            "status": "EXECUTED", "timeInForce": "FAS", "timestamp": "2024-01-01T00:00:00.000Z",
+        }
+        received = []
+        server = _start_http_server({
+            # Newest first: one missed fill, one delivered, one from before the client started
+            "GET /v1/latestExecutions": lambda r: {"list": [
+                execution(12, now()), execution(11, now()), execution(10, "2020-01-01T00:00:00.000Z"),
+            ]},
+            "GET /v1/activeOrders": {"list": []},
+            "GET /v1/orders": {"list": [order]},
+            "GET": {},
+            # No Private WS token, so the stream never comes up
+            "POST": _Reply({"status": 1, "messages": [{"message_code": "ERR-5201", "message_string": "MAINTENANCE"}]}),
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            maintenance_status_check=False, fallback_after_ms=100, fallback_interval_ms=100,
+        )
+        events = []
+        client.set_order_callback(lambda t, d: events.append((t, d)))
+        fixture = tmp_path / "session.jsonl"
+        fixture.write_text("".join(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(frame)}) + "\n" for frame in [
+            dict(order, channel="orderEvents", executedSize="0", status="ORDERED", msgType="NOR"),
+            dict(execution(11, now()), channel="executionEvents"),
+        ]))
+
+        async def run():
+            await client.replay_session(str(fixture))
+            await client.connect()
+            await asyncio.sleep(1.0)
+            await client.disconnect()
+
+        asyncio.run(run())
+        server.shutdown()
+        synthetic = [(t, d) for t, d in events if d.get("synthetic")]
+        assert [(t, d.get("executionId", d.get("status"))) for t, d in synthetic] == [("ExecutionUpdate", 12), ("OrderUpdate", "EXECUTED")]
+        fallback = [d for t, d in events if t == "PollingFallback"]
+        assert fallback[0]["active"] and fallback[0]["down_ms"] >= 100
+        # The finished order is no longer polled for
+        assert [r.path for r in received].count("/v1/orders") == 1
+        assert client.get_stats()["orders_cached"] == 1
+
+    def test_health(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({"GET": {"list": []}})
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        health = client.health()
+        # Nothing sent yet and the Private WS never connected
+        assert health["status"] == "degraded"
+        assert health["rest"] == {"reachable": None, "last_ok_ms": None, "last_failure_ms": None, "open_circuits": []}
+        assert health["private_ws"] == {"connected": False, "since_ms": None, "last_message_ms": None}
+        assert health["public_ws"] is None
+        assert sorted(health["rate_limits"]) == ["account", "order", "public"]
+        assert health["rate_limits"]["order"]["saturation"] == 0.0
+        assert not health["rate_limits"]["order"]["throttled"]
+
+        async def query():
+            return await client.get_active_orders("BTC")
+
+        asyncio.run(query())
+        assert client.health()["rest"]["reachable"] is True
+        server.shutdown()
+        server.server_close()
+        with pytest.raises(Exception):
+            asyncio.run(query())
+        health = client.health()
+        assert health["status"] == "down"
+        assert health["rest"]["reachable"] is False
+        assert health["rest"]["last_failure_ms"] >= health["rest"]["last_ok_ms"]
+
+        data_client = gmocoin.GmocoinDataClient(None, venue_profile=UNREACHABLE_PROFILE, maintenance_status_check=False)
+        client.attach_market_data(data_client)
+        assert client.health()["public_ws"]["connected"] is False
+        assert list(data_client.health()["rate_limits"]) == ["public"]
+
+    def test_order_ack_alarm(self):
+        import asyncio
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        def late(request):
+            # The second order is answered late
+            time.sleep(0.3)
+            return "801"
+
+        server = _start_http_server({"POST": "801", "POST /v1/closeOrder": late})
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            order_ack_alarm_ms=200,
+        )
+        events = []
+        client.set_order_callback(lambda t, d: events.append((t, d)))
+
+        async def run():
+            await client.submit_order("BTC", "0.01", "BUY", "LIMIT", "c-1", price="5000000")
+            await client.close_order("BTC", "SELL", "MARKET", [(1, "0.01")])
+
+        asyncio.run(run())
+        server.shutdown()
+        alarms = [d for t, d in events if t == "LatencyAlarm"]
+        assert len(alarms) == 1
+        assert alarms[0]["kind"] == "order_ack"
+        assert alarms[0]["order_id"] == 801
+        assert alarms[0]["symbol"] == "BTC"
+        assert alarms[0]["latency_ms"] >= 300 > alarms[0]["threshold_ms"] == 200
+
+    def test_order_event_kind(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def event(order_id, status, msg_type, cancel_type=""):
+            return {
+                "channel": "orderEvents", "orderId": order_id, "symbol": "BTC", "side": "BUY",
+                "executionType": "LIMIT", "settleType": "OPEN", "orderSize": "0.01",
+                "orderExecutedSize": "0", "orderPrice": "5000000", "losscutPrice": "0",
+                "orderStatus": status, "timeInForce": "FAS", "orderTimestamp": "2024-01-01T00:00:00.000Z",
+                "msgType": msg_type, "cancelType": cancel_type,
+            }
+
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(message)}) + "\n"
+            for message in [
+                event(1, "ORDERED", "NOR"), event(1, "ORDERED", "ROR"), event(1, "CANCELED", "COR", "USER"),
+                event(2, "EXPIRED", "ER", "EXPIRED_FAK"), event(3, "ORDERED", "XYZ"),
+                event(4, "CANCELED", "COR", "POSITION_LOSSCUT"), event(5, "CANCELED", "COR", "INSUFFICIENT_MARGIN"),
+                event(6, "CANCELED", "COR", "SOK_TAKER"), event(7, "CANCELED", "COR", "SOMETHING_NEW"),
+            ]
+        ))
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None, venue_profile=UNREACHABLE_PROFILE)
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        async def run():
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        updates = [data for event_type, data in events if event_type == "OrderUpdate"]
+        assert [(u["msgType"], u["orderEvent"]) for u in updates[:5]] == [
+            ("NOR", "NEW"), ("ROR", "AMENDED"), ("COR", "CANCELED"), ("ER", "EXPIRED"), ("XYZ", "UNKNOWN"),
+        ]
+        assert [u.get("cancelReason") for u in updates] == [
+            None, None, "USER_CANCELED", "EXPIRED", None, "LOSSCUT", "INSUFFICIENT_MARGIN", "POST_ONLY_REJECTED", "OTHER",
+        ]
+
+    def test_amend_lifecycle_events(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC", "tickSize": "1"}],
+            "GET /v1/orders": lambda r: {"list": [{
+                "orderId": int(r.query["orderId"]), "symbol": "BTC", "side": "BUY",
+                "executionType": "LIMIT", "size": "0.01", "executedSize": "0", "price": "5000000",
+                "status": "ORDERED", "timestamp": "2024-01-01T00:00:00.000Z",
+            }]},
+            "POST": None,
+        })
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        def event(order_id, price, status):
+            return {
+                "channel": "orderEvents", "orderId": order_id, "symbol": "BTC", "side": "BUY",
+                "executionType": "LIMIT", "settleType": "OPEN", "orderSize": "0.01",
+                "orderExecutedSize": "0", "orderPrice": price, "losscutPrice": "0",
+                "orderStatus": status, "timeInForce": "FAS", "orderTimestamp": "2024-01-01T00:00:00.000Z",
+                "msgType": "ROR",
+            }
+
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(message)}) + "\n"
+            for message in [
+                event(1, "5000000", "MODIFYING"), event(1, "5100000", "ORDERED"),
+                event(2, "4000000", "ORDERED"), event(3, "3000000", "ORDERED"),
+            ]
+        ))
+
+        async def run():
+            # Not cached yet: the order is fetched to format the price for it
+            await client.change_order("1", "5100000.4")
+            await client.change_order("2", "4100000")
+            await client.replay_session(str(fixture))
+
+        asyncio.run(run())
+        server.shutdown()
+        amends = [(event_type, data) for event_type, data in events if event_type.startswith("OrderAmend")]
+        assert amends == [
+            ("OrderAmendPending", {"order_id": 1, "price": "5100000", "losscut_price": None, "status": None, "order_price": None, "account": "main"}),
+            ("OrderAmendPending", {"order_id": 2, "price": "4100000", "losscut_price": None, "status": None, "order_price": None, "account": "main"}),
+            ("OrderAmended", {"order_id": 1, "price": "5100000", "losscut_price": None, "status": "ORDERED", "order_price": "5100000", "account": "main"}),
+            ("OrderAmendRejected", {"order_id": 2, "price": "4100000", "losscut_price": None, "status": "ORDERED", "order_price": "4000000", "account": "main"}),
+        ]
+
+    def test_open_order_exposure(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        def event(order_id, symbol, side, size, executed, price, status, execution_type="LIMIT"):
+            return {
+                "channel": "orderEvents", "orderId": order_id, "symbol": symbol, "side": side,
+                "executionType": execution_type, "settleType": "OPEN", "orderSize": size,
+                "orderExecutedSize": executed, "orderPrice": price, "losscutPrice": "0",
+                "orderStatus": status, "timeInForce": "FAS", "orderTimestamp": "2024-01-01T00:00:00.000Z",
+                "msgType": "NOR",
+            }
+
+        events = [
+            event(1, "BTC", "BUY", "0.03", "0.01", "5000000", "ORDERED"),
+            event(2, "BTC", "BUY", "0.01", "0", "5100000", "ORDERED"),
+            event(3, "BTC", "SELL", "0.02", "0", "5300000", "ORDERED"),
+            event(4, "BTC", "SELL", "0.05", "0", "5200000", "WAITING", execution_type="STOP"),
+            event(5, "BTC", "BUY", "0.01", "0", "5150000", "ORDERED"),
+            event(5, "BTC", "BUY", "0.01", "0", "5150000", "CANCELED"),
+            event(6, "ETH", "SELL", "1", "0", "300000", "MODIFYING"),
+        ]
+        fixture = tmp_path / "orders.jsonl"
+        fixture.write_text("".join(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(e)}) + "\n" for e in events))
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None, venue_profile=UNREACHABLE_PROFILE,
+        )
+
+        async def run():
+            await client.replay_session(str(fixture))
+            return await client.get_open_order_exposure(), await client.get_open_order_exposure("ETH")
+
+        everything, eth = asyncio.run(run())
+        btc = everything[0]
+        assert [e["symbol"] for e in everything] == ["BTC", "ETH"]
+        # The cancelled order is not working; the stop counts but sets no price
+        assert (btc["open_orders"], btc["buy_orders"], btc["sell_orders"]) == (4, 2, 2)
+        assert btc["buy_size"] == "0.03"
+        assert btc["sell_size"] == "0.07"
+        assert btc["best_buy_price"] == "5100000"
+        assert btc["best_sell_price"] == "5300000"
+        assert eth == [everything[1]]
+        assert eth[0]["best_buy_price"] is None
+
+    def test_export_executions(self, tmp_path):
+        import asyncio
+        import csv
+        from datetime import datetime, timezone
+        from nautilus_gmocoin import gmocoin
+
+        executions = {
+            "BTC_JPY": [
+                {"executionId": 3, "orderId": 30, "symbol": "BTC_JPY", "side": "SELL", "settleType": "CLOSE",
+                 "size": "0.01", "price": "5100000", "lossGain": "1000", "fee": "0", "timestamp": "2026-01-01T00:03:00.000Z"},
+                {"executionId": 1, "orderId": 10, "symbol": "BTC_JPY", "side": "BUY", "settleType": "OPEN",
+                 "size": "0.01", "price": "5000000", "lossGain": "0", "fee": "0", "timestamp": "2026-01-01T00:01:00.000Z"},
+            ],
+            "XRP": [
+                {"executionId": 2, "orderId": 20, "symbol": "XRP", "side": "BUY",
+                 "size": "100", "price": "80.123", "fee": "4", "timestamp": "2026-01-01T00:02:00.000Z"},
+            ],
+        }
+
+        server = _start_http_server({
+            "GET": lambda r: {"list": executions[r.query["symbol"]] if r.query["page"] == "1" else []},
+        })
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        end = int(datetime(2026, 1, 1, 0, 3, tzinfo=timezone.utc).timestamp()) * 1_000_000_000
+        path = tmp_path / "executions.csv"
+        columns = [
+            "execution_id", "order_id", "symbol", "side", "settle_type",
+            "size", "price", "loss_gain", "fee", "timestamp",
+        ]
+
+        async def run():
+            written = await client.export_executions(str(path), ["BTC_JPY", "XRP"])
+            assert written == 3
+            with pytest.raises(ValueError, match="format"):
+                await client.export_executions(str(path), ["XRP"], format="xlsx")
+            batch = await client.get_execution_history_arrow(["XRP"])
+            assert batch.num_rows == 1
+            assert batch.column_names == columns
+            return await client.export_executions(str(tmp_path / "window.csv"), ["BTC_JPY", "XRP"], end=end)
+
+        assert asyncio.run(run()) == 2
+        server.shutdown()
+
+        with open(path, newline="") as f:
+            rows = list(csv.reader(f))
+        assert rows[0] == columns
+        # Oldest first across symbols, amounts as GMO sent them
+        assert [r[0] for r in rows[1:]] == ["1", "2", "3"]
+        assert rows[2] == ["2", "20", "XRP", "BUY", "", "100", "80.123", "", "4", "2026-01-01T00:02:00.000Z"]
+        assert rows[3][7] == "1000"
+
+    def test_ambiguous_submission_recovery(self):
+        import asyncio
+        import time
+        from datetime import datetime, timezone
+        from nautilus_gmocoin import gmocoin
+
+        posted = []
+        active = []
+
+        def order(request):
+            posted.append(request.body["size"])
+            if len(posted) == 1:
+                # Reaches the venue, but the response is lost
+                active.append({
+                    "orderId": 555, "rootOrderId": 555, "symbol": "BTC", "side": "BUY",
+                    "executionType": "LIMIT", "settleType": "OPEN", "size": "0.010",
+                    "executedSize": "0", "price": "5000000", "losscutPrice": "0",
+                    "status": "ORDERED", "timeInForce": "FAS",
+                    "timestamp": datetime.now(timezone.utc).isoformat().replace("+00:00", "Z"),
+                })
+                time.sleep(0.5)
+            elif len(posted) == 2:
+                # Never reaches the venue
+                time.sleep(0.5)
+            return "777"
+
+        server = _start_http_server({"GET /v1/activeOrders": {"list": active}, "GET": {}, "POST": order})
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            found = await client.submit_order("BTC", "0.01", "BUY", "LIMIT", "r-1", price="5000000", timeout_ms=100)
+            assert found == {"order_id": 555, "duplicate": False, "recovered": True}
+            # The order found for r-1 is not claimed again
+            resent = await client.submit_order("BTC", "0.01", "BUY", "LIMIT", "r-2", price="5000000", timeout_ms=100)
+            assert resent == {"order_id": 777, "duplicate": False, "recovered": False}
+
+        asyncio.run(run())
+        server.shutdown()
+        assert posted == ["0.01", "0.01", "0.01"]
+        assert client.venue_order_id("r-1") == 555
+        assert client.venue_order_id("r-2") == 777
+
+    def test_max_order_size(self):
+        import asyncio
+        import itertools
+        from nautilus_gmocoin import gmocoin
+
+        order_ids = itertools.count(101)
+        received = []
+        server = _start_http_server({
+            "GET": [{"symbol": "BTC", "sizeStep": "0.0001", "minOrderSize": "0.01", "maxOrderSize": "5"}],
+            "POST": lambda r: str(next(order_ids)),
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+
+        async def run():
+            with pytest.raises(gmocoin.OrderTooLargeError) as excinfo:
+                await client.submit_order("BTC", "12.5", "BUY", "MARKET", "big-1")
+            within = await client.submit_order("BTC", "5", "BUY", "MARKET", "big-2", split=True)
+            split = await client.submit_order("BTC", "12.5", "BUY", "MARKET", "big-3", split=True)
+            # A 0.005 rest is below minOrderSize: the last full order tops it up
+            await client.submit_order("BTC", "10.005", "BUY", "MARKET", "big-4", split=True)
+            return excinfo.value, within, split
+
+        error, within, split = asyncio.run(run())
+        server.shutdown()
+        assert isinstance(error, ValueError)
+        assert (error.symbol, error.size, error.max_order_size) == ("BTC", "12.5000", "5")
+        assert within["order_ids"] == [101]
+        assert split == {"order_id": 102, "order_ids": [102, 103, 104], "duplicate": False, "recovered": False}
+        posted = [r.body["size"] for r in received if r.method == "POST"]
+        assert posted == ["5.0000", "5.0000", "5.0000", "2.5000", "5.0000", "4.9950", "0.0100"]
+        assert client.venue_order_id("big-3") == 102
+        assert client.venue_order_id("big-1") is None
+
+    def test_close_order_size_checks(self):
+        import asyncio
+        import itertools
+        from nautilus_gmocoin import gmocoin
+
+        order_ids = itertools.count(1)
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC_JPY", "sizeStep": "0.001", "minCloseOrderSize": "0.01"}],
+            "GET": {"list": [{
+                "symbol": "BTC_JPY", "side": "BUY", "sumPositionQuantity": "0.5", "sumOrderQuantity": "0.1",
+                "averagePositionRate": "5000000", "positionLossGain": "0",
+            }]},
+            "POST": lambda r: str(next(order_ids)),
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            position_refresh_ms=0,
+        )
+
+        async def run():
+            # Nothing to check against before the position cache is loaded
+            await client.close_bulk_order("BTC_JPY", "SELL", "MARKET", "1")
+            await client.refresh_positions()
+            with pytest.raises(ValueError, match="below minCloseOrderSize 0.01"):
+                await client.close_bulk_order("BTC_JPY", "SELL", "MARKET", "0.005")
+            # 0.1 of the 0.5 held is already being closed
+            with pytest.raises(ValueError, match="exceeds the 0.4 open on the BUY side"):
+                await client.close_bulk_order("BTC_JPY", "SELL", "MARKET", "0.5")
+            with pytest.raises(ValueError, match="exceeds the 0 open on the SELL side"):
+                await client.close_bulk_order("BTC_JPY", "BUY", "MARKET", "0.1")
+            with pytest.raises(ValueError, match="exceeds the 0.4 open"):
+                await client.close_order("BTC_JPY", "SELL", "MARKET", [(1, "0.3"), (2, "0.2")])
+            await client.close_order("BTC_JPY", "SELL", "MARKET", [(1, "0.3"), (2, "0.1")])
+            await client.close_bulk_order("BTC_JPY", "SELL", "MARKET", "0.4")
+
+        asyncio.run(run())
+        server.shutdown()
+        posted = [r.body for r in received if r.method == "POST"]
+        assert [p.get("size") for p in posted] == ["1.000", None, "0.400"]
+        assert posted[1]["settlePosition"] == [{"positionId": 1, "size": "0.300"}, {"positionId": 2, "size": "0.100"}]
+
+    def test_dead_man_switch(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC"}, {"symbol": "BTC_JPY"}],
+            "GET /v1/positionSummary": {"list": self.SUMMARIES},
+            "POST /v1/ws-auth": _Reply(status=503),
+            "POST": None,
+            # closeBulkOrder returns the closing order id
+            "POST /v1/closeBulkOrder": "900",
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            maintenance_status_check=False, deadman_timeout_ms=200, deadman_flatten=True,
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        def fired():
+            return [data for event_type, data in events if event_type == "DeadManTriggered"]
+
+        async def run():
+            await client.connect()
+            # Not armed before the first heartbeat
+            await asyncio.sleep(0.4)
+            assert not fired()
+            for _ in range(4):
+                client.heartbeat()
+                await asyncio.sleep(0.1)
+            assert not fired()
+            for _ in range(30):
+                if fired():
+                    break
+                await asyncio.sleep(0.1)
+            # Disarmed after firing
+            await asyncio.sleep(0.4)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        assert len(fired()) == 1
+        event = fired()[0]
+        assert event["idle_ms"] >= 200
+        assert event["cancelled_symbols"] == ["BTC", "BTC_JPY"]
+        assert event["errors"] == []
+        posted = [(r.path, r.body) for r in received if r.method == "POST" and r.path != "/v1/ws-auth"]
+        assert posted[0] == ("/v1/cancelBulkOrder", {"symbols": ["BTC", "BTC_JPY"]})
+        closes = [body for path, body in posted if path == "/v1/closeBulkOrder"]
+        assert [(c["symbol"], c["side"], c["executionType"]) for c in closes] == [
+            ("BTC_JPY", "SELL", "MARKET"), ("ETH_JPY", "BUY", "MARKET"),
+        ]
+
+    def test_replay_controls(self, tmp_path):
+        import asyncio
+        import json
+        import time
+        from nautilus_gmocoin import gmocoin
+
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None,
+                                                venue_profile=UNREACHABLE_PROFILE)
+        events = []
+        client.set_order_callback(
+            lambda event_type, data: events.append(data["executionId"]) if event_type == "ExecutionUpdate" else None
+        )
+        fixture = tmp_path / "session.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 1000 * i, "dir": "recv", "text": json.dumps({
+                "channel": "executionEvents", "executionId": i, "orderId": i,
+                "symbol": "BTC", "side": "BUY", "executionSize": "0.01", "executionPrice": "100",
+                "fee": "0", "executionTimestamp": "2024-01-01T00:00:00.000Z",
+            })}) + "\n"
+            for i in range(5)
+        ))
+        with pytest.raises(ValueError, match="positive"):
+            client.set_replay_speed(0.0)
+        assert client.replay_position()["active"] is False
+
+        async def run():
+            # 1s apart at 10x: paced 100ms apart
+            started = time.monotonic()
+            assert await client.replay_session(str(fixture), speed=10.0) == 5
+            assert time.monotonic() - started >= 0.35
+            events.clear()
+
+            replay = asyncio.ensure_future(client.replay_session(str(fixture), speed=1.0))
+            await asyncio.sleep(0.2)
+            client.pause_replay()
+            assert events == [0]
+            position = client.replay_position()
+            assert (position["active"], position["index"], position["ts"], position["paused"]) == (True, 1, 1000, True)
+            # Forward: the skipped frames go through at once, even while paused
+            client.seek_replay(3000)
+            await asyncio.sleep(0.1)
+            assert events == [0, 1, 2]
+            assert client.replay_position()["index"] == 3
+            # Backward: delivered again from the target
+            client.seek_replay(2000)
+            client.set_replay_speed(None)
+            client.resume_replay()
+            delivered = await replay
+            assert events == [0, 1, 2, 2, 3, 4]
+            return delivered
+
+        assert asyncio.run(run()) == 6
+        position = client.replay_position()
+        assert (position["active"], position["index"], position["total"], position["ts"]) == (False, 5, 5, 4000)
+
+    def test_multi_account(self):
+        import asyncio
+        import itertools
+        from nautilus_gmocoin import gmocoin
+
+        order_ids = itertools.count(1)
+        received = []
+        server = _start_http_server({
+            "GET /v1/symbols": [{"symbol": "BTC", "tickSize": "1"}],
+            "GET": {"list": []},
+            "POST": lambda r: str(next(order_ids)),
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "main_key", "main_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+        )
+        client.add_account("hedge", "hedge_key", "hedge_secret", rate_limit="tier2")
+        assert client.account_names() == ["hedge"]
+        with pytest.raises(ValueError, match="hedge"):
+            client.add_account("hedge", "other_key", "other_secret")
+        with pytest.raises(ValueError):
+            client.add_account("", "other_key", "other_secret")
+        with pytest.raises(ValueError, match="finite and positive"):
+            client.add_account("other", "other_key", "other_secret", group_rate_limits={"order": 0})
+        with pytest.raises(ValueError, match="Unknown account"):
+            client.get_positions(account="unknown")
+
+        async def run():
+            first = await client.submit_order("BTC", "0.01", "BUY", "LIMIT", "O-1", price="5000000")
+            second = await client.submit_order("BTC", "0.01", "SELL", "LIMIT", "O-2", price="5000001", account="hedge")
+            assert await client.get_order_history("BTC", account="hedge") == []
+            assert (await client.get_latest_executions_arrow("BTC", account="hedge")).num_rows == 0
+            queried = len([r for r in received if r.method == "GET" and r.path != "/v1/symbols"])
+            algo_id = client.start_twap("BTC", "BUY", "0.01", 60_000, 1, price="5000000", account="hedge")
+
+            async def posts(count):
+                for _ in range(50):
+                    if len([r for r in received if r.method == "POST"]) == count:
+                        break
+                    await asyncio.sleep(0.02)
+
+            await posts(3)
+            # Algorithms share one id space, found from the main client
+            assert client.get_algo(algo_id)["algo_id"] == algo_id
+            assert client.cancel_algo(algo_id)
+            await posts(4)
+            return first, second, queried
+
+        first, second, queried = asyncio.run(run())
+        server.shutdown()
+        orders = [r.headers["API-KEY"] for r in received if r.method == "POST"]
+        queries = [r.headers["API-KEY"] for r in received if r.method == "GET" and r.path != "/v1/symbols"]
+        # The TWAP child and its cancel go out on the hedge account
+        assert orders == ["main_key", "hedge_key", "hedge_key", "hedge_key"]
+        assert queries and set(queries) == {"hedge_key"}
+        assert (first["order_id"], second["order_id"]) == (1, 2)
+        assert client.get_positions(account="hedge") == []
+        # The symbol rules are shared, so the account only sent its orders and queries
+        assert client.get_stats()["accounts"]["hedge"]["requests_sent"] == 3 + len(queries)
+        assert queried == len(queries)
+
+        # Each account exports and imports its own dedupe state
+        main_state, hedge_state = client.export_state(), client.export_state(account="hedge")
+        assert [s["client_order_id"] for s in main_state["submissions"]] == ["O-1"]
+        assert [s["client_order_id"] for s in hedge_state["submissions"]] == ["O-2"]
+        restarted = gmocoin.GmocoinExecutionClient("main_key", "main_secret", 5000, None, None,
+                                                   venue_profile=UNREACHABLE_PROFILE)
+        restarted.add_account("hedge", "hedge_key", "hedge_secret")
+        restarted.import_state(main_state)
+        assert restarted.import_state(hedge_state, account="hedge")["submissions"] == 1
+        assert (restarted.venue_order_id("O-1"), restarted.venue_order_id("O-2")) == (1, None)
+        assert restarted.venue_order_id("O-2", account="hedge") == 2
+        assert client.get_execution_quality(2) is None
+        assert client.get_execution_quality(2, account="hedge")["order_id"] == 2
+
+    def test_multi_account_dead_man_switch(self):
+        import asyncio
+        from nautilus_gmocoin import gmocoin
+
+        received = []
+        server = _start_http_server({
+            "GET": [{"symbol": "BTC"}],
+            "POST /v1/ws-auth": _Reply(status=503),
+            "POST": None,
+        }, received)
+        url = server.url
+        client = gmocoin.GmocoinExecutionClient(
+            "main_key", "main_secret", 5000, None, None,
+            venue_profile=dict(UNREACHABLE_PROFILE, rest_public=url, rest_private=url),
+            maintenance_status_check=False, deadman_timeout_ms=200,
+        )
+        client.add_account("hedge", "hedge_key", "hedge_secret")
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        def fired():
+            return sorted(data["account"] for event_type, data in events if event_type == "DeadManTriggered")
+
+        async def run():
+            await client.connect()
+            # One heartbeat arms the switch of every account
+            client.heartbeat()
+            for _ in range(30):
+                if len(fired()) == 2:
+                    break
+                await asyncio.sleep(0.1)
+            await client.close()
+
+        asyncio.run(run())
+        server.shutdown()
+        assert fired() == ["hedge", "main"]
+        cancels = [r.headers["API-KEY"] for r in received if r.method == "POST" and r.path != "/v1/ws-auth"]
+        assert sorted(cancels) == ["hedge_key", "main_key"]
+
+    def test_account_label(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None,
+                                                venue_profile=UNREACHABLE_PROFILE)
+        assert client.account_label() == "main"
+        with pytest.raises(ValueError, match="empty"):
+            gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None, account_label="")
+        received = []
+        server = _start_http_server({"POST /v1/cancelOrder": None}, received)
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None,
+                                                venue_profile=dict(UNREACHABLE_PROFILE, rest_private=server.url),
+                                                account_label="node-a")
+        with pytest.raises(ValueError, match="node-a"):
+            client.add_account("node-a", "other_key", "other_secret")
+        client.add_account("hedge", "hedge_key", "hedge_secret")
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data["account"])))
+        fixture = tmp_path / "session.jsonl"
+        fixture.write_text("".join(json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(message)}) + "\n" for message in [
+            {"channel": "orderEvents", "orderId": 1, "symbol": "BTC", "settleType": "OPEN", "executionType": "LIMIT",
+             "side": "BUY", "orderStatus": "ORDERED", "orderTimestamp": "2024-01-01T00:00:00.000Z",
+             "orderPrice": "100", "orderSize": "0.01", "orderExecutedSize": "0", "losscutPrice": "0", "timeInForce": "FAS"},
+            {"channel": "executionEvents", "executionId": 1, "orderId": 1, "symbol": "BTC", "side": "BUY",
+             "executionSize": "0.01", "executionPrice": "100", "fee": "0", "executionTimestamp": "2024-01-01T00:00:00.000Z"},
+        ]))
+
+        async def run():
+            delivered = await client.replay_session(str(fixture))
+            # An event's "account" selects the client that sent it
+            await client.cancel_order("BTC", "1", account=events[0][1])
+            await client.cancel_order("BTC", "1", account="hedge")
+            return delivered
+
+        assert asyncio.run(run()) == 2
+        server.shutdown()
+        assert events == [("OrderUpdate", "node-a"), ("ExecutionUpdate", "node-a")]
+        assert [r.headers["API-KEY"] for r in received] == ["test_key", "hedge_key"]
+
+    def test_undecodable_private_frames_reported(self, tmp_path):
+        import asyncio
+        import json
+        from nautilus_gmocoin import gmocoin
+
+        client = gmocoin.GmocoinExecutionClient("test_key", "test_secret", 5000, None, None,
+                                                venue_profile=UNREACHABLE_PROFILE)
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+        # No orderId
+        bad_execution = {"channel": "executionEvents", "executionId": 9, "symbol": "BTC", "side": "BUY",
+                         "executionSize": "0.01", "executionPrice": "100"}
+        # No positionId
+        bad_position = {"channel": "positionEvents", "symbol": "BTC_JPY", "side": "BUY", "size": "0.01",
+                        "losscutPrice": "4000000", "msgType": "ULO"}
+        fixture = tmp_path / "session.jsonl"
+        fixture.write_text("".join(
+            json.dumps({"ts": 0, "dir": "recv", "text": json.dumps(message)}) + "\n"
+            for message in [bad_execution, bad_position]
+        ))
+
+        async def run():
+            return await client.replay_session(str(fixture))
+
+        assert asyncio.run(run()) == 2
+        # Reported, and the raw update still delivered
+        assert [event_type for event_type, _ in events] == ["ParseError", "ExecutionUpdate", "ParseError", "PositionUpdate"]
+        assert events[0][1]["channel"] == "executionEvents"
+        assert json.loads(events[0][1]["raw"]) == bad_execution
+        assert events[2][1]["channel"] == "positionEvents"
+        assert json.loads(events[2][1]["raw"]) == bad_position
+        assert client.get_stats()["errors"]["parse"] == 2
+
+
+@requires_rust_extension
+class TestSimExecutionClient:
+    def _client(self, **kwargs):
+        from nautilus_gmocoin import gmocoin
+        return gmocoin.GmocoinSimExecutionClient(gmocoin.GmocoinDataClient(None), **kwargs)
+
+    def test_create(self):
+        client = self._client(latency_ms=5, latency_jitter_ms=5, maker_fee=0.0, taker_fee=0.001)
+        assert client.get_stats()["orders_cached"] == 0
+        with pytest.raises(ValueError):
+            self._client(initial_balances={"JPY": "lots"})
+
+    def test_order_lifecycle_without_book(self):
+        import asyncio
+        client = self._client(initial_balances={"JPY": "10000"})
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data["orderStatus"])))
+
+        async def run():
+            res = await client.submit_order("BTC", "0.001", "BUY", "LIMIT", "O-1", price="5000000")
+            order_id = str(res["order_id"])
+            active = await client.get_active_orders("BTC")
+            assert [o["orderId"] for o in active["list"]] == [res["order_id"]]
+            assets = {a["symbol"]: a for a in await client.get_assets_py()}
+            assert assets["JPY"]["available"] == "5000"
+            # Exceeds the remaining available JPY
+            with pytest.raises(RuntimeError, match="ERR-208"):
+                await client.submit_order("BTC", "0.002", "BUY", "LIMIT", "O-2", price="5000000")
+            await client.change_order(order_id, "4000000")
+            await client.cancel_order("BTC", order_id)
+            order = await client.get_order(order_id)
+            assert order["list"][0]["status"] == "CANCELED"
+            assert (await client.get_executions(order_id))["list"] == []
+
+        asyncio.run(run())
+        assert events == [("OrderUpdate", "ORDERED"), ("OrderUpdate", "ORDERED"), ("OrderUpdate", "CANCELED")]
+
+    def test_accepts_live_client_kwargs(self):
+        import asyncio
+        client = self._client()
+        accounts = []
+        client.set_order_callback(lambda event_type, data: accounts.append(data["account"]))
+
+        async def run():
+            res = await client.submit_order(
+                "BTC", "0.001", "BUY", "LIMIT", "O-1", price="5000000",
+                arrival_bid="4999000", arrival_ask="5001000", split=True, account=None,
+            )
+            order_id = str(res["order_id"])
+            assert (await client.get_order(order_id, account=None))["list"][0]["status"] == "ORDERED"
+            assert len((await client.get_active_orders("BTC", account=None))["list"]) == 1
+            # The simulator has a single account
+            with pytest.raises(ValueError, match="Unknown account 'hedge'"):
+                await client.submit_order("BTC", "0.001", "BUY", "LIMIT", "O-2", price="5000000", account="hedge")
+            with pytest.raises(ValueError, match="Unknown account 'hedge'"):
+                await client.cancel_order("BTC", order_id, account="hedge")
+            # Its events carry "account" as the live client's do, and select it
+            await client.cancel_order("BTC", order_id, account=accounts[0])
+
+        asyncio.run(run())
+        assert client.get_stats()["requests_sent"] == 2
+        assert set(accounts) == {"main"}
+
+    def test_async_order_callback(self):
+        import asyncio
+        import threading
+        client = self._client(initial_balances={"JPY": "10000"})
+        events = []
+
+        async def on_event(event_type, data):
+            events.append((event_type, threading.current_thread() is threading.main_thread()))
+
+        with pytest.raises(RuntimeError, match="running event loop"):
+            client.set_order_callback(on_event)
+
+        async def run():
+            client.set_order_callback(on_event)
+            await client.submit_order("BTC", "0.001", "BUY", "LIMIT", "O-1", price="5000000")
+            for _ in range(100):
+                if events:
+                    break
+                await asyncio.sleep(0.01)
+
+        asyncio.run(run())
+        assert events == [("OrderUpdate", True)]
+
+    def test_callback_errors_reported(self):
+        import asyncio
+        client = self._client(initial_balances={"JPY": "10000"})
+        errors = []
+
+        def on_event(event_type, data):
+            raise ValueError("handler bug")
+
+        client.set_order_callback(on_event)
+        client.set_callback_error_handler(lambda event_type, exc: errors.append((event_type, str(exc))))
+
+        async def run():
+            await client.submit_order("BTC", "0.001", "BUY", "LIMIT", "O-1", price="5000000")
+            await client.submit_order("BTC", "0.001", "BUY", "LIMIT", "O-2", price="5000000")
+
+        asyncio.run(run())
+        assert errors == [("OrderUpdate", "handler bug")] * 2
+        stats = client.get_stats()
+        assert stats["errors"] == {"callback": 2}
+        assert stats["last_error"]["class"] == "callback"
+
+    def test_positions_not_supported(self):
+        client = self._client()
+        with pytest.raises(NotImplementedError):
+            client.close_bulk_order("BTC_JPY", "SELL", "MARKET", "0.01")
+
+
+@requires_rust_extension
+class TestArrowOutput:
+    KLINES = [{"openTime": "1735689600000", "open": "100", "high": "110", "low": "90", "close": "105", "volume": "1.5"}]
+    TRADES = {"pagination": {}, "list": [{"price": "10", "side": "BUY", "size": "0.1", "timestamp": "2026-02-13T08:09:23.047Z"}]}
+
+    @pytest.fixture
+    def rest_client(self):
+        from nautilus_gmocoin import gmocoin
+
+        server = _start_http_server({"GET /v1/klines": self.KLINES, "GET /v1/trades": self.TRADES})
+        url = server.url
+        yield gmocoin.GmocoinRestClient(
+            "", "", 5000, None, None,
+            venue_profile={"rest_public": url, "rest_private": url, "ws_public": url, "ws_private": url},
+        )
+        server.shutdown()
+
+    def test_trades_batch(self, rest_client):
+        import asyncio
+
+        async def run():
+            return await rest_client.get_trades_arrow("BTC")
+
+        batch = asyncio.run(run())
+        assert len(batch) == 1
+        assert batch.column_names == ["timestamp", "price", "size", "side"]
+        assert hasattr(batch, "__arrow_c_array__")
+        assert hasattr(batch, "__arrow_c_stream__")
+
+    def test_klines_to_pyarrow(self, rest_client):
+        import asyncio
+        pa = pytest.importorskip("pyarrow")
+
+        async def run():
+            return await rest_client.get_klines_arrow("BTC", "1hour", "20250101")
+
+        batch = pa.record_batch(asyncio.run(run()))
+        assert batch.schema.field("open_time").type == pa.timestamp("ms", tz="UTC")
+        assert batch.column("open_time")[0].value == 1735689600000
+        assert batch.column("close").to_pylist() == [105.0]
+
+
+@requires_rust_extension
+class TestReportConversion:
+    REST_ORDER = {
+        "orderId": 223456789, "rootOrderId": 223456789, "symbol": "BTC_JPY", "side": "BUY",
+        "orderType": "NORMAL", "executionType": "LIMIT", "settleType": "OPEN", "size": "0.02",
+        "executedSize": "0.01", "price": "1430001", "losscutPrice": "0", "status": "ORDERED",
+        "timeInForce": "FAS", "timestamp": "2020-10-14T20:18:59.343Z",
+    }
+
+    def test_order_status_report(self):
+        from nautilus_gmocoin import gmocoin
+        fields = gmocoin.order_status_report(self.REST_ORDER, 1_700_000_000_000_000_000)
+        assert fields["venue_order_id"] == "223456789"
+        assert fields["client_order_id"] is None
+        assert fields["order_side"] == "BUY"
+        assert fields["order_type"] == "LIMIT"
+        assert fields["time_in_force"] == "GTC"
+        assert fields["order_status"] == "ACCEPTED"
+        assert fields["quantity"] == "0.02"
+        assert fields["filled_qty"] == "0.01"
+        assert fields["price"] == fields["avg_px"] == "1430001"
+        assert fields["ts_accepted"] == 1602706739343000000
+        assert fields["ts_last"] == fields["ts_init"] == 1_700_000_000_000_000_000
+
+    def test_order_status_mapping(self):
+        from nautilus_gmocoin import gmocoin
+        mapping = {
+            "WAITING": "ACCEPTED", "ORDERED": "ACCEPTED", "MODIFYING": "ACCEPTED", "CANCELLING": "PENDING_CANCEL",
+            "CANCELED": "CANCELED", "EXECUTED": "FILLED", "EXPIRED": "EXPIRED",
+        }
+        for gmo_status, status in mapping.items():
+            assert gmocoin.order_status(gmo_status) == status
+            # The report path uses the same mapping
+            order = dict(self.REST_ORDER, status=gmo_status)
+            assert gmocoin.order_status_report(order)["order_status"] == status
+            event = {k: v for k, v in order.items() if k != "status"}
+            assert gmocoin.order_status_report(dict(event, orderStatus=gmo_status))["order_status"] == status
+
+    def test_order_event_fields(self):
+        from nautilus_gmocoin import gmocoin
+        event = {
+            "channel": "orderEvents", "orderId": 123456789, "symbol": "BTC", "settleType": "OPEN",
+            "executionType": "STOP", "side": "SELL", "orderStatus": "CANCELED", "orderTimestamp": "2019-03-19T02:15:06.059Z",
+            "orderPrice": "876045", "orderSize": "0.8", "orderExecutedSize": "0", "losscutPrice": "0",
+            "timeInForce": "FAK", "msgType": "CAN",
+        }
+        fields = gmocoin.order_status_report(event)
+        assert fields["order_side"] == "SELL"
+        assert fields["order_type"] == "STOP_MARKET"
+        assert fields["time_in_force"] == "IOC"
+        assert fields["order_status"] == "CANCELED"
+        assert fields["avg_px"] is None
+        assert fields["ts_init"] > fields["ts_accepted"]
+
+    def test_market_order_without_price_has_no_avg_px(self):
+        from nautilus_gmocoin import gmocoin
+        order = dict(self.REST_ORDER, executionType="MARKET", price=None, status="EXECUTED")
+        fields = gmocoin.order_status_report(order)
+        assert fields["order_status"] == "FILLED"
+        assert fields["price"] is None
+        assert fields["avg_px"] is None
+
+    def test_invalid_payload(self):
+        from nautilus_gmocoin import gmocoin
+        with pytest.raises(ValueError, match="order"):
+            gmocoin.order_status_report({"orderId": 1})
+        with pytest.raises(ValueError, match="timestamp"):
+            gmocoin.order_status_report(dict(self.REST_ORDER, timestamp="yesterday"))
+
+    REST_EXECUTION = {
+        "executionId": 92123912, "orderId": 223456789, "positionId": 1234567, "symbol": "BTC_JPY", "side": "BUY",
+        "settleType": "OPEN", "size": "0.02", "price": "1900000", "lossGain": "0", "fee": "223",
+        "timestamp": "2020-11-24T21:27:04.764Z",
+    }
+
+    def test_fill_report(self):
+        from nautilus_gmocoin import gmocoin
+        fields = gmocoin.fill_report(self.REST_EXECUTION, 1_700_000_000_000_000_000)
+        assert fields["venue_order_id"] == "223456789"
+        assert fields["trade_id"] == "92123912"
+        assert fields["order_side"] == "BUY"
+        assert fields["last_qty"] == "0.02"
+        assert fields["last_px"] == "1900000"
+        assert fields["commission"] == "223"
+        assert fields["commission_currency"] == "JPY"
+        assert fields["liquidity_side"] == "TAKER"
+        assert fields["ts_event"] == 1606253224764000000
+        assert fields["ts_init"] == 1_700_000_000_000_000_000
+
+    def test_execution_event_fields(self):
+        from nautilus_gmocoin import gmocoin
+        event = {
+            "channel": "executionEvents", "orderId": 123456789, "executionId": 72123911, "symbol": "BTC",
+            "settleType": "OPEN", "executionType": "LIMIT", "side": "SELL", "executionPrice": "877404",
+            "executionSize": "0.5", "positionId": 123, "orderTimestamp": "2019-03-19T02:15:06.081Z",
+            "executionTimestamp": "2019-03-19T02:15:06.081Z", "lossGain": "0", "fee": "-12",
+            "orderPrice": "877200", "orderSize": "0.8", "orderExecutedSize": "0.5", "timeInForce": "FAS",
+            "msgType": "ER",
+        }
+        fields = gmocoin.fill_report(event)
+        assert fields["last_px"] == "877404"
+        assert fields["last_qty"] == "0.5"
+        assert fields["liquidity_side"] == "MAKER"
+        assert fields["ts_event"] == 1552961706081000000
+
+    def test_fill_liquidity_side_inference(self):
+        from nautilus_gmocoin import gmocoin
+        no_fee = dict(self.REST_EXECUTION, fee="0")
+        assert gmocoin.fill_report(no_fee)["liquidity_side"] == "NO_LIQUIDITY_SIDE"
+        assert gmocoin.fill_report(dict(no_fee, executionType="MARKET"))["liquidity_side"] == "TAKER"
+        assert gmocoin.fill_report(dict(no_fee, executionType="LIMIT", timeInForce="SOK"))["liquidity_side"] == "MAKER"
+
+    def test_fill_report_rejects_non_positive_price(self):
+        from nautilus_gmocoin import gmocoin
+        with pytest.raises(ValueError, match="non-positive"):
+            gmocoin.fill_report(dict(self.REST_EXECUTION, price="0"))
+
+    REST_POSITION = {
+        "positionId": 1234567, "symbol": "BTC_JPY", "side": "SELL", "size": "0.22", "orderdSize": "0",
+        "price": "876045", "lossGain": "14", "leverage": "4", "losscutPrice": "766540",
+        "timestamp": "2019-03-19T02:15:06.094Z",
+    }
+
+    def test_position_status_report(self):
+        from nautilus_gmocoin import gmocoin
+        fields = gmocoin.position_status_report(self.REST_POSITION, 1_700_000_000_000_000_000)
+        assert fields["venue_position_id"] == "1234567"
+        assert fields["position_side"] == "SHORT"
+        assert fields["quantity"] == "0.22"
+        assert fields["signed_decimal_qty"] == "-0.22"
+        assert fields["avg_px_open"] == "876045"
+        assert fields["ts_last"] == fields["ts_init"] == 1_700_000_000_000_000_000
+
+    def test_position_event_closed_is_flat(self):
+        from nautilus_gmocoin import gmocoin
+        event = dict(self.REST_POSITION, channel="positionEvents", side="BUY", msgType="CPR")
+        fields = gmocoin.position_status_report(event)
+        assert fields["position_side"] == "FLAT"
+        assert fields["signed_decimal_qty"] == "0"
+        assert fields["avg_px_open"] is None
+        opened = gmocoin.position_status_report(dict(event, msgType="OPR"))
+        assert opened["position_side"] == "LONG"
+        assert opened["signed_decimal_qty"] == "0.22"
+
+    def test_position_summary_report(self):
+        from nautilus_gmocoin import gmocoin
+        summary = {
+            "averagePositionRate": "715656", "positionLossGain": "250675", "side": "BUY",
+            "sumOrderQuantity": "2", "sumPositionQuantity": "11.6999", "symbol": "BTC_JPY",
+        }
+        fields = gmocoin.position_summary_report(summary)
+        assert fields["venue_position_id"] is None
+        assert fields["position_side"] == "LONG"
+        assert fields["signed_decimal_qty"] == "11.6999"
+        assert fields["avg_px_open"] == "715656"
+        short = gmocoin.position_summary_report(dict(summary, side="SELL", channel="positionSummaryEvents"))
+        assert short["signed_decimal_qty"] == "-11.6999"
+
+    def test_account_balance(self):
+        from nautilus_gmocoin import gmocoin
+        fields = gmocoin.account_balance({"amount": "993982448", "available": "993882448", "conversionRate": "1", "symbol": "jpy"})
+        assert fields == {"currency": "JPY", "total": "993982448", "locked": "100000", "free": "993882448"}
+        with pytest.raises(ValueError):
+            gmocoin.account_balance({"amount": "abc", "available": "0", "symbol": "BTC"})
+
+    def test_margin_balance(self):
+        from nautilus_gmocoin import gmocoin
+        fields = gmocoin.margin_balance({
+            "actualProfitLoss": "68286188", "availableAmount": "57262506", "margin": "1021682",
+            "marginCallStatus": "NORMAL", "marginRatio": "6683.6", "profitLoss": "0",
+        })
+        assert fields["balance"] == {"currency": "JPY", "total": "68286188", "locked": "11023682", "free": "57262506"}
+        assert fields["margin"] == {"currency": "JPY", "initial": "1021682", "maintenance": "766261.5"}
+        empty = gmocoin.margin_balance({"availableAmount": "1000"})
+        assert empty["balance"]["locked"] == "0"
+        assert empty["margin"]["initial"] == "0"
+
+
+def _jst_ns(*args):
+    from datetime import datetime, timedelta, timezone
+    return int(datetime(*args, tzinfo=timezone(timedelta(hours=9))).timestamp()) * 1_000_000_000
+
+
+@requires_rust_extension
+class TestTradingCalendar:
+    def test_weekly_window(self):
+        from nautilus_gmocoin import gmocoin
+        calendar = gmocoin.TradingCalendar(["Sat 09:00-11:00"])
+        # 2024-06-01 is a Saturday
+        assert calendar.is_open(_jst_ns(2024, 6, 1, 8, 59))
+        assert not calendar.is_open(_jst_ns(2024, 6, 1, 9, 0))
+        assert calendar.is_open(_jst_ns(2024, 6, 1, 11, 0))
+        assert calendar.is_open(_jst_ns(2024, 6, 2, 10, 0))
+        assert calendar.next_close(_jst_ns(2024, 6, 1, 12, 0)) == _jst_ns(2024, 6, 8, 9, 0)
+        assert calendar.next_open(_jst_ns(2024, 6, 1, 10, 0)) == _jst_ns(2024, 6, 1, 11, 0)
+        assert calendar.next_open(_jst_ns(2024, 6, 1, 12, 0)) == _jst_ns(2024, 6, 1, 12, 0)
+
+    def test_window_past_midnight(self):
+        from nautilus_gmocoin import gmocoin
+        calendar = gmocoin.TradingCalendar(["23:30-00:30", "00:15-01:00"])
+        assert not calendar.is_open(_jst_ns(2024, 6, 1, 0, 10))
+        assert calendar.next_open(_jst_ns(2024, 6, 1, 23, 45)) == _jst_ns(2024, 6, 2, 1, 0)
+        assert calendar.next_close(_jst_ns(2024, 6, 1, 12, 0)) == _jst_ns(2024, 6, 1, 23, 30)
+        assert gmocoin.TradingCalendar().next_close() is None
+
+    def test_rollover_and_trading_day(self):
+        from nautilus_gmocoin import gmocoin
+        calendar = gmocoin.TradingCalendar(["Sat 09:00-11:00"])
+        assert calendar.trading_day(_jst_ns(2024, 6, 1, 5, 59)) == "2024-05-31"
+        assert calendar.trading_day(_jst_ns(2024, 6, 1, 6, 0)) == "2024-06-01"
+        assert calendar.next_rollover(_jst_ns(2024, 6, 1, 5, 0)) == _jst_ns(2024, 6, 1, 6, 0)
+        assert calendar.next_rollover(_jst_ns(2024, 6, 1, 6, 0)) == _jst_ns(2024, 6, 2, 6, 0)
+        assert calendar.session(_jst_ns(2024, 6, 1, 10, 0)) == {
+            "open": False, "trading_day": "2024-06-01", "reopens_at": _jst_ns(2024, 6, 1, 11, 0),
+        }
+        late = gmocoin.TradingCalendar(rollover="07:00")
+        assert late.trading_day(_jst_ns(2024, 6, 1, 6, 30)) == "2024-05-31"
+        with pytest.raises(ValueError, match="rollover"):
+            gmocoin.TradingCalendar(rollover="7am")
+        with pytest.raises(ValueError, match="maintenance window"):
+            gmocoin.TradingCalendar(["Someday 09:00-11:00"])
+
+
+@requires_rust_extension
+class TestTimestamps:
+    def test_iso_with_and_without_millis(self):
+        from nautilus_gmocoin import gmocoin
+        assert gmocoin.timestamp_ns("2019-03-19T02:15:06.059Z") == 1_552_961_706_059_000_000
+        assert gmocoin.timestamp_ns("2019-03-19T02:15:06Z") == 1_552_961_706_000_000_000
+        assert gmocoin.timestamp_ns("2019-03-19T02:15:06.059123Z") == 1_552_961_706_059_123_000
+
+    def test_jst(self):
+        from nautilus_gmocoin import gmocoin
+        utc = gmocoin.timestamp_ns("2024-06-01T00:00:00Z")
+        assert gmocoin.timestamp_ns("2024-06-01T09:00:00+09:00") == utc
+        # Without an offset GMO's local time is meant
+        assert gmocoin.timestamp_ns("2024-06-01T09:00:00") == utc
+        assert gmocoin.timestamp_ns("2024-06-01 09:00:00.000") == utc
+        assert _jst_ns(2024, 6, 1, 9, 0) == utc
+
+    def test_format_round_trip(self):
+        from nautilus_gmocoin import gmocoin
+        assert gmocoin.format_timestamp(1_552_961_706_059_000_000) == "2019-03-19T02:15:06.059Z"
+        assert gmocoin.format_timestamp(gmocoin.timestamp_ns("2019-03-19T02:15:06Z")) == "2019-03-19T02:15:06.000Z"
+
+    def test_invalid(self):
+        from nautilus_gmocoin import gmocoin
+        for value in ("yesterday", "", "1969-12-31T23:59:59Z"):
+            with pytest.raises(ValueError):
+                gmocoin.timestamp_ns(value)
+
+    def test_kline_date(self):
+        from nautilus_gmocoin import gmocoin
+        # Intraday klines are grouped by trading day from 06:00 JST
+        assert gmocoin.kline_date("1min", _jst_ns(2024, 6, 1, 5, 59)) == "20240531"
+        assert gmocoin.kline_date("1hour", _jst_ns(2024, 6, 1, 6, 0)) == "20240601"
+        # 2024-06-01 02:00 JST is still 2024-05-31 in UTC
+        assert gmocoin.kline_date("15min", _jst_ns(2024, 6, 1, 2, 0)) == "20240531"
+        assert gmocoin.kline_date("15min", _jst_ns(2024, 6, 1, 8, 0)) == "20240601"
+        # Longer intervals by JST year
+        assert gmocoin.kline_date("1day", _jst_ns(2025, 1, 1, 3, 0)) == "2025"
+        assert gmocoin.kline_date("4hour", _jst_ns(2024, 12, 31, 23, 0)) == "2024"
+        assert len(gmocoin.kline_date("1min")) == 8
+        with pytest.raises(ValueError, match="interval"):
+            gmocoin.kline_date("2min")
+
+
+@requires_rust_extension
+class TestFeeModel:
+    SYMBOLS = [
+        {"symbol": "BTC", "takerFee": "0.0005", "makerFee": "-0.0001"},
+        {"symbol": "BTC_JPY", "takerFee": "0", "makerFee": "0"},
+        {"symbol": "XRP"},
+    ]
+    VOLUME = {
+        "jpyVolume": "9988888", "tierLevel": 2,
+        "limit": [{"symbol": "BTC", "todayLimitBuySize": "1000", "takerFee": "0.0004", "makerFee": "-0.0002"}],
+    }
+
+    def test_listed_rates(self):
+        from decimal import Decimal
+        from nautilus_gmocoin import gmocoin
+        fees = gmocoin.FeeModel(self.SYMBOLS)
+        assert fees.tier_level is None
+        assert fees.rates("btc") == {"maker": "-0.0001", "taker": "0.0005"}
+        assert fees.rates("XRP") == {"maker": "0", "taker": "0"}
+        assert fees.rates("ETH") is None
+        quote = fees.quote("BTC", "TAKER", Decimal("10000000"), "0.01")
+        assert quote["notional"] == "100000"
+        assert quote["fee"] == "50"
+        assert quote["rebate"] is False
+        assert quote["charged"] is None
+        maker = fees.quote("BTC", "maker", 10000000, 0.01)
+        assert maker["fee"] == "-10"
+        assert maker["rebate"] is True
+        with pytest.raises(ValueError, match="No fee rates"):
+            fees.quote("ETH", "TAKER", "1", "1")
+        with pytest.raises(ValueError, match="liquidity side"):
+            fees.quote("BTC", "BOTH", "1", "1")
+
+    def test_tier_overrides(self):
+        from nautilus_gmocoin import gmocoin
+        fees = gmocoin.FeeModel(self.SYMBOLS, self.VOLUME)
+        assert fees.tier_level == 2
+        assert fees.rates("BTC") == {"maker": "-0.0002", "taker": "0.0004"}
+        assert fees.rates("BTC_JPY") == {"maker": "0", "taker": "0"}
+
+    def test_realized_fill(self):
+        from nautilus_gmocoin import gmocoin
+        fees = gmocoin.FeeModel(self.SYMBOLS)
+        execution = {
+            "executionId": 92123912, "orderId": 223456789, "symbol": "BTC", "side": "BUY",
+            "size": "0.02", "price": "1900000", "fee": "19", "timestamp": "2020-11-24T21:27:04.764Z",
+        }
+        fill = fees.fill(execution)
+        assert fill["liquidity_side"] == "TAKER"
+        assert fill["fee"] == "19"
+        assert fill["charged"] == "19"
+        rebate = fees.fill(dict(execution, fee="-3.8"))
+        assert rebate["liquidity_side"] == "MAKER"
+        assert rebate["fee"] == "-3.8"
+
+
+class TestTaxReport:
+    @staticmethod
+    def fill(execution_id, symbol, side, size, price, timestamp, fee="0", loss_gain=None):
+        execution = {
+            "executionId": execution_id, "orderId": execution_id, "symbol": symbol, "side": side,
+            "size": size, "price": price, "fee": fee, "timestamp": timestamp,
+        }
+        if loss_gain is not None:
+            execution.update(settleType="CLOSE", lossGain=loss_gain)
+        return execution
+
+    def test_moving_average_by_jst_year(self):
+        from nautilus_gmocoin import gmocoin
+        executions = [
+            # Listed out of order; 2025-12-31T16:00Z is already 2026 in JST
+            self.fill(3, "BTC", "SELL", "0.1", "7000000", "2026-02-01T00:00:00.000Z", fee="70"),
+            self.fill(2, "BTC", "BUY", "0.1", "6000000", "2025-12-31T16:00:00.000Z"),
+            self.fill(1, "BTC", "BUY", "0.1", "5000000", "2025-12-31T14:00:00.000Z", fee="100"),
+            self.fill(4, "BTC", "SELL", "0.2", "7000000", "2026-03-01T00:00:00.000Z"),
+            self.fill(5, "BTC_JPY", "SELL", "0.01", "7000000", "2026-03-01T00:00:00.000Z", loss_gain="2000"),
+            self.fill(6, "XRP", "SELL", "500", "60", "2026-04-01T00:00:00.000Z"),
+        ]
+        leverage_costs = [{"symbol": "BTC_JPY", "amount": "40", "timestamp": "2026-01-05T21:00:00.000Z"}]
+        opening = {"XRP": {"size": "1000", "avg_cost": "50"}}
+        y2025, y2026 = gmocoin.tax_report(executions, leverage_costs, opening)
+
+        assert y2025["year"] == 2025
+        [btc] = y2025["symbols"]
+        assert btc["realized_pnl"] == "0"
+        assert btc["fees"] == "100"
+        assert btc["closing_size"] == "0.1"
+        assert btc["closing_avg_cost"] == "5001000"
+
+        symbols = {s["symbol"]: s for s in y2026["symbols"]}
+        btc = symbols["BTC"]
+        assert btc["kind"] == "spot"
+        # 0.1 sold at the 5,500,500 average, then 0.1 more than was held
+        assert btc["cost"] == "1100100"
+        assert btc["realized_pnl"] == str(700000 - 70 - 550050 + 1400000 - 550050)
+        assert btc["unmatched_size"] == "0.1"
+        assert btc["closing_size"] == "0"
+        assert btc["closing_avg_cost"] is None
+        leverage = symbols["BTC_JPY"]
+        assert leverage["kind"] == "leverage"
+        assert leverage["leverage_fees"] == "40"
+        assert leverage["realized_pnl"] == "1960"
+        assert symbols["XRP"]["realized_pnl"] == "5000"
+        assert symbols["XRP"]["closing_size"] == "500"
+        assert y2026["fees"] == "70"
+        assert y2026["realized_pnl"] == str(999830 + 1960 + 5000)
+
+    def test_invalid_execution(self):
+        from nautilus_gmocoin import gmocoin
+        with pytest.raises(ValueError, match="Invalid executions"):
+            gmocoin.tax_report([{"symbol": "BTC"}])
+        assert gmocoin.tax_report([]) == []
@@ -0,0 +1,154 @@
+"""Regression tests replaying recorded Private WebSocket sessions.
+
+Sessions are JSONL cassettes written by `GmocoinExecutionClient.record_session`;
+the received frames are fed through the same handling as live messages.
+
+Record a session:
+    GMOCOIN_API_KEY=... GMOCOIN_API_SECRET=... pytest tests/test_ws_replay.py --record-cassettes -v
+Replay (default):
+    pytest tests/test_ws_replay.py -v
+"""
+import asyncio
+import json
+from pathlib import Path
+
+import pytest
+from tests.conftest import requires_rust_extension, integration
+
+CASSETTE_DIR = Path(__file__).parent / "cassettes"
+
+
+def _make_exec_client():
+    from nautilus_gmocoin import gmocoin
+    return gmocoin.GmocoinExecutionClient("test_key", "test_secret", 10000, None, None)
+
+
+@requires_rust_extension
+@integration
+class TestPrivateWsReplay:
+    """Replays of recorded Private WS sessions."""
+
+    def test_order_and_execution_events(self, ws_session):
+        path = ws_session()
+        client = _make_exec_client()
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        async def run():
+            return await client.replay_session(path)
+
+        replayed = asyncio.run(run())
+
+        assert replayed == 4
+        assert [e[0] for e in events] == ["OrderUpdate", "ExecutionUpdate", "OrderUpdate"]
+        execution = events[1][1]
+        assert execution["orderId"] == 123456789
+        assert execution["executionPrice"] == "9000000"
+        assert execution["fee"] == "-9"
+        assert events[2][1]["orderStatus"] == "CANCELED"
+        stats = client.get_stats()
+        assert stats["orders_cached"] == 2
+        assert stats["errors"] == {"rate_limited": 1}
+
+    def test_msgpack_payload(self):
+        msgspec = pytest.importorskip("msgspec")
+        path = str(CASSETTE_DIR / "TestPrivateWsReplay.test_order_and_execution_events.jsonl")
+        from nautilus_gmocoin import gmocoin
+        client = gmocoin.GmocoinExecutionClient(
+            "test_key", "test_secret", 10000, None, None, callback_payload="msgpack"
+        )
+        events = []
+        client.set_order_callback(lambda event_type, data: events.append((event_type, data)))
+
+        async def run():
+            return await client.replay_session(path)
+
+        asyncio.run(run())
+
+        assert all(isinstance(data, bytes) for _, data in events)
+        execution = msgspec.msgpack.decode(events[1][1])
+        assert execution["orderId"] == 123456789
+        assert execution["executionPrice"] == "9000000"
+
+
+@requires_rust_extension
+class TestSessionRecorder:
+    def test_record_session_toggles(self, tmp_path):
+        client = _make_exec_client()
+        path = tmp_path / "session.jsonl"
+        client.record_session(str(path))
+        assert path.exists()
+        client.record_session(None)
+
+    def test_record_session_bad_path(self, tmp_path):
+        client = _make_exec_client()
+        with pytest.raises(OSError):
+            client.record_session(str(tmp_path / "missing" / "session.jsonl"))
+
+    def test_replay_invalid_entry(self, tmp_path):
+        client = _make_exec_client()
+        path = tmp_path / "session.jsonl"
+        path.write_text(json.dumps({"dir": "recv"}) + "\n")
+        with pytest.raises(ValueError):
+            client.replay_session(str(path))
+
+    def test_verify_session(self, tmp_path):
+        from nautilus_gmocoin import gmocoin
+        client = _make_exec_client()
+        path = tmp_path / "session.jsonl"
+        client.record_session(str(path))
+        client.record_session(None)
+        report = gmocoin.verify_session(str(path))
+        assert report["ok"] and report["complete"]
+        assert report["meta"]["format"] == "gmocoin-session"
+        assert (report["lines"], report["verified_chunks"], report["unverified_lines"]) == (1, 1, 0)
+        lines = path.read_text().splitlines(keepends=True)
+        assert [json.loads(line)["dir"] for line in lines] == ["meta", "checksum", "end"]
+
+        # A corrupted entry
+        path.write_text(lines[0].replace("gmocoin-session", "gmocoin-sessioN") + "".join(lines[1:]))
+        report = gmocoin.verify_session(str(path))
+        assert not report["ok"]
+        assert [(p["line"], p["kind"]) for p in report["problems"]] == [(2, "checksum_mismatch")]
+
+        # Cut short: no end marker, then a cut-off line
+        path.write_text(lines[0])
+        report = gmocoin.verify_session(str(path))
+        assert (report["complete"], report["unverified_lines"]) == (False, 1)
+        assert [p["kind"] for p in report["problems"]] == ["no_checksums"]
+        path.write_text("".join(lines[:2]) + lines[2][:-5])
+        report = gmocoin.verify_session(str(path))
+        assert [p["kind"] for p in report["problems"]] == ["truncated_line", "missing_end"]
+
+        # Recorded before checksums existed
+        report = gmocoin.verify_session(str(CASSETTE_DIR / "TestPrivateWsReplay.test_order_and_execution_events.jsonl"))
+        assert not report["ok"]
+        assert report["meta"] is None
+        assert [p["kind"] for p in report["problems"]] == ["no_checksums"]
+
+    def test_verify_session_chunks(self, tmp_path):
+        import hashlib
+        from nautilus_gmocoin import gmocoin
+
+        def line(dir, text):
+            return json.dumps({"ts": 0, "dir": dir, "text": text}) + "\n"
+
+        def checksum(chunk, lines):
+            digest = hashlib.sha256("".join(lines).encode()).hexdigest()
+            return line("checksum", json.dumps({"chunk": chunk, "lines": len(lines), "sha256": digest}))
+
+        frame = json.dumps({"channel": "orderEvents"})
+        first = [line("meta", json.dumps({"format": "gmocoin-session"})), line("recv", frame)]
+        second = [line("recv", frame)]
+        path = tmp_path / "session.jsonl"
+        chunks = [*first, checksum(0, first), *second, checksum(1, second)]
+        path.write_text("".join(chunks) + line("end", json.dumps({"lines": 3, "chunks": 2})))
+        report = gmocoin.verify_session(str(path))
+        assert report["ok"], report["problems"]
+        assert (report["lines"], report["verified_chunks"]) == (3, 2)
+
+        # Chunks swapped
+        path.write_text("".join([*second, checksum(1, second), *first, checksum(0, first)]))
+        assert [p["kind"] for p in gmocoin.verify_session(str(path))["problems"]] == [
+            "chunk_out_of_order", "chunk_out_of_order", "missing_end",
+        ]